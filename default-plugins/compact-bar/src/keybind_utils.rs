@@ -715,6 +715,14 @@ impl KeybindProcessor {
                             }
                         )
                     },
+                    |action: &Action| {
+                        matches!(
+                            action,
+                            Action::SearchToggleOption {
+                                option: actions::SearchOption::Regex
+                            }
+                        )
+                    },
                 ];
                 Self::find_predetermined_actions(mode_info, mode, ordered_predicates)
             },
@@ -731,6 +739,8 @@ impl KeybindProcessor {
             InputMode::EnterSearch
             | InputMode::RenameTab
             | InputMode::RenamePane
+            | InputMode::MarkPane
+            | InputMode::FocusMark
             | InputMode::Prompt
             | InputMode::Tmux => Vec::new(),
         }
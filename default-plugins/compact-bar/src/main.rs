@@ -371,6 +371,8 @@ impl State {
                 | InputMode::EnterSearch
                 | InputMode::RenameTab
                 | InputMode::RenamePane
+                | InputMode::MarkPane
+                | InputMode::FocusMark
                 | InputMode::Prompt
                 | InputMode::Tmux
         )
@@ -117,6 +117,8 @@ impl<'a> TooltipRenderer<'a> {
                 InputMode::EnterSearch => "Entering search term...".to_owned(),
                 InputMode::RenameTab => "Renaming tab...".to_owned(),
                 InputMode::RenamePane => "Renaming pane...".to_owned(),
+                InputMode::MarkPane => "Press a key to mark the pane...".to_owned(),
+                InputMode::FocusMark => "Press the key of a mark...".to_owned(),
                 _ => {
                     format!("{:?}", self.mode_info.mode)
                 },
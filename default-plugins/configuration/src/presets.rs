@@ -39,6 +39,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Locked"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Locked"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "m" {{ SwitchToMode "Normal"; }}
@@ -266,6 +268,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Normal"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Normal"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "{primary_modifier} h" {{ SwitchToMode "Normal"; }}
@@ -504,6 +508,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Normal"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Normal"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "n" "Tab" {{ MovePane; }}
@@ -713,6 +719,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Normal"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Normal"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "{primary_modifier} h" {{ SwitchToMode "Normal"; }}
@@ -929,6 +937,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Normal"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Normal"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "n" "Tab" {{ MovePane; }}
@@ -1120,6 +1130,8 @@ keybinds clear-defaults=true {{
         bind "e" {{ TogglePaneEmbedOrFloating; SwitchToMode "Normal"; }}
         bind "c" {{ SwitchToMode "RenamePane"; PaneNameInput 0;}}
         bind "i" {{ TogglePanePinned; SwitchToMode "Normal"; }}
+        bind "m" {{ SwitchToMode "MarkPane"; }}
+        bind "'" {{ SwitchToMode "FocusMark"; }}
     }}
     move {{
         bind "{primary_modifier} m" {{ SwitchToMode "Normal"; }}
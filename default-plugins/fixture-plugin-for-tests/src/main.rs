@@ -588,6 +588,12 @@ impl ZellijPlugin for State {
                     // Test send_sigkill_to_pane_id
                     send_sigkill_to_pane_id(PaneId::Terminal(1));
                 },
+                BareKey::Char('g') if key.has_only_modifiers(&[KeyModifier::Super]) => {
+                    // Test exceeding the execution budget, this never returns
+                    loop {
+                        std::hint::spin_loop();
+                    }
+                },
                 BareKey::Char('a')
                     if key.has_only_modifiers(&[KeyModifier::Ctrl, KeyModifier::Shift]) =>
                 {
@@ -1,7 +1,6 @@
 mod search;
 
-use super::{KeyResponse, OptimisticUpdate, Screen};
-use crate::text_input::InputAction;
+use super::{KeyResponse, LayoutParamsScreen, LayoutParamsTarget, OptimisticUpdate, Screen};
 use crate::ui::{Controls, LayoutDetail, LayoutsTable};
 use crate::DisplayLayout;
 use search::SearchState;
@@ -98,12 +97,16 @@ impl LayoutListScreen {
                     KeyResponse::render()
                 },
                 BareKey::Enter if key.has_no_modifiers() => {
-                    self.open_selected_layout(display_layouts);
-                    KeyResponse::none()
+                    match self.open_selected_layout(display_layouts) {
+                        Some(new_screen) => KeyResponse::new_screen(new_screen),
+                        None => KeyResponse::none(),
+                    }
                 },
                 BareKey::Tab if key.has_no_modifiers() => {
-                    self.apply_selected_layout(display_layouts);
-                    KeyResponse::none()
+                    match self.apply_selected_layout(display_layouts) {
+                        Some(new_screen) => KeyResponse::new_screen(new_screen),
+                        None => KeyResponse::none(),
+                    }
                 },
                 BareKey::Char('t') if key.has_no_modifiers() => {
                     self.toggle_retain_options();
@@ -196,7 +199,7 @@ impl LayoutListScreen {
         match dump_session_layout() {
             Ok((session_layout, Some(session_layout_metadata))) => {
                 Screen::NewLayoutFromSession(super::NewLayoutFromCurrentSessionScreen {
-                    name_input: crate::text_input::TextInput::empty(),
+                    name_input: TextInput::empty(),
                     session_layout,
                     current_layout_metadata: session_layout_metadata,
                     editing_name: false,
@@ -293,10 +296,20 @@ impl LayoutListScreen {
         self.apply_only_to_active_tab = self.should_default_to_current_tab(display_layouts);
     }
 
-    fn apply_selected_layout(&self, display_layouts: &[DisplayLayout]) {
+    fn apply_selected_layout(&self, display_layouts: &[DisplayLayout]) -> Option<Screen> {
         if let Some(DisplayLayout::Valid(chosen_layout)) =
             display_layouts.get(self.selected_layout_index)
         {
+            let target = LayoutParamsTarget::Override {
+                retain_terminal_panes: self.retain_terminal_panes,
+                retain_plugin_panes: self.retain_plugin_panes,
+                apply_only_to_active_tab: self.apply_only_to_active_tab,
+            };
+            if let Some(params_screen) =
+                LayoutParamsScreen::new(chosen_layout.clone(), target, self.selected_layout_index)
+            {
+                return Some(Screen::LayoutParams(params_screen));
+            }
             override_layout(
                 chosen_layout,
                 self.retain_terminal_panes,
@@ -305,6 +318,7 @@ impl LayoutListScreen {
                 Default::default(),
             );
         }
+        None
     }
 
     fn toggle_retain_options(&mut self) {
@@ -363,12 +377,20 @@ impl LayoutListScreen {
         }
     }
 
-    fn open_selected_layout(&self, display_layouts: &[DisplayLayout]) {
+    fn open_selected_layout(&self, display_layouts: &[DisplayLayout]) -> Option<Screen> {
         if let Some(DisplayLayout::Valid(chosen_layout)) =
             display_layouts.get(self.selected_layout_index)
         {
+            if let Some(params_screen) = LayoutParamsScreen::new(
+                chosen_layout.clone(),
+                LayoutParamsTarget::NewTabs,
+                self.selected_layout_index,
+            ) {
+                return Some(Screen::LayoutParams(params_screen));
+            }
             new_tabs_with_layout_info(chosen_layout);
         }
+        None
     }
 
     pub fn render(&mut self, display_layouts: &[DisplayLayout], rows: usize, cols: usize) {
@@ -0,0 +1,209 @@
+use super::{ErrorScreen, KeyResponse, LayoutListScreen, Screen};
+use crate::ui::truncate_with_ellipsis_start;
+use zellij_tile::prelude::*;
+
+/// What to do with the layout once all its parameters have values
+#[derive(Clone)]
+pub enum LayoutParamsTarget {
+    NewTabs,
+    Override {
+        retain_terminal_panes: bool,
+        retain_plugin_panes: bool,
+        apply_only_to_active_tab: bool,
+    },
+}
+
+#[derive(Clone)]
+pub struct LayoutParamsScreen {
+    pub value_input: TextInput,
+    pub layout_info: LayoutInfo,
+    pub target: LayoutParamsTarget,
+    pub selected_layout_index: usize,
+}
+
+impl LayoutParamsScreen {
+    /// Returns None if the layout has no required parameters left without a value
+    pub fn new(
+        layout_info: LayoutInfo,
+        target: LayoutParamsTarget,
+        selected_layout_index: usize,
+    ) -> Option<Self> {
+        let screen = Self {
+            value_input: TextInput::empty(),
+            layout_info,
+            target,
+            selected_layout_index,
+        };
+        screen.current_param_name().map(|_| screen)
+    }
+
+    pub fn handle_key(&mut self, key: KeyWithModifier) -> KeyResponse {
+        let action = self.value_input.handle_key(key);
+
+        match action {
+            InputAction::Continue => KeyResponse::render(),
+            InputAction::Submit => self.submit_value(),
+            InputAction::Cancel => KeyResponse::new_screen(self.cancel()),
+            InputAction::Complete => KeyResponse::none(),
+            InputAction::NoAction => KeyResponse::none(),
+        }
+    }
+
+    fn current_param_name(&self) -> Option<String> {
+        match &self.layout_info {
+            LayoutInfo::File(_, metadata) => metadata
+                .missing_params()
+                .first()
+                .map(|param| param.name.clone()),
+            _ => None,
+        }
+    }
+
+    fn cancel(&self) -> Screen {
+        show_cursor(None);
+        Screen::LayoutList(LayoutListScreen::with_selected_index(
+            self.selected_layout_index,
+        ))
+    }
+
+    fn submit_value(&mut self) -> KeyResponse {
+        let value = self.value_input.get_text().to_owned();
+        if value.is_empty() {
+            return KeyResponse::new_screen(
+                self.create_error_screen("Layout parameter value cannot be empty"),
+            );
+        }
+        let Some(param_name) = self.current_param_name() else {
+            return KeyResponse::none();
+        };
+        if let LayoutInfo::File(_, metadata) = &mut self.layout_info {
+            if let Some(param) = metadata.params.iter_mut().find(|p| p.name == param_name) {
+                param.value = Some(value);
+            }
+        }
+        self.value_input.clear();
+        if self.current_param_name().is_some() {
+            return KeyResponse::render();
+        }
+        match &self.target {
+            LayoutParamsTarget::NewTabs => new_tabs_with_layout_info(&self.layout_info),
+            LayoutParamsTarget::Override {
+                retain_terminal_panes,
+                retain_plugin_panes,
+                apply_only_to_active_tab,
+            } => override_layout(
+                &self.layout_info,
+                *retain_terminal_panes,
+                *retain_plugin_panes,
+                *apply_only_to_active_tab,
+                Default::default(),
+            ),
+        }
+        KeyResponse::new_screen(self.cancel())
+    }
+
+    fn create_error_screen(&self, message: &str) -> Screen {
+        show_cursor(None);
+        Screen::Error(ErrorScreen {
+            message: message.to_string(),
+            return_to_screen: Box::new(Screen::LayoutParams(self.clone())),
+        })
+    }
+
+    fn value_line_text(&self, max_width: Option<usize>) -> (String, usize) {
+        // Returns (text, cursor_position_in_line)
+        let prompt = format!("{}: ", self.current_param_name().unwrap_or_default());
+        let prompt_len = prompt.chars().count();
+
+        let input_text = self.value_input.get_text();
+        let cursor_pos = self.value_input.get_cursor_position();
+
+        let mut text = format!("{}{}", prompt, input_text);
+        let mut cursor_position_in_line = prompt_len + cursor_pos;
+
+        if let Some(max_width) = max_width {
+            if text.chars().count() > max_width {
+                let truncated_value =
+                    truncate_with_ellipsis_start(input_text, max_width.saturating_sub(prompt_len));
+                text = format!("{}{}", prompt, truncated_value);
+                let truncated_len = truncated_value.chars().count();
+                cursor_position_in_line = prompt_len + cursor_pos.min(truncated_len);
+            }
+        }
+
+        (text, cursor_position_in_line)
+    }
+
+    fn render_value_line(&self, x: usize, y: usize, width: usize) {
+        let (text, _) = self.value_line_text(Some(width));
+        let prompt_len = self
+            .current_param_name()
+            .unwrap_or_default()
+            .chars()
+            .count();
+        let colored = Text::new(&text).color_range(3, 0..prompt_len);
+        print_text_with_coordinates(colored, x, y, None, None);
+    }
+
+    fn help_text(&self) -> (&str, &[&str]) {
+        ("<Enter> - Next, <Esc> - Cancel", &["<Enter>", "<Esc>"])
+    }
+
+    fn render_help_text(&self, x: usize, y: usize, _width: usize) {
+        let (text, items_to_color) = self.help_text();
+        let mut text_obj = Text::new(text);
+        for item in items_to_color {
+            text_obj = text_obj.color_substring(3, item);
+        }
+        print_text_with_coordinates(text_obj, x, y, None, None);
+    }
+
+    fn title_text(&self) -> String {
+        match &self.layout_info {
+            LayoutInfo::File(name, _) => format!("Parameters for {}", name),
+            _ => "Layout Parameters".to_owned(),
+        }
+    }
+
+    fn render_title(&self, x: usize, y: usize, width: usize) {
+        let title = Text::new(self.title_text()).color_all(2);
+        print_text_with_coordinates(title, x, y, Some(width), None);
+    }
+
+    fn update_cursor_position(&self, base_x: usize, cursor_y: usize, max_width: usize) {
+        let (_, cursor_position_in_line) = self.value_line_text(Some(max_width));
+        show_cursor(Some((base_x + cursor_position_in_line, cursor_y)))
+    }
+
+    pub fn render(&self, rows: usize, cols: usize) {
+        let desired_ui_width = [
+            self.help_text().0.chars().count(),
+            self.title_text().chars().count(),
+            self.value_line_text(None).0.chars().count(),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0);
+
+        // Leave at least 4 columns margin (2 on each side) to prevent text from reaching screen edge
+        let max_allowed_width = cols.saturating_sub(4);
+        let actual_ui_width = std::cmp::min(desired_ui_width, max_allowed_width);
+
+        // Calculate total height: title(1) + spacing(1) + value_line(1) + spacing(1) + help(1) = 5
+        let desired_ui_height = 5;
+        let actual_ui_height = std::cmp::min(desired_ui_height, rows);
+
+        let base_y = rows.saturating_sub(actual_ui_height) / 2;
+        let base_x = cols.saturating_sub(actual_ui_width) / 2;
+
+        // Update cursor BEFORE rendering (critical!)
+        let value_line_y = base_y + 2;
+        self.update_cursor_position(base_x, value_line_y, actual_ui_width);
+
+        self.render_title(base_x, base_y, actual_ui_width);
+        self.render_value_line(base_x, value_line_y, actual_ui_width);
+
+        let help_y = base_y + 4;
+        self.render_help_text(base_x, help_y, actual_ui_width);
+    }
+}
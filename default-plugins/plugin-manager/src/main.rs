@@ -5,6 +5,8 @@ use zellij_tile::prelude::*;
 
 use std::collections::{BTreeMap, HashMap};
 
+const SUSPENDED_INDICATION: &str = " [SUSPENDED]";
+
 pub struct SearchResult {
     plugin_id: u32,
     plugin_info: PluginInfo,
@@ -483,6 +485,177 @@ impl NewPluginScreen {
     }
 }
 
+pub struct PermissionsScreen {
+    granted: BTreeMap<String, Vec<PermissionType>>,
+    selected_index: usize,
+    error: Option<String>,
+}
+
+impl PermissionsScreen {
+    pub fn new() -> Self {
+        let mut permissions_screen = PermissionsScreen {
+            granted: BTreeMap::new(),
+            selected_index: 0,
+            error: None,
+        };
+        permissions_screen.refresh();
+        permissions_screen
+    }
+    fn refresh(&mut self) {
+        match list_granted_permissions() {
+            Ok(granted) => {
+                self.granted = granted;
+                self.error = None;
+            },
+            Err(e) => self.error = Some(e),
+        }
+        self.selected_index = self
+            .selected_index
+            .min(self.selectable_lines().len().saturating_sub(1));
+    }
+    // each plugin line is followed by a line for each of its permissions, a plugin line selects all
+    // of its permissions
+    fn selectable_lines(&self) -> Vec<(&String, Option<&PermissionType>)> {
+        let mut lines = vec![];
+        for (plugin_url, permissions) in &self.granted {
+            lines.push((plugin_url, None));
+            for permission in permissions {
+                lines.push((plugin_url, Some(permission)));
+            }
+        }
+        lines
+    }
+    pub fn render(&self, rows: usize, cols: usize) {
+        self.render_title(cols);
+        if let Some(error) = &self.error {
+            let error_text = format!("Failed to list permissions: {}", error);
+            print_text_with_coordinates(
+                Text::new(error_text).color_range(1, ..),
+                0,
+                2,
+                Some(cols),
+                None,
+            );
+        } else if self.granted.is_empty() {
+            let text = Text::new("<NO PERMISSIONS GRANTED>").color_range(0, ..);
+            print_text_with_coordinates(text, 0, 2, None, None);
+        } else {
+            self.render_permission_list(rows.saturating_sub(4), cols); // 2 top padding, 2 bottom padding
+        }
+        self.render_help(rows);
+    }
+    fn render_title(&self, cols: usize) {
+        let title_text = format!("GRANTED PERMISSIONS");
+        let title_text_len = title_text.chars().count();
+        let title = Text::new(title_text);
+        print_text_with_coordinates(
+            title,
+            (cols / 2).saturating_sub(title_text_len / 2),
+            0,
+            None,
+            None,
+        );
+    }
+    fn render_permission_list(&self, max_list_items: usize, cols: usize) {
+        let mut items = vec![];
+        for (i, (plugin_url, permission)) in self.selectable_lines().into_iter().enumerate() {
+            let mut item = match permission {
+                None => {
+                    let max_location_len = cols.saturating_sub(3); // 3 for the bulletin
+                    NestedListItem::new(truncate_string_start(plugin_url, max_location_len))
+                },
+                Some(permission) => {
+                    let permission_text = format!("{} ({})", permission, permission.display_name());
+                    let permission_len = permission.to_string().chars().count();
+                    NestedListItem::new(permission_text)
+                        .color_range(2, ..permission_len)
+                        .indent(1)
+                },
+            };
+            if i == self.selected_index {
+                item = item.selected();
+            }
+            items.push(item);
+        }
+        let list_start = self.selected_index.saturating_sub(max_list_items / 2);
+        let items: Vec<NestedListItem> = items
+            .into_iter()
+            .skip(list_start)
+            .take(max_list_items)
+            .collect();
+        print_nested_list_with_coordinates(items, 0, 2, Some(cols), None);
+    }
+    fn render_help(&self, rows: usize) {
+        let help_text = Text::new(format!(
+            "Help: <↓↑> - Navigate, <Del> - Revoke, <ESC> - Back"
+        ))
+        .color_range(3, 6..=9)
+        .color_range(3, 23..=27)
+        .color_range(3, 39..=43);
+        print_text_with_coordinates(help_text, 0, rows, None, None);
+    }
+    fn revoke_selected(&mut self) {
+        let selected = self
+            .selectable_lines()
+            .get(self.selected_index)
+            .map(|(plugin_url, permission)| ((*plugin_url).clone(), permission.copied()));
+        if let Some((plugin_url, permission)) = selected {
+            let permissions: Vec<PermissionType> = permission.into_iter().collect();
+            revoke_plugin_permissions(&plugin_url, &permissions);
+            // the revocation is applied asynchronously, so we update our own copy rather than
+            // listing the permissions again
+            match permission {
+                Some(permission) => {
+                    if let Some(granted) = self.granted.get_mut(&plugin_url) {
+                        granted.retain(|p| p != &permission);
+                        if granted.is_empty() {
+                            self.granted.remove(&plugin_url);
+                        }
+                    }
+                },
+                None => {
+                    self.granted.remove(&plugin_url);
+                },
+            }
+            self.selected_index = self
+                .selected_index
+                .min(self.selectable_lines().len().saturating_sub(1));
+        }
+    }
+    pub fn handle_key(&mut self, key: KeyWithModifier) -> (bool, bool) {
+        let mut should_render = false;
+        let mut should_close = false;
+        let line_count = self.selectable_lines().len();
+        match key.bare_key {
+            BareKey::Down if key.has_no_modifiers() => {
+                if self.selected_index + 1 >= line_count {
+                    self.selected_index = 0;
+                } else {
+                    self.selected_index += 1;
+                }
+                should_render = true;
+            },
+            BareKey::Up if key.has_no_modifiers() => {
+                if self.selected_index == 0 {
+                    self.selected_index = line_count.saturating_sub(1);
+                } else {
+                    self.selected_index -= 1;
+                }
+                should_render = true;
+            },
+            BareKey::Delete if key.has_no_modifiers() => {
+                self.revoke_selected();
+                should_render = true;
+            },
+            BareKey::Esc if key.has_no_modifiers() => {
+                should_close = true;
+            },
+            _ => {},
+        }
+        (should_render, should_close)
+    }
+}
+
 #[derive(Default)]
 struct State {
     userspace_configuration: BTreeMap<String, String>,
@@ -494,6 +667,7 @@ struct State {
     plugin_id_to_tab_position: HashMap<u32, usize>,
     search_term: String,
     new_plugin_screen: Option<NewPluginScreen>,
+    permissions_screen: Option<PermissionsScreen>,
     colors: Styling,
 }
 
@@ -586,7 +760,19 @@ impl ZellijPlugin for State {
                         should_render = should_render_new_plugin_screen;
                     }
                 },
-                None => should_render = self.handle_main_screen_key(key),
+                None => match self.permissions_screen.as_mut() {
+                    Some(permissions_screen) => {
+                        let (should_render_permissions_screen, should_close_permissions_screen) =
+                            permissions_screen.handle_key(key);
+                        if should_close_permissions_screen {
+                            self.permissions_screen = None;
+                            should_render = true;
+                        } else {
+                            should_render = should_render_permissions_screen;
+                        }
+                    },
+                    None => should_render = self.handle_main_screen_key(key),
+                },
             },
             _ => (),
         };
@@ -594,11 +780,14 @@ impl ZellijPlugin for State {
     }
 
     fn render(&mut self, rows: usize, cols: usize) {
-        match &self.new_plugin_screen {
-            Some(new_plugin_screen) => {
+        match (&self.new_plugin_screen, &self.permissions_screen) {
+            (Some(new_plugin_screen), _) => {
                 new_plugin_screen.render(rows, cols);
             },
-            None => {
+            (None, Some(permissions_screen)) => {
+                permissions_screen.render(rows, cols);
+            },
+            (None, None) => {
                 self.render_search(cols);
                 let list_y = 2;
                 let max_list_items = rows.saturating_sub(4); // 2 top padding, 2 bottom padding
@@ -718,14 +907,20 @@ impl State {
         cols: usize,
     ) -> Vec<NestedListItem> {
         let mut items = vec![];
+        let is_suspended = plugin_info.suspended.is_some();
+        let suspended_indication_len = if is_suspended {
+            SUSPENDED_INDICATION.chars().count()
+        } else {
+            0
+        };
         let plugin_location_len = plugin_info.location.chars().count();
-        let max_location_len = cols.saturating_sub(3); // 3 for the bulletin
+        let max_location_len = cols.saturating_sub(suspended_indication_len + 3); // 3 for the bulletin
         let location_string = if plugin_location_len > max_location_len {
             truncate_string_start(&plugin_info.location, max_location_len)
         } else {
             plugin_info.location.clone()
         };
-        let mut item = self.render_plugin_line(location_string, None);
+        let mut item = self.render_plugin_line(location_string, None, is_suspended);
         if is_selected {
             item = item.selected();
         }
@@ -733,6 +928,9 @@ impl State {
         if is_expanded {
             let tab_line = self.render_tab_line(plugin_id, cols);
             items.push(tab_line);
+            if let Some(reason) = &plugin_info.suspended {
+                items.push(self.render_suspended_line(reason, cols));
+            }
             if !plugin_info.configuration.is_empty() {
                 let config_line = NestedListItem::new(format!("Configuration:"))
                     .color_range(2, ..=13)
@@ -783,10 +981,17 @@ impl State {
         let plugin_info = &search_result.plugin_info;
         let plugin_id = search_result.plugin_id;
         let indices = &search_result.indices;
+        let is_suspended = plugin_info.suspended.is_some();
         let plus_indication_len = plus_indication
             .map(|p| p.to_string().chars().count() + 4)
             .unwrap_or(0); // 4 for the plus indication decorators and space
-        let max_location_len = cols.saturating_sub(plus_indication_len + 3); // 3 for the bulletin
+        let suspended_indication_len = if is_suspended && plus_indication.is_none() {
+            SUSPENDED_INDICATION.chars().count()
+        } else {
+            0
+        };
+        let max_location_len =
+            cols.saturating_sub(plus_indication_len + suspended_indication_len + 3); // 3 for the bulletin
         let (location_string, indices) = if plugin_info.location.chars().count() <= max_location_len
         {
             (plugin_info.location.clone(), indices.clone())
@@ -799,7 +1004,7 @@ impl State {
                 plus_indication,
                 Some(indices),
             ),
-            None => self.render_plugin_line(location_string, Some(indices)),
+            None => self.render_plugin_line(location_string, Some(indices), is_suspended),
         };
         if is_selected {
             item = item.selected();
@@ -808,6 +1013,9 @@ impl State {
         if is_expanded {
             let tab_line = self.render_tab_line(plugin_id, cols);
             items.push(tab_line);
+            if let Some(reason) = &plugin_info.suspended {
+                items.push(self.render_suspended_line(reason, cols));
+            }
             if !plugin_info.configuration.is_empty() {
                 let config_line = NestedListItem::new(format!("Configuration:"))
                     .color_range(2, ..=13)
@@ -838,13 +1046,35 @@ impl State {
         &self,
         location_string: String,
         indices: Option<Vec<usize>>,
+        is_suspended: bool,
     ) -> NestedListItem {
-        let mut item = NestedListItem::new(location_string).color_range(0, ..);
+        let mut item = if is_suspended {
+            let location_len = location_string.chars().count();
+            NestedListItem::new(format!("{}{}", location_string, SUSPENDED_INDICATION))
+                .color_range(0, ..location_len)
+                .color_range(1, location_len..)
+        } else {
+            NestedListItem::new(location_string).color_range(0, ..)
+        };
         if let Some(indices) = indices {
             item = item.color_indices(3, indices);
         }
         item
     }
+    fn render_suspended_line(&self, reason: &str, max_width: usize) -> NestedListItem {
+        let suspended_line_padding_count = 16; // 11 the length of "Suspended: " + 5 for the left padding
+        let reason = if reason.chars().count() + suspended_line_padding_count > max_width {
+            truncate_string_start(
+                reason,
+                max_width.saturating_sub(suspended_line_padding_count),
+            )
+        } else {
+            reason.to_owned()
+        };
+        NestedListItem::new(format!("Suspended: {}", reason))
+            .color_range(2, ..=9)
+            .indent(1)
+    }
     fn render_tab_line(&self, plugin_id: u32, max_width: usize) -> NestedListItem {
         let tab_of_plugin_id = self
             .get_tab_of_plugin_id(plugin_id)
@@ -867,11 +1097,11 @@ impl State {
         tab_line
     }
     pub fn render_help(&self, y: usize, cols: usize) {
-        let full_text = "Help: <←↓↑→> - Navigate/Expand, <ENTER> - focus, <TAB> - Reload, <Del> - Close, <Ctrl a> - New, <ESC> - Exit";
+        let full_text = "Help: <←↓↑→> - Navigate/Expand, <ENTER> - focus, <TAB> - Reload, <Del> - Close, <Ctrl a> - New, <Ctrl r> - Permissions, <ESC> - Exit";
         let middle_text =
-            "Help: <←↓↑→/ENTER> - Navigate, <TAB> - Reload, <Del> - Close, <Ctrl a> - New, <ESC> - Exit";
+            "Help: <←↓↑→/ENTER> - Navigate, <TAB> - Reload, <Del> - Close, <Ctrl a> - New, <Ctrl r> - Permissions, <ESC> - Exit";
         let short_text =
-            "<←↓↑→/ENTER/TAB/Del> - Navigate/Expand/Reload/Close, <Ctrl a> - New, <ESC> - Exit";
+            "<←↓↑→/ENTER/TAB/Del> - Navigate/Expand/Reload/Close, <Ctrl a/r> - New/Permissions, <ESC> - Exit";
         if cols >= full_text.chars().count() {
             let text = Text::new(full_text)
                 .color_range(3, 5..=11)
@@ -879,7 +1109,8 @@ impl State {
                 .color_range(3, 49..=53)
                 .color_range(3, 65..=69)
                 .color_range(3, 80..=87)
-                .color_range(3, 96..=100);
+                .color_range(3, 96..=103)
+                .color_range(3, 120..=124);
             print_text_with_coordinates(text, 0, y, Some(cols), None);
         } else if cols >= middle_text.chars().count() {
             let text = Text::new(middle_text)
@@ -887,13 +1118,14 @@ impl State {
                 .color_range(3, 31..=35)
                 .color_range(3, 47..=51)
                 .color_range(3, 62..=69)
-                .color_range(3, 78..=82);
+                .color_range(3, 78..=85)
+                .color_range(3, 102..=106);
             print_text_with_coordinates(text, 0, y, Some(cols), None);
         } else {
             let text = Text::new(short_text)
                 .color_range(3, ..=21)
-                .color_range(3, 53..=60)
-                .color_range(3, 69..=73);
+                .color_range(3, 53..=62)
+                .color_range(3, 83..=87);
             print_text_with_coordinates(text, 0, y, Some(cols), None);
         }
     }
@@ -1023,6 +1255,10 @@ impl State {
                 self.new_plugin_screen = Some(NewPluginScreen::new(self.colors));
                 should_render = true;
             },
+            BareKey::Char('r') if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                self.permissions_screen = Some(PermissionsScreen::new());
+                should_render = true;
+            },
             BareKey::Delete if key.has_no_modifiers() => {
                 self.close_selected();
             },
@@ -6,7 +6,6 @@ use crate::state::CommandStatus;
 use crate::ui::components;
 use crate::ui::fuzzy_complete;
 use crate::ui::layout_calculations::calculate_viewport;
-use crate::ui::text_input::InputAction;
 use crate::ui::truncation::truncate_middle;
 use state::State;
 use zellij_tile::prelude::actions::Action;
@@ -1,7 +1,7 @@
 use crate::path_formatting;
 use crate::state::CommandEntry;
-use crate::ui::text_input::TextInput;
 use std::path::PathBuf;
+use zellij_tile::prelude::TextInput;
 
 pub struct Editing {
     pub editing_input: Option<TextInput>,
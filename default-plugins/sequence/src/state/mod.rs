@@ -20,7 +20,6 @@ pub use execution::Execution;
 pub use layout::Layout;
 pub use selection::Selection;
 
-use crate::ui::text_input::TextInput;
 use std::path::PathBuf;
 use zellij_tile::prelude::*;
 
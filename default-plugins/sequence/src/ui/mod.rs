@@ -1,5 +1,4 @@
 pub mod components;
 pub mod fuzzy_complete;
 pub mod layout_calculations;
-pub mod text_input;
 pub mod truncation;
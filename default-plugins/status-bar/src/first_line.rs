@@ -591,7 +591,9 @@ fn get_key_shortcut_for_mode<'a>(
     let key_action = match mode {
         InputMode::Normal | InputMode::Prompt | InputMode::Tmux => return None,
         InputMode::Locked => KeyAction::Lock,
-        InputMode::Pane | InputMode::RenamePane => KeyAction::Pane,
+        InputMode::Pane | InputMode::RenamePane | InputMode::MarkPane | InputMode::FocusMark => {
+            KeyAction::Pane
+        },
         InputMode::Tab | InputMode::RenameTab => KeyAction::Tab,
         InputMode::Resize => KeyAction::Resize,
         InputMode::Move => KeyAction::Move,
@@ -18,7 +18,7 @@ use zellij_tile_utils::{palette_match, style};
 use first_line::first_line;
 use one_line_ui::one_line_ui;
 use second_line::{
-    floating_panes_are_visible, fullscreen_panes_to_hide, keybinds,
+    floating_panes_are_visible, following_client_hint, fullscreen_panes_to_hide, keybinds,
     locked_floating_panes_are_visible, locked_fullscreen_panes_to_hide, system_clipboard_error,
     text_copied_hint,
 };
@@ -335,6 +335,11 @@ impl State {
             text_copied_hint(copy_destination)
         } else if self.display_system_clipboard_failure {
             system_clipboard_error(&self.mode_info.style.colors)
+        } else if let Some(following_client) = self.mode_info.following_client {
+            following_client_hint(
+                following_client,
+                self.mode_info.following_client_name.as_deref(),
+            )
         } else if let Some(active_tab) = active_tab {
             if active_tab.is_fullscreen_active {
                 match self.mode_info.mode {
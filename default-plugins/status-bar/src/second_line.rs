@@ -3,6 +3,7 @@ use ansi_term::{
     Color::{Fixed, RGB},
     Style,
 };
+use unicode_width::UnicodeWidthStr;
 use zellij_tile::prelude::actions::Action;
 use zellij_tile::prelude::*;
 use zellij_tile_utils::palette_match;
@@ -252,6 +253,8 @@ fn get_keys_and_hints(mi: &ModeInfo) -> Vec<(String, String, Vec<KeyWithModifier
             action_key(&km, &[A::SearchToggleOption{option: SOpt::Wrap}])),
         (s("Whole words"), s("Whole"),
             action_key(&km, &[A::SearchToggleOption{option: SOpt::WholeWord}])),
+        (s("Regex"), s("Regex"),
+            action_key(&km, &[A::SearchToggleOption{option: SOpt::Regex}])),
     ]} else if mi.mode == IM::Session { vec![
         (s("Detach"), s("Detach"), action_key(&km, &[Action::Detach])),
         (s("Session Manager"), s("Manager"), action_key(&km, &[A::LaunchOrFocusPlugin{plugin: Default::default(), should_float: true, move_to_focused_tab: true, should_open_in_place: false, skip_cache: false}, TO_NORMAL])), // not entirely accurate
@@ -383,6 +386,26 @@ pub fn text_copied_hint(copy_destination: CopyDestination) -> LinePart {
     }
 }
 
+pub fn following_client_hint(client_id: ClientId, client_name: Option<&str>) -> LinePart {
+    let followed_client = match client_name {
+        Some(client_name) => client_name.to_owned(),
+        None => format!("client {}", client_id),
+    };
+    let hint = format!("Following {} (press any key to stop)", followed_client);
+    let followed_client_start = "Following ".len();
+    LinePart {
+        part: serialize_text(
+            &Text::new(&hint)
+                .color_range(
+                    3,
+                    followed_client_start..followed_client_start + followed_client.chars().count(),
+                )
+                .opaque(),
+        ),
+        len: hint.width(),
+    }
+}
+
 pub fn system_clipboard_error(palette: &Styling) -> LinePart {
     let hint = " Error using the system clipboard.";
     let red_color = palette_match!(palette.text_unselected.emphasis_3);
@@ -9,6 +9,7 @@ use nix;
 use zellij_client::{
     old_config_converter::{
         config_yaml_to_config_kdl, convert_old_yaml_files, layout_yaml_to_layout_kdl,
+        tmuxinator_to_layout_kdl, tmuxp_to_layout_kdl,
     },
     os_input_output::get_client_os_input,
     start_client as start_client_impl, ClientInfo,
@@ -38,14 +39,21 @@ use zellij_utils::web_authentication_tokens::{
 use miette::{Report, Result};
 use zellij_server::{os_input_output::get_server_os_input, start_server as start_server_impl};
 use zellij_utils::{
-    cli::{CliArgs, Command, SessionCommand, Sessions},
+    asciicast::{parse_asciicast, playback_schedule},
+    cli::{
+        CliArgs, Command, LayoutSourceFormat, PluginPermissionsCommand, SessionCommand, Sessions,
+    },
     data::ConnectToSession,
     envs,
     input::{
         actions::Action,
         config::{Config, ConfigError},
+        layout::RunPluginLocation,
+        layout_validation::validate_layout_file,
         options::Options,
+        permission::{PermissionAuditLog, PermissionCache},
     },
+    plugin_metadata::{PluginMetadata, MIN_SUPPORTED_PLUGIN_API_VERSION, PLUGIN_API_VERSION},
     setup::Setup,
 };
 
@@ -452,12 +460,17 @@ pub(crate) fn convert_old_config_file(old_config_file: PathBuf) {
     }
 }
 
-pub(crate) fn convert_old_layout_file(old_layout_file: PathBuf) {
+pub(crate) fn convert_old_layout_file(old_layout_file: PathBuf, from: LayoutSourceFormat) {
     match File::open(&old_layout_file) {
         Ok(mut handle) => {
             let mut raw_layout_file = String::new();
             let _ = handle.read_to_string(&mut raw_layout_file);
-            match layout_yaml_to_layout_kdl(&raw_layout_file) {
+            let converted = match from {
+                LayoutSourceFormat::Zellij => layout_yaml_to_layout_kdl(&raw_layout_file),
+                LayoutSourceFormat::Tmuxinator => tmuxinator_to_layout_kdl(&raw_layout_file),
+                LayoutSourceFormat::Tmuxp => tmuxp_to_layout_kdl(&raw_layout_file),
+            };
+            match converted {
                 Ok(kdl_layout) => {
                     println!("{}", kdl_layout);
                     process::exit(0);
@@ -498,6 +511,199 @@ pub(crate) fn convert_old_theme_file(old_theme_file: PathBuf) {
     }
 }
 
+pub(crate) fn playback_asciicast(file: PathBuf, speed: f64, idle_time_limit: Option<f64>) {
+    let recording = match std::fs::read_to_string(&file) {
+        Ok(recording) => recording,
+        Err(e) => {
+            eprintln!("Failed to open file: {}", e);
+            process::exit(1);
+        },
+    };
+    let (header, events) = match parse_asciicast(&recording) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Failed to parse recording: {}", e);
+            process::exit(1);
+        },
+    };
+    let idle_time_limit = idle_time_limit.or(header.idle_time_limit);
+    let mut stdout = std::io::stdout();
+    for (delay, data) in playback_schedule(&events, speed, idle_time_limit) {
+        std::thread::sleep(delay);
+        let _ = stdout.write_all(data.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+pub(crate) fn validate_layout(
+    file: PathBuf,
+    params: Vec<(String, String)>,
+    json: bool,
+    config: Option<Config>,
+) -> ! {
+    let config = config.unwrap_or_default();
+    let report = validate_layout_file(&file, config, params.into_iter().collect());
+    if json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(serialized_report) => println!("{}", serialized_report),
+            Err(e) => {
+                eprintln!("Failed to serialize diagnostics: {}", e);
+                process::exit(2);
+            },
+        }
+    } else {
+        let error_count = report.error_count();
+        let warning_count = report.warning_count();
+        for diagnostic in report.diagnostics {
+            let report: Report = diagnostic.into();
+            eprintln!("{:?}", report);
+        }
+        if error_count == 0 && warning_count == 0 {
+            println!("{} is valid", report.file);
+        } else {
+            eprintln!(
+                "{}: {} error(s), {} warning(s)",
+                report.file, error_count, warning_count
+            );
+        }
+    }
+    process::exit(if report.valid { 0 } else { 1 });
+}
+
+/// Plugins are known to the permission cache and the audit log by their location, so we accept
+/// both their url and the location printed by `zellij plugin permissions list`
+fn plugin_location_from_url(plugin_url: &str) -> String {
+    RunPluginLocation::parse(plugin_url, std::env::current_dir().ok())
+        .map(|location| location.to_string())
+        .unwrap_or_else(|_| plugin_url.to_owned())
+}
+
+pub(crate) fn plugin_permissions(permissions_command: PluginPermissionsCommand) {
+    match permissions_command {
+        PluginPermissionsCommand::List { plugin_url } => {
+            let plugin_location = plugin_url.as_deref().map(plugin_location_from_url);
+            let permission_cache = PermissionCache::from_path_or_default(None);
+            let mut granted: Vec<_> = permission_cache
+                .granted()
+                .iter()
+                .filter(|(location, _)| {
+                    plugin_location
+                        .as_ref()
+                        .map(|p| p == *location)
+                        .unwrap_or(true)
+                })
+                .collect();
+            granted.sort();
+            if granted.is_empty() {
+                eprintln!("No permissions granted.");
+            }
+            for (location, permissions) in granted {
+                println!("{}", location);
+                for permission in permissions {
+                    println!("    {}", permission);
+                }
+            }
+        },
+        PluginPermissionsCommand::Revoke {
+            plugin_url,
+            permission,
+        } => {
+            let plugin_location = plugin_location_from_url(&plugin_url);
+            let mut permission_cache = PermissionCache::from_path_or_default(None);
+            if !permission_cache.revoke(&plugin_location, permission) {
+                match permission {
+                    Some(permission) => eprintln!(
+                        "Plugin {} was not granted the {} permission.",
+                        plugin_location, permission
+                    ),
+                    None => eprintln!(
+                        "Plugin {} was not granted any permissions.",
+                        plugin_location
+                    ),
+                }
+                process::exit(2);
+            }
+            if let Err(e) = permission_cache.write_to_file() {
+                eprintln!("Failed to write permissions: {}", e);
+                process::exit(2);
+            }
+            match permission {
+                Some(permission) => println!(
+                    "Revoked the {} permission of plugin {}.",
+                    permission, plugin_location
+                ),
+                None => println!("Revoked all permissions of plugin {}.", plugin_location),
+            }
+        },
+        PluginPermissionsCommand::Audit { plugin_url } => {
+            let plugin_location = plugin_url.as_deref().map(plugin_location_from_url);
+            let audit_log = PermissionAuditLog::from_path_or_default(None);
+            match audit_log.entries(plugin_location.as_deref()) {
+                Ok(entries) => {
+                    for entry in entries {
+                        println!(
+                            "{} {} {} {}",
+                            entry.timestamp, entry.permission, entry.command, entry.plugin_name
+                        );
+                    }
+                },
+                Err(e) => {
+                    eprintln!("Failed to read the plugin audit log: {}", e);
+                    process::exit(2);
+                },
+            }
+        },
+    }
+}
+
+pub(crate) fn plugin_info(file: PathBuf) {
+    let wasm_bytes = match std::fs::read(&file) {
+        Ok(wasm_bytes) => wasm_bytes,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", file.display(), e);
+            process::exit(2);
+        },
+    };
+    let plugin_metadata = match PluginMetadata::from_wasm(&wasm_bytes) {
+        Ok(Some(plugin_metadata)) => plugin_metadata,
+        Ok(None) => {
+            println!("{}", file.display());
+            println!(
+                "    No plugin metadata, the plugin was built with a zellij-tile version that predates it and will be loaded as is"
+            );
+            return;
+        },
+        Err(e) => {
+            eprintln!("Failed to read the metadata of {}: {}", file.display(), e);
+            process::exit(2);
+        },
+    };
+    println!("{}", file.display());
+    println!("    Plugin API version: {}", plugin_metadata.api_version);
+    if let Some(zellij_tile_version) = &plugin_metadata.zellij_tile_version {
+        println!("    Built with zellij-tile: {}", zellij_tile_version);
+    }
+    match plugin_metadata.compatibility_error() {
+        Some(compatibility_error) => println!("    Incompatible: {}", compatibility_error),
+        None => println!(
+            "    Compatible (supported plugin API versions: {}-{})",
+            MIN_SUPPORTED_PLUGIN_API_VERSION, PLUGIN_API_VERSION
+        ),
+    }
+    match &plugin_metadata.permissions {
+        Some(permissions) if permissions.is_empty() => {
+            println!("    Requested permissions: none")
+        },
+        Some(permissions) => {
+            println!("    Requested permissions:");
+            for permission in permissions {
+                println!("        {} ({})", permission, permission.display_name());
+            }
+        },
+        None => println!("    Requested permissions: not declared"),
+    }
+}
+
 fn attach_with_cli_client(
     cli_action: zellij_utils::cli::CliAction,
     session_name: &str,
@@ -4,11 +4,11 @@ mod tests;
 
 use clap::Parser;
 use zellij_utils::{
-    cli::{CliAction, CliArgs, Command, Sessions},
+    cli::{CliAction, CliArgs, Command, LayoutCommand, PluginSubcommand, Sessions},
     consts::{create_config_and_cache_folders, VERSION},
     data::UnblockCondition,
     envs,
-    input::config::Config,
+    input::{command::RunCommand, config::Config},
     logging::*,
     setup::Setup,
     shared::web_server_base_url_from_config,
@@ -27,8 +27,13 @@ fn main() {
         }
         if let Some(Command::Sessions(Sessions::Run {
             command,
+            playback,
+            playback_speed,
             direction,
             cwd,
+            env,
+            restart,
+            max_restarts,
             floating,
             in_place,
             name,
@@ -50,6 +55,15 @@ fn main() {
         {
             let cwd = cwd.or_else(|| std::env::current_dir().ok());
             let skip_plugin_cache = false; // N/A for this action
+            let command = match playback {
+                Some(playback) => {
+                    let playback_command = RunCommand::playback(playback, playback_speed);
+                    std::iter::once(playback_command.command.display().to_string())
+                        .chain(playback_command.args)
+                        .collect()
+                },
+                None => command,
+            };
 
             // Compute the unblock condition
             let unblock_condition = if block_until_exit_success {
@@ -67,6 +81,9 @@ fn main() {
                 plugin: None,
                 direction,
                 cwd,
+                env,
+                restart,
+                max_restarts,
                 floating,
                 in_place,
                 name,
@@ -89,19 +106,43 @@ fn main() {
             std::process::exit(0);
         }
         if let Some(Command::Sessions(Sessions::Plugin {
-            url,
+            command: Some(PluginSubcommand::Permissions(permissions_command)),
+            ..
+        })) = opts.command
+        {
+            commands::plugin_permissions(permissions_command);
+            std::process::exit(0);
+        }
+        if let Some(Command::Sessions(Sessions::Plugin {
+            command: Some(PluginSubcommand::Info { file }),
+            ..
+        })) = opts.command
+        {
+            commands::plugin_info(file);
+            std::process::exit(0);
+        }
+        if let Some(Command::Sessions(Sessions::Plugin {
+            url: Some(url),
             floating,
             in_place,
-            configuration,
-            skip_plugin_cache,
+            mut configuration,
+            mut skip_plugin_cache,
+            dev,
             x,
             y,
             width,
             height,
             pinned,
             borderless,
+            ..
         })) = opts.command
         {
+            if dev {
+                configuration
+                    .get_or_insert_with(Default::default)
+                    .insert("dev", "true");
+                skip_plugin_cache = true;
+            }
             let cwd = None;
             let stacked = false;
             let blocking = false;
@@ -111,6 +152,9 @@ fn main() {
                 plugin: Some(url),
                 direction: None,
                 cwd,
+                env: vec![],
+                restart: None,
+                max_restarts: None,
                 floating,
                 in_place,
                 name: None,
@@ -177,14 +221,35 @@ fn main() {
             commands::convert_old_config_file(old_config_file);
             std::process::exit(0);
         }
-        if let Some(Command::Sessions(Sessions::ConvertLayout { old_layout_file })) = opts.command {
-            commands::convert_old_layout_file(old_layout_file);
+        if let Some(Command::Sessions(Sessions::ConvertLayout {
+            old_layout_file,
+            from,
+        })) = opts.command
+        {
+            commands::convert_old_layout_file(old_layout_file, from);
             std::process::exit(0);
         }
         if let Some(Command::Sessions(Sessions::ConvertTheme { old_theme_file })) = opts.command {
             commands::convert_old_theme_file(old_theme_file);
             std::process::exit(0);
         }
+        if let Some(Command::Sessions(Sessions::Playback {
+            file,
+            speed,
+            idle_time_limit,
+        })) = opts.command
+        {
+            commands::playback_asciicast(file, speed, idle_time_limit);
+            std::process::exit(0);
+        }
+        if let Some(Command::Sessions(Sessions::Layout(LayoutCommand::Validate {
+            file,
+            params,
+            json,
+        }))) = opts.command
+        {
+            commands::validate_layout(file, params, json, config);
+        }
         if let Some(Command::Sessions(Sessions::Pipe {
             name,
             payload,
@@ -252,6 +317,7 @@ fn main() {
             let new_layout_cli_action = CliAction::NewTab {
                 layout: Some(layout.clone()),
                 layout_dir: options.as_ref().and_then(|o| o.layout_dir.clone()),
+                params: opts.params.clone(),
                 name: None,
                 cwd: options.as_ref().and_then(|o| o.default_cwd.clone()),
                 initial_command: vec![],
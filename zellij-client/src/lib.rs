@@ -115,6 +115,7 @@ pub(crate) enum ClientInstruction {
     #[allow(dead_code)] // we need the session name here even though we're not currently using it
     RenamedSession(String), // String -> new session name
     ConfigFileUpdated,
+    PluginHttpResponse, // only sent to the web server, which does not go through this
 }
 
 impl From<ServerToClientMsg> for ClientInstruction {
@@ -137,6 +138,7 @@ impl From<ServerToClientMsg> for ClientInstruction {
             ServerToClientMsg::StartWebServer => ClientInstruction::StartWebServer,
             ServerToClientMsg::RenamedSession { name } => ClientInstruction::RenamedSession(name),
             ServerToClientMsg::ConfigFileUpdated => ClientInstruction::ConfigFileUpdated,
+            ServerToClientMsg::PluginHttpResponse { .. } => ClientInstruction::PluginHttpResponse,
         }
     }
 }
@@ -161,6 +163,7 @@ impl From<&ClientInstruction> for ClientContext {
             ClientInstruction::StartWebServer => ClientContext::StartWebServer,
             ClientInstruction::RenamedSession(..) => ClientContext::RenamedSession,
             ClientInstruction::ConfigFileUpdated => ClientContext::ConfigFileUpdated,
+            ClientInstruction::PluginHttpResponse => ClientContext::PluginHttpResponse,
         }
     }
 }
@@ -231,6 +234,11 @@ pub fn spawn_server(socket_path: &Path, debug: bool) -> io::Result<()> {
     }
 }
 
+/// The name other clients see for this one, the name of the user running it
+fn terminal_client_name() -> Option<String> {
+    std::env::var("USER").ok().filter(|user| !user.is_empty())
+}
+
 #[derive(Debug, Clone)]
 pub enum ClientInfo {
     Attach(String, Options),
@@ -655,13 +663,15 @@ pub fn start_client(
                             &config_options.layout_dir,
                             &config_options.default_layout,
                         )
-                    }),
+                    })
+                    .map(|l| l.with_param_values(&cli_args.params.iter().cloned().collect())),
                 terminal_window_size: full_screen_ws,
                 data_dir: cli_args.data_dir.clone(),
                 is_debug: cli_args.debug,
                 max_panes: cli_args.max_panes,
                 force_run_layout_commands: false,
                 cwd: None,
+                client_name: terminal_client_name(),
             };
             (
                 ClientToServerMsg::AttachClient {
@@ -707,6 +717,7 @@ pub fn start_client(
                 max_panes: cli_args.max_panes,
                 force_run_layout_commands: force_run_commands,
                 cwd,
+                client_name: terminal_client_name(),
             };
 
             os_input.update_session_name(name);
@@ -750,6 +761,7 @@ pub fn start_client(
                                 &config_options.default_layout,
                             )
                         })
+                        .map(|l| l.with_param_values(&cli_args.params.iter().cloned().collect()))
                 }),
                 terminal_window_size: full_screen_ws,
                 data_dir: cli_args.data_dir.clone(),
@@ -757,6 +769,7 @@ pub fn start_client(
                 max_panes: cli_args.max_panes,
                 force_run_layout_commands: false,
                 cwd: layout_cwd,
+                client_name: terminal_client_name(),
             };
 
             os_input.update_session_name(name);
@@ -1143,6 +1156,7 @@ pub fn start_server_detached(
                 max_panes: cli_args.max_panes,
                 force_run_layout_commands: force_run_commands,
                 cwd,
+                client_name: None,
             };
 
             os_input.update_session_name(name);
@@ -1186,6 +1200,7 @@ pub fn start_server_detached(
                                 &config_options.default_layout,
                             )
                         })
+                        .map(|l| l.with_param_values(&cli_args.params.iter().cloned().collect()))
                 }),
                 terminal_window_size: Size { cols: 50, rows: 50 }, // static number until a
                 // client connects
@@ -1194,6 +1209,7 @@ pub fn start_server_detached(
                 max_panes: cli_args.max_panes,
                 force_run_layout_commands: false,
                 cwd: layout_cwd,
+                client_name: None,
             };
 
             os_input.update_session_name(name);
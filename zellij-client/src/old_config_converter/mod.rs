@@ -1,6 +1,8 @@
 mod convert_old_yaml_files;
 mod old_config;
 mod old_layout;
+mod tmux_project;
 pub use convert_old_yaml_files::convert_old_yaml_files;
 pub use old_config::config_yaml_to_config_kdl;
 pub use old_layout::layout_yaml_to_layout_kdl;
+pub use tmux_project::{tmuxinator_to_layout_kdl, tmuxp_to_layout_kdl};
@@ -0,0 +1,667 @@
+// This is a converter from tmuxinator and tmuxp project files to KDL layouts.
+//
+// Both formats describe a session as a list of windows, each with a tmux layout and a list of
+// panes running shell commands. These are read into a small intermediate representation which is
+// then stringified into a layout with one tab per window.
+use serde_yaml::{Mapping, Value};
+
+// tmux's main-* layouts give the main pane a fixed share of the window, we use half of it
+const MAIN_PANE_PERCENT: usize = 50;
+
+#[derive(Debug, Default)]
+struct TmuxProject {
+    name: Option<String>,
+    root: Option<String>,
+    windows: Vec<TmuxWindow>,
+}
+
+#[derive(Debug, Default)]
+struct TmuxWindow {
+    name: Option<String>,
+    root: Option<String>,
+    layout: Option<String>,
+    panes: Vec<TmuxPane>,
+    focus: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+struct TmuxPane {
+    name: Option<String>,
+    root: Option<String>,
+    commands: Vec<String>,
+    focus: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitDirection {
+    // children are placed one on top of the other
+    Horizontal,
+    // children are placed side by side
+    Vertical,
+}
+
+impl SplitDirection {
+    fn as_kdl(&self) -> &'static str {
+        match self {
+            SplitDirection::Horizontal => "horizontal",
+            SplitDirection::Vertical => "vertical",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum SplitTree {
+    Pane,
+    Split {
+        direction: SplitDirection,
+        // the size of each child in percent, None means it takes the remaining space
+        children: Vec<(Option<usize>, SplitTree)>,
+    },
+}
+
+impl SplitTree {
+    fn pane_count(&self) -> usize {
+        match self {
+            SplitTree::Pane => 1,
+            SplitTree::Split { children, .. } => children.iter().map(|(_, c)| c.pane_count()).sum(),
+        }
+    }
+}
+
+pub fn tmuxinator_to_layout_kdl(raw_yaml_project: &str) -> Result<String, String> {
+    let project = parse_tmuxinator_project(raw_yaml_project)?;
+    stringify_project(&project)
+}
+
+pub fn tmuxp_to_layout_kdl(raw_yaml_project: &str) -> Result<String, String> {
+    let project = parse_tmuxp_project(raw_yaml_project)?;
+    stringify_project(&project)
+}
+
+fn parse_yaml_mapping(raw_yaml_project: &str) -> Result<Mapping, String> {
+    match serde_yaml::from_str(raw_yaml_project)
+        .map_err(|e| format!("Failed to parse yaml: {:?}", e))?
+    {
+        Value::Mapping(mapping) => Ok(mapping),
+        _ => Err("Project file must be a yaml mapping".to_owned()),
+    }
+}
+
+fn get<'a>(mapping: &'a Mapping, key: &str) -> Option<&'a Value> {
+    mapping
+        .get(&Value::String(key.to_owned()))
+        .filter(|v| !v.is_null())
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn get_string(mapping: &Mapping, key: &str) -> Option<String> {
+    get(mapping, key).and_then(scalar_to_string)
+}
+
+fn get_bool(mapping: &Mapping, key: &str) -> bool {
+    matches!(get(mapping, key), Some(Value::Bool(true)))
+}
+
+// commands can be given either as a single string or as a list of strings (tmuxp also allows
+// `{cmd: "..."}` entries)
+fn commands_from_value(value: Option<&Value>) -> Result<Vec<String>, String> {
+    match value {
+        None | Some(Value::Null) => Ok(vec![]),
+        Some(Value::Sequence(commands)) => {
+            let mut parsed = vec![];
+            for command in commands {
+                match command {
+                    Value::Mapping(mapping) => parsed.extend(get_string(mapping, "cmd")),
+                    Value::Null => {},
+                    command => parsed.push(
+                        scalar_to_string(command)
+                            .ok_or_else(|| format!("Invalid command: {:?}", command))?,
+                    ),
+                }
+            }
+            Ok(parsed)
+        },
+        Some(command) => {
+            Ok(vec![scalar_to_string(command).ok_or_else(|| {
+                format!("Invalid command: {:?}", command)
+            })?])
+        },
+    }
+}
+
+fn windows_from_mapping(mapping: &Mapping) -> Result<&Vec<Value>, String> {
+    match get(mapping, "windows") {
+        Some(Value::Sequence(windows)) if !windows.is_empty() => Ok(windows),
+        _ => Err("Project file must have a non-empty list of windows".to_owned()),
+    }
+}
+
+fn parse_tmuxinator_project(raw_yaml_project: &str) -> Result<TmuxProject, String> {
+    let mapping = parse_yaml_mapping(raw_yaml_project)?;
+    let mut project = TmuxProject {
+        name: get_string(&mapping, "name").or_else(|| get_string(&mapping, "project_name")),
+        root: get_string(&mapping, "root").or_else(|| get_string(&mapping, "project_root")),
+        windows: vec![],
+    };
+    // `pre` (and its replacement `on_project_start`) run once when the project starts, we run
+    // them in the first pane before anything else
+    let mut project_start_commands = commands_from_value(get(&mapping, "pre"))?;
+    project_start_commands.extend(commands_from_value(get(&mapping, "on_project_start"))?);
+    let pre_window_commands = commands_from_value(get(&mapping, "pre_window"))?;
+    for window in windows_from_mapping(&mapping)? {
+        project
+            .windows
+            .push(parse_tmuxinator_window(window, &pre_window_commands)?);
+    }
+
+    let startup_window = get_string(&mapping, "startup_window");
+    let startup_window_index = startup_window
+        .as_ref()
+        .and_then(|startup_window| {
+            project
+                .windows
+                .iter()
+                .position(|w| w.name.as_ref() == Some(startup_window))
+                .or_else(|| startup_window.parse::<usize>().ok())
+        })
+        .unwrap_or(0);
+    if let Some(window) = project.windows.get_mut(startup_window_index) {
+        if startup_window.is_some() {
+            window.focus = true;
+        }
+        if let Some(startup_pane) =
+            get_string(&mapping, "startup_pane").and_then(|p| p.parse::<usize>().ok())
+        {
+            if let Some(pane) = window.panes.get_mut(startup_pane) {
+                pane.focus = true;
+            }
+        }
+    }
+    prepend_project_start_commands(&mut project, project_start_commands);
+    Ok(project)
+}
+
+fn parse_tmuxinator_window(
+    window: &Value,
+    pre_window_commands: &[String],
+) -> Result<TmuxWindow, String> {
+    // every window is a single key mapping of the window name to its definition
+    let (name, definition) = match window {
+        Value::Mapping(mapping) if mapping.len() == 1 => mapping
+            .iter()
+            .next()
+            .map(|(name, definition)| (scalar_to_string(name), definition))
+            .unwrap(),
+        _ => return Err(format!("Invalid window definition: {:?}", window)),
+    };
+    let mut tmux_window = TmuxWindow {
+        name,
+        ..Default::default()
+    };
+    match definition {
+        Value::Mapping(definition) => {
+            tmux_window.root = get_string(definition, "root");
+            tmux_window.layout = get_string(definition, "layout");
+            let mut pre_commands = pre_window_commands.to_vec();
+            pre_commands.extend(commands_from_value(get(definition, "pre"))?);
+            match get(definition, "panes") {
+                Some(Value::Sequence(panes)) => {
+                    for pane in panes {
+                        let mut tmux_pane = parse_tmuxinator_pane(pane)?;
+                        tmux_pane
+                            .commands
+                            .splice(0..0, pre_commands.iter().cloned());
+                        tmux_window.panes.push(tmux_pane);
+                    }
+                },
+                Some(panes) => return Err(format!("Invalid panes definition: {:?}", panes)),
+                None => {},
+            }
+            if tmux_window.panes.is_empty() {
+                tmux_window.panes.push(TmuxPane {
+                    commands: pre_commands,
+                    ..Default::default()
+                });
+            }
+        },
+        // a string or a list of strings are commands to run in the window's single pane
+        definition => {
+            let mut commands = pre_window_commands.to_vec();
+            commands.extend(commands_from_value(Some(definition))?);
+            tmux_window.panes.push(TmuxPane {
+                commands,
+                ..Default::default()
+            });
+        },
+    }
+    Ok(tmux_window)
+}
+
+fn parse_tmuxinator_pane(pane: &Value) -> Result<TmuxPane, String> {
+    match pane {
+        // a named pane is a single key mapping of the pane name to its commands
+        Value::Mapping(mapping) if mapping.len() == 1 => {
+            let (name, commands) = mapping.iter().next().unwrap();
+            Ok(TmuxPane {
+                name: scalar_to_string(name),
+                commands: commands_from_value(Some(commands))?,
+                ..Default::default()
+            })
+        },
+        Value::Mapping(_) => Err(format!("Invalid pane definition: {:?}", pane)),
+        pane => Ok(TmuxPane {
+            commands: commands_from_value(Some(pane))?,
+            ..Default::default()
+        }),
+    }
+}
+
+fn parse_tmuxp_project(raw_yaml_project: &str) -> Result<TmuxProject, String> {
+    let mapping = parse_yaml_mapping(raw_yaml_project)?;
+    let mut project = TmuxProject {
+        name: get_string(&mapping, "session_name"),
+        root: get_string(&mapping, "start_directory"),
+        windows: vec![],
+    };
+    let session_commands_before = commands_from_value(get(&mapping, "shell_command_before"))?;
+    for window in windows_from_mapping(&mapping)? {
+        let window = match window {
+            Value::Mapping(window) => window,
+            _ => return Err(format!("Invalid window definition: {:?}", window)),
+        };
+        let mut commands_before = session_commands_before.clone();
+        commands_before.extend(commands_from_value(get(window, "shell_command_before"))?);
+        let mut tmux_window = TmuxWindow {
+            name: get_string(window, "window_name"),
+            root: get_string(window, "start_directory"),
+            layout: get_string(window, "layout"),
+            panes: vec![],
+            focus: get_bool(window, "focus"),
+        };
+        match get(window, "panes") {
+            Some(Value::Sequence(panes)) => {
+                for pane in panes {
+                    let mut tmux_pane = match pane {
+                        Value::Mapping(pane) => TmuxPane {
+                            name: None,
+                            root: get_string(pane, "start_directory"),
+                            commands: commands_from_value(get(pane, "shell_command"))?,
+                            focus: get_bool(pane, "focus"),
+                        },
+                        // "blank" and "pane" are tmuxp's placeholders for an empty pane
+                        Value::String(s) if s == "blank" || s == "pane" => TmuxPane::default(),
+                        pane => TmuxPane {
+                            commands: commands_from_value(Some(pane))?,
+                            ..Default::default()
+                        },
+                    };
+                    tmux_pane
+                        .commands
+                        .splice(0..0, commands_before.iter().cloned());
+                    tmux_window.panes.push(tmux_pane);
+                }
+            },
+            Some(panes) => return Err(format!("Invalid panes definition: {:?}", panes)),
+            None => {},
+        }
+        if tmux_window.panes.is_empty() {
+            tmux_window.panes.push(TmuxPane {
+                commands: commands_before,
+                ..Default::default()
+            });
+        }
+        project.windows.push(tmux_window);
+    }
+    // `before_script` is run once before the session is created
+    let before_script = get_string(&mapping, "before_script").into_iter().collect();
+    prepend_project_start_commands(&mut project, before_script);
+    Ok(project)
+}
+
+fn prepend_project_start_commands(project: &mut TmuxProject, commands: Vec<String>) {
+    if let Some(first_pane) = project
+        .windows
+        .first_mut()
+        .and_then(|w| w.panes.first_mut())
+    {
+        first_pane.commands.splice(0..0, commands);
+    }
+}
+
+fn split_tree_for_layout(layout: Option<&str>, pane_count: usize) -> Result<SplitTree, String> {
+    let even_split = |direction, pane_count| {
+        if pane_count == 1 {
+            SplitTree::Pane
+        } else {
+            SplitTree::Split {
+                direction,
+                children: (0..pane_count).map(|_| (None, SplitTree::Pane)).collect(),
+            }
+        }
+    };
+    let main_split = |direction, secondary_direction| {
+        if pane_count == 1 {
+            SplitTree::Pane
+        } else {
+            SplitTree::Split {
+                direction,
+                children: vec![
+                    (Some(MAIN_PANE_PERCENT), SplitTree::Pane),
+                    (None, even_split(secondary_direction, pane_count - 1)),
+                ],
+            }
+        }
+    };
+    match layout {
+        // tmux's default for new panes is to split the active pane, the most similar layout
+        // is to stack them
+        None | Some("even-vertical") => Ok(even_split(SplitDirection::Horizontal, pane_count)),
+        Some("even-horizontal") => Ok(even_split(SplitDirection::Vertical, pane_count)),
+        Some("main-vertical") => Ok(main_split(
+            SplitDirection::Vertical,
+            SplitDirection::Horizontal,
+        )),
+        Some("main-horizontal") => Ok(main_split(
+            SplitDirection::Horizontal,
+            SplitDirection::Vertical,
+        )),
+        Some("tiled") => Ok(tiled_split_tree(pane_count)),
+        Some(custom_layout) => {
+            let tree = parse_tmux_layout_string(custom_layout)?;
+            let layout_pane_count = tree.pane_count();
+            if layout_pane_count < pane_count {
+                return Err(format!(
+                    "Layout \"{}\" has {} panes but the window has {}",
+                    custom_layout, layout_pane_count, pane_count
+                ));
+            }
+            Ok(tree)
+        },
+    }
+}
+
+// this mirrors how tmux itself arranges tiled panes: grow the grid one row then one column at a
+// time until all panes fit
+fn tiled_split_tree(pane_count: usize) -> SplitTree {
+    let (mut rows, mut columns) = (1, 1);
+    while rows * columns < pane_count {
+        rows += 1;
+        if rows * columns < pane_count {
+            columns += 1;
+        }
+    }
+    if rows == 1 {
+        return SplitTree::Pane;
+    }
+    let mut remaining = pane_count;
+    let mut row_trees = vec![];
+    for _ in 0..rows {
+        let panes_in_row = std::cmp::min(remaining, columns);
+        remaining -= panes_in_row;
+        let row = if panes_in_row == 1 {
+            SplitTree::Pane
+        } else {
+            SplitTree::Split {
+                direction: SplitDirection::Vertical,
+                children: (0..panes_in_row).map(|_| (None, SplitTree::Pane)).collect(),
+            }
+        };
+        row_trees.push((None, row));
+    }
+    SplitTree::Split {
+        direction: SplitDirection::Horizontal,
+        children: row_trees,
+    }
+}
+
+// parses a custom tmux layout string as printed by `tmux list-windows`, eg.
+// "5e1f,238x59,0,0{119x59,0,0,1,118x59,120,0[118x29,120,0,2,118x29,120,30,3]}"
+fn parse_tmux_layout_string(layout: &str) -> Result<SplitTree, String> {
+    let invalid_layout = || format!("Unknown layout: \"{}\"", layout);
+    let (checksum, cells) = layout.split_once(',').ok_or_else(invalid_layout)?;
+    if checksum.len() != 4 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid_layout());
+    }
+    let mut chars = cells.chars().peekable();
+    let (_, _, tree) = parse_layout_cell(&mut chars).ok_or_else(invalid_layout)?;
+    if chars.next().is_some() {
+        return Err(invalid_layout());
+    }
+    Ok(tree)
+}
+
+fn parse_layout_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<usize> {
+    let mut number = String::new();
+    while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+        number.push(*c);
+        chars.next();
+    }
+    number.parse().ok()
+}
+
+fn expect_char(chars: &mut std::iter::Peekable<std::str::Chars>, expected: char) -> Option<()> {
+    chars.next().filter(|c| *c == expected).map(|_| ())
+}
+
+// returns (width, height, tree) of the cell
+fn parse_layout_cell(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Option<(usize, usize, SplitTree)> {
+    let width = parse_layout_number(chars)?;
+    expect_char(chars, 'x')?;
+    let height = parse_layout_number(chars)?;
+    expect_char(chars, ',')?;
+    let _x = parse_layout_number(chars)?;
+    expect_char(chars, ',')?;
+    let _y = parse_layout_number(chars)?;
+    let (direction, closing) = match chars.peek() {
+        Some('{') => (SplitDirection::Vertical, '}'),
+        Some('[') => (SplitDirection::Horizontal, ']'),
+        _ => {
+            expect_char(chars, ',')?;
+            let _pane_id = parse_layout_number(chars)?;
+            return Some((width, height, SplitTree::Pane));
+        },
+    };
+    chars.next();
+    let mut cells = vec![];
+    loop {
+        cells.push(parse_layout_cell(chars)?);
+        match chars.next() {
+            Some(',') => continue,
+            Some(c) if c == closing => break,
+            _ => return None,
+        }
+    }
+    let parent_size = match direction {
+        SplitDirection::Vertical => width,
+        SplitDirection::Horizontal => height,
+    };
+    let child_count = cells.len();
+    let children = cells
+        .into_iter()
+        .enumerate()
+        .map(|(i, (child_width, child_height, tree))| {
+            let child_size = match direction {
+                SplitDirection::Vertical => child_width,
+                SplitDirection::Horizontal => child_height,
+            };
+            // the last child takes whatever is left so that the sizes always add up
+            let percent = if i + 1 == child_count || parent_size == 0 {
+                None
+            } else {
+                Some(std::cmp::max(1, child_size * 100 / parent_size))
+            };
+            (percent, tree)
+        })
+        .collect();
+    Some((
+        width,
+        height,
+        SplitTree::Split {
+            direction,
+            children,
+        },
+    ))
+}
+
+fn stringify_project(project: &TmuxProject) -> Result<String, String> {
+    let mut kdl_layout = String::new();
+    kdl_layout.push_str("layout {");
+    if let Some(root) = &project.root {
+        // we use debug print here so that quotes and backslashes will be escaped
+        kdl_layout.push_str(&format!("\n    cwd {:?}", root));
+    }
+    kdl_layout.push_str("\n    default_tab_template {");
+    kdl_layout.push_str("\n        pane size=1 borderless=true {");
+    kdl_layout.push_str("\n            plugin location=\"zellij:tab-bar\"");
+    kdl_layout.push_str("\n        }");
+    kdl_layout.push_str("\n        children");
+    kdl_layout.push_str("\n        pane size=2 borderless=true {");
+    kdl_layout.push_str("\n            plugin location=\"zellij:status-bar\"");
+    kdl_layout.push_str("\n        }");
+    kdl_layout.push_str("\n    }");
+    for window in &project.windows {
+        let tree = split_tree_for_layout(window.layout.as_deref(), window.panes.len())?;
+        let mut tab_line = String::from("tab");
+        if let Some(name) = &window.name {
+            tab_line.push_str(&format!(" name={:?}", name));
+        }
+        if window.focus {
+            tab_line.push_str(" focus=true");
+        }
+        if let Some(root) = &window.root {
+            tab_line.push_str(&format!(" cwd={:?}", root));
+        }
+        kdl_layout.push_str(&format!("\n    {} {{", tab_line));
+        let mut panes = window.panes.iter();
+        stringify_split_tree(&tree, None, &mut panes, "        ", &mut kdl_layout);
+        kdl_layout.push_str("\n    }");
+    }
+    kdl_layout.push_str("\n}");
+    if let Some(name) = &project.name {
+        kdl_layout.push_str(&format!("\nsession_name {:?}", name));
+        kdl_layout.push_str("\nattach_to_session true");
+    }
+    Ok(kdl_layout)
+}
+
+fn stringify_split_tree<'a>(
+    tree: &SplitTree,
+    size: Option<usize>,
+    panes: &mut impl Iterator<Item = &'a TmuxPane>,
+    indentation: &str,
+    kdl_layout: &mut String,
+) {
+    match tree {
+        SplitTree::Pane => {
+            // layouts may have more slots than there are panes, those stay empty
+            let pane = panes.next().cloned().unwrap_or_default();
+            stringify_pane(&pane, size, indentation, kdl_layout);
+        },
+        SplitTree::Split {
+            direction,
+            children,
+        } => {
+            let mut pane_line = String::from("pane");
+            if let Some(size) = size {
+                pane_line.push_str(&format!(" size=\"{}%\"", size));
+            }
+            pane_line.push_str(&format!(" split_direction=\"{}\"", direction.as_kdl()));
+            kdl_layout.push_str(&format!("\n{}{} {{", indentation, pane_line));
+            let child_indentation = format!("{}    ", indentation);
+            for (child_size, child) in children {
+                stringify_split_tree(child, *child_size, panes, &child_indentation, kdl_layout);
+            }
+            kdl_layout.push_str(&format!("\n{}}}", indentation));
+        },
+    }
+}
+
+fn stringify_pane(
+    pane: &TmuxPane,
+    size: Option<usize>,
+    indentation: &str,
+    kdl_layout: &mut String,
+) {
+    let mut pane_line = String::from("pane");
+    if let Some(name) = &pane.name {
+        pane_line.push_str(&format!(" name={:?}", name));
+    }
+    if let Some(size) = size {
+        pane_line.push_str(&format!(" size=\"{}%\"", size));
+    }
+    if pane.focus {
+        pane_line.push_str(" focus=true");
+    }
+    if let Some(root) = &pane.root {
+        pane_line.push_str(&format!(" cwd={:?}", root));
+    }
+    let (command, args) = command_and_args(&pane.commands);
+    if let Some(command) = command {
+        pane_line.push_str(&format!(" command={:?}", command));
+    }
+    if args.is_empty() {
+        kdl_layout.push_str(&format!("\n{}{}", indentation, pane_line));
+    } else {
+        let args: Vec<String> = args.iter().map(|a| format!("{:?}", a)).collect();
+        kdl_layout.push_str(&format!(
+            "\n{}{} {{\n{}    args {}\n{}}}",
+            indentation,
+            pane_line,
+            indentation,
+            args.join(" "),
+            indentation
+        ));
+    }
+}
+
+// tmux types the commands into an interactive shell which stays around once they are done. A
+// single plain command can be run directly, anything else (several commands, pipes, shell
+// builtins...) is handed to a shell which is then replaced by the user's shell
+fn command_and_args(commands: &[String]) -> (Option<String>, Vec<String>) {
+    const SHELL_BUILTINS: &[&str] = &[
+        "cd", "export", "source", ".", "set", "unset", "alias", "eval", "ulimit", "umask",
+    ];
+    let is_plain_command = |command: &str| {
+        let is_builtin = command
+            .split_whitespace()
+            .next()
+            .map(|first_word| SHELL_BUILTINS.contains(&first_word))
+            .unwrap_or(false);
+        !is_builtin
+            && command.chars().all(|c| {
+                c.is_ascii_alphanumeric() || c.is_ascii_whitespace() || "-_./=:@%+,".contains(c)
+            })
+    };
+    match commands {
+        [] => (None, vec![]),
+        [command] if is_plain_command(command) => {
+            let mut words = command.split_whitespace().map(|w| w.to_owned());
+            match words.next() {
+                Some(command) => (Some(command), words.collect()),
+                None => (None, vec![]),
+            }
+        },
+        commands => (
+            Some("bash".to_owned()),
+            vec![
+                "-c".to_owned(),
+                format!("{}; exec \"${{SHELL:-bash}}\"", commands.join("; ")),
+            ],
+        ),
+    }
+}
+
+// The unit test location.
+#[path = "./unit/convert_tmux_project_tests.rs"]
+#[cfg(test)]
+mod convert_tmux_project_test;
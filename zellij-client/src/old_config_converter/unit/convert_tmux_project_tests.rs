@@ -0,0 +1,83 @@
+use crate::old_config_converter::{tmuxinator_to_layout_kdl, tmuxp_to_layout_kdl};
+use insta::assert_snapshot;
+use std::path::PathBuf;
+use std::{fs::File, io::prelude::*};
+
+fn read_fixture(file_name: &str) -> Result<String, String> {
+    let fixture = PathBuf::from(format!(
+        "{}/src/old_config_converter/unit/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        file_name
+    ));
+    let mut handle = File::open(&fixture).map_err(|e| format!("{}", e))?;
+    let mut raw_project_file = String::new();
+    handle
+        .read_to_string(&mut raw_project_file)
+        .map_err(|e| format!("{}", e))?;
+    Ok(raw_project_file)
+}
+
+#[test]
+fn properly_convert_tmuxinator_project() -> Result<(), String> {
+    let raw_project_file = read_fixture("tmuxinator_project.yml")?;
+    let kdl_layout = tmuxinator_to_layout_kdl(&raw_project_file)?;
+    assert_snapshot!(format!("{}", kdl_layout));
+    Ok(())
+}
+
+#[test]
+fn properly_convert_tmuxinator_project_with_custom_layout() -> Result<(), String> {
+    let raw_project_file = read_fixture("tmuxinator_project_with_custom_layout.yml")?;
+    let kdl_layout = tmuxinator_to_layout_kdl(&raw_project_file)?;
+    assert_snapshot!(format!("{}", kdl_layout));
+    Ok(())
+}
+
+#[test]
+fn properly_convert_tmuxp_session() -> Result<(), String> {
+    let raw_project_file = read_fixture("tmuxp_session.yaml")?;
+    let kdl_layout = tmuxp_to_layout_kdl(&raw_project_file)?;
+    assert_snapshot!(format!("{}", kdl_layout));
+    Ok(())
+}
+
+#[test]
+fn error_on_unknown_tmux_layout() -> Result<(), String> {
+    let raw_project_file = read_fixture("tmuxinator_project_with_unknown_layout.yml")?;
+    let error = tmuxinator_to_layout_kdl(&raw_project_file).unwrap_err();
+    assert_eq!(error, "Unknown layout: \"diagonal\"");
+    Ok(())
+}
+
+#[test]
+fn error_on_tmux_layout_with_fewer_panes_than_window() -> Result<(), String> {
+    let raw_project_file = read_fixture("tmuxinator_project_with_too_many_panes.yml")?;
+    let error = tmuxinator_to_layout_kdl(&raw_project_file).unwrap_err();
+    assert!(
+        error.contains("has 2 panes but the window has 3"),
+        "{}",
+        error
+    );
+    Ok(())
+}
+
+#[test]
+fn converted_tmux_projects_are_valid_layouts() -> Result<(), String> {
+    use zellij_utils::input::layout::Layout;
+    for (file_name, convert) in [
+        (
+            "tmuxinator_project.yml",
+            tmuxinator_to_layout_kdl as fn(&str) -> Result<String, String>,
+        ),
+        (
+            "tmuxinator_project_with_custom_layout.yml",
+            tmuxinator_to_layout_kdl,
+        ),
+        ("tmuxp_session.yaml", tmuxp_to_layout_kdl),
+    ] {
+        let kdl_layout = convert(&read_fixture(file_name)?)?;
+        Layout::from_kdl(&kdl_layout, None, None, None)
+            .map_err(|e| format!("{} did not convert to a valid layout: {:?}", file_name, e))?;
+    }
+    Ok(())
+}
@@ -1,9 +1,12 @@
+use crate::web_client::types::AuthTokenName;
 use crate::web_client::utils::parse_cookies;
 use axum::body::Body;
 use axum::http::header::SET_COOKIE;
 use axum::{extract::Request, http::StatusCode, middleware::Next, response::Response};
 use axum_extra::extract::cookie::{Cookie, SameSite};
-use zellij_utils::web_authentication_tokens::{is_session_token_read_only, validate_session_token};
+use zellij_utils::web_authentication_tokens::{
+    is_session_token_read_only, session_token_name, validate_session_token,
+};
 
 pub async fn auth_middleware(request: Request, next: Next) -> Result<Response, StatusCode> {
     let cookies = parse_cookies(&request);
@@ -21,6 +24,9 @@ pub async fn auth_middleware(request: Request, next: Next) -> Result<Response, S
             // Store in request extensions for downstream handlers
             let mut request = request;
             request.extensions_mut().insert(is_read_only);
+            if let Ok(name) = session_token_name(&session_token) {
+                request.extensions_mut().insert(AuthTokenName(name));
+            }
 
             let response = next.run(request).await;
             Ok(response)
@@ -11,9 +11,14 @@ impl ConnectionTable {
         client_id: String,
         client_os_api: Box<dyn ClientOsApi>,
         is_read_only: bool,
+        auth_token_name: Option<String>,
     ) {
         self.client_id_to_channels
             .insert(client_id.clone(), ClientChannels::new(client_os_api));
+        if let Some(auth_token_name) = auth_token_name {
+            self.client_auth_token_names
+                .insert(client_id.clone(), auth_token_name);
+        }
         self.client_read_only_status.insert(client_id, is_read_only);
     }
 
@@ -24,6 +29,10 @@ impl ConnectionTable {
             .unwrap_or(false)
     }
 
+    pub fn get_client_auth_token_name(&self, client_id: &str) -> Option<String> {
+        self.client_auth_token_names.get(client_id).cloned()
+    }
+
     pub fn add_client_control_tx(
         &mut self,
         client_id: &str,
@@ -75,6 +84,7 @@ impl ConnectionTable {
             client_channels.cleanup();
         }
         self.client_read_only_status.remove(client_id);
+        self.client_auth_token_names.remove(client_id);
     }
 }
 
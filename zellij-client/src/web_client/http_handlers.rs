@@ -1,15 +1,24 @@
-use crate::web_client::types::{AppState, CreateClientIdResponse, LoginRequest, LoginResponse};
+use crate::web_client::session_management::send_plugin_http_request;
+use crate::web_client::types::{
+    AppState, AuthTokenName, CreateClientIdResponse, LoginRequest, LoginResponse,
+};
 use crate::web_client::utils::{get_mime_type, parse_cookies};
 use axum::{
+    body::Bytes,
     extract::{Path as AxumPath, Request, State},
-    http::{header, StatusCode},
-    response::{Html, IntoResponse},
+    http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri},
+    response::{Html, IntoResponse, Response},
     Json,
 };
 use axum_extra::extract::cookie::{Cookie, SameSite};
 use include_dir;
+use std::collections::HashMap;
 use uuid::Uuid;
-use zellij_utils::{consts::VERSION, web_authentication_tokens::create_session_token};
+use zellij_utils::{
+    consts::VERSION,
+    data::{PluginHttpRequest, PluginHttpResponse},
+    web_authentication_tokens::create_session_token,
+};
 
 const WEB_CLIENT_PAGE: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -98,6 +107,10 @@ pub async fn create_new_client(
 ) -> Result<Json<CreateClientIdResponse>, (StatusCode, impl IntoResponse)> {
     // Extract is_read_only from request extensions (set by auth middleware)
     let is_read_only = request.extensions().get::<bool>().copied().unwrap_or(false);
+    let auth_token_name = request
+        .extensions()
+        .get::<AuthTokenName>()
+        .map(|name| name.0.clone());
 
     let web_client_id = String::from(Uuid::new_v4());
     let os_input = state
@@ -109,6 +122,7 @@ pub async fn create_new_client(
         web_client_id.to_owned(),
         os_input,
         is_read_only,
+        auth_token_name,
     );
 
     Ok(Json(CreateClientIdResponse {
@@ -136,3 +150,70 @@ pub async fn get_static_asset(AxumPath(path): AxumPath<String>) -> impl IntoResp
 pub async fn version_handler() -> &'static str {
     VERSION
 }
+
+pub async fn plugin_http_handler(
+    State(state): State<AppState>,
+    AxumPath(params): AxumPath<HashMap<String, String>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let Some(route) = params.get("route").cloned() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let request = PluginHttpRequest {
+        route,
+        method: method.to_string(),
+        path: format!("/{}", params.get("path").map(|p| p.as_str()).unwrap_or("")),
+        query: uri.query().unwrap_or("").to_owned(),
+        headers: headers
+            .iter()
+            // plugins do not get to see the session token
+            .filter(|(name, _value)| *name != header::COOKIE)
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_owned()))
+            })
+            .collect(),
+        body: body.to_vec(),
+    };
+    let session_manager = state.session_manager.clone();
+    let client_os_api_factory = state.client_os_api_factory.clone();
+    let response = tokio::task::spawn_blocking(move || {
+        send_plugin_http_request(
+            request,
+            session_manager.list_sessions(),
+            &*client_os_api_factory,
+        )
+    })
+    .await;
+    match response {
+        Ok(Some(response)) => plugin_http_response_into_response(response),
+        Ok(None) => (StatusCode::NOT_FOUND, "No plugin serves this route").into_response(),
+        Err(e) => {
+            log::error!("Failed to forward request to plugin: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        },
+    }
+}
+
+fn plugin_http_response_into_response(plugin_http_response: PluginHttpResponse) -> Response {
+    let status =
+        StatusCode::from_u16(plugin_http_response.status).unwrap_or(StatusCode::BAD_GATEWAY);
+    let mut response = (status, plugin_http_response.body).into_response();
+    for (name, value) in plugin_http_response.headers {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                response.headers_mut().insert(name, value);
+            },
+            _ => log::warn!("Dropping invalid header in plugin response: {}", name),
+        }
+    }
+    response
+}
@@ -91,6 +91,10 @@ pub fn zellij_server_listener(
                         .lock()
                         .unwrap()
                         .is_client_read_only(&web_client_id);
+                    let client_name = connection_table
+                        .lock()
+                        .unwrap()
+                        .get_client_auth_token_name(&web_client_id);
 
 
                     let session_exists = session_manager.session_exists(&session_name).unwrap_or(false);
@@ -102,7 +106,7 @@ pub fn zellij_server_listener(
                     }
 
                     let should_create_new_session = !session_exists;
-                    let first_message = create_first_message(is_read_only, config_file_path.clone(), client_attributes.clone(), config_options.clone(), should_create_new_session, &session_name);
+                    let first_message = create_first_message(is_read_only, client_name, config_file_path.clone(), client_attributes.clone(), config_options.clone(), should_create_new_session, &session_name);
                     let zellij_ipc_pipe = create_ipc_pipe(&session_name);
 
                     session_manager.spawn_session_if_needed(
@@ -137,6 +141,7 @@ pub fn zellij_server_listener(
                             Some(ServerToClientMsg::CliPipeOutput { .. } ) => {},
                             Some(ServerToClientMsg::UnblockCliPipeInput { .. } ) => {},
                             Some(ServerToClientMsg::StartWebServer { .. } ) => {},
+                            Some(ServerToClientMsg::PluginHttpResponse { .. } ) => {},
                             Some(ServerToClientMsg::Exit{exit_reason}) => {
                                 handle_exit_reason(&mut client_connection_bus, exit_reason);
                                 os_input.send_to_server(ClientToServerMsg::ClientExited);
@@ -1,13 +1,17 @@
 use crate::os_input_output::ClientOsApi;
 use crate::spawn_server;
+use crate::web_client::types::ClientOsApiFactory;
 
 use std::{fs, path::PathBuf};
 use zellij_utils::{
     consts::session_layout_cache_file_name,
-    data::{ConnectToSession, LayoutInfo, LayoutMetadata, WebSharing},
+    data::{
+        ConnectToSession, LayoutInfo, LayoutMetadata, PluginHttpRequest, PluginHttpResponse,
+        WebSharing,
+    },
     envs,
     input::{cli_assets::CliAssets, config::Config, options::Options},
-    ipc::{ClientAttributes, ClientToServerMsg},
+    ipc::{ClientAttributes, ClientToServerMsg, ServerToClientMsg},
     sessions::{generate_unique_session_name, resurrection_layout},
 };
 
@@ -57,6 +61,7 @@ pub fn spawn_new_session(
 
 pub fn create_first_message(
     is_read_only: bool,
+    client_name: Option<String>,
     config_file_path: Option<PathBuf>,
     client_attributes: ClientAttributes,
     mut config_opts: Options,
@@ -101,6 +106,7 @@ pub fn create_first_message(
             max_panes: None,
             force_run_layout_commands: false,
             cwd: None,
+            client_name,
         };
 
         ClientToServerMsg::FirstClientConnected {
@@ -120,6 +126,7 @@ pub fn create_first_message(
             max_panes: None,
             force_run_layout_commands: false,
             cwd: None,
+            client_name,
         };
         let is_web_client = true;
 
@@ -142,3 +149,39 @@ pub fn create_ipc_pipe(session_name: &str) -> PathBuf {
     };
     zellij_ipc_pipe
 }
+
+/// Asks each session in turn for a plugin serving this request's route, returning the response
+/// of the first one that does
+///
+/// Blocks until the serving plugin responds (or its session times the request out), so this
+/// should be called from a blocking task
+pub fn send_plugin_http_request(
+    request: PluginHttpRequest,
+    session_names: Vec<String>,
+    client_os_api_factory: &dyn ClientOsApiFactory,
+) -> Option<PluginHttpResponse> {
+    for session_name in session_names {
+        let os_input = match client_os_api_factory.create_client_os_api() {
+            Ok(os_input) => os_input,
+            Err(e) => {
+                log::error!("Failed to create client os api: {}", e);
+                return None;
+            },
+        };
+        let zellij_ipc_pipe = create_ipc_pipe(&session_name);
+        os_input.connect_to_server(&zellij_ipc_pipe);
+        os_input.send_to_server(ClientToServerMsg::PluginHttpRequest {
+            request: request.clone(),
+        });
+        // the session disconnects us once it answers
+        while let Some((msg, _)) = os_input.recv_from_server() {
+            if let ServerToClientMsg::PluginHttpResponse { response } = msg {
+                if response.is_some() {
+                    return response;
+                }
+                break;
+            }
+        }
+    }
+    None
+}
@@ -30,6 +30,7 @@ impl ClientOsApiFactory for RealClientOsApiFactory {
 
 pub trait SessionManager: Send + Sync + std::fmt::Debug {
     fn session_exists(&self, session_name: &str) -> Result<bool, Box<dyn std::error::Error>>;
+    fn list_sessions(&self) -> Vec<String>;
     fn get_resurrection_layout(
         &self,
         session_name: &str,
@@ -53,6 +54,17 @@ impl SessionManager for RealSessionManager {
             .map_err(|e| format!("Session check failed: {:?}", e).into())
     }
 
+    fn list_sessions(&self) -> Vec<String> {
+        zellij_utils::sessions::get_sessions()
+            .map(|sessions| {
+                sessions
+                    .into_iter()
+                    .map(|(session_name, _duration)| session_name)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn get_resurrection_layout(
         &self,
         session_name: &str,
@@ -82,6 +94,7 @@ impl SessionManager for RealSessionManager {
 pub struct ConnectionTable {
     pub client_id_to_channels: HashMap<String, ClientChannels>,
     pub client_read_only_status: HashMap<String, bool>,
+    pub client_auth_token_names: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone)]
@@ -164,6 +177,10 @@ pub struct AppState {
     pub is_https: bool,
 }
 
+/// The name of the login token a request was authenticated with, set by the auth middleware
+#[derive(Debug, Clone)]
+pub struct AuthTokenName(pub String);
+
 #[derive(Serialize)]
 pub struct CreateClientIdResponse {
     pub web_client_id: String,
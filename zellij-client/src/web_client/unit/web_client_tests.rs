@@ -19,7 +19,7 @@ use crate::web_client::control_message::{
 };
 use crate::web_client::ClientOsApiFactory;
 use zellij_utils::{
-    data::{LayoutInfo, Palette},
+    data::{LayoutInfo, Palette, PluginHttpRequest, PluginHttpResponse},
     errors::ErrorContext,
     ipc::{ClientAttributes, ClientToServerMsg, ServerToClientMsg},
     pane_size::Size,
@@ -1664,6 +1664,202 @@ mod web_client_tests {
         tokio::time::sleep(Duration::from_millis(100)).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_plugin_http_route_forwards_request_to_session() {
+        let _ = delete_db();
+
+        let (auth_token, _) = create_token(Some("plugin_routes".to_string()), false).unwrap();
+
+        let mut session_manager = MockSessionManager::new();
+        session_manager
+            .mock_sessions
+            .insert("dashboard-session".to_owned(), true);
+        let session_manager = Arc::new(session_manager);
+        let plugin_http_response = PluginHttpResponse::new(200, b"{\"ok\":true}".to_vec())
+            .with_header("content-type", "application/json");
+        let client_os_api_factory =
+            Arc::new(MockClientOsApiFactory::with_messages_from_server(vec![
+                ServerToClientMsg::PluginHttpResponse {
+                    response: Some(plugin_http_response),
+                },
+            ]));
+
+        let config = Config::default();
+        let options = Options::default();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let port = addr.port();
+
+        let temp_config_path = std::env::temp_dir().join("test_config.kdl");
+        let server_handle = tokio::spawn({
+            let client_os_api_factory = client_os_api_factory.clone();
+            async move {
+                serve_web_client(
+                    config,
+                    options,
+                    Some(temp_config_path),
+                    listener,
+                    None,
+                    Some(session_manager),
+                    Some(client_os_api_factory),
+                    addr.ip(),
+                    port,
+                )
+                .await;
+            }
+        });
+
+        wait_for_server(port, Duration::from_secs(5))
+            .await
+            .expect("Server failed to start");
+
+        let plugin_url = format!(
+            "http://127.0.0.1:{}/plugins/dashboard/api/status?verbose=true",
+            port
+        );
+        let unauthenticated_response = timeout(
+            Duration::from_secs(5),
+            tokio::task::spawn_blocking({
+                let plugin_url = plugin_url.clone();
+                move || isahc::get(&plugin_url)
+            }),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+        assert_eq!(unauthenticated_response.status(), 401);
+        assert!(
+            client_os_api_factory.get_all_sent_messages().is_empty(),
+            "unauthenticated requests do not reach the sessions"
+        );
+
+        let session_token = login_and_get_session_token(port, &auth_token).await;
+        let mut response = timeout(
+            Duration::from_secs(5),
+            tokio::task::spawn_blocking(move || {
+                isahc::Request::post(&plugin_url)
+                    .header("Cookie", format!("session_token={}", session_token))
+                    .header("X-Bloodbank-Event", "deploy")
+                    .body("payload")
+                    .unwrap()
+                    .send()
+            }),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        assert_eq!(response.text().unwrap(), "{\"ok\":true}");
+
+        let sent_requests: Vec<PluginHttpRequest> = client_os_api_factory
+            .get_all_sent_messages()
+            .into_iter()
+            .filter_map(|msg| match msg {
+                ClientToServerMsg::PluginHttpRequest { request } => Some(request),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(sent_requests.len(), 1);
+        let sent_request = &sent_requests[0];
+        assert_eq!(sent_request.route, "dashboard");
+        assert_eq!(sent_request.method, "POST");
+        assert_eq!(sent_request.path, "/api/status");
+        assert_eq!(sent_request.query, "verbose=true");
+        assert_eq!(sent_request.body, b"payload".to_vec());
+        assert_eq!(
+            sent_request
+                .headers
+                .get("x-bloodbank-event")
+                .map(|v| v.as_str()),
+            Some("deploy")
+        );
+        assert!(
+            !sent_request.headers.contains_key("cookie"),
+            "the session token is not forwarded to plugins"
+        );
+
+        server_handle.abort();
+        let _ = delete_db();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_plugin_http_route_not_served_by_any_session() {
+        let _ = delete_db();
+
+        let (auth_token, _) = create_token(Some("plugin_routes".to_string()), false).unwrap();
+
+        let mut session_manager = MockSessionManager::new();
+        session_manager
+            .mock_sessions
+            .insert("session-without-routes".to_owned(), true);
+        let session_manager = Arc::new(session_manager);
+        let client_os_api_factory =
+            Arc::new(MockClientOsApiFactory::with_messages_from_server(vec![
+                ServerToClientMsg::PluginHttpResponse { response: None },
+            ]));
+
+        let config = Config::default();
+        let options = Options::default();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let port = addr.port();
+
+        let temp_config_path = std::env::temp_dir().join("test_config.kdl");
+        let server_handle = tokio::spawn(async move {
+            serve_web_client(
+                config,
+                options,
+                Some(temp_config_path),
+                listener,
+                None,
+                Some(session_manager),
+                Some(client_os_api_factory),
+                addr.ip(),
+                port,
+            )
+            .await;
+        });
+
+        wait_for_server(port, Duration::from_secs(5))
+            .await
+            .expect("Server failed to start");
+
+        let session_token = login_and_get_session_token(port, &auth_token).await;
+        let plugin_url = format!("http://127.0.0.1:{}/plugins/dashboard", port);
+        let response = timeout(
+            Duration::from_secs(5),
+            tokio::task::spawn_blocking(move || {
+                isahc::Request::get(&plugin_url)
+                    .header("Cookie", format!("session_token={}", session_token))
+                    .body(())
+                    .unwrap()
+                    .send()
+            }),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(response.status(), 404);
+
+        server_handle.abort();
+        let _ = delete_db();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
     // Helper function to login and get session token
     async fn login_and_get_session_token(port: u16, auth_token: &str) -> String {
         let login_url = format!("http://127.0.0.1:{}/command/login", port);
@@ -1815,6 +2011,17 @@ impl SessionManager for MockSessionManager {
         }
     }
 
+    fn list_sessions(&self) -> Vec<String> {
+        let mut sessions: Vec<String> = self
+            .mock_sessions
+            .iter()
+            .filter(|(_session_name, exists)| **exists)
+            .map(|(session_name, _exists)| session_name.clone())
+            .collect();
+        sessions.sort();
+        sessions
+    }
+
     fn get_resurrection_layout(&self, session_name: &str) -> Option<Layout> {
         self.mock_layouts.get(session_name).cloned()
     }
@@ -1845,19 +2052,44 @@ impl SessionManager for MockSessionManager {
 #[derive(Debug, Clone)]
 struct MockClientOsApiFactory {
     mock_apis: Arc<Mutex<HashMap<String, Arc<MockClientOsApi>>>>,
+    messages_from_server: Vec<ServerToClientMsg>,
 }
 
 impl MockClientOsApiFactory {
     fn new() -> Self {
         Self {
             mock_apis: Arc::new(Mutex::new(HashMap::new())),
+            messages_from_server: vec![],
         }
     }
+
+    // every created client os api receives these messages from the server
+    fn with_messages_from_server(messages_from_server: Vec<ServerToClientMsg>) -> Self {
+        Self {
+            mock_apis: Arc::new(Mutex::new(HashMap::new())),
+            messages_from_server,
+        }
+    }
+
+    fn get_all_sent_messages(&self) -> Vec<ClientToServerMsg> {
+        self.mock_apis
+            .lock()
+            .unwrap()
+            .values()
+            .flat_map(|mock_api| mock_api.get_sent_messages())
+            .collect()
+    }
 }
 
 impl ClientOsApiFactory for MockClientOsApiFactory {
     fn create_client_os_api(&self) -> Result<Box<dyn ClientOsApi>, Box<dyn std::error::Error>> {
         let mock_api = Arc::new(MockClientOsApi::new());
+        mock_api.messages_from_server.lock().unwrap().extend(
+            self.messages_from_server
+                .iter()
+                .cloned()
+                .map(|msg| (msg, ErrorContext::new())),
+        );
 
         let client_id = uuid::Uuid::new_v4().to_string();
         self.mock_apis
@@ -6,6 +6,7 @@ use zellij_utils::consts::{
 use zellij_utils::data::{Event, HttpVerb, SessionInfo, WebServerStatus};
 use zellij_utils::errors::{prelude::*, BackgroundJobContext, ContextType};
 use zellij_utils::input::layout::RunPlugin;
+use zellij_utils::input::permission::{AuditLogEntry, PermissionAuditLog};
 use zellij_utils::shared::parse_base_url;
 
 #[cfg(feature = "web_server_capability")]
@@ -44,7 +45,9 @@ pub enum BackgroundJob {
     ReadAllSessionInfosOnMachine,                         // u32 - plugin_id
     ReportSessionInfo(String, SessionInfo),               // String - session name
     ReportPluginList(BTreeMap<PluginId, RunPlugin>),      // String - session name
+    ReportPluginSuspended(PluginId, Option<String>),      // String - reason, None if resumed
     ReportLayoutInfo((String, BTreeMap<String, String>)), // BTreeMap<file_name, pane_contents>
+    RecordPermissionUse(AuditLogEntry),
     RunCommand(
         PluginId,
         ClientId,
@@ -65,6 +68,7 @@ pub enum BackgroundJob {
     ),
     HighlightPanesWithMessage(Vec<PaneId>, String),
     RenderToClients,
+    FlushPaneOutput,
     QueryZellijWebServerStatus,
     Exit,
 }
@@ -85,7 +89,10 @@ impl From<&BackgroundJob> for BackgroundJobContext {
             BackgroundJob::RunCommand(..) => BackgroundJobContext::RunCommand,
             BackgroundJob::WebRequest(..) => BackgroundJobContext::WebRequest,
             BackgroundJob::ReportPluginList(..) => BackgroundJobContext::ReportPluginList,
+            BackgroundJob::ReportPluginSuspended(..) => BackgroundJobContext::ReportPluginSuspended,
+            BackgroundJob::RecordPermissionUse(..) => BackgroundJobContext::RecordPermissionUse,
             BackgroundJob::RenderToClients => BackgroundJobContext::ReportPluginList,
+            BackgroundJob::FlushPaneOutput => BackgroundJobContext::FlushPaneOutput,
             BackgroundJob::HighlightPanesWithMessage(..) => {
                 BackgroundJobContext::HighlightPanesWithMessage
             },
@@ -103,6 +110,7 @@ static PLUGIN_ANIMATION_OFFSET_DURATION_MD: u64 = 500;
 static SESSION_READ_DURATION: u64 = 1000;
 static DEFAULT_SERIALIZATION_INTERVAL: u64 = 60000;
 static REPAINT_DELAY_MS: u64 = 10;
+static PANE_OUTPUT_FLUSH_INTERVAL_MS: u64 = 100;
 
 pub(crate) fn background_jobs_main(
     bus: Bus<BackgroundJob>,
@@ -117,11 +125,15 @@ pub(crate) fn background_jobs_main(
     let current_session_info = Arc::new(Mutex::new(SessionInfo::default()));
     let current_session_plugin_list: Arc<Mutex<BTreeMap<PluginId, RunPlugin>>> =
         Arc::new(Mutex::new(BTreeMap::new()));
+    let suspended_plugins: Arc<Mutex<BTreeMap<PluginId, String>>> =
+        Arc::new(Mutex::new(BTreeMap::new()));
     let current_session_layout = Arc::new(Mutex::new((String::new(), BTreeMap::new())));
     let last_serialization_time = Arc::new(Mutex::new(Instant::now()));
     let serialization_interval = serialization_interval.map(|s| s * 1000); // convert to
                                                                            // milliseconds
     let last_render_request: Arc<Mutex<Option<Instant>>> = Arc::new(Mutex::new(None));
+    let pane_output_flush_pending = Arc::new(AtomicBool::new(false));
+    let mut permission_audit_log = PermissionAuditLog::from_path_or_default(None);
 
     let http_client = HttpClient::builder()
         // TODO: timeout?
@@ -188,9 +200,22 @@ pub(crate) fn background_jobs_main(
             BackgroundJob::ReportPluginList(plugin_list) => {
                 *current_session_plugin_list.lock().unwrap() = plugin_list;
             },
+            BackgroundJob::ReportPluginSuspended(plugin_id, reason) => match reason {
+                Some(reason) => {
+                    suspended_plugins.lock().unwrap().insert(plugin_id, reason);
+                },
+                None => {
+                    suspended_plugins.lock().unwrap().remove(&plugin_id);
+                },
+            },
             BackgroundJob::ReportLayoutInfo(session_layout) => {
                 *current_session_layout.lock().unwrap() = session_layout;
             },
+            BackgroundJob::RecordPermissionUse(entry) => {
+                if let Err(e) = permission_audit_log.record(&entry) {
+                    log::error!("Failed to write to the plugin audit log: {}", e);
+                }
+            },
             BackgroundJob::ReadAllSessionInfosOnMachine => {
                 // this job should only be run once and it keeps track of other sessions (as well
                 // as this one's) infos (metadata mostly) and sends it to the screen which in turn
@@ -205,6 +230,7 @@ pub(crate) fn background_jobs_main(
                     let current_session_name = current_session_name.clone();
                     let current_session_layout = current_session_layout.clone();
                     let current_session_plugin_list = current_session_plugin_list.clone();
+                    let suspended_plugins = suspended_plugins.clone();
                     let last_serialization_time = last_serialization_time.clone();
                     async move {
                         loop {
@@ -229,6 +255,9 @@ pub(crate) fn background_jobs_main(
                                     let current_session_plugin_list =
                                         current_session_plugin_list.lock().unwrap().clone();
                                     session_info.populate_plugin_list(current_session_plugin_list);
+                                    session_info.populate_suspended_plugins(
+                                        suspended_plugins.lock().unwrap().clone(),
+                                    );
                                     // these are not serialized, so must be explicitly added
                                     session_info.available_layouts = available_layouts.clone();
                                 }
@@ -455,6 +484,25 @@ pub(crate) fn background_jobs_main(
                     });
                 }
             },
+            BackgroundJob::FlushPaneOutput => {
+                // this is what rate limits the PaneOutput events: output arriving while a flush is
+                // pending is buffered by the screen and sent along with it, so plugins get at most
+                // one batch per interval no matter how chatty the pane is
+                if !pane_output_flush_pending.swap(true, Ordering::SeqCst) {
+                    task::spawn({
+                        let senders = bus.senders.clone();
+                        let pane_output_flush_pending = pane_output_flush_pending.clone();
+                        async move {
+                            task::sleep(std::time::Duration::from_millis(
+                                PANE_OUTPUT_FLUSH_INTERVAL_MS,
+                            ))
+                            .await;
+                            pane_output_flush_pending.store(false, Ordering::SeqCst);
+                            let _ = senders.send_to_screen(ScreenInstruction::FlushPaneOutput);
+                        }
+                    });
+                }
+            },
             BackgroundJob::HighlightPanesWithMessage(pane_ids, text) => {
                 if job_already_running(job, &mut running_jobs) {
                     continue;
@@ -42,7 +42,7 @@ use wasmi::Engine;
 
 use crate::{
     os_input_output::ServerOsApi,
-    plugins::{plugin_thread_main, PluginInstruction},
+    plugins::{plugin_thread_main, PluginInstruction, DEFAULT_PLUGIN_EXECUTION_BUDGET},
     pty::{get_default_shell, pty_thread_main, Pty, PtyInstruction},
     screen::{screen_thread_main, ScreenInstruction},
     thread_bus::{Bus, ThreadSenders},
@@ -55,7 +55,7 @@ use zellij_utils::{
     },
     data::{
         ConnectToSession, Event, InputMode, KeyWithModifier, LayoutInfo, LayoutWithError,
-        PluginCapabilities, Style, WebSharing,
+        PluginCapabilities, PluginHttpRequest, PluginHttpResponse, Style, WebSharing,
     },
     errors::{prelude::*, ContextType, ErrorInstruction, FatalError, ServerContext},
     home::{default_layout_dir, get_default_data_dir},
@@ -130,6 +130,8 @@ pub enum ServerInstruction {
     SendWebClientsForbidden(ClientId),
     WebServerStarted(String), // String -> base_url
     FailedToStartWebServer(String),
+    PluginHttpRequest(ClientId, PluginHttpRequest),
+    PluginHttpResponse(ClientId, Option<PluginHttpResponse>), // None -> no plugin serves the route
 }
 
 impl From<&ServerInstruction> for ServerContext {
@@ -177,6 +179,8 @@ impl From<&ServerInstruction> for ServerContext {
             ServerInstruction::SendWebClientsForbidden(..) => {
                 ServerContext::SendWebClientsForbidden
             },
+            ServerInstruction::PluginHttpRequest(..) => ServerContext::PluginHttpRequest,
+            ServerInstruction::PluginHttpResponse(..) => ServerContext::PluginHttpResponse,
         }
     }
 }
@@ -778,6 +782,16 @@ pub fn start_server(mut os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                     client_attributes.size,
                     is_web_client,
                 );
+                if let Some(client_name) = cli_assets.client_name.clone() {
+                    session_data
+                        .read()
+                        .unwrap()
+                        .as_ref()
+                        .unwrap()
+                        .senders
+                        .send_to_screen(ScreenInstruction::SetClientName(client_id, client_name))
+                        .unwrap();
+                }
 
                 let default_shell = runtime_config_options.default_shell.map(|shell| {
                     TerminalAction::RunCommand(RunCommand {
@@ -936,6 +950,12 @@ pub fn start_server(mut os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                         pane_id_to_focus,
                     ))
                     .unwrap();
+                if let Some(client_name) = cli_assets.client_name {
+                    session_data
+                        .senders
+                        .send_to_screen(ScreenInstruction::SetClientName(client_id, client_name))
+                        .unwrap();
+                }
                 session_data
                     .senders
                     .send_to_plugin(PluginInstruction::AddClient(client_id))
@@ -1646,6 +1666,30 @@ pub fn start_server(mut os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                     .send_to_plugin(PluginInstruction::FailedToStartWebServer(error))
                     .unwrap();
             },
+            ServerInstruction::PluginHttpRequest(client_id, request) => {
+                let sent_to_plugins = session_data.read().unwrap().as_ref().map(|session_data| {
+                    session_data
+                        .senders
+                        .send_to_plugin(PluginInstruction::HttpRequest {
+                            web_client_id: client_id,
+                            request,
+                        })
+                });
+                if !matches!(sent_to_plugins, Some(Ok(()))) {
+                    let _ = os_input.send_to_client(
+                        client_id,
+                        ServerToClientMsg::PluginHttpResponse { response: None },
+                    );
+                    remove_client!(client_id, os_input, session_state);
+                }
+            },
+            ServerInstruction::PluginHttpResponse(client_id, response) => {
+                let _ = os_input.send_to_client(
+                    client_id,
+                    ServerToClientMsg::PluginHttpResponse { response },
+                );
+                remove_client!(client_id, os_input, session_state);
+            },
         }
     }
 
@@ -1812,6 +1856,9 @@ fn init_session(
                 .clone()
                 .or_else(|| default_layout_dir());
             let background_plugins = config.background_plugins.clone();
+            let execution_budget = config_options
+                .plugin_execution_budget
+                .unwrap_or(DEFAULT_PLUGIN_EXECUTION_BUDGET);
             move || {
                 plugin_thread_main(
                     plugin_bus,
@@ -1830,6 +1877,7 @@ fn init_session(
                     default_mode,
                     default_keybinds,
                     background_plugins,
+                    execution_budget,
                     client_id,
                 )
                 .fatal()
@@ -2135,7 +2183,11 @@ fn update_new_saved_config(
 
 pub fn get_engine() -> Engine {
     log::info!("Loading plugins using Wasmi interpreter");
-    Engine::default()
+    let mut config = wasmi::Config::default();
+    // fuel lets us suspend plugins that exceed their execution budget rather than having them
+    // block their thread indefinitely
+    config.consume_fuel(true);
+    Engine::new(&config)
 }
 
 // TODO: move elsewhere
@@ -16,7 +16,7 @@ use std::{
 use vte;
 use zellij_utils::{
     consts::{DEFAULT_SCROLL_BUFFER_SIZE, SCROLL_BUFFER_SIZE},
-    data::{Palette, PaletteColor, Styling},
+    data::{DumpScreenFormat, Palette, PaletteColor, Styling},
     input::mouse::{MouseEvent, MouseEventType},
     pane_size::SizeInPixels,
     position::Position,
@@ -33,6 +33,7 @@ use crate::panes::alacritty_functions::{parse_number, xparse_color};
 use crate::panes::hyperlink_tracker::HyperlinkTracker;
 use crate::panes::link_handler::LinkHandler;
 use crate::panes::search::SearchResult;
+use crate::panes::styled_dump::{dump_styled_rows, DumpPalette};
 use crate::panes::terminal_character::{
     AnsiCode, CharsetIndex, Cursor, CursorShape, RcCharacterStyles, StandardCharset,
     TerminalCharacter, EMPTY_TERMINAL_CHARACTER,
@@ -1233,6 +1234,51 @@ impl Grid {
         scrollback.push_str(&viewport);
         scrollback
     }
+    pub fn dump_styled_screen(&self, full: bool, format: DumpScreenFormat) -> String {
+        if format == DumpScreenFormat::Text {
+            return self.dump_screen(full);
+        }
+        let rows: Vec<&Row> = if full {
+            self.lines_above
+                .iter()
+                .chain(self.viewport.iter())
+                .collect()
+        } else {
+            self.viewport.iter().collect()
+        };
+        dump_styled_rows(
+            &rows,
+            format,
+            &self.dump_palette(),
+            &self.link_handler.borrow(),
+            self.title.as_deref(),
+        )
+    }
+    fn dump_palette(&self) -> DumpPalette {
+        let mut palette = DumpPalette::default();
+        let terminal_emulator_colors = self.terminal_emulator_colors.borrow();
+        if let PaletteColor::Rgb(foreground) = terminal_emulator_colors.fg {
+            palette.foreground = foreground;
+        }
+        if let PaletteColor::Rgb(background) = terminal_emulator_colors.bg {
+            palette.background = background;
+        }
+        // the colors of the terminal we're running in, as reported by it
+        for (index, color) in self.terminal_emulator_color_codes.borrow().iter() {
+            if let Some(AnsiCode::RgbCode(rgb)) = xparse_color(color.as_bytes()) {
+                palette.set_indexed(*index, rgb);
+            }
+        }
+        // the colors changed by the application running inside the pane
+        if let Some(changed_colors) = self.changed_colors {
+            for (index, color) in changed_colors.iter().enumerate() {
+                if let Some(AnsiCode::RgbCode(rgb)) = color {
+                    palette.set_indexed(index, *rgb);
+                }
+            }
+        }
+        palette
+    }
     pub fn move_viewport_up(&mut self, count: usize) {
         for _ in 0..count {
             self.scroll_up_one_line();
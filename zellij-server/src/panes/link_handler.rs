@@ -88,6 +88,10 @@ impl LinkHandler {
         })
     }
 
+    pub fn get_link(&self, link_index: u16) -> Option<&Link> {
+        self.links.get(&link_index)
+    }
+
     #[cfg(test)]
     pub fn links(&self) -> HashMap<u16, Link> {
         self.links.clone()
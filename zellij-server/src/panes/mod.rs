@@ -2,12 +2,16 @@ pub mod alacritty_functions;
 pub mod grid;
 pub mod hyperlink_tracker;
 pub mod link_handler;
+pub mod output_log;
+pub mod output_stream;
 pub mod selection;
 pub mod sixel;
+pub mod styled_dump;
 pub mod terminal_character;
 
 mod active_panes;
 pub mod floating_panes;
+pub mod pane_recorder;
 mod plugin_pane;
 mod search;
 pub mod terminal_pane;
@@ -18,6 +22,9 @@ pub use alacritty_functions::*;
 pub use floating_panes::*;
 pub use grid::*;
 pub use link_handler::*;
+pub use output_log::PaneOutputLog;
+pub use output_stream::PaneOutputSubscriptions;
+pub use pane_recorder::PaneRecorder;
 pub(crate) use plugin_pane::*;
 pub use selection::Selection;
 pub use sixel::*;
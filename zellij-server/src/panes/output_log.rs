@@ -0,0 +1,133 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zellij_utils::data::PaneLogOptions;
+use zellij_utils::errors::prelude::*;
+
+/// Continuously writes the raw output of a terminal pane to a file (the equivalent of tmux's
+/// pipe-pane), optionally stripping ANSI escape sequences and rotating the file once it grows
+/// beyond a given size
+pub struct PaneOutputLog {
+    options: PaneLogOptions,
+    file: File,
+    bytes_written: u64,
+    ansi_stripper: Option<(vte::Parser, AnsiStripper)>,
+}
+
+impl std::fmt::Debug for PaneOutputLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaneOutputLog")
+            .field("options", &self.options)
+            .field("bytes_written", &self.bytes_written)
+            .finish()
+    }
+}
+
+impl PaneOutputLog {
+    pub fn new(options: PaneLogOptions) -> Result<Self> {
+        let file = open_log_file(&options.path, options.append)?;
+        let bytes_written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let ansi_stripper = if options.strip_ansi {
+            Some((vte::Parser::new(), AnsiStripper::default()))
+        } else {
+            None
+        };
+        Ok(PaneOutputLog {
+            options,
+            file,
+            bytes_written,
+            ansi_stripper,
+        })
+    }
+    pub fn path(&self) -> &Path {
+        &self.options.path
+    }
+    pub fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let bytes = match self.ansi_stripper.as_mut() {
+            Some((parser, stripper)) => {
+                for byte in bytes {
+                    parser.advance(stripper, *byte);
+                }
+                std::mem::take(&mut stripper.output)
+            },
+            None => bytes.to_vec(),
+        };
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        if let Some(max_size) = self.options.max_size {
+            if self.bytes_written > 0 && self.bytes_written + bytes.len() as u64 > max_size {
+                self.rotate()?;
+            }
+        }
+        self.file
+            .write_all(&bytes)
+            .with_context(|| format!("failed to write to {}", self.options.path.display()))?;
+        self.bytes_written += bytes.len() as u64;
+        Ok(())
+    }
+    fn rotate(&mut self) -> Result<()> {
+        let path = &self.options.path;
+        let rotated_path = |index: usize| {
+            let mut rotated = path.clone().into_os_string();
+            rotated.push(format!(".{}", index));
+            PathBuf::from(rotated)
+        };
+        if self.options.max_files > 0 {
+            // the oldest file is overwritten by the rename below, so we only need to shift the
+            // rest of them up by one
+            for index in (1..self.options.max_files).rev() {
+                let from = rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(index + 1))
+                        .with_context(|| format!("failed to rotate {}", from.display()))?;
+                }
+            }
+            fs::rename(path, rotated_path(1))
+                .with_context(|| format!("failed to rotate {}", path.display()))?;
+        }
+        self.file = open_log_file(path, false)?;
+        self.bytes_written = 0;
+        Ok(())
+    }
+}
+
+fn open_log_file(path: &Path, append: bool) -> Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let mut open_options = OpenOptions::new();
+    open_options.create(true);
+    if append {
+        open_options.append(true);
+    } else {
+        open_options.write(true).truncate(true);
+    }
+    open_options
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))
+}
+
+#[derive(Default)]
+pub(crate) struct AnsiStripper {
+    pub(crate) output: Vec<u8>,
+}
+
+impl vte::Perform for AnsiStripper {
+    fn print(&mut self, c: char) {
+        let mut buf = [0; 4];
+        self.output
+            .extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    }
+    fn execute(&mut self, byte: u8) {
+        // keep only the control characters that affect the layout of plain text
+        if matches!(byte, b'\n' | b'\r' | b'\t') {
+            self.output.push(byte);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/output_log_tests.rs"]
+mod output_log_tests;
@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use zellij_utils::data::{PaneId, PaneOutput, PaneOutputFormat};
+
+use crate::panes::output_log::AnsiStripper;
+use crate::plugins::PluginId;
+use crate::ClientId;
+
+// caps on how much output is kept for a subscriber between flushes, once reached the oldest
+// output is dropped so that a plugin that cannot keep up does not grow the server's memory
+const MAX_BUFFERED_BYTES: usize = 64 * 1024;
+const MAX_BUFFERED_LINES: usize = 1000;
+const MAX_LINE_LENGTH: usize = 16 * 1024;
+
+/// Fans out the output of terminal panes to the plugins that subscribed to it with
+/// `SubscribeToPaneOutput`
+///
+/// Output is buffered per subscriber and only handed out when drained, so that a chatty pane
+/// results in one event per flush interval rather than one event per read from its pty
+#[derive(Default)]
+pub struct PaneOutputSubscriptions {
+    // terminal pane id -> subscribers
+    subscribers: HashMap<u32, BTreeMap<(PluginId, ClientId), PaneOutputSubscriber>>,
+}
+
+impl PaneOutputSubscriptions {
+    pub fn subscribe(
+        &mut self,
+        terminal_id: u32,
+        plugin_id: PluginId,
+        client_id: ClientId,
+        format: PaneOutputFormat,
+    ) {
+        self.subscribers
+            .entry(terminal_id)
+            .or_default()
+            .insert((plugin_id, client_id), PaneOutputSubscriber::new(format));
+    }
+    pub fn unsubscribe(&mut self, terminal_id: u32, plugin_id: PluginId, client_id: ClientId) {
+        if let Some(subscribers) = self.subscribers.get_mut(&terminal_id) {
+            subscribers.remove(&(plugin_id, client_id));
+            if subscribers.is_empty() {
+                self.subscribers.remove(&terminal_id);
+            }
+        }
+    }
+    pub fn unsubscribe_plugin(&mut self, plugin_id: PluginId, client_id: ClientId) {
+        self.subscribers.retain(|_terminal_id, subscribers| {
+            subscribers.remove(&(plugin_id, client_id));
+            !subscribers.is_empty()
+        });
+    }
+    pub fn retain_panes(&mut self, mut pane_exists: impl FnMut(u32) -> bool) {
+        self.subscribers
+            .retain(|terminal_id, _subscribers| pane_exists(*terminal_id));
+    }
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+    /// Buffers the bytes for all subscribers of this pane, returns true if there were any
+    pub fn push(&mut self, terminal_id: u32, bytes: &[u8]) -> bool {
+        match self.subscribers.get_mut(&terminal_id) {
+            Some(subscribers) => {
+                for subscriber in subscribers.values_mut() {
+                    subscriber.push(bytes);
+                }
+                true
+            },
+            None => false,
+        }
+    }
+    pub fn drain(&mut self) -> Vec<(PluginId, ClientId, PaneId, PaneOutput)> {
+        let mut output = vec![];
+        for (terminal_id, subscribers) in self.subscribers.iter_mut() {
+            for ((plugin_id, client_id), subscriber) in subscribers.iter_mut() {
+                if let Some(pane_output) = subscriber.drain() {
+                    output.push((
+                        *plugin_id,
+                        *client_id,
+                        PaneId::Terminal(*terminal_id),
+                        pane_output,
+                    ));
+                }
+            }
+        }
+        output
+    }
+}
+
+struct PaneOutputSubscriber {
+    format: PaneOutputFormat,
+    pending_bytes: VecDeque<u8>,
+    pending_lines: VecDeque<String>,
+    partial_line: Vec<u8>,
+    parser: vte::Parser,
+    ansi_stripper: AnsiStripper,
+}
+
+impl PaneOutputSubscriber {
+    pub fn new(format: PaneOutputFormat) -> Self {
+        PaneOutputSubscriber {
+            format,
+            pending_bytes: VecDeque::new(),
+            pending_lines: VecDeque::new(),
+            partial_line: vec![],
+            parser: vte::Parser::new(),
+            ansi_stripper: AnsiStripper::default(),
+        }
+    }
+    pub fn push(&mut self, bytes: &[u8]) {
+        match self.format {
+            PaneOutputFormat::Raw => {
+                self.pending_bytes.extend(bytes);
+                let overflow = self.pending_bytes.len().saturating_sub(MAX_BUFFERED_BYTES);
+                self.pending_bytes.drain(..overflow);
+            },
+            PaneOutputFormat::Lines => {
+                for byte in bytes {
+                    self.parser.advance(&mut self.ansi_stripper, *byte);
+                }
+                for byte in std::mem::take(&mut self.ansi_stripper.output) {
+                    if byte == b'\n' || self.partial_line.len() >= MAX_LINE_LENGTH {
+                        self.complete_line();
+                    }
+                    if byte != b'\n' {
+                        self.partial_line.push(byte);
+                    }
+                }
+            },
+        }
+    }
+    pub fn drain(&mut self) -> Option<PaneOutput> {
+        match self.format {
+            PaneOutputFormat::Raw if !self.pending_bytes.is_empty() => {
+                Some(PaneOutput::Raw(self.pending_bytes.drain(..).collect()))
+            },
+            PaneOutputFormat::Lines if !self.pending_lines.is_empty() => {
+                Some(PaneOutput::Lines(self.pending_lines.drain(..).collect()))
+            },
+            _ => None,
+        }
+    }
+    fn complete_line(&mut self) {
+        let line = String::from_utf8_lossy(&self.partial_line).to_string();
+        self.partial_line.clear();
+        // a carriage return in the middle of a line means the program went back to overwrite it
+        // (eg. a progress bar), so only what was written last is part of the line
+        let line = line.trim_end_matches('\r');
+        let line = line.rsplit('\r').next().unwrap_or(line).to_owned();
+        self.pending_lines.push_back(line);
+        if self.pending_lines.len() > MAX_BUFFERED_LINES {
+            self.pending_lines.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/output_stream_tests.rs"]
+mod output_stream_tests;
@@ -0,0 +1,100 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use zellij_utils::asciicast::{AsciicastEvent, AsciicastHeader};
+use zellij_utils::errors::prelude::*;
+
+/// Records the output of a terminal pane along with its timing into an asciicast v2 file, so
+/// that it can later be replayed with `zellij playback` or asciinema
+pub struct PaneRecorder {
+    path: PathBuf,
+    file: File,
+    started_at: Instant,
+    size: (usize, usize), // (width, height)
+    // the tail of the last chunk of bytes if it ended in the middle of a multi-byte character
+    incomplete_utf8: Vec<u8>,
+}
+
+impl std::fmt::Debug for PaneRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaneRecorder")
+            .field("path", &self.path)
+            .field("started_at", &self.started_at)
+            .finish()
+    }
+}
+
+impl PaneRecorder {
+    pub fn new(path: PathBuf, width: usize, height: usize, title: Option<String>) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let mut file =
+            File::create(&path).with_context(|| format!("failed to create {}", path.display()))?;
+        let mut header = AsciicastHeader::new(width, height);
+        header.timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+        header.title = title;
+        let header = serde_json::to_string(&header).context("failed to serialize header")?;
+        writeln!(file, "{}", header)
+            .with_context(|| format!("failed to write to {}", path.display()))?;
+        Ok(PaneRecorder {
+            path,
+            file,
+            started_at: Instant::now(),
+            size: (width, height),
+            incomplete_utf8: vec![],
+        })
+    }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    pub fn record_output(&mut self, bytes: &[u8]) -> Result<()> {
+        self.incomplete_utf8.extend_from_slice(bytes);
+        let data = match std::str::from_utf8(&self.incomplete_utf8) {
+            Ok(data) => {
+                let data = data.to_owned();
+                self.incomplete_utf8.clear();
+                data
+            },
+            Err(e) if e.error_len().is_none() => {
+                // the bytes end in the middle of a character, keep its start for the next chunk
+                let rest = self.incomplete_utf8.split_off(e.valid_up_to());
+                let data = String::from_utf8_lossy(&self.incomplete_utf8).to_string();
+                self.incomplete_utf8 = rest;
+                data
+            },
+            Err(_) => {
+                let data = String::from_utf8_lossy(&self.incomplete_utf8).to_string();
+                self.incomplete_utf8.clear();
+                data
+            },
+        };
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.write_event(AsciicastEvent::output(self.elapsed(), data))
+    }
+    pub fn record_resize(&mut self, width: usize, height: usize) -> Result<()> {
+        if self.size == (width, height) {
+            return Ok(());
+        }
+        self.size = (width, height);
+        self.write_event(AsciicastEvent::resize(self.elapsed(), width, height))
+    }
+    fn elapsed(&self) -> f64 {
+        self.started_at.elapsed().as_secs_f64()
+    }
+    fn write_event(&mut self, event: AsciicastEvent) -> Result<()> {
+        writeln!(self.file, "{}", event.to_line())
+            .with_context(|| format!("failed to write to {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/pane_recorder_tests.rs"]
+mod pane_recorder_tests;
@@ -19,7 +19,7 @@ use crate::ClientId;
 use std::cell::RefCell;
 use std::rc::Rc;
 use vte;
-use zellij_utils::data::PaneContents;
+use zellij_utils::data::{DumpScreenFormat, PaneContents};
 use zellij_utils::data::{
     BareKey, KeyWithModifier, PermissionStatus, PermissionType, PluginPermission,
 };
@@ -97,6 +97,7 @@ pub(crate) struct PluginPane {
     frame: HashMap<ClientId, PaneFrame>,
     borderless: bool,
     exclude_from_sync: bool,
+    mark: Option<String>,
     pane_frame_color_override: Option<(PaletteColor, Option<String>)>,
     invoked_with: Option<Run>,
     loading_indication: LoadingIndication,
@@ -147,6 +148,7 @@ impl PluginPane {
             terminal_emulator_colors,
             terminal_emulator_color_codes,
             exclude_from_sync: false,
+            mark: None,
             link_handler,
             character_cell_size,
             sixel_image_store,
@@ -286,6 +288,20 @@ impl Pane for PluginPane {
         {
             self.reset_selection(client_id);
             None
+        } else if self.loading_indication.is_suspended() {
+            let is_enter = match key_with_modifier {
+                Some(key_with_modifier) => {
+                    key_with_modifier.bare_key == BareKey::Enter
+                        && key_with_modifier.has_no_modifiers()
+                },
+                // CR or LF
+                None => matches!(raw_input_bytes.as_slice(), [13] | [10]),
+            };
+            if is_enter {
+                Some(AdjustedInput::ReloadPlugin)
+            } else {
+                None
+            }
         } else if let Some(requesting_permissions) = &self.requesting_permissions {
             let permissions = requesting_permissions.permissions.clone();
             if let Some(key_with_modifier) = key_with_modifier {
@@ -449,6 +465,10 @@ impl Pane for PluginPane {
         } else {
             self.pane_name.clone()
         };
+        let pane_title = match &self.mark {
+            Some(mark) => format!("[{}] {}", mark, pane_title),
+            None => pane_title,
+        };
 
         let is_pinned = frame_geom.is_pinned;
         let mut frame = PaneFrame::new(
@@ -577,6 +597,17 @@ impl Pane for PluginPane {
             .map(|g| g.dump_screen(full))
             .unwrap_or_else(|| "".to_owned())
     }
+    fn dump_styled_screen(
+        &self,
+        full: bool,
+        format: DumpScreenFormat,
+        client_id: Option<ClientId>,
+    ) -> String {
+        client_id
+            .and_then(|c| self.grids.get(&c))
+            .map(|g| g.dump_styled_screen(full, format))
+            .unwrap_or_else(|| "".to_owned())
+    }
     fn scroll_up(&mut self, count: usize, client_id: ClientId) {
         self.send_plugin_instructions
             .send(PluginInstruction::Update(vec![(
@@ -726,6 +757,13 @@ impl Pane for PluginPane {
     fn exclude_from_sync(&self) -> bool {
         self.exclude_from_sync
     }
+    fn set_mark(&mut self, mark: Option<String>) {
+        self.mark = mark;
+        self.set_should_render(true);
+    }
+    fn mark(&self) -> Option<&str> {
+        self.mark.as_deref()
+    }
     fn handle_right_click(&mut self, to: &Position, client_id: ClientId) {
         self.send_plugin_instructions
             .send(PluginInstruction::Update(vec![(
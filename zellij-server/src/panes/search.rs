@@ -1,8 +1,11 @@
 use super::Selection;
 use crate::panes::terminal_character::TerminalCharacter;
 use crate::panes::{Grid, Row};
+use regex::{Regex, RegexBuilder};
 use std::borrow::Cow;
 use std::fmt::Debug;
+use zellij_utils::data::{SearchMatch, SearchQuery};
+use zellij_utils::errors::prelude::*;
 use zellij_utils::input::actions::SearchDirection;
 use zellij_utils::position::Position;
 
@@ -11,6 +14,50 @@ fn is_word_boundary(x: &Option<char>) -> bool {
     x.map_or(true, |c| !c.is_ascii_alphanumeric() && c != '_')
 }
 
+fn build_search_regex(needle: &str, is_regex: bool, case_insensitive: bool) -> Result<Regex> {
+    let pattern = if is_regex {
+        Cow::Borrowed(needle)
+    } else {
+        Cow::Owned(regex::escape(needle))
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .with_context(|| format!("invalid search pattern: {}", needle))
+}
+
+// Byte ranges of all matches of `regex` in `haystack`, empty matches are skipped.
+// With `whole_word_only`, the characters surrounding a match need to be word-boundaries
+// (the same rule the literal search uses)
+fn find_matches(
+    regex: &Regex,
+    haystack: &str,
+    whole_word_only: bool,
+) -> Vec<std::ops::Range<usize>> {
+    regex
+        .find_iter(haystack)
+        .filter(|m| !m.range().is_empty())
+        .filter(|m| {
+            !whole_word_only
+                || (is_word_boundary(&haystack[..m.start()].chars().next_back())
+                    && is_word_boundary(&haystack[m.end()..].chars().next()))
+        })
+        .map(|m| m.range())
+        .collect()
+}
+
+// The tail may have not been wrapped yet (when coming from lines_below),
+// so it could be that the end extends across more characters than the row is wide.
+// Therefore we need to reflow the end:
+fn reflow_selection_ends(selections: &mut [Selection], row_width: usize) {
+    for s in selections.iter_mut() {
+        while s.end.column() > row_width {
+            s.end.column.0 -= row_width;
+            s.end.line.0 += 1;
+        }
+    }
+}
+
 #[derive(Debug)]
 enum SearchSource<'a> {
     Main(&'a Row),
@@ -95,9 +142,18 @@ pub struct SearchResult {
     // Does case matter?
     pub case_insensitive: bool,
     // Only search whole words, not parts inside a word
-    pub whole_word_only: bool, // TODO
+    pub whole_word_only: bool,
     // Jump from the bottom to the top (or vice versa), if we run out of lines to search
     pub wrap_search: bool,
+    // Treat the needle as a regular expression
+    pub regex: bool,
+    // The compiled needle when searching with a regular expression (None if it does not compile)
+    compiled_regex: Option<Regex>,
+}
+
+// Rows are padded to the width of the pane, we don't want to report that padding as context
+fn trimmed_lines(lines: &[String]) -> Vec<String> {
+    lines.iter().map(|l| l.trim_end().to_owned()).collect()
 }
 
 impl SearchResult {
@@ -141,7 +197,18 @@ impl SearchResult {
     }
 
     pub fn has_modifiers_set(&self) -> bool {
-        self.wrap_search || self.whole_word_only || self.case_insensitive
+        self.wrap_search || self.whole_word_only || self.case_insensitive || self.regex
+    }
+
+    /// Needs to be called whenever the needle or one of the modifiers changes
+    pub(crate) fn update_compiled_regex(&mut self) {
+        self.compiled_regex = None;
+        if self.regex && !self.needle.is_empty() {
+            match build_search_regex(&self.needle, true, self.case_insensitive) {
+                Ok(regex) => self.compiled_regex = Some(regex),
+                Err(e) => log::debug!("{:?}", e),
+            }
+        }
     }
 
     fn check_if_haystack_char_matches_needle(
@@ -181,6 +248,9 @@ impl SearchResult {
         if self.needle.is_empty() || row.columns.is_empty() {
             return res;
         }
+        if self.regex {
+            return self.search_row_with_regex(ridx, row, tail);
+        }
 
         let mut tailit = tail.iter();
         let mut source = SearchSource::Main(row); // Where we currently get the haystack-characters from
@@ -267,15 +337,48 @@ impl SearchResult {
             }
         }
 
-        // The tail may have not been wrapped yet (when coming from lines_below),
-        // so it could be that the end extends across more characters than the row is wide.
-        // Therefore we need to reflow the end:
-        for s in res.iter_mut() {
-            while s.end.column() > row.width() {
-                s.end.column.0 -= row.width();
-                s.end.line.0 += 1;
+        reflow_selection_ends(&mut res, row.width());
+        res
+    }
+
+    /// Search a row and its tail with the compiled regex.
+    /// The row and its tail are glued together so that matches can span wrapped lines, only
+    /// matches starting in `row` itself are returned (the others are found when searching the
+    /// rows they start in).
+    fn search_row_with_regex(&self, ridx: usize, row: &Row, tail: &[&Row]) -> Vec<Selection> {
+        let mut res = Vec::new();
+        let regex = match &self.compiled_regex {
+            Some(regex) => regex,
+            None => return res,
+        };
+
+        let mut haystack = String::new();
+        // (byte offset in the haystack, line, column) of every character
+        let mut positions: Vec<(usize, usize, usize)> = Vec::new();
+        for (line_offset, current_row) in
+            std::iter::once(row).chain(tail.iter().copied()).enumerate()
+        {
+            for (column, terminal_character) in current_row.columns.iter().enumerate() {
+                positions.push((haystack.len(), ridx + line_offset, column));
+                haystack.push(terminal_character.character);
             }
         }
+
+        for range in find_matches(regex, &haystack, self.whole_word_only) {
+            let start_idx = positions.partition_point(|(offset, ..)| *offset < range.start);
+            let end_idx = positions.partition_point(|(offset, ..)| *offset < range.end) - 1;
+            let (_, start_line, start_column) = positions[start_idx];
+            if start_line != ridx {
+                break;
+            }
+            let (_, end_line, end_column) = positions[end_idx];
+            let mut selection = Selection::default();
+            selection.start(Position::new(start_line as i32, start_column as u16));
+            selection.end(Position::new(end_line as i32, (end_column + 1) as u16));
+            res.push(selection);
+        }
+
+        reflow_selection_ends(&mut res, row.width());
         res
     }
 
@@ -412,6 +515,7 @@ impl Grid {
 
     pub fn set_search_string(&mut self, needle: &str) {
         self.search_results.needle = needle.to_string();
+        self.search_results.update_compiled_regex();
         self.search_viewport();
         // If the current viewport does not contain any hits,
         // we jump around until we find something. Starting
@@ -454,6 +558,7 @@ impl Grid {
 
     pub fn toggle_search_case_sensitivity(&mut self) {
         self.search_results.case_insensitive = !self.search_results.case_insensitive;
+        self.search_results.update_compiled_regex();
         for line in self.search_results.selections.drain(..) {
             self.output_buffer
                 .update_lines(line.start.line() as usize, line.end.line() as usize);
@@ -467,6 +572,115 @@ impl Grid {
         self.search_results.wrap_search = !self.search_results.wrap_search;
     }
 
+    pub fn toggle_search_regex(&mut self) {
+        self.search_results.regex = !self.search_results.regex;
+        self.search_results.update_compiled_regex();
+        for line in self.search_results.selections.drain(..) {
+            self.output_buffer
+                .update_lines(line.start.line() as usize, line.end.line() as usize);
+        }
+        self.search_results.active = None;
+        self.search_viewport();
+    }
+
+    /// Search the whole scrollback (lines above, viewport and lines below) at once, without
+    /// touching the interactive search state of this grid.
+    pub fn search_scrollback(&self, query: &SearchQuery) -> Result<Vec<SearchMatch>> {
+        let mut matches = Vec::new();
+        if query.needle.is_empty() {
+            return Ok(matches);
+        }
+        let regex = build_search_regex(&query.needle, query.regex, query.case_insensitive)?;
+        let lines = self.logical_lines();
+        for (line, haystack) in lines.iter().enumerate() {
+            for range in find_matches(&regex, haystack, query.whole_word) {
+                let start_column = haystack[..range.start].chars().count();
+                let text = haystack[range].to_owned();
+                let context_start = line.saturating_sub(query.context_lines);
+                let context_end = (line + 1 + query.context_lines).min(lines.len());
+                matches.push(SearchMatch {
+                    line,
+                    start_column,
+                    end_column: start_column + text.chars().count(),
+                    text,
+                    context_before: trimmed_lines(&lines[context_start..line]),
+                    context_after: trimmed_lines(&lines[line + 1..context_end]),
+                });
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Scroll the viewport so that the given logical line (as reported by `search_scrollback`)
+    /// is visible, placing it at the top of the viewport if we need to move at all
+    pub fn scroll_to_logical_line(&mut self, line: usize) {
+        let first_line_in_viewport = self
+            .lines_above
+            .iter()
+            .chain(self.viewport.iter().take(1))
+            .skip(1)
+            .filter(|r| r.is_canonical)
+            .count();
+        let last_line_in_viewport = first_line_in_viewport
+            + self
+                .viewport
+                .iter()
+                .skip(1)
+                .filter(|r| r.is_canonical)
+                .count();
+        if line >= first_line_in_viewport && line <= last_line_in_viewport {
+            return;
+        }
+        // scrolling is only possible when the viewport is full
+        if self.viewport.len() != self.height {
+            return;
+        }
+        let mut current_line = first_line_in_viewport;
+        if line < current_line {
+            loop {
+                let reached_line = current_line == line
+                    && self.viewport.first().is_none_or(|r| r.is_canonical);
+                if reached_line || self.lines_above.is_empty() {
+                    break;
+                }
+                if self.viewport.first().is_some_and(|r| r.is_canonical) {
+                    current_line -= 1;
+                }
+                self.scroll_up_one_line();
+            }
+        } else {
+            while !self.lines_below.is_empty() && current_line < line {
+                self.scroll_down_one_line();
+                if self.viewport.first().is_some_and(|r| r.is_canonical) {
+                    current_line += 1;
+                }
+            }
+        }
+        self.output_buffer.update_all_lines();
+    }
+
+    // All lines of the scrollback, with soft-wrapped rows glued back together so that every
+    // canonical row and its continuation rows form one line
+    fn logical_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for row in self
+            .lines_above
+            .iter()
+            .chain(self.viewport.iter())
+            .chain(self.lines_below.iter())
+        {
+            let line = match lines.last_mut() {
+                Some(line) if !row.is_canonical => line,
+                _ => {
+                    lines.push(String::new());
+                    lines.last_mut().unwrap() // we just pushed it
+                },
+            };
+            line.extend(row.columns.iter().map(|c| c.character));
+        }
+        lines
+    }
+
     pub fn toggle_search_whole_words(&mut self) {
         self.search_results.whole_word_only = !self.search_results.whole_word_only;
         for line in self.search_results.selections.drain(..) {
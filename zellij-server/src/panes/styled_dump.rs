@@ -0,0 +1,524 @@
+//! Rendering of the contents of a grid with their colors, styles and hyperlinks, for dumping a
+//! pane as ANSI text, HTML or SVG
+
+use std::fmt::Write;
+
+use crate::panes::grid::Row;
+use crate::panes::link_handler::LinkHandler;
+use crate::panes::terminal_character::{
+    AnsiCode, AnsiStyledUnderline, CharacterStyles, LinkAnchor, NamedColor, TerminalCharacter,
+};
+use zellij_utils::data::DumpScreenFormat;
+use zellij_utils::shared::eightbit_to_rgb;
+
+type Rgb = (u8, u8, u8);
+
+const DEFAULT_FOREGROUND: Rgb = (0xd0, 0xd0, 0xd0);
+const DEFAULT_BACKGROUND: Rgb = (0x00, 0x00, 0x00);
+
+// svg geometry, in pixels
+const SVG_FONT_SIZE: usize = 15;
+const SVG_CELL_WIDTH: usize = 9;
+const SVG_LINE_HEIGHT: usize = 18;
+const SVG_BASELINE: usize = 14;
+
+/// The actual colors behind the default foreground/background and the 256 indexed colors of a
+/// terminal, used to resolve the colors of the HTML and SVG formats
+#[derive(Debug, Clone)]
+pub struct DumpPalette {
+    pub foreground: Rgb,
+    pub background: Rgb,
+    indexed: Vec<Rgb>,
+}
+
+impl Default for DumpPalette {
+    fn default() -> Self {
+        DumpPalette {
+            foreground: DEFAULT_FOREGROUND,
+            background: DEFAULT_BACKGROUND,
+            indexed: (0..=255).map(eightbit_to_rgb).collect(),
+        }
+    }
+}
+
+impl DumpPalette {
+    pub fn set_indexed(&mut self, index: usize, color: Rgb) {
+        if let Some(indexed) = self.indexed.get_mut(index) {
+            *indexed = color;
+        }
+    }
+    fn resolve(&self, code: Option<AnsiCode>, default: Rgb) -> Rgb {
+        match code {
+            Some(AnsiCode::RgbCode(rgb)) => rgb,
+            Some(AnsiCode::ColorIndex(index)) => self.indexed[index as usize],
+            Some(AnsiCode::NamedColor(named_color)) => self.indexed[named_color_index(named_color)],
+            _ => default,
+        }
+    }
+    // the foreground and background colors of a character, taking reverse video and dim text
+    // into account
+    fn colors_of(&self, styles: &CharacterStyles) -> (Rgb, Rgb) {
+        let mut foreground = self.resolve(styles.foreground, self.foreground);
+        let mut background = self.resolve(styles.background, self.background);
+        if is_on(styles.reverse) {
+            std::mem::swap(&mut foreground, &mut background);
+        }
+        if is_on(styles.dim) {
+            foreground = blend(foreground, background);
+        }
+        (foreground, background)
+    }
+}
+
+/// Renders the given rows in the requested format, `DumpScreenFormat::Text` is rendered like
+/// `DumpScreenFormat::Ansi` but without any escape sequences
+pub fn dump_styled_rows(
+    rows: &[&Row],
+    format: DumpScreenFormat,
+    palette: &DumpPalette,
+    link_handler: &LinkHandler,
+    title: Option<&str>,
+) -> String {
+    match format {
+        DumpScreenFormat::Text | DumpScreenFormat::Ansi => {
+            let lines = logical_lines(rows);
+            dump_ansi(&lines, link_handler, format == DumpScreenFormat::Ansi)
+        },
+        DumpScreenFormat::Html => dump_html(&logical_lines(rows), palette, link_handler, title),
+        DumpScreenFormat::Svg => {
+            let lines: Vec<Vec<&TerminalCharacter>> =
+                rows.iter().map(|row| trimmed(row.columns.iter())).collect();
+            dump_svg(&lines, palette, link_handler, title)
+        },
+    }
+}
+
+// a run of consecutive characters with the same styles
+struct Run<'a> {
+    styles: &'a CharacterStyles,
+    text: String,
+    column: usize,
+    width: usize,
+}
+
+// lines that were wrapped by the terminal are joined back together, like in the plain text dump
+fn logical_lines<'a>(rows: &[&'a Row]) -> Vec<Vec<&'a TerminalCharacter>> {
+    let mut lines: Vec<Vec<&'a TerminalCharacter>> = vec![];
+    for row in rows {
+        match lines.last_mut() {
+            Some(line) if !row.is_canonical => line.extend(row.columns.iter()),
+            _ => lines.push(row.columns.iter().collect()),
+        }
+    }
+    lines
+        .into_iter()
+        .map(|line| trimmed(line.into_iter()))
+        .collect()
+}
+
+// drop the unstyled spaces the terminal pads the end of its lines with
+fn trimmed<'a>(
+    characters: impl Iterator<Item = &'a TerminalCharacter>,
+) -> Vec<&'a TerminalCharacter> {
+    let mut characters: Vec<&'a TerminalCharacter> = characters.collect();
+    while characters.last().map(|c| is_blank(c)).unwrap_or(false) {
+        characters.pop();
+    }
+    characters
+}
+
+fn is_blank(character: &TerminalCharacter) -> bool {
+    let styles: &CharacterStyles = &character.styles;
+    character.character == ' '
+        && !styles.background.map(is_color).unwrap_or(false)
+        && !is_on(styles.reverse)
+        && styles.underline.is_none()
+        && styles.strike.is_none()
+        && link_index(styles).is_none()
+}
+
+// when `split_wide` is true, wide characters get a run of their own so that they can be
+// positioned on their exact column regardless of the width of the glyph in the font
+fn runs<'a>(line: &[&'a TerminalCharacter], split_wide: bool) -> Vec<Run<'a>> {
+    let mut runs: Vec<Run<'a>> = vec![];
+    let mut column = 0;
+    for character in line {
+        let styles: &'a CharacterStyles = &character.styles;
+        let is_wide = character.width() > 1;
+        match runs.last_mut() {
+            Some(run)
+                if run.styles == styles
+                    && !(split_wide && (is_wide || run.ends_with_wide_character())) =>
+            {
+                run.text.push(character.character);
+                run.width += character.width();
+            },
+            _ => runs.push(Run {
+                styles,
+                text: character.character.to_string(),
+                column,
+                width: character.width(),
+            }),
+        }
+        column += character.width();
+    }
+    runs
+}
+
+impl<'a> Run<'a> {
+    fn ends_with_wide_character(&self) -> bool {
+        self.text.chars().count() == 1 && self.width > 1
+    }
+    fn visible_text(&self) -> String {
+        if is_on(self.styles.hidden) {
+            " ".repeat(self.width)
+        } else {
+            self.text.clone()
+        }
+    }
+}
+
+fn dump_ansi(
+    lines: &[Vec<&TerminalCharacter>],
+    link_handler: &LinkHandler,
+    with_styles: bool,
+) -> String {
+    let mut dump = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            dump.push('\n');
+        }
+        let mut current_link = None;
+        for run in runs(line, false) {
+            let link = link_index(run.styles);
+            if with_styles && link != current_link {
+                if current_link.is_some() {
+                    dump.push_str(
+                        &link_handler
+                            .output_osc8(Some(LinkAnchor::End))
+                            .unwrap_or_default(),
+                    );
+                }
+                if let Some(link) = link {
+                    dump.push_str(
+                        &link_handler
+                            .output_osc8(Some(LinkAnchor::Start(link)))
+                            .unwrap_or_default(),
+                    );
+                }
+                current_link = link;
+            }
+            if with_styles {
+                let _ = write!(dump, "\u{1b}[m{}", sgr_styles(run.styles));
+            }
+            dump.push_str(&run.text);
+        }
+        if with_styles {
+            if current_link.is_some() {
+                dump.push_str(
+                    &link_handler
+                        .output_osc8(Some(LinkAnchor::End))
+                        .unwrap_or_default(),
+                );
+            }
+            dump.push_str("\u{1b}[m");
+        }
+    }
+    dump
+}
+
+// since every run starts from a clean slate, only the attributes that are on are needed
+fn sgr_styles(styles: &CharacterStyles) -> CharacterStyles {
+    let on = |code: Option<AnsiCode>| match code {
+        Some(AnsiCode::Reset) => None,
+        code => code,
+    };
+    CharacterStyles {
+        foreground: on(styles.foreground),
+        background: on(styles.background),
+        underline_color: on(styles.underline_color),
+        strike: on(styles.strike),
+        hidden: on(styles.hidden),
+        reverse: on(styles.reverse),
+        slow_blink: on(styles.slow_blink),
+        fast_blink: on(styles.fast_blink),
+        underline: on(styles.underline),
+        bold: on(styles.bold),
+        dim: on(styles.dim),
+        italic: on(styles.italic),
+        link_anchor: None,
+        styled_underlines_enabled: true,
+    }
+}
+
+fn dump_html(
+    lines: &[Vec<&TerminalCharacter>],
+    palette: &DumpPalette,
+    link_handler: &LinkHandler,
+    title: Option<&str>,
+) -> String {
+    let mut dump = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    if let Some(title) = title {
+        let _ = writeln!(dump, "<title>{}</title>", escape(title));
+    }
+    let _ = write!(
+        dump,
+        "</head>\n<body style=\"margin:0\">\n<pre style=\"margin:0;padding:8px;font-family:monospace;white-space:pre-wrap;color:{};background-color:{}\">",
+        hex(palette.foreground),
+        hex(palette.background)
+    );
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            dump.push('\n');
+        }
+        for run in runs(line, false) {
+            let text = escape(&run.visible_text());
+            let css = css_of(run.styles, palette);
+            let span = if css.is_empty() {
+                text
+            } else {
+                format!("<span style=\"{}\">{}</span>", css, text)
+            };
+            match link_uri(run.styles, link_handler) {
+                Some(uri) => {
+                    let _ = write!(
+                        dump,
+                        "<a href=\"{}\" style=\"color:inherit\">{}</a>",
+                        escape(&uri),
+                        span
+                    );
+                },
+                None => dump.push_str(&span),
+            }
+        }
+    }
+    dump.push_str("</pre>\n</body>\n</html>\n");
+    dump
+}
+
+fn css_of(styles: &CharacterStyles, palette: &DumpPalette) -> String {
+    let (foreground, background) = palette.colors_of(styles);
+    let mut css = vec![];
+    if foreground != palette.foreground {
+        css.push(format!("color:{}", hex(foreground)));
+    }
+    if background != palette.background {
+        css.push(format!("background-color:{}", hex(background)));
+    }
+    if is_on(styles.bold) {
+        css.push("font-weight:bold".to_owned());
+    }
+    if is_on(styles.italic) {
+        css.push("font-style:italic".to_owned());
+    }
+    let mut decorations = vec![];
+    if let Some(AnsiCode::Underline(styled)) = styles.underline {
+        decorations.push("underline");
+        let style = match styled {
+            Some(AnsiStyledUnderline::Double) => Some("double"),
+            Some(AnsiStyledUnderline::Undercurl) => Some("wavy"),
+            Some(AnsiStyledUnderline::Underdotted) => Some("dotted"),
+            Some(AnsiStyledUnderline::Underdashed) => Some("dashed"),
+            None => None,
+        };
+        if let Some(style) = style {
+            css.push(format!("text-decoration-style:{}", style));
+        }
+        if styles.underline_color.map(is_color).unwrap_or(false) {
+            let underline_color = palette.resolve(styles.underline_color, foreground);
+            css.push(format!("text-decoration-color:{}", hex(underline_color)));
+        }
+    }
+    if is_on(styles.strike) {
+        decorations.push("line-through");
+    }
+    if !decorations.is_empty() {
+        css.push(format!("text-decoration-line:{}", decorations.join(" ")));
+    }
+    css.join(";")
+}
+
+fn dump_svg(
+    lines: &[Vec<&TerminalCharacter>],
+    palette: &DumpPalette,
+    link_handler: &LinkHandler,
+    title: Option<&str>,
+) -> String {
+    let columns = lines
+        .iter()
+        .map(|line| line.iter().map(|c| c.width()).sum::<usize>())
+        .max()
+        .unwrap_or(0);
+    let width = columns * SVG_CELL_WIDTH;
+    let height = lines.len() * SVG_LINE_HEIGHT;
+    let mut backgrounds = String::new();
+    let mut texts = String::new();
+    for (row, line) in lines.iter().enumerate() {
+        let y = row * SVG_LINE_HEIGHT;
+        for run in runs(line, true) {
+            let (foreground, background) = palette.colors_of(run.styles);
+            let x = run.column * SVG_CELL_WIDTH;
+            if background != palette.background {
+                let _ = writeln!(
+                    backgrounds,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x,
+                    y,
+                    run.width * SVG_CELL_WIDTH,
+                    SVG_LINE_HEIGHT,
+                    hex(background)
+                );
+            }
+            let text = run.visible_text();
+            let has_decorations = run.styles.underline.is_some() || is_on(run.styles.strike);
+            if text.trim().is_empty() && !has_decorations {
+                continue;
+            }
+            let mut text_element = format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\"",
+                x,
+                y + SVG_BASELINE,
+                hex(foreground)
+            );
+            if run.width > 1 && text.chars().count() == 1 {
+                // keep wide characters within their two cells
+                let _ = write!(
+                    text_element,
+                    " textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\"",
+                    run.width * SVG_CELL_WIDTH
+                );
+            }
+            if is_on(run.styles.bold) {
+                text_element.push_str(" font-weight=\"bold\"");
+            }
+            if is_on(run.styles.italic) {
+                text_element.push_str(" font-style=\"italic\"");
+            }
+            let mut decorations = vec![];
+            if let Some(AnsiCode::Underline(_)) = run.styles.underline {
+                decorations.push("underline");
+            }
+            if is_on(run.styles.strike) {
+                decorations.push("line-through");
+            }
+            if !decorations.is_empty() {
+                let _ = write!(
+                    text_element,
+                    " text-decoration=\"{}\"",
+                    decorations.join(" ")
+                );
+            }
+            let _ = write!(text_element, ">{}</text>", escape(&text));
+            match link_uri(run.styles, link_handler) {
+                Some(uri) => {
+                    let _ = writeln!(texts, "<a href=\"{}\">{}</a>", escape(&uri), text_element);
+                },
+                None => {
+                    let _ = writeln!(texts, "{}", text_element);
+                },
+            }
+        }
+    }
+    let mut dump = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"{}\" xml:space=\"preserve\">\n",
+        SVG_FONT_SIZE,
+        w = width,
+        h = height,
+    );
+    if let Some(title) = title {
+        let _ = writeln!(dump, "<title>{}</title>", escape(title));
+    }
+    let _ = writeln!(
+        dump,
+        "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+        hex(palette.background)
+    );
+    dump.push_str(&backgrounds);
+    dump.push_str(&texts);
+    dump.push_str("</svg>\n");
+    dump
+}
+
+fn link_index(styles: &CharacterStyles) -> Option<u16> {
+    match styles.link_anchor {
+        Some(LinkAnchor::Start(index)) => Some(index),
+        _ => None,
+    }
+}
+
+fn link_uri(styles: &CharacterStyles, link_handler: &LinkHandler) -> Option<String> {
+    let uri = &link_handler.get_link(link_index(styles)?)?.uri;
+    // links are clickable in the dump, so only keep the ones that can't run scripts
+    let scheme = uri.split(':').next().unwrap_or("").trim().to_lowercase();
+    if ["javascript", "vbscript", "data"].contains(&scheme.as_str()) {
+        None
+    } else {
+        Some(uri.clone())
+    }
+}
+
+fn is_on(code: Option<AnsiCode>) -> bool {
+    matches!(code, Some(AnsiCode::On))
+}
+
+fn is_color(code: AnsiCode) -> bool {
+    matches!(
+        code,
+        AnsiCode::RgbCode(_) | AnsiCode::ColorIndex(_) | AnsiCode::NamedColor(_)
+    )
+}
+
+fn named_color_index(named_color: NamedColor) -> usize {
+    match named_color {
+        NamedColor::Black => 0,
+        NamedColor::Red => 1,
+        NamedColor::Green => 2,
+        NamedColor::Yellow => 3,
+        NamedColor::Blue => 4,
+        NamedColor::Magenta => 5,
+        NamedColor::Cyan => 6,
+        NamedColor::White => 7,
+        NamedColor::BrightBlack => 8,
+        NamedColor::BrightRed => 9,
+        NamedColor::BrightGreen => 10,
+        NamedColor::BrightYellow => 11,
+        NamedColor::BrightBlue => 12,
+        NamedColor::BrightMagenta => 13,
+        NamedColor::BrightCyan => 14,
+        NamedColor::BrightWhite => 15,
+    }
+}
+
+fn blend(color: Rgb, other: Rgb) -> Rgb {
+    let mix = |a: u8, b: u8| ((a as u16 + b as u16) / 2) as u8;
+    (
+        mix(color.0, other.0),
+        mix(color.1, other.1),
+        mix(color.2, other.2),
+    )
+}
+
+fn hex((r, g, b): Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            // control characters are not allowed in xml
+            c if c.is_control() && c != '\n' && c != '\t' => {},
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[path = "./unit/styled_dump_tests.rs"]
+mod styled_dump_tests;
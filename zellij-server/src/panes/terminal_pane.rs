@@ -3,6 +3,8 @@ use crate::panes::sixel::SixelImageStore;
 use crate::panes::LinkHandler;
 use crate::panes::{
     grid::Grid,
+    output_log::PaneOutputLog,
+    pane_recorder::PaneRecorder,
     terminal_character::{render_first_run_banner, TerminalCharacter, EMPTY_TERMINAL_CHARACTER},
 };
 use crate::pty::VteBytes;
@@ -12,10 +14,11 @@ use crate::ClientId;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::time::{self, Instant};
 use vte;
-use zellij_utils::data::PaneContents;
+use zellij_utils::data::{DumpScreenFormat, PaneContents, SearchMatch, SearchQuery};
 use zellij_utils::input::command::RunCommand;
 use zellij_utils::input::mouse::{MouseEvent, MouseEventType};
 use zellij_utils::notification::Notification;
@@ -26,7 +29,7 @@ use zellij_utils::{
         Style, Styling,
     },
     errors::prelude::*,
-    input::layout::Run,
+    input::layout::{PaneDependency, Run},
     pane_size::PaneGeom,
     pane_size::SizeInPixels,
     position::Position,
@@ -133,6 +136,7 @@ pub struct TerminalPane {
     frame: HashMap<ClientId, PaneFrame>,
     borderless: bool,
     exclude_from_sync: bool,
+    mark: Option<String>,
     fake_cursor_locations: HashSet<(usize, usize)>, // (x, y) - these hold a record of previous fake cursors which we need to clear on render
     search_term: String,
     is_held: Option<(Option<i32>, IsFirstRun, RunCommand)>, // a "held" pane means that its command has either exited and the pane is waiting for a
@@ -146,6 +150,10 @@ pub struct TerminalPane {
     notification_end: Option<NotificationEnd>,
     /// Perth visual notification (STORY-003)
     pub notification: Option<Notification>,
+    output_log: Option<PaneOutputLog>,
+    recorder: Option<PaneRecorder>,
+    pending_dependencies: Vec<PaneDependency>,
+    restarts: Option<(usize, usize)>, // (restarts so far, max restarts)
 }
 
 impl Pane for TerminalPane {
@@ -196,6 +204,18 @@ impl Pane for TerminalPane {
     }
     fn handle_pty_bytes(&mut self, bytes: VteBytes) {
         self.set_should_render(true);
+        if let Some(output_log) = self.output_log.as_mut() {
+            if let Err(e) = output_log.write(&bytes) {
+                log::error!("Stopped logging output of pane {}: {:?}", self.pid, e);
+                self.output_log = None;
+            }
+        }
+        if let Some(recorder) = self.recorder.as_mut() {
+            if let Err(e) = recorder.record_output(&bytes) {
+                log::error!("Stopped recording pane {}: {:?}", self.pid, e);
+                self.recorder = None;
+            }
+        }
         for &byte in &bytes {
             self.vte_parser.advance(&mut self.grid, byte);
         }
@@ -373,6 +393,9 @@ impl Pane for TerminalPane {
                 if self.grid.search_results.wrap_search {
                     modifiers.push("w")
                 }
+                if self.grid.search_results.regex {
+                    modifiers.push("r")
+                }
                 modifier_text.push_str(&modifiers.join(", "));
                 modifier_text.push(']');
             }
@@ -385,6 +408,16 @@ impl Pane for TerminalPane {
         } else {
             self.pane_name.clone()
         };
+        let pane_title = match self.restarts {
+            Some((restarts, max_restarts)) => {
+                format!("{} (restart {}/{})", pane_title, restarts, max_restarts)
+            },
+            None => pane_title,
+        };
+        let pane_title = match &self.mark {
+            Some(mark) => format!("[{}] {}", mark, pane_title),
+            None => pane_title,
+        };
 
         let frame_geom = self.current_geom();
         let is_pinned = frame_geom.is_pinned;
@@ -398,6 +431,14 @@ impl Pane for TerminalPane {
         if let Some((exit_status, is_first_run, _run_command)) = &self.is_held {
             if *is_first_run {
                 frame.indicate_first_run();
+                if !self.pending_dependencies.is_empty() {
+                    frame.indicate_waiting_for(
+                        self.pending_dependencies
+                            .iter()
+                            .map(|d| d.pane_name.clone())
+                            .collect(),
+                    );
+                }
             } else {
                 frame.add_exit_status(exit_status.as_ref().copied());
             }
@@ -531,6 +572,14 @@ impl Pane for TerminalPane {
     fn dump_screen(&self, full: bool, _client_id: Option<ClientId>) -> String {
         self.grid.dump_screen(full)
     }
+    fn dump_styled_screen(
+        &self,
+        full: bool,
+        format: DumpScreenFormat,
+        _client_id: Option<ClientId>,
+    ) -> String {
+        self.grid.dump_styled_screen(full, format)
+    }
     fn clear_screen(&mut self) {
         self.grid.clear_screen()
     }
@@ -687,6 +736,15 @@ impl Pane for TerminalPane {
         self.exclude_from_sync
     }
 
+    fn set_mark(&mut self, mark: Option<String>) {
+        self.mark = mark;
+        self.set_should_render(true);
+    }
+
+    fn mark(&self) -> Option<&str> {
+        self.mark.as_deref()
+    }
+
     fn mouse_event(&self, event: &MouseEvent, _client_id: ClientId) -> Option<String> {
         self.grid.mouse_event_signal(event)
     }
@@ -769,6 +827,55 @@ impl Pane for TerminalPane {
     fn toggle_search_wrap(&mut self) {
         self.grid.toggle_search_wrap();
     }
+    fn toggle_search_regex(&mut self) {
+        self.grid.toggle_search_regex();
+        self.set_should_render(true);
+    }
+    fn search_scrollback(&self, query: &SearchQuery) -> Result<Vec<SearchMatch>> {
+        self.grid.search_scrollback(query)
+    }
+    fn set_output_log(&mut self, output_log: Option<PaneOutputLog>) -> Result<()> {
+        self.output_log = output_log;
+        Ok(())
+    }
+    fn output_log_path(&self) -> Option<PathBuf> {
+        self.output_log
+            .as_ref()
+            .map(|output_log| output_log.path().to_path_buf())
+    }
+    fn set_recorder(&mut self, recorder: Option<PaneRecorder>) -> Result<()> {
+        self.recorder = recorder;
+        Ok(())
+    }
+    fn recording_path(&self) -> Option<PathBuf> {
+        self.recorder
+            .as_ref()
+            .map(|recorder| recorder.path().to_path_buf())
+    }
+    fn set_pending_dependencies(&mut self, pending_dependencies: Vec<PaneDependency>) {
+        if pending_dependencies != self.pending_dependencies {
+            self.pending_dependencies = pending_dependencies;
+            self.set_should_render(true);
+        }
+    }
+    fn pending_dependencies(&self) -> &[PaneDependency] {
+        &self.pending_dependencies
+    }
+    fn set_restarts(&mut self, restarts: usize, max_restarts: usize) {
+        let restarts = if restarts > 0 {
+            Some((restarts, max_restarts))
+        } else {
+            None
+        };
+        if restarts != self.restarts {
+            self.restarts = restarts;
+            self.set_should_render(true);
+        }
+    }
+    fn scroll_to_line(&mut self, line: usize) {
+        self.grid.scroll_to_logical_line(line);
+        self.set_should_render(true);
+    }
     fn clear_search(&mut self) {
         self.grid.clear_search();
         self.search_term.clear();
@@ -868,6 +975,7 @@ impl Pane for TerminalPane {
         // is not in the right sort of state
         self.is_held.take().map(|(_, _, run_command)| {
             self.is_held = None;
+            self.pending_dependencies.clear();
             self.grid.reset_terminal_state();
             self.set_should_render(true);
             self.remove_banner();
@@ -1019,6 +1127,7 @@ impl TerminalPane {
             prev_pane_name: pane_name,
             borderless: false,
             exclude_from_sync: false,
+            mark: None,
             fake_cursor_locations: HashSet::new(),
             search_term: String::new(),
             is_held: None,
@@ -1028,6 +1137,10 @@ impl TerminalPane {
             arrow_fonts,
             notification_end,
             notification: None, // STORY-003: Perth notification system
+            output_log: None,
+            recorder: None,
+            pending_dependencies: vec![],
+            restarts: None,
         }
     }
     pub fn get_x(&self) -> usize {
@@ -1058,6 +1171,9 @@ impl TerminalPane {
         let rows = self.get_content_rows();
         let cols = self.get_content_columns();
         self.grid.force_change_size(rows, cols);
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record_resize(cols, rows).non_fatal();
+        }
         if self.banner.is_some() {
             self.grid.reset_terminal_state();
             self.render_first_run_banner();
@@ -1202,6 +1318,7 @@ impl TerminalPane {
     fn handle_held_run(&mut self) -> Option<AdjustedInput> {
         self.is_held.take().map(|(_, _, run_command)| {
             self.is_held = None;
+            self.pending_dependencies.clear();
             self.grid.reset_terminal_state();
             self.set_should_render(true);
             self.remove_banner();
@@ -1213,6 +1330,7 @@ impl TerminalPane {
             // Drop to shell in the same working directory as the command was run
             let working_dir = run_command.cwd.clone();
             self.is_held = None;
+            self.pending_dependencies.clear();
             self.grid.reset_terminal_state();
             self.set_should_render(true);
             self.remove_banner();
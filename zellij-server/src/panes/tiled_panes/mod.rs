@@ -1,9 +1,11 @@
 mod pane_resizer;
 mod stacked_panes;
 mod tiled_pane_grid;
+mod tiling_modes;
 
 use crate::resize_pty;
 use tiled_pane_grid::{split, TiledPaneGrid, RESIZE_PERCENT};
+use tiling_modes::tiling_mode_layout;
 
 use crate::{
     os_input_output::ServerOsApi,
@@ -18,7 +20,7 @@ use crate::{
 };
 use stacked_panes::StackedPanes;
 use zellij_utils::{
-    data::{Direction, ModeInfo, PaneInfo, Resize, ResizeStrategy, Style, Styling},
+    data::{Direction, ModeInfo, PaneInfo, Resize, ResizeStrategy, Style, Styling, TilingMode},
     errors::prelude::*,
     input::{
         command::RunCommand,
@@ -542,6 +544,48 @@ impl TiledPanes {
 
         self.set_pane_frames(self.draw_pane_frames);
     }
+    pub fn rebalance(&mut self, tiling_mode: TilingMode) -> Result<()> {
+        let err_context = || {
+            format!(
+                "failed to rebalance tiled panes in {} tiling mode",
+                tiling_mode
+            )
+        };
+        if !self.non_selectable_pane_geoms_inside_viewport().is_empty() {
+            // we only rearrange the viewport, if there are panes in it that cannot be moved
+            // around (eg. a plugin in the middle of the tab) we leave things as they are
+            return Ok(());
+        }
+        let viewport = *self.viewport.borrow();
+        let pane_ids: Vec<PaneId> = self
+            .panes
+            .iter()
+            .filter(|(pane_id, pane)| pane.selectable() && !self.panes_to_hide.contains(pane_id))
+            .map(|(pane_id, _)| *pane_id)
+            .collect();
+        let layout =
+            match tiling_mode_layout(tiling_mode, pane_ids.len(), viewport.cols, viewport.rows) {
+                Some(layout) => layout,
+                None => return Ok(()),
+            };
+        let mut space = PaneGeom {
+            x: viewport.x,
+            y: viewport.y,
+            ..Default::default()
+        };
+        space.cols.set_inner(viewport.cols);
+        space.rows.set_inner(viewport.rows);
+        let positions = layout
+            .position_panes_in_space(&space, None, false, false)
+            .map_err(|e| anyhow!(e))
+            .with_context(err_context)?;
+        for (pane_id, (_layout, position_and_size)) in pane_ids.iter().zip(positions) {
+            self.set_geom_for_pane_with_id(pane_id, position_and_size);
+        }
+        self.reset_boundaries();
+        self.set_pane_frames(self.draw_pane_frames);
+        Ok(())
+    }
     pub fn reapply_pane_frames(&mut self) {
         // same as set_pane_frames except it reapplies the current situation
         self.set_pane_frames(self.draw_pane_frames);
@@ -0,0 +1,103 @@
+use zellij_utils::{
+    data::TilingMode,
+    input::layout::{SplitDirection, TiledPaneLayout},
+};
+
+/// The layout `pane_count` panes should be arranged in when occupying a space of `cols` x `rows`
+/// according to the given tiling mode.
+///
+/// The leaves of the returned layout are ordered, so that when flattened (eg. with
+/// `position_panes_in_space`) the first position is meant for the first pane, and so on.
+/// Returns None for the manual tiling mode, in which panes are never rearranged.
+pub fn tiling_mode_layout(
+    tiling_mode: TilingMode,
+    pane_count: usize,
+    cols: usize,
+    rows: usize,
+) -> Option<TiledPaneLayout> {
+    if pane_count == 0 {
+        return None;
+    }
+    match tiling_mode {
+        TilingMode::Manual => None,
+        TilingMode::Bsp => Some(bsp_layout(pane_count, cols, rows)),
+        TilingMode::MasterStack => Some(master_stack_layout(pane_count, cols, rows)),
+        TilingMode::Grid => Some(grid_layout(pane_count, cols, rows)),
+    }
+}
+
+fn bsp_layout(pane_count: usize, cols: usize, rows: usize) -> TiledPaneLayout {
+    if pane_count <= 1 {
+        return TiledPaneLayout::default();
+    }
+    let split_direction = longest_side(cols, rows);
+    let (remaining_cols, remaining_rows) = match split_direction {
+        SplitDirection::Vertical => (cols / 2, rows),
+        SplitDirection::Horizontal => (cols, rows / 2),
+    };
+    split_evenly(
+        split_direction,
+        vec![
+            TiledPaneLayout::default(),
+            bsp_layout(pane_count - 1, remaining_cols, remaining_rows),
+        ],
+    )
+}
+
+fn master_stack_layout(pane_count: usize, cols: usize, rows: usize) -> TiledPaneLayout {
+    if pane_count <= 1 {
+        return TiledPaneLayout::default();
+    }
+    let split_direction = longest_side(cols, rows);
+    let stack = split_evenly(
+        !split_direction,
+        vec![TiledPaneLayout::default(); pane_count - 1],
+    );
+    split_evenly(split_direction, vec![TiledPaneLayout::default(), stack])
+}
+
+fn grid_layout(pane_count: usize, cols: usize, rows: usize) -> TiledPaneLayout {
+    // lines are split along the longest side, so that side also gets the most of them
+    let split_direction = longest_side(cols, rows);
+    let lines_count = (pane_count as f64).sqrt().ceil() as usize;
+    let mut panes_left = pane_count;
+    let mut lines = vec![];
+    for line_index in 0..lines_count {
+        // spread the remainder over the last lines rather than leaving one mostly empty line
+        let lines_left = lines_count - line_index;
+        let panes_in_line = panes_left / lines_left;
+        panes_left -= panes_in_line;
+        lines.push(split_evenly(
+            !split_direction,
+            vec![TiledPaneLayout::default(); panes_in_line],
+        ));
+    }
+    split_evenly(split_direction, lines)
+}
+
+fn split_evenly(
+    split_direction: SplitDirection,
+    mut children: Vec<TiledPaneLayout>,
+) -> TiledPaneLayout {
+    if children.len() == 1 {
+        return children.remove(0);
+    }
+    TiledPaneLayout {
+        children_split_direction: split_direction,
+        children,
+        ..Default::default()
+    }
+}
+
+fn longest_side(cols: usize, rows: usize) -> SplitDirection {
+    // terminal cells are roughly twice as tall as they are wide
+    if cols >= rows * 2 {
+        SplitDirection::Vertical
+    } else {
+        SplitDirection::Horizontal
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/tiling_modes_tests.rs"]
+mod tiling_modes_tests;
@@ -1214,6 +1214,12 @@ impl Pane for MockPane {
     fn exclude_from_sync(&self) -> bool {
         unimplemented!()
     }
+    fn set_mark(&mut self, _mark: Option<String>) {
+        unimplemented!()
+    }
+    fn mark(&self) -> Option<&str> {
+        unimplemented!()
+    }
 
     fn add_red_pane_frame_color_override(&mut self, _error_text: Option<String>) {
         unimplemented!()
@@ -0,0 +1,113 @@
+use super::tiling_mode_layout;
+use zellij_utils::data::TilingMode;
+use zellij_utils::pane_size::PaneGeom;
+
+fn positions_for(
+    tiling_mode: TilingMode,
+    pane_count: usize,
+    cols: usize,
+    rows: usize,
+) -> Vec<(usize, usize, usize, usize)> {
+    // (x, y, cols, rows)
+    let mut space = PaneGeom::default();
+    space.cols.set_inner(cols);
+    space.rows.set_inner(rows);
+    tiling_mode_layout(tiling_mode, pane_count, cols, rows)
+        .unwrap()
+        .position_panes_in_space(&space, None, false, false)
+        .unwrap()
+        .into_iter()
+        .map(|(_, geom)| (geom.x, geom.y, geom.cols.as_usize(), geom.rows.as_usize()))
+        .collect()
+}
+
+#[test]
+fn manual_tiling_mode_has_no_layout() {
+    assert!(tiling_mode_layout(TilingMode::Manual, 3, 120, 40).is_none());
+}
+
+#[test]
+fn single_pane_takes_up_the_whole_space_in_all_tiling_modes() {
+    for tiling_mode in [TilingMode::Bsp, TilingMode::MasterStack, TilingMode::Grid] {
+        assert_eq!(
+            positions_for(tiling_mode, 1, 120, 40),
+            vec![(0, 0, 120, 40)],
+            "{}",
+            tiling_mode
+        );
+    }
+}
+
+#[test]
+fn bsp_splits_the_remaining_space_along_its_longest_side() {
+    assert_eq!(
+        positions_for(TilingMode::Bsp, 4, 120, 40),
+        vec![
+            (0, 0, 60, 40),
+            (60, 0, 60, 20),
+            (60, 20, 30, 20),
+            (90, 20, 30, 20)
+        ]
+    );
+}
+
+#[test]
+fn master_stack_places_all_panes_but_the_first_in_a_stack() {
+    assert_eq!(
+        positions_for(TilingMode::MasterStack, 4, 120, 40),
+        vec![
+            (0, 0, 60, 40),
+            (60, 0, 60, 13),
+            (60, 13, 60, 13),
+            (60, 26, 60, 14)
+        ]
+    );
+}
+
+#[test]
+fn master_stack_on_a_narrow_space_stacks_below_the_first_pane() {
+    assert_eq!(
+        positions_for(TilingMode::MasterStack, 3, 60, 40),
+        vec![(0, 0, 60, 20), (0, 20, 30, 20), (30, 20, 30, 20)]
+    );
+}
+
+#[test]
+fn grid_arranges_panes_evenly() {
+    assert_eq!(
+        positions_for(TilingMode::Grid, 4, 120, 40),
+        vec![
+            (0, 0, 60, 20),
+            (60, 0, 60, 20),
+            (0, 20, 60, 20),
+            (60, 20, 60, 20)
+        ]
+    );
+}
+
+#[test]
+fn grid_spreads_uneven_pane_counts_over_the_last_lines() {
+    assert_eq!(
+        positions_for(TilingMode::Grid, 5, 120, 40),
+        vec![
+            (0, 0, 40, 40),
+            (40, 0, 40, 20),
+            (80, 0, 40, 20),
+            (40, 20, 40, 20),
+            (80, 20, 40, 20)
+        ]
+    );
+}
+
+#[test]
+fn grid_of_twelve_panes_fills_the_space_with_four_columns_of_three() {
+    let positions = positions_for(TilingMode::Grid, 12, 200, 50);
+    assert_eq!(positions.len(), 12);
+    let mut total_area = 0;
+    for (_x, _y, cols, rows) in positions {
+        assert_eq!(cols, 50);
+        assert!((16..=18).contains(&rows), "rows: {}", rows);
+        total_area += cols * rows;
+    }
+    assert_eq!(total_area, 200 * 50);
+}
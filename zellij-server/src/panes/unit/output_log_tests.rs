@@ -0,0 +1,83 @@
+use super::PaneOutputLog;
+use std::path::PathBuf;
+use zellij_utils::data::PaneLogOptions;
+
+fn log_options(path: PathBuf) -> PaneLogOptions {
+    PaneLogOptions {
+        path,
+        strip_ansi: false,
+        append: false,
+        max_size: None,
+        max_files: PaneLogOptions::DEFAULT_MAX_FILES,
+    }
+}
+
+#[test]
+fn writes_raw_pane_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.log");
+    let mut output_log = PaneOutputLog::new(log_options(path.clone())).unwrap();
+    output_log.write(b"\x1b[31mred\x1b[0m text\r\n").unwrap();
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        b"\x1b[31mred\x1b[0m text\r\n".to_vec()
+    );
+}
+
+#[test]
+fn strips_ansi_escape_sequences() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.log");
+    let mut options = log_options(path.clone());
+    options.strip_ansi = true;
+    let mut output_log = PaneOutputLog::new(options).unwrap();
+    // the escape sequence is split between two writes, as it might be when read from the pty
+    output_log
+        .write(b"\x1b[1;31mbold red\x1b[0m\x1b]0;title\x07\x1b[")
+        .unwrap();
+    output_log.write(b"2Jcleared\x08\ttab\r\n").unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "bold redcleared\ttab\r\n"
+    );
+}
+
+#[test]
+fn appends_to_existing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.log");
+    std::fs::write(&path, "existing\n").unwrap();
+    let mut options = log_options(path.clone());
+    options.append = true;
+    let mut output_log = PaneOutputLog::new(options).unwrap();
+    output_log.write(b"new\n").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "existing\nnew\n");
+}
+
+#[test]
+fn truncates_existing_file_when_not_appending() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.log");
+    std::fs::write(&path, "existing\n").unwrap();
+    let mut output_log = PaneOutputLog::new(log_options(path.clone())).unwrap();
+    output_log.write(b"new\n").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "new\n");
+}
+
+#[test]
+fn rotates_file_when_exceeding_max_size() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.log");
+    let mut options = log_options(path.clone());
+    options.max_size = Some(10);
+    options.max_files = 2;
+    let mut output_log = PaneOutputLog::new(options).unwrap();
+    for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+        output_log.write(line.as_bytes()).unwrap();
+    }
+    let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).unwrap();
+    assert_eq!(read("pane.log"), "fourth\n");
+    assert_eq!(read("pane.log.1"), "third\n");
+    assert_eq!(read("pane.log.2"), "second\n");
+    assert!(!dir.path().join("pane.log.3").exists());
+}
@@ -0,0 +1,119 @@
+use super::PaneOutputSubscriptions;
+use zellij_utils::data::{PaneId, PaneOutput, PaneOutputFormat};
+
+#[test]
+fn delivers_raw_output_to_subscribers() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Raw);
+    assert!(subscriptions.push(1, b"\x1b[31mred"));
+    assert!(subscriptions.push(1, b"\x1b[0m\r\n"));
+    assert!(!subscriptions.push(2, b"not subscribed"));
+    assert_eq!(
+        subscriptions.drain(),
+        vec![(
+            10,
+            1,
+            PaneId::Terminal(1),
+            PaneOutput::Raw(b"\x1b[31mred\x1b[0m\r\n".to_vec())
+        )]
+    );
+    assert_eq!(subscriptions.drain(), vec![], "nothing is delivered twice");
+}
+
+#[test]
+fn delivers_only_completed_lines() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Lines);
+    // the escape sequence and the line are split between reads, as they might be from the pty
+    subscriptions.push(1, b"\x1b[1;31mfirst\x1b[");
+    subscriptions.push(1, b"0m line\r\nsecond");
+    assert_eq!(
+        subscriptions.drain(),
+        vec![(
+            10,
+            1,
+            PaneId::Terminal(1),
+            PaneOutput::Lines(vec!["first line".to_owned()])
+        )]
+    );
+    subscriptions.push(1, b" line\r\n\r\n");
+    assert_eq!(
+        subscriptions.drain(),
+        vec![(
+            10,
+            1,
+            PaneId::Terminal(1),
+            PaneOutput::Lines(vec!["second line".to_owned(), "".to_owned()])
+        )]
+    );
+}
+
+#[test]
+fn overwritten_line_keeps_only_last_write() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Lines);
+    subscriptions.push(1, b"10%\r50%\r100%\r\n");
+    assert_eq!(
+        subscriptions.drain(),
+        vec![(
+            10,
+            1,
+            PaneId::Terminal(1),
+            PaneOutput::Lines(vec!["100%".to_owned()])
+        )]
+    );
+}
+
+#[test]
+fn each_subscriber_gets_its_own_format() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Raw);
+    subscriptions.subscribe(1, 11, 1, PaneOutputFormat::Lines);
+    subscriptions.push(1, b"foo\n");
+    assert_eq!(
+        subscriptions.drain(),
+        vec![
+            (
+                10,
+                1,
+                PaneId::Terminal(1),
+                PaneOutput::Raw(b"foo\n".to_vec())
+            ),
+            (
+                11,
+                1,
+                PaneId::Terminal(1),
+                PaneOutput::Lines(vec!["foo".to_owned()])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn buffered_output_is_capped() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Lines);
+    for i in 0..1500 {
+        subscriptions.push(1, format!("line {}\n", i).as_bytes());
+    }
+    match subscriptions.drain().pop() {
+        Some((_, _, _, PaneOutput::Lines(lines))) => {
+            assert_eq!(lines.len(), 1000);
+            assert_eq!(lines.first(), Some(&"line 500".to_owned()));
+            assert_eq!(lines.last(), Some(&"line 1499".to_owned()));
+        },
+        other => panic!("unexpected output: {:?}", other),
+    }
+}
+
+#[test]
+fn unsubscribing_stops_delivery() {
+    let mut subscriptions = PaneOutputSubscriptions::default();
+    subscriptions.subscribe(1, 10, 1, PaneOutputFormat::Raw);
+    subscriptions.subscribe(2, 10, 1, PaneOutputFormat::Raw);
+    subscriptions.unsubscribe(1, 10, 1);
+    assert!(!subscriptions.push(1, b"foo"));
+    subscriptions.unsubscribe_plugin(10, 1);
+    assert!(!subscriptions.push(2, b"foo"));
+    assert!(subscriptions.is_empty());
+}
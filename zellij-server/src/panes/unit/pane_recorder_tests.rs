@@ -0,0 +1,44 @@
+use super::PaneRecorder;
+use zellij_utils::asciicast::{parse_asciicast, OUTPUT_EVENT, RESIZE_EVENT};
+
+#[test]
+fn records_output_and_resizes_as_asciicast() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.cast");
+    let mut recorder = PaneRecorder::new(path.clone(), 80, 24, Some("my pane".to_owned())).unwrap();
+    recorder.record_output(b"\x1b[31mhello\x1b[0m\r\n").unwrap();
+    recorder.record_resize(80, 24).unwrap(); // unchanged, should not be recorded
+    recorder.record_resize(100, 30).unwrap();
+    recorder.record_output(b"world").unwrap();
+    let (header, events) = parse_asciicast(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!((header.width, header.height), (80, 24));
+    assert_eq!(header.title, Some("my pane".to_owned()));
+    assert!(header.timestamp.is_some());
+    let events: Vec<(&str, &str)> = events
+        .iter()
+        .map(|e| (e.code.as_str(), e.data.as_str()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (OUTPUT_EVENT, "\u{1b}[31mhello\u{1b}[0m\r\n"),
+            (RESIZE_EVENT, "100x30"),
+            (OUTPUT_EVENT, "world"),
+        ]
+    );
+}
+
+#[test]
+fn keeps_characters_split_between_chunks_intact() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pane.cast");
+    let mut recorder = PaneRecorder::new(path.clone(), 80, 24, None).unwrap();
+    let bytes = "a€b".as_bytes();
+    // split the 3 byte euro sign between two reads from the pty
+    recorder.record_output(&bytes[..2]).unwrap();
+    recorder.record_output(&bytes[2..]).unwrap();
+    let (_header, events) = parse_asciicast(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let output: String = events.iter().map(|e| e.data.as_str()).collect();
+    assert_eq!(output, "a€b");
+    assert_eq!(events[0].data, "a");
+}
@@ -6,7 +6,7 @@ use insta::assert_snapshot;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
-use zellij_utils::data::{Palette, Style};
+use zellij_utils::data::{Palette, SearchQuery, Style};
 use zellij_utils::pane_size::PaneGeom;
 
 fn read_fixture() -> Vec<u8> {
@@ -424,3 +424,160 @@ pub fn searching_whole_word_case_insensitive() {
         format!("{:?}", terminal_pane.grid)
     );
 }
+
+#[test]
+pub fn searching_with_regex() {
+    let mut terminal_pane = create_pane();
+    terminal_pane.update_search_term("qu[a]m");
+    assert!(terminal_pane.grid.search_results.selections.is_empty());
+
+    terminal_pane.toggle_search_regex();
+    assert_snapshot!(
+        "grid_copy_quam_highlighted",
+        format!("{:?}", terminal_pane.grid)
+    );
+
+    terminal_pane.toggle_search_whole_words();
+    assert_snapshot!(
+        "grid_copy_quam_whole_word_only",
+        format!("{:?}", terminal_pane.grid)
+    );
+}
+
+#[test]
+pub fn searching_with_regex_across_line_wrap() {
+    let mut terminal_pane = create_pane();
+    terminal_pane.handle_pty_bytes(
+        "\n\rfailed with error: 12345678 at the end"
+            .as_bytes()
+            .to_vec(),
+    );
+    // Spread across four lines
+    terminal_pane.grid.change_size(20, 5);
+    terminal_pane.update_search_term("error: [0-9]+");
+    terminal_pane.toggle_search_regex();
+    let selections = &terminal_pane.grid.search_results.selections;
+    assert_eq!(selections.len(), 1, "found exactly one match");
+    assert_eq!(selections[0].start.line(), 14);
+    assert_eq!(selections[0].start.column(), 2);
+    assert_eq!(selections[0].end.line(), 17);
+    assert_eq!(selections[0].end.column(), 2);
+}
+
+#[test]
+pub fn searching_with_invalid_regex_finds_nothing() {
+    let mut terminal_pane = create_pane();
+    terminal_pane.toggle_search_regex();
+    terminal_pane.update_search_term("quam(");
+    assert!(terminal_pane.grid.search_results.selections.is_empty());
+}
+
+#[test]
+pub fn searching_the_whole_scrollback() {
+    let mut terminal_pane = create_pane();
+    terminal_pane.handle_pty_bytes(
+        "\n\rfailed with error: 12345678 at the end"
+            .as_bytes()
+            .to_vec(),
+    );
+    terminal_pane.grid.change_size(20, 5);
+
+    let literal_matches = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "quam".to_owned(),
+            ..Default::default()
+        })
+        .unwrap();
+    let regex_matches = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "qu[a]m".to_owned(),
+            regex: true,
+            ..Default::default()
+        })
+        .unwrap();
+    assert!(!literal_matches.is_empty());
+    assert_eq!(literal_matches, regex_matches);
+
+    let wrapped_matches = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "ERROR: \\d+".to_owned(),
+            regex: true,
+            case_insensitive: true,
+            ..Default::default()
+        })
+        .unwrap();
+    let last_match = wrapped_matches.last().unwrap();
+    assert_eq!(last_match.text, "error: 12345678");
+    assert_eq!(last_match.start_column, 12);
+    assert_eq!(last_match.end_column, 27);
+
+    assert!(terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "quam(".to_owned(),
+            regex: true,
+            ..Default::default()
+        })
+        .is_err());
+}
+
+#[test]
+pub fn searching_the_whole_scrollback_with_context() {
+    let mut terminal_pane = create_pane();
+    terminal_pane.handle_pty_bytes(
+        "\n\rfailed with error: 12345678 at the end"
+            .as_bytes()
+            .to_vec(),
+    );
+    let search_matches = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "error: \\d+".to_owned(),
+            regex: true,
+            context_lines: 2,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(search_matches.len(), 1);
+    assert_eq!(
+        search_matches[0].context_before,
+        vec![
+            "zellij on \u{e0a0} mouse-support [?] is 📦 v0.14.0 via 🦀 v1.53.0-beta.3".to_owned(),
+            "❯".to_owned(),
+        ]
+    );
+    assert!(
+        search_matches[0].context_after.is_empty(),
+        "no context after the last line"
+    );
+}
+
+#[test]
+pub fn scrolling_to_a_search_match() {
+    let mut terminal_pane = create_pane();
+    let first_match = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "quam".to_owned(),
+            ..Default::default()
+        })
+        .unwrap()
+        .remove(0);
+    terminal_pane.scroll_to_line(first_match.line);
+    assert_snapshot!(
+        "grid_scrolled_to_search_match",
+        format!("{:?}", terminal_pane.grid)
+    );
+
+    // the last line is below the viewport now
+    let last_match = terminal_pane
+        .search_scrollback(&SearchQuery {
+            needle: "fringilla".to_owned(),
+            ..Default::default()
+        })
+        .unwrap()
+        .pop()
+        .unwrap();
+    terminal_pane.scroll_to_line(last_match.line);
+    assert_snapshot!(
+        "grid_scrolled_to_last_search_match",
+        format!("{:?}", terminal_pane.grid)
+    );
+}
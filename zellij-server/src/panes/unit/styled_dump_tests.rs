@@ -0,0 +1,115 @@
+use crate::panes::grid::Grid;
+use crate::panes::link_handler::LinkHandler;
+use crate::panes::sixel::SixelImageStore;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use zellij_utils::data::{DumpScreenFormat, Palette, Style};
+
+fn grid_with_content(columns: usize, rows: usize, content: &str) -> Grid {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(
+        rows,
+        columns,
+        Rc::new(RefCell::new(Palette::default())),
+        Rc::new(RefCell::new(HashMap::new())),
+        Rc::new(RefCell::new(LinkHandler::new())),
+        Rc::new(RefCell::new(None)),
+        Rc::new(RefCell::new(SixelImageStore::default())),
+        Style::default(),
+        false, // debug
+        true,  // arrow_fonts
+        true,  // styled_underlines
+        true,  // osc8_hyperlinks
+        false, // explicitly_disable_kitty_keyboard_protocol
+    );
+    for byte in content.as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    grid
+}
+
+#[test]
+fn ansi_dump_keeps_styles_and_hyperlinks() {
+    let grid = grid_with_content(
+        40,
+        5,
+        "\u{1b}[1;31mred\u{1b}[m plain \u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\\r\nnext",
+    );
+    let dump = grid.dump_styled_screen(false, DumpScreenFormat::Ansi);
+    assert_eq!(
+        dump,
+        concat!(
+            "\u{1b}[m\u{1b}[31m\u{1b}[1mred",
+            "\u{1b}[m plain ",
+            "\u{1b}]8;;https://example.com\u{1b}\\\u{1b}[mlink\u{1b}]8;;\u{1b}\\\u{1b}[m\n",
+            "\u{1b}[mnext\u{1b}[m",
+        )
+    );
+}
+
+#[test]
+fn text_format_is_the_plain_dump() {
+    let grid = grid_with_content(40, 5, "\u{1b}[1;31mred\u{1b}[m plain");
+    assert_eq!(
+        grid.dump_styled_screen(true, DumpScreenFormat::Text),
+        grid.dump_screen(true)
+    );
+}
+
+#[test]
+fn html_dump_escapes_text_and_styles_spans() {
+    let grid = grid_with_content(
+        40,
+        2,
+        "<\u{1b}[3;4:3;44mstyled\u{1b}[m> & \u{1b}[7mreversed\u{1b}[m \u{1b}[8msecret\u{1b}[m",
+    );
+    let dump = grid.dump_styled_screen(false, DumpScreenFormat::Html);
+    assert!(dump.starts_with("<!DOCTYPE html>\n"));
+    assert!(dump.ends_with("</pre>\n</body>\n</html>\n"));
+    assert!(dump.contains(concat!(
+        "&lt;",
+        "<span style=\"background-color:#000080;font-style:italic;text-decoration-style:wavy;text-decoration-line:underline\">styled</span>",
+        "&gt; &amp; ",
+        "<span style=\"color:#000000;background-color:#d0d0d0\">reversed</span>",
+        "       </pre>",
+    )));
+    assert!(!dump.contains("secret"));
+}
+
+#[test]
+fn html_dump_links_hyperlinks_and_drops_scripts() {
+    let grid = grid_with_content(
+        60,
+        2,
+        "\u{1b}]8;;https://example.com/?a=1&b=2\u{1b}\\safe\u{1b}]8;;\u{1b}\\ \u{1b}]8;;javascript:alert(1)\u{1b}\\unsafe\u{1b}]8;;\u{1b}\\",
+    );
+    let dump = grid.dump_styled_screen(false, DumpScreenFormat::Html);
+    assert!(dump.contains(
+        "<a href=\"https://example.com/?a=1&amp;b=2\" style=\"color:inherit\">safe</a> unsafe"
+    ));
+    assert!(!dump.contains("javascript"));
+}
+
+#[test]
+fn html_dump_joins_wrapped_lines() {
+    let grid = grid_with_content(5, 3, "0123456789");
+    let dump = grid.dump_styled_screen(false, DumpScreenFormat::Html);
+    assert!(dump.contains(">0123456789</pre>"));
+}
+
+#[test]
+fn svg_dump_places_wide_characters_on_their_cells() {
+    let grid = grid_with_content(10, 2, "a中\u{1b}[42mb\u{1b}[m");
+    let dump = grid.dump_styled_screen(false, DumpScreenFormat::Svg);
+    assert!(dump.starts_with(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36\" height=\"18\" viewBox=\"0 0 36 18\""
+    ));
+    assert!(dump.contains("<text x=\"0\" y=\"14\" fill=\"#d0d0d0\">a</text>"));
+    assert!(dump.contains(
+        "<text x=\"9\" y=\"14\" fill=\"#d0d0d0\" textLength=\"18\" lengthAdjust=\"spacingAndGlyphs\">中</text>"
+    ));
+    assert!(dump.contains("<rect x=\"27\" y=\"0\" width=\"9\" height=\"18\" fill=\"#008000\"/>"));
+    assert!(dump.contains("<text x=\"27\" y=\"14\" fill=\"#d0d0d0\">b</text>"));
+    assert!(dump.ends_with("</svg>\n"));
+}
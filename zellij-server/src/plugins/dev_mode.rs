@@ -0,0 +1,158 @@
+use super::{PluginId, PluginInstruction};
+use crate::thread_bus::ThreadSenders;
+use crate::ClientId;
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher},
+    DebounceEventResult, Debouncer, FileIdMap,
+};
+use zellij_utils::{
+    errors::prelude::*,
+    input::layout::{RunPlugin, RunPluginLocation},
+    pane_size::Size,
+};
+
+// plugin configuration key (eg. `dev true`) that marks a plugin for reloading when its file changes
+pub const DEV_MODE_CONFIG_KEY: &str = "dev";
+
+// compilers tend to write the file in several steps, so we wait for them to settle
+const DEBOUNCE_DURATION_MS: u64 = 300;
+
+/// The file to watch if the plugin was loaded in dev mode (ie. `dev true` with a `file:` location)
+pub fn dev_mode_plugin_path(run_plugin: &RunPlugin, cwd: &Path) -> Option<PathBuf> {
+    let dev_mode = run_plugin
+        .configuration
+        .inner()
+        .get(DEV_MODE_CONFIG_KEY)
+        .map(|dev| dev == "true")
+        .unwrap_or(false);
+    match &run_plugin.location {
+        RunPluginLocation::File(path) if dev_mode => Some(cwd.join(path)),
+        _ => None,
+    }
+}
+
+/// Everything needed to load a dev mode plugin again in place, including after it failed to load
+/// (in which case it is not in the plugin map)
+#[derive(Debug, Clone)]
+pub struct DevModePlugin {
+    pub run_plugin: RunPlugin,
+    pub path: PathBuf,
+    pub tab_index: Option<usize>,
+    pub size: Size,
+    pub cwd: PathBuf,
+    pub client_id: ClientId,
+}
+
+#[derive(Default)]
+pub struct DevModePlugins {
+    plugins: BTreeMap<PluginId, DevModePlugin>,
+    watcher: Option<Debouncer<RecommendedWatcher, FileIdMap>>,
+    watched_folders: HashSet<PathBuf>,
+}
+
+impl DevModePlugins {
+    pub fn add(
+        &mut self,
+        plugin_id: PluginId,
+        dev_mode_plugin: DevModePlugin,
+        senders: &ThreadSenders,
+    ) -> Result<()> {
+        // we watch the folder rather than the file, because builds often replace the file
+        // (which would silently drop a watch on the file itself)
+        let folder = dev_mode_plugin
+            .path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .with_context(|| format!("{:?} is not in a folder", dev_mode_plugin.path))?;
+        self.plugins.insert(plugin_id, dev_mode_plugin);
+        if !self.watched_folders.contains(&folder) {
+            let watcher = match self.watcher.as_mut() {
+                Some(watcher) => watcher,
+                None => self.watcher.insert(watch_plugin_files(senders.clone())?),
+            };
+            watcher
+                .watcher()
+                .watch(&folder, RecursiveMode::NonRecursive)?;
+            watcher
+                .cache()
+                .add_root(&folder, RecursiveMode::NonRecursive);
+            self.watched_folders.insert(folder);
+        }
+        Ok(())
+    }
+    pub fn remove(&mut self, plugin_id: PluginId) {
+        self.plugins.remove(&plugin_id);
+    }
+    pub fn get(&self, plugin_id: PluginId) -> Option<&DevModePlugin> {
+        self.plugins.get(&plugin_id)
+    }
+    pub fn resize(&mut self, plugin_id: PluginId, rows: usize, cols: usize) {
+        if let Some(dev_mode_plugin) = self.plugins.get_mut(&plugin_id) {
+            dev_mode_plugin.size = Size { rows, cols };
+        }
+    }
+    pub fn plugin_ids_for_paths(&self, paths: &[PathBuf]) -> Vec<PluginId> {
+        self.plugins
+            .iter()
+            .filter(|(_plugin_id, dev_mode_plugin)| {
+                paths
+                    .iter()
+                    .any(|path| same_file(path, &dev_mode_plugin.path))
+            })
+            .map(|(plugin_id, _dev_mode_plugin)| *plugin_id)
+            .collect()
+    }
+    pub fn stop(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.stop_nonblocking();
+        }
+        self.watched_folders.clear();
+    }
+}
+
+fn same_file(changed_path: &Path, plugin_path: &Path) -> bool {
+    if changed_path == plugin_path {
+        return true;
+    }
+    match (changed_path.canonicalize(), plugin_path.canonicalize()) {
+        (Ok(changed_path), Ok(plugin_path)) => changed_path == plugin_path,
+        _ => false,
+    }
+}
+
+fn watch_plugin_files(senders: ThreadSenders) -> Result<Debouncer<RecommendedWatcher, FileIdMap>> {
+    let debouncer = new_debouncer(
+        Duration::from_millis(DEBOUNCE_DURATION_MS),
+        None,
+        move |result: DebounceEventResult| match result {
+            Ok(events) => {
+                let changed_paths: Vec<PathBuf> = events
+                    .into_iter()
+                    .filter(|event| {
+                        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                    })
+                    .flat_map(|event| event.event.paths)
+                    .collect();
+                if !changed_paths.is_empty() {
+                    let _ = senders.send_to_plugin(PluginInstruction::DevModePluginFilesChanged(
+                        changed_paths,
+                    ));
+                }
+            },
+            Err(errors) => errors
+                .iter()
+                .for_each(|error| log::error!("dev mode watch error: {error:?}")),
+        },
+    )?;
+    Ok(debouncer)
+}
+
+#[cfg(test)]
+#[path = "./unit/dev_mode_tests.rs"]
+mod dev_mode_tests;
@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::plugins::PluginId;
+use crate::ClientId;
+
+pub const HTTP_REQUEST_TIMEOUT_MS: u64 = 30000;
+
+/// Routes the requests the web server receives under `/plugins/<route>/` to the plugin that
+/// registered `<route>` with `RegisterHttpRoute`, and keeps track of the requests that are still
+/// waiting for a response so that each web client gets exactly one - the reply, a timeout or an
+/// error
+#[derive(Default)]
+pub struct HttpRouter {
+    routes: BTreeMap<String, (PluginId, ClientId)>,
+    pending_requests: HashMap<u64, PendingHttpRequest>,
+    next_request_id: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingHttpRequest {
+    web_client_id: ClientId,
+    plugin_id: PluginId,
+}
+
+/// Route names become the first segment of the url path, so we keep them to characters that do
+/// not need escaping
+pub fn validate_route_name(route: &str) -> Result<(), String> {
+    if route.is_empty() {
+        Err("HTTP route names cannot be empty".to_owned())
+    } else if !route
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Err(format!(
+            "Invalid HTTP route name \"{}\", only letters, digits, '-' and '_' are allowed",
+            route
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+impl HttpRouter {
+    /// A route belongs to one plugin at a time, registering it again from the same plugin (eg.
+    /// after a reload) just updates the instance it is routed to
+    pub fn register(
+        &mut self,
+        plugin_id: PluginId,
+        client_id: ClientId,
+        route: String,
+    ) -> Result<(), String> {
+        validate_route_name(&route)?;
+        match self.routes.get(&route) {
+            Some((registered_plugin_id, _client_id)) if *registered_plugin_id != plugin_id => {
+                Err(format!(
+                    "The HTTP route \"{}\" is already served by plugin {}",
+                    route, registered_plugin_id
+                ))
+            },
+            _ => {
+                self.routes.insert(route, (plugin_id, client_id));
+                Ok(())
+            },
+        }
+    }
+    pub fn unregister(&mut self, plugin_id: PluginId, route: &str) {
+        if let Some((registered_plugin_id, _client_id)) = self.routes.get(route) {
+            if *registered_plugin_id == plugin_id {
+                self.routes.remove(route);
+            }
+        }
+    }
+    /// Records a request for this route as pending, returning its id and the plugin instance that
+    /// should handle it, or None if no plugin serves the route
+    pub fn route(
+        &mut self,
+        web_client_id: ClientId,
+        route: &str,
+    ) -> Option<(u64, (PluginId, ClientId))> {
+        let (plugin_id, client_id) = *self.routes.get(route)?;
+        self.next_request_id += 1;
+        let request_id = self.next_request_id;
+        self.pending_requests.insert(
+            request_id,
+            PendingHttpRequest {
+                web_client_id,
+                plugin_id,
+            },
+        );
+        Some((request_id, (plugin_id, client_id)))
+    }
+    /// Returns the web client waiting for this response, or None if the request already timed
+    /// out or the responder is not the plugin the request was routed to
+    pub fn respond(&mut self, request_id: u64, responder_plugin_id: PluginId) -> Option<ClientId> {
+        match self.pending_requests.get(&request_id) {
+            Some(pending_request) if pending_request.plugin_id == responder_plugin_id => self
+                .pending_requests
+                .remove(&request_id)
+                .map(|pending_request| pending_request.web_client_id),
+            _ => None,
+        }
+    }
+    /// Returns the web client of this request if it is still waiting for a response
+    pub fn time_out(&mut self, request_id: u64) -> Option<ClientId> {
+        self.pending_requests
+            .remove(&request_id)
+            .map(|pending_request| pending_request.web_client_id)
+    }
+    /// Forgets the routes of an unloaded plugin, returning the web clients that were waiting on
+    /// it so that they can be answered right away
+    pub fn remove_plugin(&mut self, plugin_id: PluginId) -> Vec<ClientId> {
+        self.routes
+            .retain(|_route, (registered_plugin_id, _client_id)| {
+                *registered_plugin_id != plugin_id
+            });
+        let mut web_client_ids = vec![];
+        self.pending_requests
+            .retain(|_request_id, pending_request| {
+                if pending_request.plugin_id == plugin_id {
+                    web_client_ids.push(pending_request.web_client_id);
+                    false
+                } else {
+                    true
+                }
+            });
+        web_client_ids.sort();
+        web_client_ids
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/http_routes_tests.rs"]
+mod http_routes_tests;
@@ -0,0 +1,166 @@
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use url::Url;
+use zellij_utils::consts::{ZELLIJ_CACHE_DIR, ZELLIJ_SESSION_CACHE_DIR};
+use zellij_utils::data::KvScope;
+use zellij_utils::errors::prelude::*;
+use zellij_utils::input::plugins::PluginConfig;
+
+// keys are hex encoded into file names, which most file systems limit to 255 bytes
+pub const MAX_KEY_LENGTH: usize = 120;
+pub const MAX_VALUE_SIZE: u64 = 1024 * 1024;
+pub const MAX_STORE_SIZE: u64 = 16 * 1024 * 1024;
+pub const MAX_KEYS: usize = 4096;
+
+const TMP_FILE_PREFIX: &str = ".tmp-";
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A plugin's key/value store, backing the `KvGet`, `KvSet`, `KvDelete` and `KvList` commands
+///
+/// Every key is kept in its own file (named after the hex encoding of the key so that any string
+/// is a valid key), which makes each write a single atomic rename and lets several instances of
+/// the same plugin - or several sessions, for the global scope - share the store without locking
+pub struct KvStore {
+    dir: PathBuf,
+}
+
+impl KvStore {
+    pub fn new(dir: PathBuf) -> Self {
+        KvStore { dir }
+    }
+    pub fn for_plugin(plugin: &PluginConfig, scope: KvScope) -> Self {
+        let plugin_dir = Url::from(&plugin.location).to_string();
+        let dir = match scope {
+            KvScope::Session => ZELLIJ_SESSION_CACHE_DIR.join(plugin_dir).join("kv"),
+            KvScope::Global => ZELLIJ_CACHE_DIR.join(plugin_dir).join("kv"),
+        };
+        KvStore::new(dir)
+    }
+    pub fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path_for_key(key)?;
+        match fs::read(&path) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+    pub fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        let path = self.path_for_key(key)?;
+        let value_size = value.len() as u64;
+        if value_size > MAX_VALUE_SIZE {
+            return Err(anyhow!(
+                "Value of {} bytes exceeds the maximum of {} bytes",
+                value_size,
+                MAX_VALUE_SIZE
+            ));
+        }
+        let (key_count, store_size) = self.usage()?;
+        let previous_size = fs::metadata(&path).ok().map(|m| m.len());
+        if previous_size.is_none() && key_count >= MAX_KEYS {
+            return Err(anyhow!("Store is full ({} keys)", MAX_KEYS));
+        }
+        if store_size - previous_size.unwrap_or(0) + value_size > MAX_STORE_SIZE {
+            return Err(anyhow!(
+                "Store would exceed its quota of {} bytes",
+                MAX_STORE_SIZE
+            ));
+        }
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("failed to create {}", self.dir.display()))?;
+        // write to a temporary file next to the final one and rename it into place, so that a
+        // crash or a concurrent reader never sees a partially written value
+        let tmp_path = self.dir.join(format!(
+            "{}{}-{}",
+            TMP_FILE_PREFIX,
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let write_tmp_file = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&tmp_path)?;
+            file.write_all(value)?;
+            file.sync_all()
+        };
+        if let Err(e) = write_tmp_file() {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e).with_context(|| format!("failed to write {}", tmp_path.display()));
+        }
+        fs::rename(&tmp_path, &path).with_context(|| format!("failed to write {}", path.display()))
+    }
+    pub fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path_for_key(key)?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("failed to delete {}", path.display())),
+        }
+    }
+    pub fn list(&self) -> Result<Vec<String>> {
+        let mut keys: Vec<String> = self
+            .entries()?
+            .into_iter()
+            .filter_map(|(file_name, _size)| decode_key(&file_name))
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+    fn usage(&self) -> Result<(usize, u64)> {
+        let entries = self.entries()?;
+        let store_size = entries.iter().map(|(_file_name, size)| size).sum();
+        Ok((entries.len(), store_size))
+    }
+    fn entries(&self) -> Result<Vec<(String, u64)>> {
+        let read_dir = match fs::read_dir(&self.dir) {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => {
+                return Err(e).with_context(|| format!("failed to read {}", self.dir.display()))
+            },
+        };
+        Ok(read_dir
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                if file_name.starts_with(TMP_FILE_PREFIX) {
+                    return None;
+                }
+                let size = entry.metadata().ok()?.len();
+                Some((file_name, size))
+            })
+            .collect())
+    }
+    fn path_for_key(&self, key: &str) -> Result<PathBuf> {
+        if key.is_empty() {
+            return Err(anyhow!("Key cannot be empty"));
+        }
+        if key.len() > MAX_KEY_LENGTH {
+            return Err(anyhow!(
+                "Key of {} bytes exceeds the maximum of {} bytes",
+                key.len(),
+                MAX_KEY_LENGTH
+            ));
+        }
+        Ok(self.dir.join(encode_key(key)))
+    }
+}
+
+fn encode_key(key: &str) -> String {
+    key.bytes().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_key(file_name: &str) -> Option<String> {
+    if !file_name.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..file_name.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(file_name.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+#[path = "./unit/kv_store_tests.rs"]
+mod kv_store_tests;
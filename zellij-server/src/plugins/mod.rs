@@ -1,8 +1,12 @@
+mod dev_mode;
+mod http_routes;
+mod kv_store;
 mod pinned_executor;
 mod pipes;
 mod plugin_loader;
 mod plugin_map;
 mod plugin_worker;
+mod rpc;
 mod wasm_bridge;
 mod watch_filesystem;
 mod zellij_exports;
@@ -17,6 +21,7 @@ use std::{
 };
 use wasmi::Engine;
 
+use crate::global_async_runtime::get_tokio_runtime;
 use crate::panes::PaneId;
 use crate::route::NotificationEnd;
 use crate::screen::ScreenInstruction;
@@ -25,6 +30,9 @@ use crate::{pty::PtyInstruction, thread_bus::Bus, ClientId, ServerInstruction};
 use zellij_utils::data::PaneRenderReport;
 use zellij_utils::input::layout::TabLayoutInfo;
 
+use http_routes::{HttpRouter, HTTP_REQUEST_TIMEOUT_MS};
+pub use plugin_map::DEFAULT_PLUGIN_EXECUTION_BUDGET;
+use rpc::RpcRouter;
 pub use wasm_bridge::PluginRenderAsset;
 use wasm_bridge::WasmBridge;
 
@@ -33,7 +41,8 @@ use zellij_utils::{
     data::{
         ClientInfo, CommandOrPlugin, Event, EventType, FloatingPaneCoordinates, InputMode,
         LayoutInfo, LayoutWithError, MessageToPlugin, PermissionStatus, PermissionType,
-        PipeMessage, PipeSource, PluginCapabilities, WebServerStatus,
+        PipeMessage, PipeSource, PluginCapabilities, PluginHttpRequest, PluginHttpResponse,
+        WebServerStatus,
     },
     errors::{prelude::*, ContextType, PluginContext},
     input::{
@@ -207,6 +216,39 @@ pub enum PluginInstruction {
     },
     LayoutListUpdate(Vec<LayoutInfo>, Vec<LayoutWithError>),
     RequestStateUpdateForPlugin(PluginId),
+    RegisterRpcMethod(PluginId, ClientId, String),
+    UnregisterRpcMethod(PluginId, ClientId, String),
+    RpcRequest {
+        request_id: u64,
+        caller_plugin_id: PluginId,
+        caller_client_id: ClientId,
+        method: String,
+        payload: Vec<u8>,
+        destination_plugin_id: Option<PluginId>,
+    },
+    RpcResponse {
+        request_id: u64,
+        responder_plugin_id: PluginId,
+        result: Result<Vec<u8>, String>,
+    },
+    RpcTimeout(u64), // request id
+    DevModePluginFilesChanged(Vec<PathBuf>),
+    RegisterHttpRoute(PluginId, ClientId, String),
+    UnregisterHttpRoute(PluginId, String),
+    HttpRequest {
+        web_client_id: ClientId,
+        request: PluginHttpRequest,
+    },
+    HttpResponse {
+        request_id: u64,
+        responder_plugin_id: PluginId,
+        response: PluginHttpResponse,
+    },
+    HttpRequestTimeout(u64), // request id
+    RevokePluginPermissions {
+        plugin_url: String,
+        permissions: Vec<PermissionType>, // all of them if empty
+    },
     Exit,
 }
 
@@ -263,6 +305,22 @@ impl From<&PluginInstruction> for PluginContext {
             PluginInstruction::RequestStateUpdateForPlugin(..) => {
                 PluginContext::RequestStateUpdateForPlugin
             },
+            PluginInstruction::RegisterRpcMethod(..) => PluginContext::RegisterRpcMethod,
+            PluginInstruction::UnregisterRpcMethod(..) => PluginContext::UnregisterRpcMethod,
+            PluginInstruction::RpcRequest { .. } => PluginContext::RpcRequest,
+            PluginInstruction::RpcResponse { .. } => PluginContext::RpcResponse,
+            PluginInstruction::RpcTimeout(..) => PluginContext::RpcTimeout,
+            PluginInstruction::DevModePluginFilesChanged(..) => {
+                PluginContext::DevModePluginFilesChanged
+            },
+            PluginInstruction::RegisterHttpRoute(..) => PluginContext::RegisterHttpRoute,
+            PluginInstruction::UnregisterHttpRoute(..) => PluginContext::UnregisterHttpRoute,
+            PluginInstruction::HttpRequest { .. } => PluginContext::HttpRequest,
+            PluginInstruction::HttpResponse { .. } => PluginContext::HttpResponse,
+            PluginInstruction::HttpRequestTimeout(..) => PluginContext::HttpRequestTimeout,
+            PluginInstruction::RevokePluginPermissions { .. } => {
+                PluginContext::RevokePluginPermissions
+            },
         }
     }
 }
@@ -284,6 +342,7 @@ pub(crate) fn plugin_thread_main(
     default_mode: InputMode,
     default_keybinds: Keybinds,
     background_plugins: HashSet<RunPluginOrAlias>,
+    execution_budget: u64,
     // the client id that started the session,
     // we need it here because the thread's own list of connected clients might not yet be updated
     // on session start when we need to load the background plugins, and so we must have an
@@ -314,6 +373,7 @@ pub(crate) fn plugin_thread_main(
         available_layout_errors,
         default_mode,
         default_keybinds,
+        execution_budget,
     );
 
     for run_plugin_or_alias in background_plugins {
@@ -326,6 +386,9 @@ pub(crate) fn plugin_thread_main(
         );
     }
 
+    let mut rpc_router = RpcRouter::default();
+    let mut http_router = HttpRouter::default();
+
     loop {
         let (event, mut err_ctx) = bus.recv().expect("failed to receive event on channel");
         err_ctx.add_call(ContextType::Plugin((&event).into()));
@@ -404,7 +467,35 @@ pub(crate) fn plugin_thread_main(
                 wasm_bridge.update_plugins(updates, shutdown_send.clone())?;
             },
             PluginInstruction::Unload(pid) => {
+                let failed_rpc_requests = rpc_router.remove_plugin(pid);
+                for web_client_id in http_router.remove_plugin(pid) {
+                    let response = PluginHttpResponse::new(
+                        502,
+                        b"The plugin serving this route was unloaded".to_vec(),
+                    );
+                    drop(
+                        bus.senders
+                            .send_to_server(ServerInstruction::PluginHttpResponse(
+                                web_client_id,
+                                Some(response),
+                            )),
+                    );
+                }
                 wasm_bridge.unload_plugin(pid)?;
+                let updates = failed_rpc_requests
+                    .into_iter()
+                    .map(|(request_id, (caller_plugin_id, caller_client_id))| {
+                        (
+                            Some(caller_plugin_id),
+                            Some(caller_client_id),
+                            Event::RpcResponse(
+                                request_id,
+                                Err("The plugin handling the request was unloaded".to_owned()),
+                            ),
+                        )
+                    })
+                    .collect();
+                wasm_bridge.update_plugins(updates, shutdown_send.clone())?;
             },
             PluginInstruction::Reload(
                 should_float,
@@ -1203,6 +1294,147 @@ pub(crate) fn plugin_thread_main(
             PluginInstruction::RequestStateUpdateForPlugin(plugin_id) => {
                 wasm_bridge.state_update_for_plugin(plugin_id);
             },
+            PluginInstruction::RegisterRpcMethod(plugin_id, client_id, method) => {
+                rpc_router.register(plugin_id, client_id, method);
+            },
+            PluginInstruction::UnregisterRpcMethod(plugin_id, client_id, method) => {
+                rpc_router.unregister(plugin_id, client_id, &method);
+            },
+            PluginInstruction::RpcRequest {
+                request_id,
+                caller_plugin_id,
+                caller_client_id,
+                method,
+                payload,
+                destination_plugin_id,
+            } => {
+                let update = match rpc_router.route(
+                    request_id,
+                    (caller_plugin_id, caller_client_id),
+                    &method,
+                    destination_plugin_id,
+                ) {
+                    Ok((callee_plugin_id, callee_client_id)) => (
+                        Some(callee_plugin_id),
+                        Some(callee_client_id),
+                        Event::RpcRequest(request_id, method, payload, caller_plugin_id),
+                    ),
+                    Err(e) => (
+                        Some(caller_plugin_id),
+                        Some(caller_client_id),
+                        Event::RpcResponse(request_id, Err(e)),
+                    ),
+                };
+                wasm_bridge.update_plugins(vec![update], shutdown_send.clone())?;
+            },
+            PluginInstruction::RpcResponse {
+                request_id,
+                responder_plugin_id,
+                result,
+            } => {
+                if let Some((caller_plugin_id, caller_client_id)) =
+                    rpc_router.respond(request_id, responder_plugin_id)
+                {
+                    let updates = vec![(
+                        Some(caller_plugin_id),
+                        Some(caller_client_id),
+                        Event::RpcResponse(request_id, result),
+                    )];
+                    wasm_bridge.update_plugins(updates, shutdown_send.clone())?;
+                }
+            },
+            PluginInstruction::RpcTimeout(request_id) => {
+                if let Some((caller_plugin_id, caller_client_id)) = rpc_router.time_out(request_id)
+                {
+                    let updates = vec![(
+                        Some(caller_plugin_id),
+                        Some(caller_client_id),
+                        Event::RpcResponse(request_id, Err("Request timed out".to_owned())),
+                    )];
+                    wasm_bridge.update_plugins(updates, shutdown_send.clone())?;
+                }
+            },
+            PluginInstruction::DevModePluginFilesChanged(changed_paths) => {
+                wasm_bridge
+                    .reload_dev_mode_plugins(&changed_paths)
+                    .non_fatal();
+            },
+            PluginInstruction::RegisterHttpRoute(plugin_id, client_id, route) => {
+                if let Err(e) = http_router.register(plugin_id, client_id, route) {
+                    log::error!("Plugin {} failed to register HTTP route: {}", plugin_id, e);
+                }
+            },
+            PluginInstruction::UnregisterHttpRoute(plugin_id, route) => {
+                http_router.unregister(plugin_id, &route);
+            },
+            PluginInstruction::HttpRequest {
+                web_client_id,
+                request,
+            } => match http_router.route(web_client_id, &request.route) {
+                Some((request_id, (plugin_id, client_id))) => {
+                    let updates = vec![(
+                        Some(plugin_id),
+                        Some(client_id),
+                        Event::HttpRequest(request_id, request),
+                    )];
+                    wasm_bridge.update_plugins(updates, shutdown_send.clone())?;
+                    let send_plugin_instructions = bus.senders.to_plugin.clone();
+                    get_tokio_runtime().spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(HTTP_REQUEST_TIMEOUT_MS)).await;
+                        if let Some(sender) = send_plugin_instructions {
+                            // ignored if the plugin already responded
+                            let _ = sender.send(PluginInstruction::HttpRequestTimeout(request_id));
+                        }
+                    });
+                },
+                None => {
+                    drop(
+                        bus.senders
+                            .send_to_server(ServerInstruction::PluginHttpResponse(
+                                web_client_id,
+                                None,
+                            )),
+                    );
+                },
+            },
+            PluginInstruction::HttpResponse {
+                request_id,
+                responder_plugin_id,
+                response,
+            } => {
+                if let Some(web_client_id) = http_router.respond(request_id, responder_plugin_id) {
+                    drop(
+                        bus.senders
+                            .send_to_server(ServerInstruction::PluginHttpResponse(
+                                web_client_id,
+                                Some(response),
+                            )),
+                    );
+                }
+            },
+            PluginInstruction::HttpRequestTimeout(request_id) => {
+                if let Some(web_client_id) = http_router.time_out(request_id) {
+                    let response = PluginHttpResponse::new(
+                        504,
+                        b"The plugin did not respond in time".to_vec(),
+                    );
+                    drop(
+                        bus.senders
+                            .send_to_server(ServerInstruction::PluginHttpResponse(
+                                web_client_id,
+                                Some(response),
+                            )),
+                    );
+                }
+            },
+            PluginInstruction::RevokePluginPermissions {
+                plugin_url,
+                permissions,
+            } => {
+                if let Err(e) = wasm_bridge.revoke_plugin_permissions(&plugin_url, permissions) {
+                    log::error!("{}", e);
+                }
+            },
             PluginInstruction::Exit => {
                 break;
             },
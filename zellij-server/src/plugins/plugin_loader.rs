@@ -1,5 +1,5 @@
 use crate::plugins::plugin_map::{
-    PluginEnv, PluginMap, RunningPlugin, VecDequeInputStream, WriteOutputStream,
+    refuel, PluginEnv, PluginMap, RunningPlugin, VecDequeInputStream, WriteOutputStream,
 };
 use crate::plugins::plugin_worker::{plugin_worker, RunningWorker};
 use crate::plugins::wasm_bridge::{LoadingContext, PluginCache};
@@ -34,6 +34,7 @@ use zellij_utils::{
     input::plugins::PluginConfig,
     ipc::ClientAttributes,
     pane_size::Size,
+    plugin_metadata::PluginMetadata,
 };
 
 fn create_plugin_fs_entries(plugin_own_data_dir: &PathBuf, plugin_own_cache_dir: &PathBuf) {
@@ -68,6 +69,7 @@ pub struct PluginLoader<'a> {
     keybinds: Keybinds,
     plugin_dir: PathBuf,
     size: Size,
+    execution_budget: u64,
     loading_indication: LoadingIndication,
     senders: ThreadSenders,
     engine: Engine,
@@ -112,6 +114,7 @@ impl<'a> PluginLoader<'a> {
             keybinds: loading_context.keybinds,
             plugin_dir: loading_context.plugin_dir,
             size: loading_context.size,
+            execution_budget: loading_context.execution_budget,
 
             skip_cache,
             senders,
@@ -142,6 +145,9 @@ impl<'a> PluginLoader<'a> {
     fn interpret_module(&mut self) -> Result<Module> {
         self.loading_indication.override_previous_error();
         let wasm_bytes = self.plugin_config.resolve_wasm_bytes(&self.plugin_dir)?;
+        if !self.plugin_config.is_builtin() {
+            self.check_plugin_compatibility(&wasm_bytes)?;
+        }
         let timer = std::time::Instant::now();
         let module = Module::new(&self.engine, &wasm_bytes)?;
         log::info!(
@@ -151,6 +157,36 @@ impl<'a> PluginLoader<'a> {
         );
         Ok(module)
     }
+    /// Refuse plugins built against a plugin API this version cannot talk to, rather than have
+    /// them misbehave once they are running. Plugins without metadata predate the API version and
+    /// are loaded as they are.
+    fn check_plugin_compatibility(&self, wasm_bytes: &[u8]) -> Result<()> {
+        match PluginMetadata::from_wasm(wasm_bytes) {
+            Ok(Some(plugin_metadata)) => match plugin_metadata.compatibility_error() {
+                Some(compatibility_error) => Err(anyhow!(
+                    "Cannot load plugin {}: {}",
+                    self.plugin_config.path.display(),
+                    compatibility_error
+                )),
+                None => Ok(()),
+            },
+            Ok(None) => {
+                log::warn!(
+                    "Plugin '{}' does not declare the plugin API version it was built against, loading it as is",
+                    self.plugin_config.path.display()
+                );
+                Ok(())
+            },
+            Err(e) => {
+                log::warn!(
+                    "Failed to read the metadata of plugin '{}': {}",
+                    self.plugin_config.path.display(),
+                    e
+                );
+                Ok(())
+            },
+        }
+    }
     fn load_module_from_memory(&mut self) -> Result<Module> {
         let module = self
             .plugin_cache
@@ -185,6 +221,7 @@ impl<'a> PluginLoader<'a> {
                 let start_function_for_worker = instance
                     .get_typed_func::<(), ()>(&mut store, "_start")
                     .with_context(err_context)?;
+                refuel(&mut store);
                 start_function_for_worker
                     .call(&mut store, ())
                     .with_context(err_context)?;
@@ -210,6 +247,7 @@ impl<'a> PluginLoader<'a> {
             workers,
         );
 
+        plugin.lock().unwrap().refuel();
         start_function
             .call(&mut plugin.lock().unwrap().store, ())
             .with_context(err_context)?;
@@ -223,6 +261,7 @@ impl<'a> PluginLoader<'a> {
         let protobuf_bytes = protobuf_plugin_configuration.encode_to_vec();
         wasi_write_object(plugin.lock().unwrap().store.data(), &protobuf_bytes)
             .with_context(err_context)?;
+        plugin.lock().unwrap().refuel();
         load_function
             .call(&mut plugin.lock().unwrap().store, ())
             .with_context(err_context)?;
@@ -279,8 +318,10 @@ impl<'a> PluginLoader<'a> {
             stdin_pipe,
             stdout_pipe,
             store_limits: create_optimized_store_limits(),
+            execution_budget: self.execution_budget,
         };
         let mut store = Store::new(&self.engine, plugin_env);
+        refuel(&mut store);
 
         // Apply optimized resource limits for memory efficiency
         store.limiter(|plugin_env| &mut plugin_env.store_limits);
@@ -393,8 +434,10 @@ impl<'a> PluginLoader<'a> {
             stdin_pipe,
             stdout_pipe,
             store_limits: create_optimized_store_limits(),
+            execution_budget: self.execution_budget,
         };
         let mut store = Store::new(&self.engine, plugin_env);
+        refuel(&mut store);
 
         // Apply optimized resource limits for memory efficiency
         store.limiter(|plugin_env| &mut plugin_env.store_limits);
@@ -6,7 +6,7 @@ use std::{
     path::PathBuf,
     sync::{Arc, Mutex},
 };
-use wasmi::{Instance, Store, StoreLimits};
+use wasmi::{Instance, Store, StoreLimits, TrapCode};
 use wasmi_wasi::WasiCtx;
 
 use crate::{thread_bus::ThreadSenders, ClientId};
@@ -272,6 +272,10 @@ impl PluginMap {
 
 pub type Subscriptions = HashSet<EventType>;
 
+/// The fuel a plugin may consume in a single call into it when no `plugin_execution_budget` is
+/// configured
+pub const DEFAULT_PLUGIN_EXECUTION_BUDGET: u64 = 1_000_000_000;
+
 pub struct PluginEnv {
     pub plugin_id: PluginId,
     pub plugin: PluginConfig,
@@ -299,6 +303,7 @@ pub struct PluginEnv {
     pub keybinds: Keybinds,
     pub intercepting_key_presses: bool,
     pub store_limits: StoreLimits,
+    pub execution_budget: u64,
 }
 
 #[derive(Clone)]
@@ -350,6 +355,22 @@ impl PluginEnv {
     }
 }
 
+/// Fill the store's fuel up to the plugin's execution budget, this should be done before every
+/// call into the plugin so that the budget applies to each call separately
+pub fn refuel(store: &mut Store<PluginEnv>) {
+    let execution_budget = store.data().execution_budget;
+    // this fails if the engine does not meter fuel, in which case there is nothing to fill
+    let _ = store.set_fuel(execution_budget);
+}
+
+/// Whether this error was caused by a plugin running out of fuel in the middle of a call
+pub fn exceeded_execution_budget(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .filter_map(|e| e.downcast_ref::<wasmi::Error>())
+        .any(|e| e.as_trap_code() == Some(TrapCode::OutOfFuel))
+}
+
 #[derive(Eq, PartialEq, Hash)]
 pub enum AtomicEvent {
     Resize,
@@ -360,6 +381,7 @@ pub struct RunningPlugin {
     pub instance: Instance,
     pub rows: usize,
     pub columns: usize,
+    pub suspended: bool,
     next_event_ids: HashMap<AtomicEvent, usize>,
     last_applied_event_ids: HashMap<AtomicEvent, usize>,
 }
@@ -371,6 +393,7 @@ impl RunningPlugin {
             instance,
             rows,
             columns,
+            suspended: false,
             next_event_ids: HashMap::new(),
             last_applied_event_ids: HashMap::new(),
         }
@@ -409,7 +432,52 @@ impl RunningPlugin {
     pub fn update_layout_dir(&mut self, layout_dir: Option<PathBuf>) {
         self.store.data_mut().layout_dir = layout_dir;
     }
+    pub fn refuel(&mut self) {
+        refuel(&mut self.store);
+    }
     pub fn intercepting_key_presses(&self) -> bool {
         self.store.data().intercepting_key_presses
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasmi::{Config, Engine, Module};
+
+    // (module (func (export "spin") (loop (br 0))))
+    const SPINNING_MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+        0x03, 0x02, 0x01, 0x00, // function section
+        0x07, 0x08, 0x01, 0x04, 0x73, 0x70, 0x69, 0x6e, 0x00, 0x00, // export section
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x0b, // code section
+    ];
+
+    #[test]
+    fn running_out_of_fuel_exceeds_execution_budget() {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, SPINNING_MODULE).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = wasmi::Linker::new(&engine)
+            .instantiate_and_start(&mut store, &module)
+            .unwrap();
+        let spin = instance
+            .get_typed_func::<(), ()>(&mut store, "spin")
+            .unwrap();
+        store.set_fuel(1000).unwrap();
+        let error = spin
+            .call(&mut store, ())
+            .context("failed to call plugin")
+            .unwrap_err();
+        assert!(exceeded_execution_budget(&error));
+    }
+
+    #[test]
+    fn other_errors_do_not_exceed_execution_budget() {
+        let error = anyhow!("plugin panicked").context("failed to call plugin");
+        assert!(!exceeded_execution_budget(&error));
+    }
+}
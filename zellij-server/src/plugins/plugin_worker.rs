@@ -1,4 +1,4 @@
-use crate::plugins::plugin_map::PluginEnv;
+use crate::plugins::plugin_map::{refuel, PluginEnv};
 use crate::plugins::zellij_exports::wasi_write_object;
 use wasmi::{Instance, Store};
 
@@ -35,6 +35,7 @@ impl RunningWorker {
             .get_typed_func::<(), ()>(&mut self.store, &self.name)
             .with_context(err_context)?;
         wasi_write_object(self.store.data(), &protobuf_bytes).with_context(err_context)?;
+        refuel(&mut self.store);
         work_function
             .call(&mut self.store, ())
             .with_context(err_context)?;
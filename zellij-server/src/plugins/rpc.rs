@@ -0,0 +1,123 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::plugins::PluginId;
+use crate::ClientId;
+
+pub const DEFAULT_RPC_TIMEOUT_MS: u64 = 5000;
+
+/// Routes `SendRpcRequest` calls to the plugins that registered the requested method with
+/// `RegisterRpcMethod`, and keeps track of the requests that are still waiting for a response so
+/// that each caller gets exactly one `RpcResponse` - the reply, a timeout or an error
+#[derive(Default)]
+pub struct RpcRouter {
+    methods: BTreeMap<(PluginId, ClientId), HashSet<String>>,
+    pending_requests: HashMap<u64, PendingRequest>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingRequest {
+    caller: (PluginId, ClientId),
+    callee: (PluginId, ClientId),
+}
+
+impl RpcRouter {
+    pub fn register(&mut self, plugin_id: PluginId, client_id: ClientId, method: String) {
+        self.methods
+            .entry((plugin_id, client_id))
+            .or_default()
+            .insert(method);
+    }
+    pub fn unregister(&mut self, plugin_id: PluginId, client_id: ClientId, method: &str) {
+        if let Some(methods) = self.methods.get_mut(&(plugin_id, client_id)) {
+            methods.remove(method);
+            if methods.is_empty() {
+                self.methods.remove(&(plugin_id, client_id));
+            }
+        }
+    }
+    /// Finds the plugin instance that should handle this request and records the request as
+    /// pending, returns an error message for the caller if there is no such instance
+    ///
+    /// When several instances registered the method (eg. the same plugin loaded for several
+    /// clients), the one belonging to the caller's client is preferred
+    pub fn route(
+        &mut self,
+        request_id: u64,
+        caller: (PluginId, ClientId),
+        method: &str,
+        destination_plugin_id: Option<PluginId>,
+    ) -> Result<(PluginId, ClientId), String> {
+        let candidates: Vec<(PluginId, ClientId)> = self
+            .methods
+            .iter()
+            .filter(|((plugin_id, _client_id), methods)| {
+                destination_plugin_id
+                    .map(|destination| destination == *plugin_id)
+                    .unwrap_or(true)
+                    && methods.contains(method)
+            })
+            .map(|(plugin_instance, _methods)| *plugin_instance)
+            .collect();
+        let callee = candidates
+            .iter()
+            .find(|(_plugin_id, client_id)| *client_id == caller.1)
+            .or_else(|| candidates.first())
+            .copied()
+            .ok_or_else(|| match destination_plugin_id {
+                Some(destination) => format!(
+                    "Plugin {} did not register the RPC method \"{}\"",
+                    destination, method
+                ),
+                None => format!("No plugin registered the RPC method \"{}\"", method),
+            })?;
+        self.pending_requests
+            .insert(request_id, PendingRequest { caller, callee });
+        Ok(callee)
+    }
+    /// Returns the caller waiting for this response, or None if the request already timed out
+    /// or the responder is not the plugin the request was routed to
+    pub fn respond(
+        &mut self,
+        request_id: u64,
+        responder_plugin_id: PluginId,
+    ) -> Option<(PluginId, ClientId)> {
+        match self.pending_requests.get(&request_id) {
+            Some(pending_request) if pending_request.callee.0 == responder_plugin_id => self
+                .pending_requests
+                .remove(&request_id)
+                .map(|pending_request| pending_request.caller),
+            _ => None,
+        }
+    }
+    /// Returns the caller of this request if it is still waiting for a response
+    pub fn time_out(&mut self, request_id: u64) -> Option<(PluginId, ClientId)> {
+        self.pending_requests
+            .remove(&request_id)
+            .map(|pending_request| pending_request.caller)
+    }
+    /// Forgets everything about an unloaded plugin, returning the requests that were waiting on
+    /// it along with their callers so that they can be failed right away
+    pub fn remove_plugin(&mut self, plugin_id: PluginId) -> Vec<(u64, (PluginId, ClientId))> {
+        self.methods
+            .retain(|(registered_plugin_id, _client_id), _methods| {
+                *registered_plugin_id != plugin_id
+            });
+        let mut failed_requests = vec![];
+        self.pending_requests.retain(|request_id, pending_request| {
+            if pending_request.caller.0 == plugin_id {
+                false
+            } else if pending_request.callee.0 == plugin_id {
+                failed_requests.push((*request_id, pending_request.caller));
+                false
+            } else {
+                true
+            }
+        });
+        failed_requests.sort();
+        failed_requests
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/rpc_tests.rs"]
+mod rpc_tests;
@@ -0,0 +1,127 @@
+use super::{dev_mode_plugin_path, DevModePlugin, DevModePlugins};
+use crate::plugins::PluginInstruction;
+use crate::thread_bus::ThreadSenders;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use zellij_utils::channels::{self, ChannelWithContext, SenderWithContext};
+use zellij_utils::data::PluginTag;
+use zellij_utils::input::layout::{PluginUserConfiguration, RunPlugin, RunPluginLocation};
+use zellij_utils::pane_size::Size;
+
+fn run_plugin(location: RunPluginLocation, dev: Option<&str>) -> RunPlugin {
+    let mut configuration = BTreeMap::new();
+    if let Some(dev) = dev {
+        configuration.insert("dev".to_owned(), dev.to_owned());
+    }
+    RunPlugin {
+        _allow_exec_host_cmd: false,
+        location,
+        configuration: PluginUserConfiguration::new(configuration),
+        initial_cwd: None,
+    }
+}
+
+fn dev_mode_plugin(path: &Path) -> DevModePlugin {
+    DevModePlugin {
+        run_plugin: run_plugin(RunPluginLocation::File(path.to_path_buf()), Some("true")),
+        path: path.to_path_buf(),
+        tab_index: Some(0),
+        size: Size { rows: 10, cols: 20 },
+        cwd: PathBuf::from("/"),
+        client_id: 1,
+    }
+}
+
+#[test]
+fn only_file_plugins_with_dev_true_are_watched() {
+    let cwd = PathBuf::from("/tmp");
+    let file = RunPluginLocation::File(PathBuf::from("/plugins/my-plugin.wasm"));
+    assert_eq!(
+        dev_mode_plugin_path(&run_plugin(file.clone(), Some("true")), &cwd),
+        Some(PathBuf::from("/plugins/my-plugin.wasm"))
+    );
+    assert_eq!(
+        dev_mode_plugin_path(&run_plugin(file.clone(), Some("false")), &cwd),
+        None
+    );
+    assert_eq!(dev_mode_plugin_path(&run_plugin(file, None), &cwd), None);
+    assert_eq!(
+        dev_mode_plugin_path(
+            &run_plugin(
+                RunPluginLocation::Zellij(PluginTag::new("tab-bar")),
+                Some("true")
+            ),
+            &cwd
+        ),
+        None
+    );
+}
+
+#[test]
+fn relative_plugin_paths_are_resolved_against_the_cwd() {
+    let location = RunPluginLocation::File(PathBuf::from("target/my-plugin.wasm"));
+    assert_eq!(
+        dev_mode_plugin_path(&run_plugin(location, Some("true")), Path::new("/project")),
+        Some(PathBuf::from("/project/target/my-plugin.wasm"))
+    );
+}
+
+#[test]
+fn changed_paths_map_to_the_plugins_loaded_from_them() {
+    let folder = tempfile::tempdir().unwrap();
+    let first_plugin = folder.path().join("first.wasm");
+    let second_plugin = folder.path().join("second.wasm");
+    let mut dev_mode_plugins = DevModePlugins::default();
+    let senders = ThreadSenders::default().silently_fail_on_send();
+    dev_mode_plugins
+        .add(1, dev_mode_plugin(&first_plugin), &senders)
+        .unwrap();
+    dev_mode_plugins
+        .add(2, dev_mode_plugin(&first_plugin), &senders)
+        .unwrap();
+    dev_mode_plugins
+        .add(3, dev_mode_plugin(&second_plugin), &senders)
+        .unwrap();
+    assert_eq!(
+        dev_mode_plugins.plugin_ids_for_paths(std::slice::from_ref(&first_plugin)),
+        vec![1, 2]
+    );
+    dev_mode_plugins.remove(1);
+    assert_eq!(
+        dev_mode_plugins.plugin_ids_for_paths(&[first_plugin, second_plugin]),
+        vec![2, 3]
+    );
+    dev_mode_plugins.stop();
+}
+
+#[test]
+fn rewriting_the_plugin_file_notifies_the_plugin_thread() {
+    let folder = tempfile::tempdir().unwrap();
+    let plugin_path = folder.path().join("my-plugin.wasm");
+    std::fs::write(&plugin_path, b"old").unwrap();
+    let (to_plugin, plugin_receiver): ChannelWithContext<PluginInstruction> = channels::unbounded();
+    let senders = ThreadSenders {
+        to_plugin: Some(SenderWithContext::new(to_plugin)),
+        ..Default::default()
+    };
+    let mut dev_mode_plugins = DevModePlugins::default();
+    dev_mode_plugins
+        .add(1, dev_mode_plugin(&plugin_path), &senders)
+        .unwrap();
+    std::fs::write(&plugin_path, b"new").unwrap();
+    let changed_paths = loop {
+        match plugin_receiver.recv_timeout(Duration::from_secs(5)) {
+            Ok((PluginInstruction::DevModePluginFilesChanged(changed_paths), _)) => {
+                break changed_paths
+            },
+            Ok(_) => continue,
+            Err(e) => panic!("plugin file change was not reported: {:?}", e),
+        }
+    };
+    assert_eq!(
+        dev_mode_plugins.plugin_ids_for_paths(&changed_paths),
+        vec![1]
+    );
+    dev_mode_plugins.stop();
+}
@@ -0,0 +1,75 @@
+use super::{validate_route_name, HttpRouter};
+
+#[test]
+fn routes_request_to_registered_route() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    let (request_id, plugin_instance) = router.route(10, "dashboard").unwrap();
+    assert_eq!(plugin_instance, (2, 1));
+    assert_eq!(router.respond(request_id, 2), Some(10));
+    assert_eq!(
+        router.respond(request_id, 2),
+        None,
+        "a request is answered only once"
+    );
+}
+
+#[test]
+fn unknown_route_is_not_routed() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    assert_eq!(router.route(10, "webhooks"), None);
+    router.unregister(2, "dashboard");
+    assert_eq!(router.route(10, "dashboard"), None);
+}
+
+#[test]
+fn a_route_belongs_to_one_plugin() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    assert!(router.register(3, 1, "dashboard".to_owned()).is_err());
+    router.unregister(3, "dashboard");
+    assert_eq!(router.route(10, "dashboard").unwrap().1, (2, 1));
+    router.register(2, 2, "dashboard".to_owned()).unwrap();
+    assert_eq!(router.route(10, "dashboard").unwrap().1, (2, 2));
+}
+
+#[test]
+fn only_the_serving_plugin_can_respond() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    let (request_id, _) = router.route(10, "dashboard").unwrap();
+    assert_eq!(router.respond(request_id, 3), None);
+    assert_eq!(router.respond(request_id, 2), Some(10));
+}
+
+#[test]
+fn timed_out_requests_cannot_be_answered() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    let (request_id, _) = router.route(10, "dashboard").unwrap();
+    assert_eq!(router.time_out(request_id), Some(10));
+    assert_eq!(router.respond(request_id, 2), None);
+    assert_eq!(router.time_out(request_id), None);
+}
+
+#[test]
+fn removing_a_plugin_fails_the_requests_waiting_on_it() {
+    let mut router = HttpRouter::default();
+    router.register(2, 1, "dashboard".to_owned()).unwrap();
+    router.register(3, 1, "webhooks".to_owned()).unwrap();
+    router.route(11, "dashboard").unwrap();
+    router.route(10, "dashboard").unwrap();
+    let (webhook_request_id, _) = router.route(12, "webhooks").unwrap();
+    assert_eq!(router.remove_plugin(2), vec![10, 11]);
+    assert_eq!(router.route(13, "dashboard"), None);
+    assert_eq!(router.respond(webhook_request_id, 3), Some(12));
+}
+
+#[test]
+fn route_names_are_url_safe() {
+    assert!(validate_route_name("bloodbank-webhooks_v2").is_ok());
+    assert!(validate_route_name("").is_err());
+    assert!(validate_route_name("a/b").is_err());
+    assert!(validate_route_name("dash board").is_err());
+}
@@ -0,0 +1,98 @@
+use super::{KvStore, MAX_KEYS, MAX_KEY_LENGTH, MAX_STORE_SIZE, MAX_VALUE_SIZE};
+
+#[test]
+fn set_get_and_delete_a_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = KvStore::new(dir.path().join("kv"));
+    assert_eq!(
+        store.get("filter").unwrap(),
+        None,
+        "missing store reads as empty"
+    );
+    store.set("filter", b"status:open").unwrap();
+    assert_eq!(store.get("filter").unwrap(), Some(b"status:open".to_vec()));
+    store.set("filter", b"status:closed").unwrap();
+    assert_eq!(
+        store.get("filter").unwrap(),
+        Some(b"status:closed".to_vec())
+    );
+    store.delete("filter").unwrap();
+    assert_eq!(store.get("filter").unwrap(), None);
+    store
+        .delete("filter")
+        .expect("deleting a missing key is not an error");
+}
+
+#[test]
+fn list_returns_sorted_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = KvStore::new(dir.path().to_path_buf());
+    store.set("page", b"2").unwrap();
+    store.set("filters/by owner", b"me").unwrap();
+    store.set("ünïcödé ✓", b"").unwrap();
+    // leftovers of an interrupted write are not keys
+    std::fs::write(dir.path().join(".tmp-1-0"), b"partial").unwrap();
+    assert_eq!(
+        store.list().unwrap(),
+        vec![
+            "filters/by owner".to_owned(),
+            "page".to_owned(),
+            "ünïcödé ✓".to_owned()
+        ]
+    );
+}
+
+#[test]
+fn stores_are_isolated_by_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let session_store = KvStore::new(dir.path().join("session"));
+    let global_store = KvStore::new(dir.path().join("global"));
+    session_store.set("key", b"session").unwrap();
+    global_store.set("key", b"global").unwrap();
+    assert_eq!(session_store.get("key").unwrap(), Some(b"session".to_vec()));
+    assert_eq!(global_store.get("key").unwrap(), Some(b"global".to_vec()));
+}
+
+#[test]
+fn rejects_invalid_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = KvStore::new(dir.path().to_path_buf());
+    assert!(store.set("", b"value").is_err());
+    assert!(store
+        .set(&"k".repeat(MAX_KEY_LENGTH + 1), b"value")
+        .is_err());
+    store
+        .set(&"k".repeat(MAX_KEY_LENGTH), b"value")
+        .expect("longest allowed key fits in a file name");
+}
+
+#[test]
+fn enforces_quotas() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = KvStore::new(dir.path().to_path_buf());
+    let too_large = vec![0; MAX_VALUE_SIZE as usize + 1];
+    assert!(store.set("too large", &too_large).is_err());
+    assert_eq!(store.get("too large").unwrap(), None);
+
+    let max_value = vec![0; MAX_VALUE_SIZE as usize];
+    let values_that_fit = (MAX_STORE_SIZE / MAX_VALUE_SIZE) as usize;
+    for i in 0..values_that_fit {
+        store.set(&format!("key {}", i), &max_value).unwrap();
+    }
+    assert!(store.set("one too many", b"x").is_err());
+    store
+        .set("key 0", b"x")
+        .expect("overwriting frees the space of the previous value");
+    store.set("one too many", b"x").unwrap();
+}
+
+#[test]
+fn enforces_key_count() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = KvStore::new(dir.path().to_path_buf());
+    for i in 0..MAX_KEYS {
+        store.set(&i.to_string(), b"").unwrap();
+    }
+    assert!(store.set("one too many", b"").is_err());
+    store.set("0", b"overwrite").unwrap();
+}
@@ -1,4 +1,4 @@
-use super::plugin_thread_main;
+use super::{plugin_thread_main, DEFAULT_PLUGIN_EXECUTION_BUDGET};
 use crate::screen::ScreenInstruction;
 use crate::{channels::SenderWithContext, thread_bus::Bus, ServerInstruction};
 use insta::assert_snapshot;
@@ -299,6 +299,17 @@ fn create_plugin_thread(
     SenderWithContext<PluginInstruction>,
     Receiver<(ScreenInstruction, ErrorContext)>,
     Box<dyn FnOnce()>,
+) {
+    create_plugin_thread_with_execution_budget(zellij_cwd, DEFAULT_PLUGIN_EXECUTION_BUDGET)
+}
+
+fn create_plugin_thread_with_execution_budget(
+    zellij_cwd: Option<PathBuf>,
+    execution_budget: u64,
+) -> (
+    SenderWithContext<PluginInstruction>,
+    Receiver<(ScreenInstruction, ErrorContext)>,
+    Box<dyn FnOnce()>,
 ) {
     let zellij_cwd = zellij_cwd.unwrap_or_else(|| PathBuf::from("."));
     let initiating_client_id = 1;
@@ -336,6 +347,7 @@ fn create_plugin_thread(
     let mut config = wasmi::Config::default();
     config.set_max_stack_height(1024 * 1024);
     config.set_max_recursion_depth(1000);
+    config.consume_fuel(true);
     let engine = Engine::new(&config);
     let data_dir = PathBuf::from(tempdir().unwrap().path());
     let layout_dir = PathBuf::from(tempdir().unwrap().path());
@@ -375,6 +387,7 @@ fn create_plugin_thread(
                 InputMode::Normal,
                 Keybinds::default(),
                 Default::default(),
+                execution_budget,
                 initiating_client_id,
             )
             .expect("TEST")
@@ -436,6 +449,7 @@ fn create_plugin_thread_with_server_receiver(
     let mut config = wasmi::Config::default();
     config.set_max_stack_height(1024 * 1024);
     config.set_max_recursion_depth(1000);
+    config.consume_fuel(true);
     let engine = Engine::new(&config);
     let data_dir = PathBuf::from(tempdir().unwrap().path());
     let default_shell = PathBuf::from(".");
@@ -464,6 +478,7 @@ fn create_plugin_thread_with_server_receiver(
                 InputMode::Normal,
                 Keybinds::default(),
                 Default::default(),
+                DEFAULT_PLUGIN_EXECUTION_BUDGET,
                 initiating_client_id,
             )
             .expect("TEST");
@@ -532,6 +547,7 @@ fn create_plugin_thread_with_pty_receiver(
     let mut config = wasmi::Config::default();
     config.set_max_stack_height(1024 * 1024);
     config.set_max_recursion_depth(1000);
+    config.consume_fuel(true);
     let engine = Engine::new(&config);
     let data_dir = PathBuf::from(tempdir().unwrap().path());
     let layout_dir = layout_dir.unwrap_or_else(|| PathBuf::from(tempdir().unwrap().path()));
@@ -561,6 +577,7 @@ fn create_plugin_thread_with_pty_receiver(
                 InputMode::Normal,
                 Keybinds::default(),
                 Default::default(),
+                DEFAULT_PLUGIN_EXECUTION_BUDGET,
                 initiating_client_id,
             )
             .expect("TEST")
@@ -623,6 +640,7 @@ fn create_plugin_thread_with_background_jobs_receiver(
     let mut config = wasmi::Config::default();
     config.set_max_stack_height(1024 * 1024);
     config.set_max_recursion_depth(1000);
+    config.consume_fuel(true);
     let engine = Engine::new(&config);
     let data_dir = PathBuf::from(tempdir().unwrap().path());
     let default_shell = PathBuf::from(".");
@@ -651,6 +669,7 @@ fn create_plugin_thread_with_background_jobs_receiver(
                 InputMode::Normal,
                 Keybinds::default(),
                 Default::default(),
+                DEFAULT_PLUGIN_EXECUTION_BUDGET,
                 initiating_client_id,
             )
             .expect("TEST")
@@ -11830,3 +11849,152 @@ pub fn save_layout_permission_denied() {
     // Should be None because permission was denied
     assert_snapshot!(format!("{:#?}", plugin_bytes_with_save));
 }
+
+#[test]
+#[ignore]
+pub fn plugins_exceeding_their_execution_budget_are_suspended_until_reloaded() {
+    // the fixture plugin spins forever when it receives Super+g, here we make sure it is suspended
+    // once it exhausts its budget, that the events and resizes it gets while suspended reach
+    // nothing (so that the suspension frame stays on screen) and that reloading it brings it back
+    // with the size it was given while suspended
+    let temp_folder = tempdir().unwrap(); // placed explicitly in the test scope because its
+    let plugin_host_folder = PathBuf::from(temp_folder.path());
+    let cache_path = plugin_host_folder.join("permissions_test.kdl");
+    let (plugin_thread_sender, screen_receiver, teardown) =
+        create_plugin_thread_with_execution_budget(None, 100_000_000);
+    let plugin_should_float = Some(false);
+    let plugin_title = Some("test_plugin".to_owned());
+    let run_plugin = RunPluginOrAlias::RunPlugin(RunPlugin {
+        _allow_exec_host_cmd: false,
+        location: RunPluginLocation::File(PathBuf::from(&*PLUGIN_FIXTURE)),
+        configuration: Default::default(),
+        ..Default::default()
+    });
+    let tab_index = 1;
+    let client_id = 1;
+    let plugin_id = 0;
+    let size = Size {
+        cols: 121,
+        rows: 20,
+    };
+    let received_screen_instructions = Arc::new(Mutex::new(vec![]));
+    let (suspended_sender, suspended_receiver) = std::sync::mpsc::channel();
+    let screen_thread = std::thread::Builder::new()
+        .name("fake_screen_thread".to_string())
+        .spawn({
+            let log = received_screen_instructions.clone();
+            let cache_path = cache_path.clone();
+            let plugin_thread_sender = plugin_thread_sender.clone();
+            move || loop {
+                let (event, _err_ctx) = screen_receiver
+                    .recv()
+                    .expect("failed to receive event on channel");
+                match event {
+                    ScreenInstruction::RequestPluginPermissions(_, plugin_permission) => {
+                        let _ =
+                            plugin_thread_sender.send(PluginInstruction::PermissionRequestResult(
+                                plugin_id,
+                                Some(client_id),
+                                plugin_permission.permissions,
+                                PermissionStatus::Granted,
+                                Some(cache_path.clone()),
+                            ));
+                    },
+                    ScreenInstruction::UpdatePluginLoadingStage(_, ref loading_indication)
+                        if loading_indication.is_suspended() =>
+                    {
+                        log.lock().unwrap().push(event);
+                        let _ = suspended_sender.send(());
+                    },
+                    ScreenInstruction::PluginBytes(ref plugin_render_assets) => {
+                        // the first render after the reload, with the size given while suspended
+                        let rendered_reloaded_plugin = plugin_render_assets.iter().any(|asset| {
+                            String::from_utf8_lossy(&asset.bytes).contains("Rows: 30, Cols: 100")
+                        });
+                        log.lock().unwrap().push(event);
+                        if rendered_reloaded_plugin {
+                            break;
+                        }
+                    },
+                    _ => {
+                        log.lock().unwrap().push(event);
+                    },
+                }
+            }
+        })
+        .unwrap();
+
+    let _ = plugin_thread_sender.send(PluginInstruction::AddClient(client_id));
+    let _ = plugin_thread_sender.send(PluginInstruction::Load(
+        plugin_should_float,
+        false,
+        plugin_title,
+        run_plugin,
+        Some(tab_index),
+        None,
+        client_id,
+        size,
+        None,
+        None,
+        false,
+        None,
+        None,
+        None,
+    ));
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let _ = plugin_thread_sender.send(PluginInstruction::Update(vec![(
+        None,
+        Some(client_id),
+        Event::Key(KeyWithModifier::new(BareKey::Char('g')).with_super_modifier()), // this makes the fixture spin forever
+    )]));
+    suspended_receiver
+        .recv_timeout(std::time::Duration::from_secs(60))
+        .expect("plugin was not suspended");
+    let _ = plugin_thread_sender.send(PluginInstruction::Update(vec![(
+        None,
+        Some(client_id),
+        Event::InputReceived,
+    )]));
+    let _ = plugin_thread_sender.send(PluginInstruction::Resize(plugin_id, 100, 30));
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let _ = plugin_thread_sender.send(PluginInstruction::ReloadPluginWithId(plugin_id));
+    screen_thread.join().unwrap(); // this might take a while if the cache is cold
+    teardown();
+    let received_screen_instructions = received_screen_instructions.lock().unwrap();
+    let suspended_at = received_screen_instructions
+        .iter()
+        .position(|i| {
+            matches!(
+                i,
+                ScreenInstruction::UpdatePluginLoadingStage(id, loading_indication)
+                    if *id == plugin_id && loading_indication.is_suspended()
+            )
+        })
+        .expect("plugin was not suspended");
+    let reloading_at = received_screen_instructions
+        .iter()
+        .skip(suspended_at + 1)
+        .position(|i| {
+            matches!(
+                i,
+                ScreenInstruction::StartPluginLoadingIndication(id, _) if *id == plugin_id
+            )
+        })
+        .map(|position| suspended_at + 1 + position)
+        .expect("plugin was not reloaded");
+    // a suspended plugin that is called into again either renders over its suspension frame or
+    // (since it was stopped in the middle of a call) crashes and replaces it with an error
+    let reached_while_suspended = received_screen_instructions[suspended_at + 1..reloading_at]
+        .iter()
+        .any(|i| match i {
+            ScreenInstruction::PluginBytes(plugin_render_assets) => plugin_render_assets
+                .iter()
+                .any(|asset| asset.plugin_id == plugin_id),
+            ScreenInstruction::UpdatePluginLoadingStage(id, _) => *id == plugin_id,
+            _ => false,
+        });
+    assert!(
+        !reached_while_suspended,
+        "suspended plugin was called into before being reloaded"
+    );
+}
@@ -0,0 +1,74 @@
+use super::RpcRouter;
+
+#[test]
+fn routes_request_to_registered_method() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    assert_eq!(router.route(1, (1, 1), "list_issues", None), Ok((2, 1)));
+    assert_eq!(router.respond(1, 2), Some((1, 1)));
+    assert_eq!(
+        router.respond(1, 2),
+        None,
+        "a request is answered only once"
+    );
+}
+
+#[test]
+fn unknown_method_is_an_error() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    assert!(router.route(1, (1, 1), "close_issue", None).is_err());
+    assert!(router.route(2, (1, 1), "list_issues", Some(3)).is_err());
+    router.unregister(2, 1, "list_issues");
+    assert!(router.route(3, (1, 1), "list_issues", None).is_err());
+    assert_eq!(router.time_out(1), None, "failed requests are not pending");
+}
+
+#[test]
+fn prefers_the_instance_of_the_callers_client() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    router.register(2, 2, "list_issues".to_owned());
+    router.register(3, 3, "list_issues".to_owned());
+    assert_eq!(router.route(1, (1, 2), "list_issues", None), Ok((2, 2)));
+    assert_eq!(router.route(2, (1, 4), "list_issues", None), Ok((2, 1)));
+    assert_eq!(router.route(3, (1, 2), "list_issues", Some(3)), Ok((3, 3)));
+}
+
+#[test]
+fn only_the_callee_can_respond() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    router.route(1, (1, 1), "list_issues", None).unwrap();
+    assert_eq!(router.respond(1, 3), None);
+    assert_eq!(router.respond(1, 2), Some((1, 1)));
+}
+
+#[test]
+fn timed_out_requests_cannot_be_answered() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    router.route(1, (1, 1), "list_issues", None).unwrap();
+    assert_eq!(router.time_out(1), Some((1, 1)));
+    assert_eq!(router.respond(1, 2), None);
+    assert_eq!(router.time_out(1), None);
+}
+
+#[test]
+fn removing_a_plugin_fails_the_requests_waiting_on_it() {
+    let mut router = RpcRouter::default();
+    router.register(2, 1, "list_issues".to_owned());
+    router.register(3, 1, "close_issue".to_owned());
+    router.route(1, (1, 1), "list_issues", None).unwrap();
+    router.route(2, (4, 1), "list_issues", None).unwrap();
+    router.route(3, (1, 1), "close_issue", None).unwrap();
+    router.route(4, (2, 1), "close_issue", None).unwrap();
+    assert_eq!(router.remove_plugin(2), vec![(1, (1, 1)), (2, (4, 1))]);
+    assert!(router.route(5, (1, 1), "list_issues", None).is_err());
+    assert_eq!(
+        router.time_out(4),
+        None,
+        "requests of the removed plugin are dropped"
+    );
+    assert_eq!(router.respond(3, 3), Some((1, 1)));
+}
@@ -1,10 +1,14 @@
 use super::{PinnedExecutor, PluginId, PluginInstruction};
 use crate::global_async_runtime::get_tokio_runtime;
+use crate::plugins::dev_mode::{dev_mode_plugin_path, DevModePlugin, DevModePlugins};
 use crate::plugins::pipes::{
     apply_pipe_message_to_plugin, pipes_to_block_or_unblock, PendingPipes, PipeStateChange,
 };
 use crate::plugins::plugin_loader::PluginLoader;
-use crate::plugins::plugin_map::{AtomicEvent, PluginEnv, PluginMap, RunningPlugin, Subscriptions};
+use crate::plugins::plugin_map::{
+    exceeded_execution_budget, refuel, AtomicEvent, PluginEnv, PluginMap, RunningPlugin,
+    Subscriptions,
+};
 
 use crate::plugins::plugin_worker::MessageToWorker;
 use crate::plugins::watch_filesystem::watch_filesystem;
@@ -98,6 +102,7 @@ pub struct LoadingContext {
     pub keybinds: Keybinds,
     pub plugin_dir: PathBuf,
     pub size: Size,
+    pub execution_budget: u64,
 }
 
 impl LoadingContext {
@@ -144,6 +149,7 @@ impl LoadingContext {
             tab_index,
             plugin_dir: wasm_bridge.plugin_dir.clone(),
             size,
+            execution_budget: wasm_bridge.execution_budget,
         }
     }
     pub fn update_plugin_path(&mut self, new_path: PathBuf) {
@@ -187,6 +193,8 @@ pub struct WasmBridge {
     base_modes: HashMap<ClientId, InputMode>,
     downloader: Downloader,
     previous_pane_render_report: Option<PaneRenderReport>,
+    execution_budget: u64,
+    dev_mode_plugins: DevModePlugins,
 }
 
 impl WasmBridge {
@@ -205,6 +213,7 @@ impl WasmBridge {
         available_layout_errors: Vec<LayoutWithError>,
         default_mode: InputMode,
         default_keybinds: Keybinds,
+        execution_budget: u64,
     ) -> Self {
         let plugin_map = Arc::new(Mutex::new(PluginMap::default()));
         let connected_clients: Arc<Mutex<Vec<ClientId>>> = Arc::new(Mutex::new(vec![]));
@@ -252,6 +261,8 @@ impl WasmBridge {
             base_modes: HashMap::new(),
             downloader,
             previous_pane_render_report: None,
+            execution_budget,
+            dev_mode_plugins: DevModePlugins::default(),
         }
     }
     pub fn load_plugin(
@@ -404,56 +415,33 @@ impl WasmBridge {
                         );
                     });
                 } else {
-                    let _ = senders
-                        .send_to_background_jobs(BackgroundJob::AnimatePluginLoading(plugin_id));
-                    let mut loading_indication = LoadingIndication::new(plugin_name.clone());
-
-                    self.plugin_executor.execute_plugin_load(
+                    let loading_indication = LoadingIndication::new(plugin_name.clone());
+                    self.load_local_plugin(
                         plugin_id,
-                        move |senders,
-                              plugin_map,
-                              connected_clients,
-                              default_layout,
-                              plugin_cache: PluginCache,
-                              engine: Engine| {
-                            let mut plugin_map = plugin_map.lock().unwrap();
-                            match PluginLoader::new(
-                                skip_cache,
-                                loading_context,
-                                senders.clone(),
-                                engine.clone(),
-                                default_layout.clone(),
-                                plugin_cache.clone(),
-                                &mut plugin_map,
-                                connected_clients.clone(),
-                            )
-                            .start_plugin()
-                            {
-                                Ok(_) => {
-                                    let plugin_list = plugin_map.list_plugins();
-                                    handle_plugin_successful_loading(
-                                        &senders,
-                                        plugin_id,
-                                        plugin_list,
-                                    );
-                                },
-                                Err(e) => handle_plugin_loading_failure(
-                                    &senders,
-                                    plugin_id,
-                                    &mut loading_indication,
-                                    e,
-                                    Some(client_id),
-                                ),
-                            }
-
-                            let _ = senders.send_to_plugin(PluginInstruction::ApplyCachedEvents {
-                                plugin_ids: vec![plugin_id],
-                                done_receiving_permissions: false,
-                            });
-                        },
+                        loading_context,
+                        loading_indication,
+                        skip_cache,
+                        client_id,
                     );
                 }
 
+                if let Some(path) = dev_mode_plugin_path(run, &zellij_cwd) {
+                    let dev_mode_plugin = DevModePlugin {
+                        run_plugin: run.clone(),
+                        path,
+                        tab_index,
+                        size,
+                        cwd: zellij_cwd,
+                        client_id,
+                    };
+                    if let Err(e) =
+                        self.dev_mode_plugins
+                            .add(plugin_id, dev_mode_plugin, &self.senders)
+                    {
+                        log::error!("Failed to watch plugin file for dev mode: {:?}", e);
+                    }
+                }
+
                 self.next_plugin_id += 1;
             },
             None => {
@@ -470,8 +458,111 @@ impl WasmBridge {
         }
         Ok((plugin_id, client_id))
     }
+    fn load_local_plugin(
+        &self,
+        plugin_id: PluginId,
+        loading_context: LoadingContext,
+        mut loading_indication: LoadingIndication,
+        skip_cache: bool,
+        client_id: ClientId,
+    ) {
+        let _ = self
+            .senders
+            .send_to_background_jobs(BackgroundJob::AnimatePluginLoading(plugin_id));
+        self.plugin_executor.execute_plugin_load(
+            plugin_id,
+            move |senders,
+                  plugin_map,
+                  connected_clients,
+                  default_layout,
+                  plugin_cache: PluginCache,
+                  engine: Engine| {
+                let mut plugin_map = plugin_map.lock().unwrap();
+                match PluginLoader::new(
+                    skip_cache,
+                    loading_context,
+                    senders.clone(),
+                    engine.clone(),
+                    default_layout.clone(),
+                    plugin_cache.clone(),
+                    &mut plugin_map,
+                    connected_clients.clone(),
+                )
+                .start_plugin()
+                {
+                    Ok(_) => {
+                        let plugin_list = plugin_map.list_plugins();
+                        handle_plugin_successful_loading(&senders, plugin_id, plugin_list);
+                    },
+                    Err(e) => handle_plugin_loading_failure(
+                        &senders,
+                        plugin_id,
+                        &mut loading_indication,
+                        e,
+                        Some(client_id),
+                    ),
+                }
+
+                let _ = senders.send_to_plugin(PluginInstruction::ApplyCachedEvents {
+                    plugin_ids: vec![plugin_id],
+                    done_receiving_permissions: false,
+                });
+            },
+        );
+    }
+    pub fn reload_dev_mode_plugins(&mut self, changed_paths: &[PathBuf]) -> Result<()> {
+        for plugin_id in self.dev_mode_plugins.plugin_ids_for_paths(changed_paths) {
+            let Some(dev_mode_plugin) = self.dev_mode_plugins.get(plugin_id).cloned() else {
+                continue;
+            };
+            if self.run_plugin_of_loading_plugin_id(plugin_id).is_some() {
+                // will be reloaded once it is done loading
+                self.pending_plugin_reloads
+                    .insert(dev_mode_plugin.run_plugin);
+            } else if self.run_plugin_of_plugin_id(plugin_id).is_some() {
+                self.reload_plugin_with_id(plugin_id)?;
+            } else {
+                // the previous version failed to load, so there is nothing to reload in place -
+                // we load it again into the same pane
+                let plugin_config = PluginConfig::from_run_plugin(&dev_mode_plugin.run_plugin)
+                    .with_context(|| {
+                        format!("failed to resolve plugin {:?}", dev_mode_plugin.run_plugin)
+                    })?;
+                let size = dev_mode_plugin.size;
+                self.cached_events_for_pending_plugins
+                    .insert(plugin_id, vec![]);
+                self.cached_resizes_for_pending_plugins
+                    .insert(plugin_id, (size.rows, size.cols));
+                self.loading_plugins
+                    .insert((plugin_id, dev_mode_plugin.run_plugin.clone()));
+                let mut loading_indication =
+                    LoadingIndication::new(dev_mode_plugin.run_plugin.location.to_string());
+                loading_indication.override_previous_error();
+                self.start_plugin_loading_indication(&[plugin_id], &loading_indication);
+                let loading_context = LoadingContext::new(
+                    self,
+                    Some(dev_mode_plugin.cwd),
+                    plugin_config,
+                    plugin_id,
+                    dev_mode_plugin.client_id,
+                    dev_mode_plugin.tab_index,
+                    size,
+                );
+                let skip_cache = true;
+                self.load_local_plugin(
+                    plugin_id,
+                    loading_context,
+                    loading_indication,
+                    skip_cache,
+                    dev_mode_plugin.client_id,
+                );
+            }
+        }
+        Ok(())
+    }
     pub fn unload_plugin(&mut self, pid: PluginId) -> Result<()> {
         info!("Bye from plugin {}", &pid);
+        self.dev_mode_plugins.remove(pid);
 
         // Remove from plugin_map on main thread
         let plugins_to_cleanup: Vec<_> = {
@@ -488,6 +579,11 @@ impl WasmBridge {
                     .senders
                     .send_to_screen(ScreenInstruction::ClearKeyPressesIntercepts(client_id));
             }
+            let _ = self
+                .senders
+                .send_to_screen(ScreenInstruction::ClearPaneOutputSubscriptions(
+                    plugin_id, client_id,
+                ));
 
             // Send worker exit messages
             for (_worker_name, worker_sender) in workers {
@@ -574,6 +670,8 @@ impl WasmBridge {
             .insert(plugin_id, (rows, columns));
 
         let mut loading_indication = LoadingIndication::new(run_plugin.location.to_string());
+        // clear the error of the previous run (eg. if the plugin was suspended)
+        loading_indication.override_previous_error();
         self.start_plugin_loading_indication(&[plugin_id], &loading_indication);
         self.loading_plugins.insert((plugin_id, run_plugin.clone()));
 
@@ -809,10 +907,16 @@ impl WasmBridge {
                             let old_columns = running_plugin.columns;
                             running_plugin.rows = new_rows;
                             running_plugin.columns = new_columns;
+                            if running_plugin.suspended {
+                                // suspended plugins receive nothing until they are reloaded, we
+                                // only keep their size so that they are reloaded with it
+                                return;
+                            }
 
                             // in the below conditional, we check if event_id == 0 so that we'll
                             // make sure to always render on the first resize event
                             if old_rows != new_rows || old_columns != new_columns || event_id == 0 {
+                                running_plugin.refuel();
                                 let rendered_bytes = running_plugin
                                     .instance
                                     .clone()
@@ -845,6 +949,9 @@ impl WasmBridge {
                                             ]))
                                             .unwrap();
                                     },
+                                    Err(e) if exceeded_execution_budget(&e) => {
+                                        suspend_plugin(plugin_id, &mut running_plugin, &senders);
+                                    },
                                     Err(e) => log::error!("{}", e),
                                 }
                             }
@@ -859,6 +966,7 @@ impl WasmBridge {
                 current_size.1 = new_columns;
             }
         }
+        self.dev_mode_plugins.resize(pid, new_rows, new_columns);
         Ok(())
     }
     pub fn update_plugins(
@@ -926,6 +1034,9 @@ impl WasmBridge {
                                             ScreenInstruction::PluginBytes(plugin_render_assets),
                                         );
                                     },
+                                    Err(e) if exceeded_execution_budget(&e) => {
+                                        suspend_plugin(plugin_id, &mut running_plugin, &senders);
+                                    },
                                     Err(e) => {
                                         log::error!("{:?}", e);
 
@@ -1151,6 +1262,9 @@ impl WasmBridge {
                                         plugin_render_assets,
                                     ));
                                 },
+                                Err(e) if exceeded_execution_budget(&e) => {
+                                    suspend_plugin(plugin_id, &mut running_plugin, &senders);
+                                },
                                 Err(e) => {
                                     log::error!("{:?}", e);
 
@@ -1310,6 +1424,7 @@ impl WasmBridge {
         if let Some(watcher) = self.watcher.take() {
             watcher.stop_nonblocking();
         }
+        self.dev_mode_plugins.stop();
     }
     pub fn run_plugin_of_loading_plugin_id(&self, plugin_id: PluginId) -> Option<&RunPlugin> {
         self.loading_plugins
@@ -1445,6 +1560,13 @@ impl WasmBridge {
                                                             ),
                                                         );
                                                     },
+                                                    Err(e) if exceeded_execution_budget(&e) => {
+                                                        suspend_plugin(
+                                                            plugin_id,
+                                                            &mut running_plugin,
+                                                            &senders,
+                                                        );
+                                                    },
                                                     Err(e) => {
                                                         log::error!("{}", e);
                                                     },
@@ -1474,6 +1596,13 @@ impl WasmBridge {
                                                     ),
                                                 );
                                             },
+                                            Err(e) if exceeded_execution_budget(&e) => {
+                                                suspend_plugin(
+                                                    plugin_id,
+                                                    &mut running_plugin,
+                                                    &senders,
+                                                );
+                                            },
                                             Err(e) => {
                                                 log::error!("{:?}", e);
 
@@ -1713,6 +1842,44 @@ impl WasmBridge {
 
         permission_cache.write_to_file().with_context(err_context)
     }
+    /// Removes the permissions from the cache so that the plugin has to ask for them again the
+    /// next time it loads, and takes them away from the instances of it that are already running
+    pub fn revoke_plugin_permissions(
+        &mut self,
+        plugin_url: &str,
+        permissions: Vec<PermissionType>,
+    ) -> Result<()> {
+        let err_context = || format!("Failed to revoke permissions of plugin {plugin_url}");
+
+        let mut permission_cache = PermissionCache::from_path_or_default(None);
+        if permissions.is_empty() {
+            permission_cache.revoke(plugin_url, None);
+        } else {
+            for permission in &permissions {
+                permission_cache.revoke(plugin_url, Some(*permission));
+            }
+        }
+        permission_cache.write_to_file().with_context(err_context)?;
+
+        for (_plugin_id, _client_id, running_plugin) in
+            self.plugin_map.lock().unwrap().running_plugins()
+        {
+            let running_plugin = running_plugin.lock().unwrap();
+            let plugin_env = running_plugin.store.data();
+            if plugin_env.plugin.location.to_string() != plugin_url {
+                continue;
+            }
+            let mut granted = plugin_env.permissions.lock().unwrap();
+            if let Some(granted) = granted.as_mut() {
+                if permissions.is_empty() {
+                    granted.clear();
+                } else {
+                    granted.retain(|permission| !permissions.contains(permission));
+                }
+            }
+        }
+        Ok(())
+    }
     pub fn cache_plugin_events(&mut self, plugin_id: PluginId) {
         self.plugin_ids_waiting_for_permission_request
             .insert(plugin_id);
@@ -1891,6 +2058,7 @@ fn handle_plugin_successful_loading(
     let _ = senders.send_to_background_jobs(BackgroundJob::StopPluginLoadingAnimation(plugin_id));
     let _ = senders.send_to_screen(ScreenInstruction::RequestStateUpdateForPlugins);
     let _ = senders.send_to_background_jobs(BackgroundJob::ReportPluginList(plugin_list));
+    let _ = senders.send_to_background_jobs(BackgroundJob::ReportPluginSuspended(plugin_id, None));
     let _ = senders.send_to_plugin(PluginInstruction::RequestStateUpdateForPlugin(plugin_id));
 }
 
@@ -1945,8 +2113,9 @@ fn check_event_permission(
         | Event::AvailableLayoutInfo(..)
         | Event::InputReceived => PermissionType::ReadApplicationState,
         Event::WebServerStatus(..) => PermissionType::StartWebServer,
-        Event::PaneRenderReport(..) => PermissionType::ReadPaneContents,
+        Event::PaneRenderReport(..) | Event::PaneOutput(..) => PermissionType::ReadPaneContents,
         Event::UserAction(..) => PermissionType::InterceptInput,
+        Event::HttpRequest(..) => PermissionType::ServeHttpRoutes,
         _ => return (PermissionStatus::Granted, None),
     };
 
@@ -1967,6 +2136,10 @@ pub fn apply_event_to_plugin(
     plugin_render_assets: &mut Vec<PluginRenderAsset>,
     senders: ThreadSenders,
 ) -> Result<()> {
+    if running_plugin.suspended {
+        // suspended plugins receive nothing until they are reloaded
+        return Ok(());
+    }
     let instance = &running_plugin.instance;
     let rows = running_plugin.rows;
     let columns = running_plugin.columns;
@@ -1994,6 +2167,7 @@ pub fn apply_event_to_plugin(
                         .with_context(err_context)?;
                     wasi_write_object(running_plugin.store.data(), &protobuf_event.encode_to_vec())
                         .with_context(err_context)?;
+                    refuel(&mut running_plugin.store);
                     let should_render = update
                         .call(&mut running_plugin.store, ())
                         .with_context(err_context)?;
@@ -2004,6 +2178,7 @@ pub fn apply_event_to_plugin(
                         should_render = true;
                     }
                     if rows > 0 && columns > 0 && should_render {
+                        refuel(&mut running_plugin.store);
                         let rendered_bytes = instance
                             .get_typed_func::<(i32, i32), ()>(&mut running_plugin.store, "render")
                             .and_then(|render| {
@@ -2059,6 +2234,31 @@ pub fn apply_event_to_plugin(
     Ok(())
 }
 
+// plugins that run out of fuel are suspended rather than crashed, so that they can be reloaded
+// from their pane once the user has had a chance to see what happened
+fn suspend_plugin(
+    plugin_id: PluginId,
+    running_plugin: &mut RunningPlugin,
+    senders: &ThreadSenders,
+) {
+    running_plugin.suspended = true;
+    let reason = format!(
+        "exceeded its execution budget of {} fuel",
+        running_plugin.store.data().execution_budget
+    );
+    log::error!("Plugin {} {}, suspending it", plugin_id, reason);
+    let mut loading_indication = LoadingIndication::new("Suspended".to_owned());
+    loading_indication.indicate_suspended(format!("Plugin {} and was suspended", reason));
+    let _ = senders.send_to_screen(ScreenInstruction::UpdatePluginLoadingStage(
+        plugin_id,
+        loading_indication,
+    ));
+    let _ = senders.send_to_background_jobs(BackgroundJob::ReportPluginSuspended(
+        plugin_id,
+        Some(reason),
+    ));
+}
+
 pub fn handle_plugin_crash(plugin_id: PluginId, message: String, senders: ThreadSenders) {
     let mut loading_indication = LoadingIndication::new("Panic!".to_owned());
     loading_indication.indicate_loading_error(message);
@@ -2087,6 +2287,7 @@ pub fn apply_before_close_event_to_plugin(
         .with_context(err_context)?;
     wasi_write_object(running_plugin.store.data(), &protobuf_event.encode_to_vec())
         .with_context(err_context)?;
+    refuel(&mut running_plugin.store);
     let _should_render = update
         .call(&mut running_plugin.store, ())
         .with_context(err_context)?;
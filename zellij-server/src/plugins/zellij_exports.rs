@@ -1,7 +1,10 @@
 use super::PluginInstruction;
 use crate::background_jobs::BackgroundJob;
 use crate::global_async_runtime::get_tokio_runtime;
+use crate::plugins::http_routes::validate_route_name;
+use crate::plugins::kv_store::KvStore;
 use crate::plugins::plugin_map::PluginEnv;
+use crate::plugins::rpc::DEFAULT_RPC_TIMEOUT_MS;
 use crate::plugins::wasm_bridge::handle_plugin_crash;
 use crate::pty::{ClientTabIndexOrPaneId, PtyInstruction};
 use crate::route::{route_action, wait_for_action_completion, NotificationEnd};
@@ -15,6 +18,7 @@ use std::{
     path::PathBuf,
     process,
     str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
     thread,
     time::{Duration, Instant},
 };
@@ -23,12 +27,13 @@ use wasmi::{Caller, Linker};
 use zellij_utils::data::{
     CommandType, ConnectToSession, DeleteLayoutResponse, EditLayoutResponse, Event,
     FloatingPaneCoordinates, GetFocusedPaneInfoResponse, GetPanePidResponse, HttpVerb,
-    KeyWithModifier, LayoutInfo, LayoutMetadata, LayoutParsingError, MessageToPlugin,
-    NewPanePlacement, OriginatingPlugin, PaneScrollbackResponse, PermissionStatus, PermissionType,
-    PluginPermission, RenameLayoutResponse, SaveLayoutResponse, TabMetadata,
+    KeyWithModifier, KvResponse, KvScope, LayoutInfo, LayoutMetadata, LayoutParsingError,
+    MessageToPlugin, NewPanePlacement, OriginatingPlugin, PaneOutputFormat, PaneScrollbackResponse,
+    PermissionStatus, PermissionType, PluginHttpResponse, PluginPermission, RenameLayoutResponse,
+    SaveLayoutResponse, SearchQuery, SessionSearchResponse, TabMetadata,
 };
 use zellij_utils::home::default_layout_dir;
-use zellij_utils::input::permission::PermissionCache;
+use zellij_utils::input::permission::{AuditLogEntry, PermissionCache, AUDITED_PERMISSIONS};
 use zellij_utils::ipc::{ClientToServerMsg, IpcSenderWithContext};
 use zellij_utils::sessions::generate_random_name as generate_random_name_impl;
 #[cfg(feature = "web_server_capability")]
@@ -65,10 +70,14 @@ use zellij_utils::{
             ProtobufDeleteLayoutResponse, ProtobufDumpLayoutResponse,
             ProtobufDumpSessionLayoutResponse, ProtobufEditLayoutResponse,
             ProtobufGenerateRandomNameResponse, ProtobufGetFocusedPaneInfoResponse,
-            ProtobufGetLayoutDirResponse, ProtobufGetPanePidResponse, ProtobufParseLayoutResponse,
+            ProtobufGetLayoutDirResponse, ProtobufGetPanePidResponse,
+            ProtobufGrantedPluginPermissions, ProtobufKvResponse,
+            ProtobufListGrantedPermissionsResponse, ProtobufParseLayoutResponse,
             ProtobufPluginCommand, ProtobufRenameLayoutResponse, ProtobufSaveLayoutResponse,
+            ProtobufSearchSessionResponse, ProtobufSendRpcRequestResponse,
         },
         plugin_ids::{ProtobufPluginIds, ProtobufZellijVersion},
+        plugin_permission::ProtobufPermissionType,
     },
 };
 
@@ -116,7 +125,11 @@ fn host_run_plugin_command(mut caller: Caller<'_, PluginEnv>) {
             let command: PluginCommand = command
                 .try_into()
                 .map_err(|e| anyhow!("failed to convert serialized command: {}", e))?;
-            match check_command_permission(&env, &command) {
+            let (permission_status, permission) = check_command_permission(&env, &command);
+            if let (PermissionStatus::Granted, Some(permission)) = (permission_status, permission) {
+                audit_permission_use(&env, permission, &command);
+            }
+            match (permission_status, permission) {
                 (PermissionStatus::Granted, _) => match command {
                     PluginCommand::Subscribe(event_list) => subscribe(env, event_list)?,
                     PluginCommand::Unsubscribe(event_list) => unsubscribe(env, event_list)?,
@@ -129,6 +142,44 @@ fn host_run_plugin_command(mut caller: Caller<'_, PluginEnv>) {
                     PluginCommand::ParseLayout(layout_string) => parse_layout(env, layout_string),
                     PluginCommand::GetLayoutDir => get_layout_dir(env),
                     PluginCommand::GetFocusedPaneInfo => get_focused_pane_info(env),
+                    PluginCommand::SearchSession(query) => search_session(env, query),
+                    PluginCommand::JumpToSearchMatch { pane_id, line } => {
+                        jump_to_search_match(env, pane_id.into(), line)
+                    },
+                    PluginCommand::SubscribeToPaneOutput(pane_id, format) => {
+                        subscribe_to_pane_output(env, pane_id.into(), format)
+                    },
+                    PluginCommand::UnsubscribeFromPaneOutput(pane_id) => {
+                        unsubscribe_from_pane_output(env, pane_id.into())
+                    },
+                    PluginCommand::KvGet { scope, key } => kv_get(env, scope, key),
+                    PluginCommand::KvSet { scope, key, value } => kv_set(env, scope, key, value),
+                    PluginCommand::KvDelete { scope, key } => kv_delete(env, scope, key),
+                    PluginCommand::KvList { scope } => kv_list(env, scope),
+                    PluginCommand::RegisterRpcMethod(method) => register_rpc_method(env, method)?,
+                    PluginCommand::UnregisterRpcMethod(method) => {
+                        unregister_rpc_method(env, method)
+                    },
+                    PluginCommand::SendRpcRequest {
+                        method,
+                        payload,
+                        destination_plugin_id,
+                        timeout_ms,
+                    } => send_rpc_request(env, method, payload, destination_plugin_id, timeout_ms)?,
+                    PluginCommand::RespondToRpcRequest { request_id, result } => {
+                        respond_to_rpc_request(env, request_id, result)
+                    },
+                    PluginCommand::RegisterHttpRoute(route) => register_http_route(env, route)?,
+                    PluginCommand::UnregisterHttpRoute(route) => unregister_http_route(env, route),
+                    PluginCommand::RespondToHttpRequest {
+                        request_id,
+                        response,
+                    } => respond_to_http_request(env, request_id, response),
+                    PluginCommand::ListGrantedPermissions => list_granted_permissions(env),
+                    PluginCommand::RevokePluginPermissions {
+                        plugin_url,
+                        permissions,
+                    } => revoke_plugin_permissions(env, plugin_url, permissions),
                     PluginCommand::OpenFile(file_to_open, context) => {
                         open_file(env, file_to_open, context)
                     },
@@ -879,11 +930,14 @@ fn parse_layout(env: &PluginEnv, layout_string: String) {
     use std::time::{SystemTime, UNIX_EPOCH};
 
     // Parse the KDL layout - same parameters as list_available_layouts in layout.rs:1260
-    let parse_result = Layout::from_kdl(
+    let params = Layout::params_from_kdl(&layout_string).unwrap_or_default();
+    let parse_result = Layout::from_kdl_with_params(
         &layout_string,
         Some("parse_layout_api".to_string()), // file_name for error reporting
         None,                                 // no swap layouts
         None,                                 // no cwd
+        LayoutMetadata::placeholder_param_values(&params),
+        env.layout_dir.clone().or_else(default_layout_dir),
     );
 
     let response = match parse_result {
@@ -918,6 +972,7 @@ fn parse_layout(env: &PluginEnv, layout_string: String) {
                 tabs,
                 creation_time: current_time.clone(),
                 update_time: current_time,
+                params,
             };
 
             // Convert LayoutMetadata to protobuf
@@ -1398,6 +1453,8 @@ fn open_command_pane_in_place_of_plugin(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let run_cmd = TerminalAction::RunCommand(run_command_action.into());
     let _ = env
@@ -1438,6 +1495,8 @@ fn open_command_pane(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let action = Action::NewTiledPane {
         direction,
@@ -1475,6 +1534,8 @@ fn open_command_pane_near_plugin(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let run_cmd = TerminalAction::RunCommand(run_command_action.into());
     let _ = env.senders.send_to_pty(PtyInstruction::SpawnTerminal(
@@ -1519,6 +1580,8 @@ fn open_command_pane_floating(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let action = Action::NewFloatingPane {
         command: Some(run_command_action),
@@ -1556,6 +1619,8 @@ fn open_command_pane_floating_near_plugin(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let run_cmd = TerminalAction::RunCommand(run_command_action.into());
     let _ = env.senders.send_to_pty(PtyInstruction::SpawnTerminal(
@@ -1596,6 +1661,8 @@ fn open_command_pane_in_place(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let action = Action::NewInPlacePane {
         command: Some(run_command_action),
@@ -1637,6 +1704,8 @@ fn open_command_pane_background(
             context,
         )),
         use_terminal_title,
+        env: command_to_run.env,
+        ..Default::default()
     };
     let run_cmd = TerminalAction::RunCommand(run_command_action.into());
     let _ = env.senders.send_to_pty(PtyInstruction::SpawnTerminal(
@@ -2758,6 +2827,259 @@ fn get_pane_scrollback(env: &PluginEnv, pane_id: PaneId, get_full_scrollback: bo
         .non_fatal();
 }
 
+fn search_session(env: &PluginEnv, query: SearchQuery) {
+    use crossbeam::channel::RecvTimeoutError;
+    use std::time::Duration;
+
+    let err_context = || format!("failed to search session from plugin {}", env.name());
+
+    // Create oneshot channel for response
+    let (response_sender, response_receiver) = crossbeam::channel::bounded(1);
+
+    // Send request to screen thread
+    env.senders
+        .send_to_screen(ScreenInstruction::GetSessionSearchResults {
+            query,
+            response_channel: response_sender,
+        })
+        .with_context(err_context)
+        .non_fatal();
+
+    // Block waiting for response with 5 second timeout
+    let response = match response_receiver.recv_timeout(Duration::from_secs(5)) {
+        Ok(response) => response,
+        Err(RecvTimeoutError::Timeout) => {
+            log::error!(
+                "SearchSession timed out after 5s for plugin {}",
+                env.plugin_id
+            );
+            SessionSearchResponse::Err("Timeout searching the session".to_owned())
+        },
+        Err(RecvTimeoutError::Disconnected) => {
+            log::error!(
+                "SearchSession channel disconnected for plugin {}",
+                env.plugin_id
+            );
+            SessionSearchResponse::Err(
+                "Channel disconnected while searching the session".to_owned(),
+            )
+        },
+    };
+
+    // Convert to protobuf and write back to plugin
+    ProtobufSearchSessionResponse::try_from(response)
+        .map_err(|e| anyhow!("Failed to serialize session search response: {}", e))
+        .and_then(|serialized| {
+            wasi_write_object(env, &serialized.encode_to_vec())?;
+            Ok(())
+        })
+        .with_context(err_context)
+        .non_fatal();
+}
+
+fn jump_to_search_match(env: &PluginEnv, pane_id: PaneId, line: usize) {
+    let _ = env
+        .senders
+        .send_to_screen(ScreenInstruction::JumpToSearchMatch {
+            pane_id,
+            line,
+            client_id: env.client_id,
+            completion_tx: None,
+        });
+}
+
+fn subscribe_to_pane_output(env: &PluginEnv, pane_id: PaneId, format: PaneOutputFormat) {
+    let _ = env
+        .senders
+        .send_to_screen(ScreenInstruction::SubscribeToPaneOutput {
+            pane_id,
+            format,
+            plugin_id: env.plugin_id,
+            client_id: env.client_id,
+        });
+}
+
+fn unsubscribe_from_pane_output(env: &PluginEnv, pane_id: PaneId) {
+    let _ = env
+        .senders
+        .send_to_screen(ScreenInstruction::UnsubscribeFromPaneOutput {
+            pane_id,
+            plugin_id: env.plugin_id,
+            client_id: env.client_id,
+        });
+}
+
+fn kv_get(env: &PluginEnv, scope: KvScope, key: String) {
+    let response = match KvStore::for_plugin(&env.plugin, scope).get(&key) {
+        Ok(value) => KvResponse::Value(value),
+        Err(e) => KvResponse::Err(format!("{:#}", e)),
+    };
+    send_kv_response(env, response);
+}
+
+fn kv_set(env: &PluginEnv, scope: KvScope, key: String, value: Vec<u8>) {
+    let response = match KvStore::for_plugin(&env.plugin, scope).set(&key, &value) {
+        Ok(()) => KvResponse::Done,
+        Err(e) => KvResponse::Err(format!("{:#}", e)),
+    };
+    send_kv_response(env, response);
+}
+
+fn kv_delete(env: &PluginEnv, scope: KvScope, key: String) {
+    let response = match KvStore::for_plugin(&env.plugin, scope).delete(&key) {
+        Ok(()) => KvResponse::Done,
+        Err(e) => KvResponse::Err(format!("{:#}", e)),
+    };
+    send_kv_response(env, response);
+}
+
+fn kv_list(env: &PluginEnv, scope: KvScope) {
+    let response = match KvStore::for_plugin(&env.plugin, scope).list() {
+        Ok(keys) => KvResponse::Keys(keys),
+        Err(e) => KvResponse::Err(format!("{:#}", e)),
+    };
+    send_kv_response(env, response);
+}
+
+fn send_kv_response(env: &PluginEnv, response: KvResponse) {
+    let protobuf_response = ProtobufKvResponse::from(response);
+    wasi_write_object(env, &protobuf_response.encode_to_vec())
+        .with_context(|| format!("failed to send key/value response to plugin {}", env.name()))
+        .non_fatal();
+}
+
+// shared by all plugins so that a response can never be mistaken for one to another request
+static NEXT_RPC_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+fn register_rpc_method(env: &PluginEnv, method: String) -> Result<()> {
+    subscribe(env, HashSet::from([EventType::RpcRequest]))?;
+    env.senders
+        .send_to_plugin(PluginInstruction::RegisterRpcMethod(
+            env.plugin_id,
+            env.client_id,
+            method,
+        ))
+}
+
+fn unregister_rpc_method(env: &PluginEnv, method: String) {
+    let _ = env
+        .senders
+        .send_to_plugin(PluginInstruction::UnregisterRpcMethod(
+            env.plugin_id,
+            env.client_id,
+            method,
+        ));
+}
+
+fn send_rpc_request(
+    env: &PluginEnv,
+    method: String,
+    payload: Vec<u8>,
+    destination_plugin_id: Option<u32>,
+    timeout_ms: Option<u64>,
+) -> Result<()> {
+    let err_context = || format!("failed to send RPC request from plugin {}", env.name());
+    let request_id = NEXT_RPC_REQUEST_ID.fetch_add(1, Ordering::SeqCst);
+    // the caller learns the id before anything is routed, so that it can match the response
+    // even if it arrives before the plugin is called again
+    wasi_write_object(
+        env,
+        &ProtobufSendRpcRequestResponse { request_id }.encode_to_vec(),
+    )
+    .with_context(err_context)?;
+    subscribe(env, HashSet::from([EventType::RpcResponse])).with_context(err_context)?;
+    env.senders
+        .send_to_plugin(PluginInstruction::RpcRequest {
+            request_id,
+            caller_plugin_id: env.plugin_id,
+            caller_client_id: env.client_id,
+            method,
+            payload,
+            destination_plugin_id,
+        })
+        .with_context(err_context)?;
+    let send_plugin_instructions = env.senders.to_plugin.clone();
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_RPC_TIMEOUT_MS));
+    get_tokio_runtime().spawn(async move {
+        tokio::time::sleep(timeout).await;
+        if let Some(sender) = send_plugin_instructions {
+            // the plugin thread ignores this if the request was already answered
+            let _ = sender.send(PluginInstruction::RpcTimeout(request_id));
+        }
+    });
+    Ok(())
+}
+
+fn respond_to_rpc_request(env: &PluginEnv, request_id: u64, result: Result<Vec<u8>, String>) {
+    let _ = env.senders.send_to_plugin(PluginInstruction::RpcResponse {
+        request_id,
+        responder_plugin_id: env.plugin_id,
+        result,
+    });
+}
+
+fn register_http_route(env: &PluginEnv, route: String) -> Result<()> {
+    validate_route_name(&route).map_err(|e| anyhow!(e))?;
+    subscribe(env, HashSet::from([EventType::HttpRequest]))?;
+    env.senders
+        .send_to_plugin(PluginInstruction::RegisterHttpRoute(
+            env.plugin_id,
+            env.client_id,
+            route,
+        ))
+}
+
+fn unregister_http_route(env: &PluginEnv, route: String) {
+    let _ = env
+        .senders
+        .send_to_plugin(PluginInstruction::UnregisterHttpRoute(env.plugin_id, route));
+}
+
+fn respond_to_http_request(env: &PluginEnv, request_id: u64, response: PluginHttpResponse) {
+    let _ = env.senders.send_to_plugin(PluginInstruction::HttpResponse {
+        request_id,
+        responder_plugin_id: env.plugin_id,
+        response,
+    });
+}
+
+fn list_granted_permissions(env: &PluginEnv) {
+    let permission_cache = PermissionCache::from_path_or_default(None);
+    let mut granted: Vec<ProtobufGrantedPluginPermissions> = permission_cache
+        .granted()
+        .iter()
+        .map(
+            |(plugin_url, permissions)| ProtobufGrantedPluginPermissions {
+                plugin_url: plugin_url.clone(),
+                permissions: permissions
+                    .iter()
+                    .filter_map(|p| ProtobufPermissionType::try_from(*p).ok())
+                    .map(|p| p as i32)
+                    .collect(),
+            },
+        )
+        .collect();
+    granted.sort_by(|a, b| a.plugin_url.cmp(&b.plugin_url));
+    let response = ProtobufListGrantedPermissionsResponse {
+        granted,
+        error: None,
+    };
+    let _ = wasi_write_object(env, &response.encode_to_vec());
+}
+
+fn revoke_plugin_permissions(
+    env: &PluginEnv,
+    plugin_url: String,
+    permissions: Vec<PermissionType>,
+) {
+    let _ = env
+        .senders
+        .send_to_plugin(PluginInstruction::RevokePluginPermissions {
+            plugin_url,
+            permissions,
+        });
+}
+
 fn write_to_pane_id(env: &PluginEnv, bytes: Vec<u8>, pane_id: PaneId) {
     let _ = env
         .senders
@@ -3691,6 +4013,25 @@ pub fn wasi_read_bytes(plugin_env: &PluginEnv) -> Result<Vec<u8>> {
         .with_context(|| format!("failed to deserialize object from WASI env"))
 }
 
+fn audit_permission_use(
+    plugin_env: &PluginEnv,
+    permission: PermissionType,
+    command: &PluginCommand,
+) {
+    if !AUDITED_PERMISSIONS.contains(&permission) {
+        return;
+    }
+    let entry = AuditLogEntry::new(
+        plugin_env.plugin.location.to_string(),
+        permission,
+        command.to_string(),
+    );
+    // written on the background jobs thread to keep file I/O off the plugin's thread
+    let _ = plugin_env
+        .senders
+        .send_to_background_jobs(BackgroundJob::RecordPermissionUse(entry));
+}
+
 // TODO: move to permissions?
 fn check_command_permission(
     plugin_env: &PluginEnv,
@@ -3730,6 +4071,9 @@ fn check_command_permission(
         | PluginCommand::WriteToPaneId(..)
         | PluginCommand::WriteCharsToPaneId(..) => PermissionType::WriteToStdin,
         PluginCommand::CopyToClipboard(..) => PermissionType::WriteToClipboard,
+        PluginCommand::RegisterHttpRoute(..)
+        | PluginCommand::UnregisterHttpRoute(..)
+        | PluginCommand::RespondToHttpRequest { .. } => PermissionType::ServeHttpRoutes,
         PluginCommand::SwitchTabTo(..)
         | PluginCommand::SwitchToMode(..)
         | PluginCommand::NewTabsWithLayout(..)
@@ -3819,11 +4163,15 @@ fn check_command_permission(
         | PluginCommand::SaveLayout { .. }
         | PluginCommand::DeleteLayout { .. }
         | PluginCommand::RenameLayout { .. }
-        | PluginCommand::EditLayout { .. } => PermissionType::ChangeApplicationState,
+        | PluginCommand::EditLayout { .. }
+        | PluginCommand::JumpToSearchMatch { .. }
+        | PluginCommand::RevokePluginPermissions { .. } => PermissionType::ChangeApplicationState,
         PluginCommand::UnblockCliPipeInput(..)
         | PluginCommand::BlockCliPipeInput(..)
         | PluginCommand::CliPipeOutput(..) => PermissionType::ReadCliPipes,
-        PluginCommand::MessageToPlugin(..) => PermissionType::MessageAndLaunchOtherPlugins,
+        PluginCommand::MessageToPlugin(..) | PluginCommand::SendRpcRequest { .. } => {
+            PermissionType::MessageAndLaunchOtherPlugins
+        },
         PluginCommand::ListClients
         | PluginCommand::DumpSessionLayout { .. }
         | PluginCommand::GetPanePid { .. }
@@ -3831,7 +4179,8 @@ fn check_command_permission(
         | PluginCommand::GetLayoutDir
         | PluginCommand::GetFocusedPaneInfo
         | PluginCommand::DumpLayout(..)
-        | PluginCommand::ParseLayout(..) => PermissionType::ReadApplicationState,
+        | PluginCommand::ParseLayout(..)
+        | PluginCommand::ListGrantedPermissions => PermissionType::ReadApplicationState,
         PluginCommand::RebindKeys { .. } | PluginCommand::Reconfigure(..) => {
             PermissionType::Reconfigure
         },
@@ -3849,14 +4198,16 @@ fn check_command_permission(
         PluginCommand::InterceptKeyPresses | PluginCommand::ClearKeyPressesIntercepts => {
             PermissionType::InterceptInput
         },
-        PluginCommand::GetPaneScrollback { .. } => PermissionType::ReadPaneContents,
+        PluginCommand::GetPaneScrollback { .. }
+        | PluginCommand::SearchSession(..)
+        | PluginCommand::SubscribeToPaneOutput(..) => PermissionType::ReadPaneContents,
         PluginCommand::RunAction(..) => PermissionType::RunActionsAsUser,
         _ => return (PermissionStatus::Granted, None),
     };
 
     if let Some(permissions) = plugin_env.permissions.lock().unwrap().as_ref() {
         if permissions.contains(&permission) {
-            return (PermissionStatus::Granted, None);
+            return (PermissionStatus::Granted, Some(permission));
         }
     }
 
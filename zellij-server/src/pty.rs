@@ -15,6 +15,7 @@ use async_std::{
 };
 use nix::unistd::Pid;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, os::unix::io::RawFd, path::PathBuf};
 use zellij_utils::{
     data::{
@@ -24,16 +25,20 @@ use zellij_utils::{
     errors::prelude::*,
     errors::{ContextType, PtyContext},
     input::{
-        command::{OpenFilePayload, RunCommand, TerminalAction},
+        command::{OpenFilePayload, RestartPolicy, RunCommand, TerminalAction},
         layout::{
             FloatingPaneLayout, Layout, Run, RunPluginOrAlias, SwapFloatingLayout, SwapTiledLayout,
             TabLayoutInfo, TiledPaneLayout,
         },
     },
+    notification::Notification,
     pane_size::Size,
     session_serialization,
 };
 
+/// A command that ran for at least this long before exiting gets a fresh set of restarts
+const RESTART_COUNT_RESET_AFTER: Duration = Duration::from_secs(60);
+
 pub type VteBytes = Vec<u8>;
 pub type TabIndex = u32;
 
@@ -89,6 +94,12 @@ pub enum PtyInstruction {
     ),
     ClosePane(PaneId, Option<NotificationEnd>),
     CloseTab(Vec<PaneId>),
+    CommandExited {
+        pane_id: PaneId,
+        exit_status: Option<i32>,
+        run_command: RunCommand, // has a restart policy
+        ran_for: Duration,
+    },
     ReRunCommandInPane(PaneId, RunCommand, Option<NotificationEnd>),
     DropToShellInPane {
         pane_id: PaneId,
@@ -152,6 +163,7 @@ impl From<&PtyInstruction> for PtyContext {
             PtyInstruction::GoToTab(..) => PtyContext::GoToTab,
             PtyInstruction::ClosePane(..) => PtyContext::ClosePane,
             PtyInstruction::CloseTab(_) => PtyContext::CloseTab,
+            PtyInstruction::CommandExited { .. } => PtyContext::CommandExited,
             PtyInstruction::NewTab(..) => PtyContext::NewTab,
             PtyInstruction::OverrideLayout(..) => PtyContext::OverrideLayout,
             PtyInstruction::ReRunCommandInPane(..) => PtyContext::ReRunCommandInPane,
@@ -185,6 +197,7 @@ pub(crate) struct Pty {
     post_command_discovery_hook: Option<String>,
     plugin_cwds: HashMap<u32, PathBuf>,   // plugin_id -> cwd
     terminal_cwds: HashMap<u32, PathBuf>, // terminal_id -> cwd
+    restart_counts: HashMap<u32, usize>,  // terminal_id -> consecutive restarts
 }
 
 pub(crate) fn pty_thread_main(mut pty: Pty, layout: Box<Layout>) -> Result<()> {
@@ -563,6 +576,16 @@ pub(crate) fn pty_thread_main(mut pty: Pty, layout: Box<Layout>) -> Result<()> {
                     })
                     .context("failed to close tabs")?;
             },
+            PtyInstruction::CommandExited {
+                pane_id,
+                exit_status,
+                run_command,
+                ran_for,
+            } => {
+                pty.handle_command_exit(pane_id, exit_status, run_command, ran_for)
+                    .with_context(|| format!("failed to handle command exit in pane {pane_id:?}"))
+                    .non_fatal();
+            },
             PtyInstruction::ReRunCommandInPane(pane_id, run_command, _completion_tx) => {
                 let err_context = || format!("failed to rerun command in pane {:?}", pane_id);
 
@@ -838,6 +861,7 @@ impl Pty {
             post_command_discovery_hook,
             plugin_cwds: HashMap::new(),
             terminal_cwds: HashMap::new(),
+            restart_counts: HashMap::new(),
         }
     }
     pub fn get_default_terminal(
@@ -983,6 +1007,7 @@ impl Pty {
         let originating_edit_plugin = Arc::new(originating_edit_plugin.clone());
         let quit_cb = Box::new({
             let senders = self.bus.senders.clone();
+            let started_at = Instant::now();
             move |pane_id, exit_status, command| {
                 // if this command originated in a plugin, we send the plugin an event letting it
                 // know the command exited and some other useful information
@@ -1013,20 +1038,14 @@ impl Pty {
                     }
                 }
 
-                if hold_on_close {
-                    let _ = senders.send_to_screen(ScreenInstruction::HoldPane(
-                        pane_id,
-                        exit_status,
-                        command,
-                    ));
-                } else {
-                    let _ = senders.send_to_screen(ScreenInstruction::ClosePane(
-                        pane_id,
-                        None,
-                        None,
-                        exit_status,
-                    ));
-                }
+                report_command_exit(
+                    &senders,
+                    pane_id,
+                    exit_status,
+                    command,
+                    hold_on_close,
+                    started_at,
+                );
             }
         });
         let (terminal_id, pid_primary, child_fd): (u32, RawFd, RawFd) = self
@@ -1106,7 +1125,7 @@ impl Pty {
         let extracted_floating_run_instructions = floating_panes_layout
             .iter()
             .filter(|f| !f.already_running)
-            .map(|f| f.run.clone());
+            .map(|f| f.run_instruction());
         let mut new_pane_pids: Vec<(u32, bool, Option<RunCommand>, Result<RawFd>)> = vec![]; // (terminal_id,
                                                                                              // starts_held,
                                                                                              // run_command,
@@ -1300,7 +1319,7 @@ impl Pty {
         let extracted_floating_run_instructions = floating_panes_layout
             .iter()
             .filter(|f| !f.already_running)
-            .map(|f| f.run.clone());
+            .map(|f| f.run_instruction());
         let mut new_pane_pids: Vec<(u32, bool, Option<RunCommand>, Result<RawFd>)> = vec![]; // (terminal_id,
                                                                                              // starts_held,
                                                                                              // run_command,
@@ -1495,21 +1514,16 @@ impl Pty {
                 let hold_on_close = command.hold_on_close;
                 let quit_cb = Box::new({
                     let senders = self.bus.senders.clone();
+                    let started_at = Instant::now();
                     move |pane_id, exit_status, command| {
-                        if hold_on_close {
-                            let _ = senders.send_to_screen(ScreenInstruction::HoldPane(
-                                pane_id,
-                                exit_status,
-                                command,
-                            ));
-                        } else {
-                            let _ = senders.send_to_screen(ScreenInstruction::ClosePane(
-                                pane_id,
-                                None,
-                                None,
-                                exit_status,
-                            ));
-                        }
+                        report_command_exit(
+                            &senders,
+                            pane_id,
+                            exit_status,
+                            command,
+                            hold_on_close,
+                            started_at,
+                        );
                     }
                 });
                 if command.cwd.is_none() {
@@ -1660,6 +1674,7 @@ impl Pty {
         match id {
             PaneId::Terminal(id) => {
                 self.task_handles.remove(&id);
+                self.restart_counts.remove(&id);
                 if let Some(child_fd) = self.id_to_child_pid.remove(&id) {
                     task::block_on(async {
                         let err_context = || format!("failed to run async task for pane {id}");
@@ -1700,6 +1715,96 @@ impl Pty {
             self.active_panes.insert(client_id, pane_id);
         }
     }
+    /// Restarts the exited command of a pane according to its restart policy, waiting longer
+    /// between each consecutive restart, and gives up once it was restarted `max_restarts` times
+    pub fn handle_command_exit(
+        &mut self,
+        pane_id: PaneId,
+        exit_status: Option<i32>,
+        run_command: RunCommand,
+        ran_for: Duration,
+    ) -> Result<()> {
+        let err_context = || format!("failed to handle command exit in pane {:?}", pane_id);
+        let senders = self.bus.senders.clone();
+        let terminal_id = match pane_id {
+            PaneId::Terminal(terminal_id) => terminal_id,
+            PaneId::Plugin(_) => {
+                let hold_on_close = run_command.hold_on_close;
+                return hold_or_close_exited_pane(
+                    &senders,
+                    pane_id,
+                    exit_status,
+                    run_command,
+                    hold_on_close,
+                )
+                .with_context(err_context);
+            },
+        };
+        if ran_for >= RESTART_COUNT_RESET_AFTER {
+            self.restart_counts.remove(&terminal_id);
+        }
+        let restarts = self.restart_counts.get(&terminal_id).copied().unwrap_or(0);
+        let max_restarts = run_command.max_restarts();
+        let hold_on_close = run_command.hold_on_close;
+        if !run_command.restart.should_restart(exit_status) {
+            self.restart_counts.remove(&terminal_id);
+            senders
+                .send_to_screen(ScreenInstruction::UpdatePaneRestarts(
+                    pane_id,
+                    0,
+                    max_restarts,
+                ))
+                .with_context(err_context)?;
+            hold_or_close_exited_pane(&senders, pane_id, exit_status, run_command, hold_on_close)
+                .with_context(err_context)
+        } else if restarts < max_restarts {
+            let restarts = restarts + 1;
+            self.restart_counts.insert(terminal_id, restarts);
+            let backoff = RestartPolicy::backoff(restarts);
+            log::info!(
+                "Command {} exited, restarting it in {:?} (attempt {}/{})",
+                run_command,
+                backoff,
+                restarts,
+                max_restarts
+            );
+            senders
+                .send_to_screen(ScreenInstruction::UpdatePaneRestarts(
+                    pane_id,
+                    restarts,
+                    max_restarts,
+                ))
+                .with_context(err_context)?;
+            // the pane is held until it is restarted, so that it can be re-run from the screen
+            // with the command it was held with
+            senders
+                .send_to_screen(ScreenInstruction::HoldPane(
+                    pane_id,
+                    exit_status,
+                    run_command,
+                ))
+                .with_context(err_context)?;
+            task::spawn(async move {
+                task::sleep(backoff).await;
+                let _ =
+                    senders.send_to_screen(ScreenInstruction::RerunCommandPane(terminal_id, None));
+            });
+            Ok(())
+        } else {
+            self.restart_counts.remove(&terminal_id);
+            senders
+                .send_to_screen(ScreenInstruction::Notify(
+                    pane_id,
+                    Notification::error(format!(
+                        "{} exited, gave up after {} restarts",
+                        run_command, max_restarts
+                    )),
+                ))
+                .with_context(err_context)?;
+            hold_or_close_exited_pane(&senders, pane_id, exit_status, run_command, hold_on_close)
+                .with_context(err_context)
+        }
+    }
     pub fn rerun_command_in_pane(
         &mut self,
         pane_id: PaneId,
@@ -1719,6 +1824,7 @@ impl Pty {
                 let originating_plugin = Arc::new(run_command.originating_plugin.clone());
                 let quit_cb = Box::new({
                     let senders = self.bus.senders.clone();
+                    let started_at = Instant::now();
                     move |pane_id, exit_status, command| {
                         if let PaneId::Terminal(pane_id) = pane_id {
                             if let Some(originating_plugin) = originating_plugin.as_ref() {
@@ -1734,20 +1840,14 @@ impl Pty {
                                 )]));
                             }
                         }
-                        if hold_on_close {
-                            let _ = senders.send_to_screen(ScreenInstruction::HoldPane(
-                                pane_id,
-                                exit_status,
-                                command,
-                            ));
-                        } else {
-                            let _ = senders.send_to_screen(ScreenInstruction::ClosePane(
-                                pane_id,
-                                None,
-                                None,
-                                exit_status,
-                            ));
-                        }
+                        report_command_exit(
+                            &senders,
+                            pane_id,
+                            exit_status,
+                            command,
+                            hold_on_close,
+                            started_at,
+                        );
                     }
                 });
                 let (pid_primary, child_fd): (RawFd, RawFd) = self
@@ -2080,6 +2180,47 @@ fn send_command_not_found_to_screen(
     Ok(())
 }
 
+/// Lets the screen know that the command of a pane exited, unless the command has a restart
+/// policy, in which case the pty thread decides whether to restart it
+fn report_command_exit(
+    senders: &ThreadSenders,
+    pane_id: PaneId,
+    exit_status: Option<i32>,
+    command: RunCommand,
+    hold_on_close: bool,
+    started_at: Instant,
+) {
+    if command.restart != RestartPolicy::Never {
+        let _ = senders.send_to_pty(PtyInstruction::CommandExited {
+            pane_id,
+            exit_status,
+            run_command: command,
+            ran_for: started_at.elapsed(),
+        });
+    } else {
+        let _ = hold_or_close_exited_pane(senders, pane_id, exit_status, command, hold_on_close);
+    }
+}
+
+fn hold_or_close_exited_pane(
+    senders: &ThreadSenders,
+    pane_id: PaneId,
+    exit_status: Option<i32>,
+    command: RunCommand,
+    hold_on_close: bool,
+) -> Result<()> {
+    if hold_on_close {
+        senders.send_to_screen(ScreenInstruction::HoldPane(pane_id, exit_status, command))
+    } else {
+        senders.send_to_screen(ScreenInstruction::ClosePane(
+            pane_id,
+            None,
+            None,
+            exit_status,
+        ))
+    }
+}
+
 pub fn get_default_shell() -> PathBuf {
     PathBuf::from(std::env::var("SHELL").unwrap_or_else(|_| {
         log::warn!("Cannot read SHELL env, falling back to use /bin/sh");
@@ -364,12 +364,14 @@ pub(crate) fn route_action(
         Action::DumpScreen {
             file_path,
             include_scrollback,
+            format,
         } => {
             senders
                 .send_to_screen(ScreenInstruction::DumpScreen(
                     file_path,
                     client_id,
                     include_scrollback,
+                    format,
                     Some(NotificationEnd::new(completion_tx)),
                 ))
                 .with_context(err_context)?;
@@ -828,6 +830,35 @@ pub(crate) fn route_action(
                 ))
                 .with_context(err_context)?;
         },
+        Action::MarkPane { name } => {
+            senders
+                .send_to_screen(ScreenInstruction::MarkPane(
+                    name,
+                    client_id,
+                    Some(NotificationEnd::new(completion_tx)),
+                ))
+                .with_context(err_context)?;
+        },
+        Action::FocusMark { name } => {
+            senders
+                .send_to_screen(ScreenInstruction::FocusMark(
+                    name,
+                    client_id,
+                    Some(NotificationEnd::new(completion_tx)),
+                ))
+                .with_context(err_context)?;
+        },
+        Action::Follow {
+            client_id: followed_client_id,
+        } => {
+            senders
+                .send_to_screen(ScreenInstruction::Follow(
+                    followed_client_id,
+                    client_id,
+                    Some(NotificationEnd::new(completion_tx)),
+                ))
+                .with_context(err_context)?;
+        },
         Action::Run {
             command,
             near_current_pane,
@@ -1112,6 +1143,9 @@ pub(crate) fn route_action(
                 SearchOption::Wrap => {
                     ScreenInstruction::SearchToggleWrap(client_id, notification_end)
                 },
+                SearchOption::Regex => {
+                    ScreenInstruction::SearchToggleRegex(client_id, notification_end)
+                },
             };
             senders
                 .send_to_screen(instruction)
@@ -1134,6 +1168,15 @@ pub(crate) fn route_action(
                 ))
                 .with_context(err_context)?;
         },
+        Action::SetTilingMode { tiling_mode } => {
+            senders
+                .send_to_screen(ScreenInstruction::SetTilingMode(
+                    tiling_mode,
+                    client_id,
+                    Some(NotificationEnd::new(completion_tx)),
+                ))
+                .with_context(err_context)?;
+        },
         Action::OverrideLayout {
             tabs,
             retain_existing_terminal_panes,
@@ -1588,6 +1631,63 @@ pub(crate) fn route_action(
                 .with_context(err_context)?;
             // Note: No NotificationEnd needed - notification applies immediately
         },
+        Action::SearchPane {
+            pane_id,
+            query,
+            json,
+        } => {
+            senders
+                .send_to_screen(ScreenInstruction::SearchPane {
+                    pane_id: pane_id.into(),
+                    query,
+                    json,
+                    client_id,
+                    completion_tx: Some(NotificationEnd::new(completion_tx)),
+                })
+                .with_context(err_context)?;
+        },
+        Action::SearchSession { query } => {
+            senders
+                .send_to_screen(ScreenInstruction::SearchSession {
+                    query,
+                    client_id,
+                    completion_tx: Some(NotificationEnd::new(completion_tx)),
+                })
+                .with_context(err_context)?;
+        },
+        Action::JumpToSearchMatch { pane_id, line } => {
+            senders
+                .send_to_screen(ScreenInstruction::JumpToSearchMatch {
+                    pane_id: pane_id.into(),
+                    line,
+                    client_id,
+                    completion_tx: Some(NotificationEnd::new(completion_tx)),
+                })
+                .with_context(err_context)?;
+        },
+        Action::LogPane {
+            pane_id,
+            log_options,
+        } => {
+            senders
+                .send_to_screen(ScreenInstruction::LogPane {
+                    pane_id: pane_id.into(),
+                    log_options,
+                    client_id,
+                    completion_tx: Some(NotificationEnd::new(completion_tx)),
+                })
+                .with_context(err_context)?;
+        },
+        Action::RecordPane { pane_id, out } => {
+            senders
+                .send_to_screen(ScreenInstruction::RecordPane {
+                    pane_id: pane_id.into(),
+                    out,
+                    client_id,
+                    completion_tx: Some(NotificationEnd::new(completion_tx)),
+                })
+                .with_context(err_context)?;
+        },
     }
     let result = wait_for_action_completion(completion_rx, &action_name, wait_forever);
     if let Some(exit_status) = result.exit_status {
@@ -1737,6 +1837,10 @@ pub(crate) fn route_thread_main(
                                     client_input_mode,
                                 )) = session_data_assets
                                 {
+                                    // any keypress stops this client from following another
+                                    let _ = senders.send_to_screen(
+                                        ScreenInstruction::StopFollowing(client_id),
+                                    );
                                     for action in keybinds
                                         .get_actions_for_key_in_mode_or_default_action(
                                             &input_mode,
@@ -2061,6 +2165,12 @@ pub(crate) fn route_thread_main(
                             let _ =
                                 to_server.send(ServerInstruction::FailedToStartWebServer(error));
                         },
+                        ClientToServerMsg::PluginHttpRequest { request } => {
+                            // the server answers and disconnects the client once a plugin responds
+                            let _ = to_server
+                                .send(ServerInstruction::PluginHttpRequest(client_id, request));
+                            should_break = true;
+                        },
                     }
                     Ok(should_break)
                 };
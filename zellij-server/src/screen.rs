@@ -12,9 +12,11 @@ use crate::route::NotificationEnd;
 
 use log::{debug, warn};
 use zellij_utils::data::{
-    CommandOrPlugin, Direction, FloatingPaneCoordinates, GetFocusedPaneInfoResponse,
-    KeyWithModifier, NewPanePlacement, PaneContents, PaneManifest, PaneScrollbackResponse,
-    PluginPermission, Resize, ResizeStrategy, SessionInfo, Styling, WebSharing,
+    CommandOrPlugin, Direction, DumpScreenFormat, FloatingPaneCoordinates,
+    GetFocusedPaneInfoResponse, KeyWithModifier, NewPanePlacement, PaneContents, PaneLogOptions,
+    PaneManifest, PaneOutputFormat, PaneScrollbackResponse, PaneSearchResults, PluginPermission,
+    Resize, ResizeStrategy, SearchQuery, SessionInfo, SessionSearchResponse, Styling, TilingMode,
+    WebSharing,
 };
 use zellij_utils::errors::prelude::*;
 use zellij_utils::input::command::RunCommand;
@@ -46,7 +48,7 @@ use crate::session_layout_metadata::{PaneLayoutMetadata, SessionLayoutMetadata};
 use crate::{
     output::Output,
     panes::sixel::SixelImageStore,
-    panes::PaneId,
+    panes::{PaneId, PaneOutputLog, PaneOutputSubscriptions, PaneRecorder},
     plugins::{DumpSessionLayoutResponse, PluginId, PluginInstruction, PluginRenderAsset},
     pty::{get_default_shell, ClientTabIndexOrPaneId, PtyInstruction, VteBytes},
     tab::{SuppressedPanes, Tab},
@@ -203,7 +205,13 @@ pub enum ScreenInstruction {
     MovePaneLeft(ClientId, Option<NotificationEnd>),
     Exit,
     ClearScreen(ClientId, Option<NotificationEnd>),
-    DumpScreen(String, ClientId, bool, Option<NotificationEnd>),
+    DumpScreen(
+        String,
+        ClientId,
+        bool,
+        DumpScreenFormat,
+        Option<NotificationEnd>,
+    ),
     DumpLayout(Option<PathBuf>, ClientId, Option<NotificationEnd>), // PathBuf is the default configured
     // shell
     DumpLayoutToPlugin {
@@ -246,8 +254,14 @@ pub enum ScreenInstruction {
     ), // i32 -> optional exit
     // status
     HoldPane(PaneId, Option<i32>, RunCommand),
+    CheckPaneDependencies,
+    UpdatePaneRestarts(PaneId, usize, usize), // restarts so far, max restarts
     UpdatePaneName(Vec<u8>, ClientId, Option<NotificationEnd>),
     UndoRenamePane(ClientId, Option<NotificationEnd>),
+    MarkPane(String, ClientId, Option<NotificationEnd>),
+    FocusMark(String, ClientId, Option<NotificationEnd>),
+    Follow(ClientId, ClientId, Option<NotificationEnd>), // followed client id, following client id
+    StopFollowing(ClientId),
     NewTab(
         Option<PathBuf>,
         Option<TerminalAction>,
@@ -307,16 +321,19 @@ pub enum ScreenInstruction {
         Option<(u32, bool)>, // (pane_id, is_plugin) => pane_id to focus
     ),
     RemoveClient(ClientId),
+    SetClientName(ClientId, String),
     UpdateSearch(Vec<u8>, ClientId, Option<NotificationEnd>),
     SearchDown(ClientId, Option<NotificationEnd>),
     SearchUp(ClientId, Option<NotificationEnd>),
     SearchToggleCaseSensitivity(ClientId, Option<NotificationEnd>),
     SearchToggleWholeWord(ClientId, Option<NotificationEnd>),
     SearchToggleWrap(ClientId, Option<NotificationEnd>),
+    SearchToggleRegex(ClientId, Option<NotificationEnd>),
     AddRedPaneFrameColorOverride(Vec<PaneId>, Option<String>), // Option<String> => optional error text
     ClearPaneFrameColorOverride(Vec<PaneId>),
     PreviousSwapLayout(ClientId, Option<NotificationEnd>),
     NextSwapLayout(ClientId, Option<NotificationEnd>),
+    SetTilingMode(TilingMode, ClientId, Option<NotificationEnd>),
     OverrideLayout(
         Option<PathBuf>,        // cwd (applies to all tabs)
         Option<TerminalAction>, // default_shell (applies to all tabs)
@@ -517,6 +534,53 @@ pub enum ScreenInstruction {
     WatcherTerminalResize(ClientId, Size),
     /// Perth STORY-003: Send notification to a pane
     Notify(PaneId, zellij_utils::notification::Notification),
+    SearchPane {
+        pane_id: PaneId,
+        query: SearchQuery,
+        json: bool,
+        client_id: ClientId,
+        completion_tx: Option<NotificationEnd>,
+    },
+    SearchSession {
+        query: SearchQuery,
+        client_id: ClientId,
+        completion_tx: Option<NotificationEnd>,
+    },
+    GetSessionSearchResults {
+        query: SearchQuery,
+        response_channel: crossbeam::channel::Sender<SessionSearchResponse>,
+    },
+    JumpToSearchMatch {
+        pane_id: PaneId,
+        line: usize,
+        client_id: ClientId,
+        completion_tx: Option<NotificationEnd>,
+    },
+    LogPane {
+        pane_id: PaneId,
+        log_options: Option<PaneLogOptions>,
+        client_id: ClientId,
+        completion_tx: Option<NotificationEnd>,
+    },
+    RecordPane {
+        pane_id: PaneId,
+        out: Option<PathBuf>,
+        client_id: ClientId,
+        completion_tx: Option<NotificationEnd>,
+    },
+    SubscribeToPaneOutput {
+        pane_id: PaneId,
+        format: PaneOutputFormat,
+        plugin_id: PluginId,
+        client_id: ClientId,
+    },
+    UnsubscribeFromPaneOutput {
+        pane_id: PaneId,
+        plugin_id: PluginId,
+        client_id: ClientId,
+    },
+    ClearPaneOutputSubscriptions(PluginId, ClientId),
+    FlushPaneOutput,
 }
 
 impl From<&ScreenInstruction> for ScreenContext {
@@ -602,8 +666,14 @@ impl From<&ScreenInstruction> for ScreenContext {
             ScreenInstruction::ShowPluginCursor(..) => ScreenContext::ShowPluginCursor,
             ScreenInstruction::ClosePane(..) => ScreenContext::ClosePane,
             ScreenInstruction::HoldPane(..) => ScreenContext::HoldPane,
+            ScreenInstruction::CheckPaneDependencies => ScreenContext::CheckPaneDependencies,
+            ScreenInstruction::UpdatePaneRestarts(..) => ScreenContext::UpdatePaneRestarts,
             ScreenInstruction::UpdatePaneName(..) => ScreenContext::UpdatePaneName,
             ScreenInstruction::UndoRenamePane(..) => ScreenContext::UndoRenamePane,
+            ScreenInstruction::MarkPane(..) => ScreenContext::MarkPane,
+            ScreenInstruction::FocusMark(..) => ScreenContext::FocusMark,
+            ScreenInstruction::Follow(..) => ScreenContext::Follow,
+            ScreenInstruction::StopFollowing(..) => ScreenContext::StopFollowing,
             ScreenInstruction::NewTab(..) => ScreenContext::NewTab,
             ScreenInstruction::ApplyLayout(..) => ScreenContext::ApplyLayout,
             ScreenInstruction::SwitchTabNext(..) => ScreenContext::SwitchTabNext,
@@ -638,6 +708,7 @@ impl From<&ScreenInstruction> for ScreenContext {
             ScreenInstruction::ToggleTab(..) => ScreenContext::ToggleTab,
             ScreenInstruction::AddClient(..) => ScreenContext::AddClient,
             ScreenInstruction::RemoveClient(..) => ScreenContext::RemoveClient,
+            ScreenInstruction::SetClientName(..) => ScreenContext::SetClientName,
             ScreenInstruction::UpdateSearch(..) => ScreenContext::UpdateSearch,
             ScreenInstruction::SearchDown(..) => ScreenContext::SearchDown,
             ScreenInstruction::SearchUp(..) => ScreenContext::SearchUp,
@@ -646,6 +717,7 @@ impl From<&ScreenInstruction> for ScreenContext {
             },
             ScreenInstruction::SearchToggleWholeWord(..) => ScreenContext::SearchToggleWholeWord,
             ScreenInstruction::SearchToggleWrap(..) => ScreenContext::SearchToggleWrap,
+            ScreenInstruction::SearchToggleRegex(..) => ScreenContext::SearchToggleRegex,
             ScreenInstruction::AddRedPaneFrameColorOverride(..) => {
                 ScreenContext::AddRedPaneFrameColorOverride
             },
@@ -654,6 +726,7 @@ impl From<&ScreenInstruction> for ScreenContext {
             },
             ScreenInstruction::PreviousSwapLayout(..) => ScreenContext::PreviousSwapLayout,
             ScreenInstruction::NextSwapLayout(..) => ScreenContext::NextSwapLayout,
+            ScreenInstruction::SetTilingMode(..) => ScreenContext::SetTilingMode,
             ScreenInstruction::OverrideLayout(..) => ScreenContext::OverrideLayout,
             ScreenInstruction::OverrideLayoutComplete(..) => ScreenContext::OverrideLayoutComplete,
             ScreenInstruction::QueryTabNames(..) => ScreenContext::QueryTabNames,
@@ -763,6 +836,22 @@ impl From<&ScreenInstruction> for ScreenContext {
             ScreenInstruction::SetFollowedClient(..) => ScreenContext::SetFollowedClient,
             ScreenInstruction::WatcherTerminalResize(..) => ScreenContext::WatcherTerminalResize, // NEW
             ScreenInstruction::Notify(..) => ScreenContext::Notify, // Perth STORY-003
+            ScreenInstruction::SearchPane { .. } => ScreenContext::SearchPane,
+            ScreenInstruction::SearchSession { .. } => ScreenContext::SearchSession,
+            ScreenInstruction::GetSessionSearchResults { .. } => {
+                ScreenContext::GetSessionSearchResults
+            },
+            ScreenInstruction::JumpToSearchMatch { .. } => ScreenContext::JumpToSearchMatch,
+            ScreenInstruction::LogPane { .. } => ScreenContext::LogPane,
+            ScreenInstruction::RecordPane { .. } => ScreenContext::RecordPane,
+            ScreenInstruction::SubscribeToPaneOutput { .. } => ScreenContext::SubscribeToPaneOutput,
+            ScreenInstruction::UnsubscribeFromPaneOutput { .. } => {
+                ScreenContext::UnsubscribeFromPaneOutput
+            },
+            ScreenInstruction::ClearPaneOutputSubscriptions(..) => {
+                ScreenContext::ClearPaneOutputSubscriptions
+            },
+            ScreenInstruction::FlushPaneOutput => ScreenContext::FlushPaneOutput,
         }
     }
 }
@@ -943,6 +1032,9 @@ pub(crate) struct Screen {
     render_blocker: RenderBlocker,
     watcher_clients: HashMap<ClientId, WatcherState>,
     followed_client_id: Option<ClientId>,
+    following_clients: BTreeMap<ClientId, ClientId>, // following client id -> followed client id
+    client_names: HashMap<ClientId, String>,
+    pane_output_subscriptions: PaneOutputSubscriptions,
 }
 
 impl Screen {
@@ -1030,6 +1122,9 @@ impl Screen {
             render_blocker: RenderBlocker::new(100),
             watcher_clients: HashMap::new(),
             followed_client_id: None,
+            following_clients: BTreeMap::new(),
+            client_names: HashMap::new(),
+            pane_output_subscriptions: PaneOutputSubscriptions::default(),
         }
     }
 
@@ -1953,6 +2048,9 @@ impl Screen {
             }
         }
 
+        self.following_clients.remove(&client_id);
+        self.client_names.remove(&client_id);
+
         for (_, tab) in self.tabs.iter_mut() {
             tab.remove_client(client_id);
             if tab.has_no_connected_clients() {
@@ -2430,6 +2528,9 @@ impl Screen {
             }
         }
 
+        mode_info.following_client = self.following_clients.get(&client_id).copied();
+        mode_info.following_client_name = self.following_client_name(client_id);
+
         self.style = mode_info.style;
         self.mode_info.insert(client_id, mode_info.clone());
         for tab in self.tabs.values_mut() {
@@ -2640,6 +2741,279 @@ impl Screen {
         };
         Ok(())
     }
+    /// Mark the pane focused by this client, moving the mark from any other pane (in any tab)
+    /// that might have it
+    pub fn mark_focused_pane(&mut self, mark: String, client_id: ClientId) -> Result<()> {
+        let err_context = || format!("failed to mark focused pane of client {client_id}");
+        for tab in self.tabs.values_mut() {
+            tab.clear_mark(&mark);
+        }
+        self.get_active_tab_mut(client_id)
+            .with_context(err_context)?
+            .mark_active_pane(mark, client_id);
+        Ok(())
+    }
+    /// Focus the pane with the given mark, switching this client to its tab if needed
+    pub fn focus_mark(&mut self, mark: &str, client_id: ClientId) -> Result<()> {
+        let mut tabs: Vec<&Tab> = self.tabs.values().collect();
+        tabs.sort_by_key(|tab| tab.position);
+        match tabs.iter().find_map(|tab| tab.pane_id_with_mark(mark)) {
+            Some(pane_id) => {
+                let should_float_if_hidden = false;
+                let should_be_in_place_if_hidden = false;
+                self.focus_pane_with_id(
+                    pane_id,
+                    should_float_if_hidden,
+                    should_be_in_place_if_hidden,
+                    client_id,
+                )
+            },
+            None => {
+                log::error!("No pane is marked with: {}", mark);
+                Ok(())
+            },
+        }
+    }
+    /// Have this client mirror the tab and pane focus of another client until it stops following
+    /// (with its next keypress)
+    pub fn follow_client(
+        &mut self,
+        followed_client_id: ClientId,
+        client_id: ClientId,
+    ) -> Result<()> {
+        if followed_client_id == client_id {
+            log::error!("Client {} cannot follow itself", client_id);
+            return Ok(());
+        }
+        if !self
+            .connected_clients
+            .borrow()
+            .contains_key(&followed_client_id)
+        {
+            log::error!("No client with id: {}", followed_client_id);
+            return Ok(());
+        }
+        self.following_clients.insert(client_id, followed_client_id);
+        self.update_following_client_mode_info(client_id)?;
+        self.sync_following_clients()
+    }
+    pub fn stop_following(&mut self, client_id: ClientId) -> Result<()> {
+        if self.following_clients.remove(&client_id).is_some() {
+            self.update_following_client_mode_info(client_id)?;
+        }
+        Ok(())
+    }
+    /// The name a client is shown with to other clients, eg. the login token name of a web
+    /// client
+    pub fn set_client_name(&mut self, client_id: ClientId, client_name: String) {
+        self.client_names.insert(client_id, client_name);
+    }
+    fn following_client_name(&self, client_id: ClientId) -> Option<String> {
+        self.following_clients
+            .get(&client_id)
+            .and_then(|followed_client_id| self.client_names.get(followed_client_id))
+            .cloned()
+    }
+    pub fn has_following_clients(&self) -> bool {
+        !self.following_clients.is_empty()
+    }
+    /// Move every following client to the tab and pane focused by the client it follows, if it
+    /// is not already there. Followed clients that are gone are no longer followed
+    pub fn sync_following_clients(&mut self) -> Result<()> {
+        let err_context = || "failed to sync following clients";
+        let following_clients: Vec<(ClientId, ClientId)> = self
+            .following_clients
+            .iter()
+            .map(|(client_id, followed_client_id)| (*client_id, *followed_client_id))
+            .collect();
+        for (client_id, followed_client_id) in following_clients {
+            let followed_focus = self
+                .get_active_tab(followed_client_id)
+                .ok()
+                .map(|tab| (tab.position, tab.get_active_pane_id(followed_client_id)));
+            let Some((followed_tab_position, followed_pane_id)) = followed_focus else {
+                self.stop_following(client_id).with_context(err_context)?;
+                continue;
+            };
+            let tab_position = self
+                .get_active_tab(client_id)
+                .with_context(err_context)?
+                .position;
+            if tab_position != followed_tab_position {
+                self.go_to_tab(followed_tab_position + 1, client_id)
+                    .with_context(err_context)?;
+            }
+            if let Some(followed_pane_id) = followed_pane_id {
+                let active_tab = self
+                    .get_active_tab_mut(client_id)
+                    .with_context(err_context)?;
+                if active_tab.get_active_pane_id(client_id) != Some(followed_pane_id) {
+                    let should_float_if_hidden = false;
+                    let should_be_in_place_if_hidden = false;
+                    active_tab
+                        .focus_pane_with_id(
+                            followed_pane_id,
+                            should_float_if_hidden,
+                            should_be_in_place_if_hidden,
+                            client_id,
+                        )
+                        .with_context(err_context)?;
+                    self.render(None).with_context(err_context)?;
+                }
+            }
+        }
+        Ok(())
+    }
+    fn update_following_client_mode_info(&mut self, client_id: ClientId) -> Result<()> {
+        let mut mode_info = self
+            .mode_info
+            .get(&client_id)
+            .unwrap_or(&self.default_mode_info)
+            .clone();
+        mode_info.following_client = self.following_clients.get(&client_id).copied();
+        mode_info.following_client_name = self.following_client_name(client_id);
+        self.mode_info.insert(client_id, mode_info.clone());
+        for tab in self.tabs.values_mut() {
+            tab.change_mode_info(mode_info.clone(), client_id);
+            tab.update_input_modes()?;
+        }
+        Ok(())
+    }
+    /// Search the scrollback of every terminal pane in the session, ordered by tab position.
+    /// Panes without any matches are left out
+    pub fn search_session(&self, query: &SearchQuery) -> Result<Vec<PaneSearchResults>> {
+        let mut tabs: Vec<&Tab> = self.tabs.values().collect();
+        tabs.sort_by_key(|tab| tab.position);
+        let mut search_results = vec![];
+        for tab in tabs {
+            for pane_id in tab.get_all_pane_ids() {
+                if !matches!(pane_id, PaneId::Terminal(_)) {
+                    continue;
+                }
+                if let Some(pane) = tab.get_pane_with_id(pane_id) {
+                    let matches = pane.search_scrollback(query)?;
+                    if !matches.is_empty() {
+                        search_results.push(PaneSearchResults {
+                            tab_position: tab.position,
+                            tab_name: tab.name.clone(),
+                            pane_id: pane_id.into(),
+                            pane_title: pane.current_title(),
+                            matches,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(search_results)
+    }
+    /// Focus the pane (switching to its tab if needed) and scroll it so that the given line of
+    /// its scrollback is visible
+    pub fn jump_to_search_match(
+        &mut self,
+        pane_id: PaneId,
+        line: usize,
+        client_id: ClientId,
+    ) -> Result<()> {
+        self.focus_pane_with_id(pane_id, false, false, client_id)?;
+        for tab in self.tabs.values_mut() {
+            if let Some(pane) = tab.get_pane_with_id_mut(pane_id) {
+                pane.scroll_to_line(line);
+                break;
+            }
+        }
+        Ok(())
+    }
+    pub fn log_pane(&mut self, pane_id: PaneId, log_options: Option<PaneLogOptions>) -> Result<()> {
+        let pane = self
+            .tabs
+            .values_mut()
+            .find_map(|tab| tab.get_pane_with_id_mut(pane_id))
+            .ok_or_else(|| anyhow!("Pane {:?} not found", pane_id))?;
+        let output_log = match log_options {
+            Some(log_options) => Some(PaneOutputLog::new(log_options)?),
+            None => None,
+        };
+        pane.set_output_log(output_log)
+    }
+    pub fn record_pane(&mut self, pane_id: PaneId, out: Option<PathBuf>) -> Result<()> {
+        let pane = self
+            .tabs
+            .values_mut()
+            .find_map(|tab| tab.get_pane_with_id_mut(pane_id))
+            .ok_or_else(|| anyhow!("Pane {:?} not found", pane_id))?;
+        let recorder = match out {
+            Some(out) => Some(PaneRecorder::new(
+                out,
+                pane.get_content_columns(),
+                pane.get_content_rows(),
+                Some(pane.current_title()),
+            )?),
+            None => None,
+        };
+        pane.set_recorder(recorder)
+    }
+    pub fn subscribe_to_pane_output(
+        &mut self,
+        pane_id: PaneId,
+        format: PaneOutputFormat,
+        plugin_id: PluginId,
+        client_id: ClientId,
+    ) -> Result<()> {
+        match pane_id {
+            PaneId::Terminal(terminal_id)
+                if self
+                    .tabs
+                    .values()
+                    .any(|tab| tab.has_terminal_pid(terminal_id)) =>
+            {
+                self.pane_output_subscriptions
+                    .subscribe(terminal_id, plugin_id, client_id, format);
+                Ok(())
+            },
+            PaneId::Terminal(_) => Err(anyhow!("Pane {:?} not found", pane_id)),
+            PaneId::Plugin(_) => Err(anyhow!(
+                "Cannot subscribe to the output of plugin pane {:?}",
+                pane_id
+            )),
+        }
+    }
+    pub fn unsubscribe_from_pane_output(
+        &mut self,
+        pane_id: PaneId,
+        plugin_id: PluginId,
+        client_id: ClientId,
+    ) {
+        if let PaneId::Terminal(terminal_id) = pane_id {
+            self.pane_output_subscriptions
+                .unsubscribe(terminal_id, plugin_id, client_id);
+        }
+    }
+    /// Send the output buffered since the last flush to the subscribed plugins, dropping the
+    /// subscriptions of panes that have since been closed
+    pub fn flush_pane_output(&mut self) -> Result<()> {
+        let tabs = &self.tabs;
+        self.pane_output_subscriptions
+            .retain_panes(|terminal_id| tabs.values().any(|tab| tab.has_terminal_pid(terminal_id)));
+        let events: Vec<_> = self
+            .pane_output_subscriptions
+            .drain()
+            .into_iter()
+            .map(|(plugin_id, client_id, pane_id, output)| {
+                (
+                    Some(plugin_id),
+                    Some(client_id),
+                    Event::PaneOutput(pane_id, output),
+                )
+            })
+            .collect();
+        if !events.is_empty() {
+            self.bus
+                .senders
+                .send_to_plugin(PluginInstruction::Update(events))
+                .with_context(|| "failed to send pane output to plugins")?;
+        }
+        Ok(())
+    }
     pub fn rerun_command_pane_with_id(
         &mut self,
         terminal_pane_id: u32,
@@ -3438,6 +3812,7 @@ impl Screen {
                             None
                         },
                         focused_clients,
+                        p.mark().map(|mark| mark.to_owned()),
                     )
                 })
                 .collect();
@@ -3476,6 +3851,7 @@ impl Screen {
                             None
                         },
                         focused_clients,
+                        p.mark().map(|mark| mark.to_owned()),
                     )
                 })
                 .collect();
@@ -3483,6 +3859,7 @@ impl Screen {
                 tab.name.clone(),
                 tab_is_focused,
                 hide_floating_panes,
+                tab.tiling_mode(),
                 tiled_panes,
                 floating_panes,
             );
@@ -3814,6 +4191,12 @@ pub(crate) fn screen_thread_main(
 
         match event {
             ScreenInstruction::PtyBytes(pid, vte_bytes) => {
+                if screen.pane_output_subscriptions.push(pid, &vte_bytes) {
+                    let _ = screen
+                        .bus
+                        .senders
+                        .send_to_background_jobs(BackgroundJob::FlushPaneOutput);
+                }
                 let all_tabs = screen.get_tabs_mut();
                 for tab in all_tabs.values_mut() {
                     if tab.has_terminal_pid(pid) {
@@ -4247,6 +4630,7 @@ pub(crate) fn screen_thread_main(
                 file,
                 client_id,
                 full,
+                format,
                 _completion_tx, // the action ends here, dropping this will release anything
                                 // waiting for it
             ) => {
@@ -4256,7 +4640,8 @@ pub(crate) fn screen_thread_main(
                     |tab: &mut Tab, client_id: ClientId| tab.dump_active_terminal_screen(
                         Some(file.to_string()),
                         client_id,
-                        full
+                        full,
+                        format
                     ),
                     ?
                 );
@@ -4706,6 +5091,21 @@ pub(crate) fn screen_thread_main(
                 }
                 screen.log_and_report_session_state()?;
             },
+            ScreenInstruction::CheckPaneDependencies => {
+                for tab in screen.tabs.values_mut() {
+                    tab.update_pane_dependencies();
+                }
+                screen.render(None)?;
+            },
+            ScreenInstruction::UpdatePaneRestarts(pane_id, restarts, max_restarts) => {
+                for tab in screen.tabs.values_mut() {
+                    if let Some(pane) = tab.get_pane_with_id_mut(pane_id) {
+                        pane.set_restarts(restarts, max_restarts);
+                        break;
+                    }
+                }
+                screen.render(None)?;
+            },
             ScreenInstruction::UpdatePaneName(
                 c,
                 client_id,
@@ -4732,6 +5132,39 @@ pub(crate) fn screen_thread_main(
                 );
                 screen.render(None)?;
             },
+            ScreenInstruction::MarkPane(
+                name,
+                client_id,
+                _completion_tx, // the action ends here, dropping this will release anything
+                                // waiting for it
+            ) => {
+                screen.mark_focused_pane(name, client_id)?;
+                screen.render(None)?;
+                screen.log_and_report_session_state()?;
+            },
+            ScreenInstruction::FocusMark(
+                name,
+                client_id,
+                _completion_tx, // the action ends here, dropping this will release anything
+                                // waiting for it
+            ) => {
+                screen.focus_mark(&name, client_id)?;
+                screen.render(None)?;
+                screen.log_and_report_session_state()?;
+            },
+            ScreenInstruction::Follow(
+                followed_client_id,
+                client_id,
+                _completion_tx, // the action ends here, dropping this will release anything
+                                // waiting for it
+            ) => {
+                screen.follow_client(followed_client_id, client_id)?;
+                screen.render(None)?;
+                screen.log_and_report_session_state()?;
+            },
+            ScreenInstruction::StopFollowing(client_id) => {
+                screen.stop_following(client_id)?;
+            },
             ScreenInstruction::ToggleActiveTerminalFullscreen(
                 client_id,
                 _completion_tx, // the action ends here, dropping this will release anything
@@ -5151,6 +5584,9 @@ pub(crate) fn screen_thread_main(
                 screen.log_and_report_session_state()?;
                 screen.render(None)?;
             },
+            ScreenInstruction::SetClientName(client_id, client_name) => {
+                screen.set_client_name(client_id, client_name);
+            },
             ScreenInstruction::UpdateSearch(
                 c,
                 client_id,
@@ -5225,6 +5661,18 @@ pub(crate) fn screen_thread_main(
                 );
                 screen.render(None)?;
             },
+            ScreenInstruction::SearchToggleRegex(
+                client_id,
+                _completion_tx, // the action ends here, dropping this will release anything
+                                // waiting for it
+            ) => {
+                active_tab_and_connected_client_id!(
+                    screen,
+                    client_id,
+                    |tab: &mut Tab, client_id: ClientId| tab.toggle_search_regex(client_id)
+                );
+                screen.render(None)?;
+            },
             ScreenInstruction::AddRedPaneFrameColorOverride(pane_ids, error_text) => {
                 let all_tabs = screen.get_tabs_mut();
                 for pane_id in pane_ids {
@@ -5293,6 +5741,21 @@ pub(crate) fn screen_thread_main(
                 screen.render(None)?;
                 screen.log_and_report_session_state()?;
             },
+            ScreenInstruction::SetTilingMode(
+                tiling_mode,
+                client_id,
+                _completion_tx, // the action ends here, dropping this will release anything
+                                // waiting for it
+            ) => {
+                active_tab_and_connected_client_id!(
+                    screen,
+                    client_id,
+                    |tab: &mut Tab, _client_id: ClientId| tab.set_tiling_mode(tiling_mode),
+                    ?
+                );
+                screen.render(None)?;
+                screen.log_and_report_session_state()?;
+            },
             ScreenInstruction::OverrideLayout(
                 cwd,
                 default_shell,
@@ -6725,6 +7188,149 @@ pub(crate) fn screen_thread_main(
                 screen.set_pane_notification(pane_id, notification)?;
                 screen.render(None)?;
             },
+            ScreenInstruction::SearchPane {
+                pane_id,
+                query,
+                json,
+                client_id,
+                completion_tx,
+            } => {
+                let search_results = screen
+                    .get_tabs()
+                    .values()
+                    .find_map(|tab| tab.get_pane_with_id(pane_id))
+                    .ok_or_else(|| format!("Pane {:?} not found", pane_id))
+                    .and_then(|pane| {
+                        pane.search_scrollback(&query)
+                            .map_err(|e| format!("{:#}", e))
+                    });
+                let instruction = match search_results {
+                    Ok(search_matches) if json => ServerInstruction::Log(
+                        vec![serde_json::to_string(&search_matches).unwrap_or_default()],
+                        client_id,
+                        completion_tx,
+                    ),
+                    Ok(search_matches) => ServerInstruction::Log(
+                        search_matches
+                            .iter()
+                            .map(|m| format!("{}:{}: {}", m.line, m.start_column, m.text))
+                            .collect(),
+                        client_id,
+                        completion_tx,
+                    ),
+                    Err(e) => ServerInstruction::LogError(vec![e], client_id, completion_tx),
+                };
+                screen.bus.senders.send_to_server(instruction)?;
+            },
+            ScreenInstruction::SearchSession {
+                query,
+                client_id,
+                completion_tx,
+            } => {
+                let instruction = match screen.search_session(&query) {
+                    Ok(search_results) => ServerInstruction::Log(
+                        vec![serde_json::to_string(&search_results).unwrap_or_default()],
+                        client_id,
+                        completion_tx,
+                    ),
+                    Err(e) => ServerInstruction::LogError(
+                        vec![format!("{:#}", e)],
+                        client_id,
+                        completion_tx,
+                    ),
+                };
+                screen.bus.senders.send_to_server(instruction)?;
+            },
+            ScreenInstruction::GetSessionSearchResults {
+                query,
+                response_channel,
+            } => {
+                let response = match screen.search_session(&query) {
+                    Ok(search_results) => SessionSearchResponse::Ok(search_results),
+                    Err(e) => SessionSearchResponse::Err(format!("{:#}", e)),
+                };
+                if response_channel.send(response).is_err() {
+                    // the plugin likely timed out and dropped the receiver
+                    log::debug!("Plugin timed out before session search response was sent");
+                }
+            },
+            ScreenInstruction::JumpToSearchMatch {
+                pane_id,
+                line,
+                client_id,
+                completion_tx: _completion_tx, // the action ends here, dropping this will release anything
+                                               // waiting for it
+            } => {
+                screen.jump_to_search_match(pane_id, line, client_id)?;
+                screen.render(None)?;
+                screen.log_and_report_session_state()?;
+            },
+            ScreenInstruction::LogPane {
+                pane_id,
+                log_options,
+                client_id,
+                completion_tx,
+            } => {
+                if let Err(e) = screen.log_pane(pane_id, log_options) {
+                    screen
+                        .bus
+                        .senders
+                        .send_to_server(ServerInstruction::LogError(
+                            vec![format!("{:#}", e)],
+                            client_id,
+                            completion_tx,
+                        ))?;
+                }
+            },
+            ScreenInstruction::RecordPane {
+                pane_id,
+                out,
+                client_id,
+                completion_tx,
+            } => {
+                if let Err(e) = screen.record_pane(pane_id, out) {
+                    screen
+                        .bus
+                        .senders
+                        .send_to_server(ServerInstruction::LogError(
+                            vec![format!("{:#}", e)],
+                            client_id,
+                            completion_tx,
+                        ))?;
+                }
+            },
+            ScreenInstruction::SubscribeToPaneOutput {
+                pane_id,
+                format,
+                plugin_id,
+                client_id,
+            } => {
+                screen
+                    .subscribe_to_pane_output(pane_id, format, plugin_id, client_id)
+                    .with_context(|| {
+                        format!("plugin {} failed to subscribe to pane output", plugin_id)
+                    })
+                    .non_fatal();
+            },
+            ScreenInstruction::UnsubscribeFromPaneOutput {
+                pane_id,
+                plugin_id,
+                client_id,
+            } => {
+                screen.unsubscribe_from_pane_output(pane_id, plugin_id, client_id);
+            },
+            ScreenInstruction::ClearPaneOutputSubscriptions(plugin_id, client_id) => {
+                screen
+                    .pane_output_subscriptions
+                    .unsubscribe_plugin(plugin_id, client_id);
+            },
+            ScreenInstruction::FlushPaneOutput => {
+                screen.flush_pane_output()?;
+            },
+        }
+        // any of the above might have changed the focus of a followed client
+        if screen.has_following_clients() {
+            screen.sync_following_clients().non_fatal();
         }
     }
     Ok(())
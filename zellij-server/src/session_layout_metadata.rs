@@ -6,7 +6,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use zellij_utils::common_path::common_path_all;
 use zellij_utils::pane_size::PaneGeom;
 use zellij_utils::{
-    data::{LayoutMetadata, PaneMetadata, TabMetadata},
+    data::{LayoutMetadata, PaneMetadata, TabMetadata, TilingMode},
     input::command::RunCommand,
     input::layout::{Layout, Run, RunPlugin, RunPluginOrAlias},
     input::plugins::PluginAliases,
@@ -200,6 +200,7 @@ impl SessionLayoutMetadata {
         name: String,
         is_focused: bool,
         hide_floating_panes: bool,
+        tiling_mode: TilingMode,
         tiled_panes: Vec<PaneLayoutMetadata>,
         floating_panes: Vec<PaneLayoutMetadata>,
     ) {
@@ -207,6 +208,7 @@ impl SessionLayoutMetadata {
             name: Some(name),
             is_focused,
             hide_floating_panes,
+            tiling_mode,
             tiled_panes,
             floating_panes,
         })
@@ -280,6 +282,14 @@ impl SessionLayoutMetadata {
                         } else {
                             let mut run_command = RunCommand::new(PathBuf::from(command_name));
                             run_command.args = args;
+                            // the discovered command line does not include the environment or
+                            // restart policy the pane was started with, so we carry them over
+                            // from how it was invoked
+                            if let Some(Run::Command(invoked_with)) = &pane_layout_metadata.run {
+                                run_command.env = invoked_with.env.clone();
+                                run_command.restart = invoked_with.restart;
+                                run_command.max_restarts = invoked_with.max_restarts;
+                            }
                             pane_layout_metadata.run = Some(Run::Command(run_command));
                         }
                     }
@@ -367,6 +377,7 @@ impl SessionLayoutMetadata {
             tabs,
             creation_time: current_time.clone(),
             update_time: current_time,
+            params: vec![],
         }
     }
 }
@@ -393,6 +404,7 @@ impl Into<TabLayoutManifest> for TabLayoutMetadata {
             floating_panes: self.floating_panes.into_iter().map(|t| t.into()).collect(),
             is_focused: self.is_focused,
             hide_floating_panes: self.hide_floating_panes,
+            tiling_mode: self.tiling_mode,
         }
     }
 }
@@ -428,6 +440,7 @@ impl Into<PaneLayoutManifest> for PaneLayoutMetadata {
             title: self.title,
             is_focused: self.is_focused,
             pane_contents: self.pane_contents,
+            mark: self.mark,
         }
     }
 }
@@ -439,6 +452,7 @@ pub struct TabLayoutMetadata {
     floating_panes: Vec<PaneLayoutMetadata>,
     is_focused: bool,
     hide_floating_panes: bool,
+    tiling_mode: TilingMode,
 }
 
 #[derive(Debug, Clone)]
@@ -452,6 +466,7 @@ pub struct PaneLayoutMetadata {
     is_focused: bool,
     pane_contents: Option<String>,
     focused_clients: Vec<ClientId>,
+    mark: Option<String>,
 }
 
 impl PaneLayoutMetadata {
@@ -464,6 +479,7 @@ impl PaneLayoutMetadata {
         is_focused: bool,
         pane_contents: Option<String>,
         focused_clients: Vec<ClientId>,
+        mark: Option<String>,
     ) -> Self {
         PaneLayoutMetadata {
             id,
@@ -475,6 +491,7 @@ impl PaneLayoutMetadata {
             is_focused,
             pane_contents,
             focused_clients,
+            mark,
         }
     }
     fn to_pane_metadata(&self) -> PaneMetadata {
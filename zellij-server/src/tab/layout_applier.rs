@@ -7,7 +7,7 @@ use crate::{
     os_input_output::ServerOsApi,
     panes::sixel::SixelImageStore,
     panes::{FloatingPanes, TiledPanes},
-    panes::{LinkHandler, PaneId, PluginPane, TerminalPane},
+    panes::{LinkHandler, PaneId, PaneOutputLog, PluginPane, TerminalPane},
     plugins::PluginInstruction,
     pty::PtyInstruction,
     thread_bus::ThreadSenders,
@@ -15,9 +15,10 @@ use crate::{
 };
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::rc::Rc;
 use zellij_utils::{
-    data::{Palette, Style},
+    data::{Palette, PaneLogOptions, Style},
     input::layout::{FloatingPaneLayout, Run, RunPluginOrAlias, TiledPaneLayout},
     pane_size::{Offset, PaneGeom, Size, SizeInPixels, Viewport},
 };
@@ -548,6 +549,7 @@ impl<'a> LayoutApplier<'a> {
         if let Some(exclude_from_sync) = layout.exclude_from_sync {
             new_plugin.set_exclude_from_sync(exclude_from_sync);
         }
+        new_plugin.set_mark(layout.mark.clone());
         self.tiled_panes
             .add_pane_with_existing_geom(PaneId::Plugin(pid), Box::new(new_plugin));
         Ok(pid)
@@ -597,6 +599,7 @@ impl<'a> LayoutApplier<'a> {
         } else {
             new_pane.set_borderless(false);
         }
+        new_pane.set_mark(floating_pane_layout.mark.clone());
         resize_pty!(
             new_pane,
             self.os_api,
@@ -648,13 +651,18 @@ impl<'a> LayoutApplier<'a> {
             new_pane.handle_pty_bytes(pane_initial_contents.as_bytes().into());
             new_pane.handle_pty_bytes("\n\r".as_bytes().into());
         }
+        if let Some(log_to) = &floating_pane_layout.log_to {
+            start_output_log(&mut new_pane, log_to, &floating_pane_layout.run);
+        }
         if floating_pane_layout.borderless.unwrap_or(false) {
             new_pane.set_borderless(true);
         } else {
             new_pane.set_borderless(false);
         }
+        new_pane.set_mark(floating_pane_layout.mark.clone());
         if let Some(held_command) = hold_for_command {
             new_pane.hold(None, true, held_command.clone());
+            new_pane.set_pending_dependencies(floating_pane_layout.depends_on.clone());
         }
         resize_pty!(
             new_pane,
@@ -718,12 +726,17 @@ impl<'a> LayoutApplier<'a> {
             new_pane.handle_pty_bytes(pane_initial_contents.as_bytes().into());
             new_pane.handle_pty_bytes("\n\r".as_bytes().into());
         }
+        if let Some(log_to) = &layout.log_to {
+            start_output_log(&mut new_pane, log_to, &layout.run);
+        }
         new_pane.set_borderless(layout.borderless.unwrap_or(false));
         if let Some(exclude_from_sync) = layout.exclude_from_sync {
             new_pane.set_exclude_from_sync(exclude_from_sync);
         }
+        new_pane.set_mark(layout.mark.clone());
         if let Some(held_command) = hold_for_command {
             new_pane.hold(None, true, held_command.clone());
+            new_pane.set_pending_dependencies(layout.depends_on.clone());
         }
         self.tiled_panes
             .add_pane_with_existing_geom(PaneId::Terminal(pid), Box::new(new_pane));
@@ -1396,3 +1409,19 @@ impl<'a> PaneApplier<'a> {
         }
     }
 }
+
+fn start_output_log(pane: &mut TerminalPane, log_to: &PathBuf, run: &Option<Run>) {
+    // relative paths are relative to the pane's cwd, same as the command it runs
+    let path = match run.as_ref().and_then(|r| r.get_cwd()) {
+        Some(cwd) if log_to.is_relative() => cwd.join(log_to),
+        _ => log_to.clone(),
+    };
+    match PaneOutputLog::new(PaneLogOptions::new(path)) {
+        Ok(output_log) => {
+            pane.set_output_log(Some(output_log)).non_fatal();
+        },
+        Err(e) => {
+            log::error!("Failed to log pane output to {}: {:?}", log_to.display(), e);
+        },
+    }
+}
@@ -4,17 +4,19 @@
 mod clipboard;
 mod copy_command;
 mod layout_applier;
+mod pane_dependencies;
 mod swap_layouts;
 
 use copy_command::CopyCommand;
+use pane_dependencies::PaneDependencies;
 use std::env::temp_dir;
 use std::net::IpAddr;
 use std::path::PathBuf;
 use uuid::Uuid;
-use zellij_utils::data::PaneContents;
+use zellij_utils::data::{DumpScreenFormat, PaneContents};
 use zellij_utils::data::{
     Direction, KeyWithModifier, NewPanePlacement, PaneInfo, PermissionStatus, PermissionType,
-    PluginPermission, ResizeStrategy, WebSharing,
+    PluginPermission, ResizeStrategy, SearchMatch, SearchQuery, TilingMode, WebSharing,
 };
 use zellij_utils::errors::prelude::*;
 use zellij_utils::input::command::RunCommand;
@@ -39,7 +41,7 @@ use crate::{
     panes::floating_panes::floating_pane_grid::half_size_middle_geom,
     panes::sixel::SixelImageStore,
     panes::{FloatingPanes, TiledPanes},
-    panes::{LinkHandler, PaneId, PluginPane, TerminalPane},
+    panes::{LinkHandler, PaneId, PaneOutputLog, PaneRecorder, PluginPane, TerminalPane},
     plugins::PluginInstruction,
     pty::{ClientTabIndexOrPaneId, PtyInstruction, VteBytes},
     thread_bus::ThreadSenders,
@@ -59,8 +61,8 @@ use zellij_utils::{
     input::{
         command::TerminalAction,
         layout::{
-            FloatingPaneLayout, Run, RunPluginOrAlias, SwapFloatingLayout, SwapTiledLayout,
-            TiledPaneLayout,
+            FloatingPaneLayout, PaneDependency, Run, RunPluginOrAlias, SwapFloatingLayout,
+            SwapTiledLayout, TiledPaneLayout,
         },
         parse_keys,
     },
@@ -251,6 +253,7 @@ pub(crate) struct Tab {
     terminal_emulator_colors: Rc<RefCell<Palette>>,
     terminal_emulator_color_codes: Rc<RefCell<HashMap<usize, String>>>,
     pids_waiting_resize: HashSet<u32>, // u32 is the terminal_id
+    pane_dependencies: PaneDependencies,
     cursor_positions_and_shape: HashMap<ClientId, (usize, usize, String)>, // (x_position,
     // y_position,
     // cursor_shape_csi)
@@ -258,6 +261,7 @@ pub(crate) struct Tab {
     pending_instructions: Vec<BufferedTabInstruction>, // instructions that came while the tab was
     // pending and need to be re-applied
     swap_layouts: SwapLayouts,
+    tiling_mode: TilingMode,
     default_shell: PathBuf,
     default_editor: Option<PathBuf>,
     debug: bool,
@@ -346,6 +350,14 @@ pub trait Pane {
     fn dump_screen(&self, _full: bool, _client_id: Option<ClientId>) -> String {
         "".to_owned()
     }
+    fn dump_styled_screen(
+        &self,
+        full: bool,
+        _format: DumpScreenFormat,
+        client_id: Option<ClientId>,
+    ) -> String {
+        self.dump_screen(full, client_id)
+    }
     fn scroll_up(&mut self, count: usize, client_id: ClientId);
     fn scroll_down(&mut self, count: usize, client_id: ClientId);
     fn clear_scroll(&mut self);
@@ -509,6 +521,8 @@ pub trait Pane {
     fn clear_notification(&mut self);
     fn set_exclude_from_sync(&mut self, exclude_from_sync: bool);
     fn exclude_from_sync(&self) -> bool;
+    fn set_mark(&mut self, mark: Option<String>);
+    fn mark(&self) -> Option<&str>;
 
     // TODO: this should probably be merged with the mouse_right_click
     fn handle_right_click(&mut self, _to: &Position, _client_id: ClientId) {}
@@ -566,9 +580,40 @@ pub trait Pane {
     fn toggle_search_wrap(&mut self) {
         // No-op by default (only terminal-panes currently have search capability)
     }
+    fn toggle_search_regex(&mut self) {
+        // No-op by default (only terminal-panes currently have search capability)
+    }
+    fn search_scrollback(&self, _query: &SearchQuery) -> Result<Vec<SearchMatch>> {
+        // Nothing to find by default (only terminal-panes currently have search capability)
+        Ok(vec![])
+    }
+    fn scroll_to_line(&mut self, _line: usize) {
+        // No-op by default (only terminal-panes currently have search capability)
+    }
     fn clear_search(&mut self) {
         // No-op by default (only terminal-panes currently have search capability)
     }
+    fn set_output_log(&mut self, _output_log: Option<PaneOutputLog>) -> Result<()> {
+        Err(anyhow!("only terminal panes can be logged"))
+    }
+    fn output_log_path(&self) -> Option<PathBuf> {
+        None
+    }
+    fn set_recorder(&mut self, _recorder: Option<PaneRecorder>) -> Result<()> {
+        Err(anyhow!("only terminal panes can be recorded"))
+    }
+    fn recording_path(&self) -> Option<PathBuf> {
+        None
+    }
+    fn set_pending_dependencies(&mut self, _pending_dependencies: Vec<PaneDependency>) {
+        // No-op by default, only terminal panes can wait for other panes
+    }
+    fn pending_dependencies(&self) -> &[PaneDependency] {
+        &[]
+    }
+    fn set_restarts(&mut self, _restarts: usize, _max_restarts: usize) {
+        // No-op by default, only command panes are restarted
+    }
     fn is_alternate_mode_active(&self) -> bool {
         // False by default (only terminal-panes support alternate mode)
         false
@@ -638,6 +683,7 @@ pub enum AdjustedInput {
     CloseThisPane,
     DropToShellInThisPane { working_dir: Option<PathBuf> },
     WriteKeyToPlugin(KeyWithModifier),
+    ReloadPlugin,
 }
 pub fn get_next_terminal_position(
     tiled_panes: &TiledPanes,
@@ -783,10 +829,12 @@ impl Tab {
             terminal_emulator_colors,
             terminal_emulator_color_codes,
             pids_waiting_resize: HashSet::new(),
+            pane_dependencies: PaneDependencies::default(),
             cursor_positions_and_shape: HashMap::new(),
             is_pending: true, // will be switched to false once the layout is applied
             pending_instructions: vec![],
             swap_layouts,
+            tiling_mode: TilingMode::default(),
             default_shell,
             debug,
             arrow_fonts,
@@ -818,6 +866,7 @@ impl Tab {
     ) -> Result<()> {
         self.swap_layouts
             .set_base_layout((layout.clone(), floating_panes_layout.clone()));
+        let tiling_mode = layout.tiling_mode;
         match LayoutApplier::new(
             &self.viewport,
             &self.senders,
@@ -859,6 +908,10 @@ impl Tab {
                 }
                 self.tiled_panes.reapply_pane_frames();
                 self.is_pending = false;
+                if let Some(tiling_mode) = tiling_mode {
+                    self.set_tiling_mode(tiling_mode).non_fatal();
+                }
+                self.update_pane_dependencies();
                 self.apply_buffered_instructions().non_fatal();
             },
             Err(e) => {
@@ -958,6 +1011,7 @@ impl Tab {
                     self.draw_pane_frames,
                 );
 
+                self.update_pane_dependencies();
                 self.apply_buffered_instructions().non_fatal();
             },
             Err(e) => {
@@ -973,6 +1027,9 @@ impl Tab {
     pub fn swap_layout_info(&self) -> (Option<String>, bool) {
         if self.floating_panes.panes_are_visible() {
             self.swap_layouts.floating_layout_info()
+        } else if !self.tiling_mode.is_manual() {
+            // the tiling mode takes over from the swap layouts until it is set back to manual
+            (Some(self.tiling_mode.to_string().to_uppercase()), false)
         } else {
             let selectable_tiled_panes =
                 self.tiled_panes.get_panes().filter(|(_, p)| p.selectable());
@@ -1079,6 +1136,34 @@ impl Tab {
         }
         Ok(())
     }
+    pub fn tiling_mode(&self) -> TilingMode {
+        self.tiling_mode
+    }
+    pub fn set_tiling_mode(&mut self, tiling_mode: TilingMode) -> Result<()> {
+        self.tiling_mode = tiling_mode;
+        if !tiling_mode.is_manual() {
+            // so that the swap layouts will not rearrange the panes behind our back
+            self.swap_layouts.set_is_tiled_damaged();
+        }
+        self.rebalance_tiled_panes()
+    }
+    fn rebalance_tiled_panes(&mut self) -> Result<()> {
+        if self.tiling_mode.is_manual() {
+            return Ok(());
+        }
+        if self.tiled_panes.fullscreen_is_active() {
+            self.tiled_panes.unset_fullscreen();
+        }
+        self.tiled_panes.rebalance(self.tiling_mode).non_fatal();
+        let display_area = *self.display_area.borrow();
+        // we do this so that the new arrangement has a chance to pass through the constraint system
+        self.tiled_panes.resize(display_area);
+        self.set_should_clear_display_before_rendering();
+        self.senders
+            .send_to_pty_writer(PtyWriteInstruction::ApplyCachedResizes)
+            .with_context(|| "failed to apply cached resizes")?;
+        Ok(())
+    }
     pub fn next_swap_layout(&mut self) -> Result<()> {
         let search_backwards = false;
         if self.floating_panes.panes_are_visible() {
@@ -2311,6 +2396,7 @@ impl Tab {
                 self.set_should_clear_display_before_rendering();
                 self.tiled_panes.focus_pane(pid, client_id);
                 self.swap_layouts.set_is_tiled_damaged();
+                self.rebalance_tiled_panes().with_context(err_context)?;
             }
         } else {
             log::error!("No room to split pane horizontally");
@@ -2378,6 +2464,7 @@ impl Tab {
                 self.set_should_clear_display_before_rendering();
                 self.tiled_panes.focus_pane(pid, client_id);
                 self.swap_layouts.set_is_tiled_damaged();
+                self.rebalance_tiled_panes().with_context(err_context)?;
             }
         } else {
             log::error!("No room to split pane vertically");
@@ -2573,6 +2660,7 @@ impl Tab {
                 )
                 .with_context(err_context)?;
             }
+            let dependency_became_ready = self.pane_dependencies.handle_pty_bytes(pid, &bytes);
             terminal_output.handle_pty_bytes(bytes);
             let messages_to_pty = terminal_output.drain_messages_to_pty();
             let clipboard_update = terminal_output.drain_clipboard_update();
@@ -2584,6 +2672,9 @@ impl Tab {
                 self.write_selection_to_clipboard(&string)
                     .with_context(err_context)?;
             }
+            if dependency_became_ready {
+                self.update_pane_dependencies();
+            }
         }
         Ok(())
     }
@@ -2812,6 +2903,11 @@ impl Tab {
                         .with_context(err_context)?;
                     should_update_ui = true;
                 },
+                Some(AdjustedInput::ReloadPlugin) => {
+                    self.senders
+                        .send_to_plugin(PluginInstruction::ReloadPluginWithId(pid))
+                        .with_context(err_context)?;
+                },
                 Some(_) => {},
                 None => {},
             },
@@ -3643,7 +3739,9 @@ impl Tab {
             let closed_pane = self.tiled_panes.remove_pane(id);
             self.set_force_render();
             self.tiled_panes.set_force_render();
-            if self.auto_layout && !self.swap_layouts.is_tiled_damaged() {
+            if !self.tiling_mode.is_manual() {
+                self.rebalance_tiled_panes().non_fatal();
+            } else if self.auto_layout && !self.swap_layouts.is_tiled_damaged() {
                 self.swap_layouts.set_is_tiled_damaged();
                 // only relayout if the user is already "in" a layout, otherwise this might be
                 // confusing
@@ -3653,6 +3751,12 @@ impl Tab {
         };
         if let Some(exit_status) = exit_status {
             if let Some(mut closed_pane) = closed_pane {
+                if exit_status == 0 {
+                    if let Some(pane_name) = closed_pane.custom_title() {
+                        self.pane_dependencies.pane_exited_successfully(pane_name);
+                        self.update_pane_dependencies();
+                    }
+                }
                 // in case we need to update on Drop
                 closed_pane.update_exit_status(exit_status);
             }
@@ -3766,6 +3870,50 @@ impl Tab {
         } else if let Some(pane) = self.suppressed_panes.values_mut().find(|p| p.1.pid() == id) {
             pane.1.hold(exit_status, is_first_run, run_command);
         }
+        if exit_status == Some(0) && !is_first_run {
+            if let Some(pane_name) = self.get_pane_with_id(id).and_then(|p| p.custom_title()) {
+                self.pane_dependencies.pane_exited_successfully(pane_name);
+                self.update_pane_dependencies();
+            }
+        }
+    }
+    /// Runs the held command panes whose layout dependencies (`depends_on`) are all ready and
+    /// starts watching the ones that are not
+    pub fn update_pane_dependencies(&mut self) {
+        let mut named_terminal_panes = HashMap::new();
+        let mut waiting_panes = vec![];
+        let all_panes = self
+            .tiled_panes
+            .get_panes()
+            .chain(self.floating_panes.get_panes())
+            .chain(
+                self.suppressed_panes
+                    .iter()
+                    .map(|(id, (_, pane))| (id, pane)),
+            );
+        for (pane_id, pane) in all_panes {
+            if let PaneId::Terminal(terminal_id) = pane_id {
+                if let Some(pane_name) = pane.custom_title() {
+                    named_terminal_panes.insert(pane_name, *terminal_id);
+                }
+                if !pane.pending_dependencies().is_empty() {
+                    waiting_panes.push((*terminal_id, pane.pending_dependencies().to_vec()));
+                }
+            }
+        }
+        let mut all_pending_dependencies = vec![];
+        for (terminal_id, dependencies) in waiting_panes {
+            let pending_dependencies =
+                self.pane_dependencies
+                    .pending(&dependencies, &named_terminal_panes, &self.senders);
+            if pending_dependencies.is_empty() {
+                self.rerun_terminal_pane_with_id(terminal_id, None);
+            } else if let Some(pane) = self.get_pane_with_id_mut(PaneId::Terminal(terminal_id)) {
+                pane.set_pending_dependencies(pending_dependencies.clone());
+                all_pending_dependencies.extend(pending_dependencies);
+            }
+        }
+        self.pane_dependencies.retain(&all_pending_dependencies);
     }
     pub fn replace_pane_with_suppressed_pane(
         &mut self,
@@ -3860,12 +4008,13 @@ impl Tab {
         file: Option<String>,
         client_id: ClientId,
         full: bool,
+        format: DumpScreenFormat,
     ) -> Result<()> {
         let err_context =
             || format!("failed to dump active terminal screen for client {client_id}");
 
         if let Some(active_pane) = self.get_active_pane_or_floating_pane_mut(client_id) {
-            let dump = active_pane.dump_screen(full, Some(client_id));
+            let dump = active_pane.dump_styled_screen(full, format, Some(client_id));
             self.os_api
                 .write_to_file(dump, file)
                 .with_context(err_context)?;
@@ -3897,6 +4046,7 @@ impl Tab {
             Some(String::from(file.to_string_lossy())),
             client_id,
             true,
+            DumpScreenFormat::Text,
         )
         .with_context(err_context)?;
         let line_number = self
@@ -5000,6 +5150,30 @@ impl Tab {
         Ok(())
     }
 
+    pub fn mark_active_pane(&mut self, mark: String, client_id: ClientId) {
+        if let Some(active_pane) = self.get_active_pane_or_floating_pane_mut(client_id) {
+            active_pane.set_mark(Some(mark));
+        }
+    }
+
+    pub fn clear_mark(&mut self, mark: &str) {
+        for pane_id in self.get_all_pane_ids() {
+            if let Some(pane) = self.get_pane_with_id_mut(pane_id) {
+                if pane.mark() == Some(mark) {
+                    pane.set_mark(None);
+                }
+            }
+        }
+    }
+
+    pub fn pane_id_with_mark(&self, mark: &str) -> Option<PaneId> {
+        self.get_all_pane_ids().into_iter().find(|pane_id| {
+            self.get_pane_with_id(*pane_id)
+                .map(|pane| pane.mark() == Some(mark))
+                .unwrap_or(false)
+        })
+    }
+
     pub fn is_position_inside_viewport(&self, point: &Position) -> Result<bool> {
         let Position {
             line: Line(line),
@@ -5075,6 +5249,12 @@ impl Tab {
         }
     }
 
+    pub fn toggle_search_regex(&mut self, client_id: ClientId) {
+        if let Some(active_pane) = self.get_active_pane_or_floating_pane_mut(client_id) {
+            active_pane.toggle_search_regex();
+        }
+    }
+
     pub fn clear_search(&mut self, client_id: ClientId) {
         if let Some(active_pane) = self.get_active_pane_or_floating_pane_mut(client_id) {
             active_pane.clear_search();
@@ -5494,13 +5674,16 @@ impl Tab {
         if self.tiled_panes.fullscreen_is_active() {
             self.tiled_panes.unset_fullscreen();
         }
-        let should_auto_layout =
-            self.auto_layout && !self.swap_layouts.is_tiled_damaged() && !without_relayout;
+        let should_rebalance = !self.tiling_mode.is_manual() && !without_relayout;
+        let should_auto_layout = self.auto_layout
+            && !self.swap_layouts.is_tiled_damaged()
+            && !without_relayout
+            && !should_rebalance;
         if self.tiled_panes.has_room_for_new_pane() {
             pane.set_active_at(Instant::now());
-            if should_auto_layout {
-                // no need to relayout here, we'll do it when reapplying the swap layout
-                // below
+            if should_auto_layout || should_rebalance {
+                // no need to relayout here, we'll do it when reapplying the swap layout or
+                // rebalancing below
                 self.tiled_panes
                     .insert_pane_without_relayout(pane_id, pane, client_id);
             } else {
@@ -5517,6 +5700,8 @@ impl Tab {
             self.swap_layouts.set_is_tiled_damaged(); // we do this so that we won't skip to the
                                                       // next layout
             self.relayout_tiled_panes(false)?;
+        } else if should_rebalance {
+            self.rebalance_tiled_panes()?;
         }
         Ok(())
     }
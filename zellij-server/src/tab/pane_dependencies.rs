@@ -0,0 +1,247 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::{screen::ScreenInstruction, thread_bus::ThreadSenders};
+use zellij_utils::input::layout::{PaneDependency, PaneReadiness};
+
+const PORT_PROBE_INTERVAL: Duration = Duration::from_millis(500);
+const MAX_WATCHED_LINE_LENGTH: usize = 4096;
+
+/// Keeps track of the readiness of panes that other (held) command panes depend on, as
+/// specified with `depends_on` in the layout. The panes themselves hold the list of their
+/// pending dependencies, this only watches the conditions those dependencies are waiting for.
+#[derive(Default)]
+pub struct PaneDependencies {
+    ready: HashSet<PaneDependency>,
+    output_watchers: HashMap<u32, OutputWatcher>, // u32 is the terminal id of the watched pane
+    port_probes: HashMap<u16, PortProbe>,
+}
+
+impl PaneDependencies {
+    pub fn pane_exited_successfully(&mut self, pane_name: String) {
+        self.ready.insert(PaneDependency {
+            pane_name,
+            ready_when: PaneReadiness::ExitedSuccessfully,
+        });
+    }
+    /// returns true if this output made a dependency ready
+    pub fn handle_pty_bytes(&mut self, terminal_id: u32, bytes: &[u8]) -> bool {
+        match self.output_watchers.get_mut(&terminal_id) {
+            Some(output_watcher) => {
+                let newly_ready = output_watcher.watch(bytes);
+                let found_match = !newly_ready.is_empty();
+                self.ready.extend(newly_ready);
+                found_match
+            },
+            None => false,
+        }
+    }
+    /// Returns the dependencies that are not yet ready, starting to watch the ones we're not
+    /// watching yet. `named_terminal_panes` are the terminal panes in the tab that have a name,
+    /// keyed by it.
+    pub fn pending(
+        &mut self,
+        dependencies: &[PaneDependency],
+        named_terminal_panes: &HashMap<String, u32>,
+        senders: &ThreadSenders,
+    ) -> Vec<PaneDependency> {
+        let mut pending = vec![];
+        for dependency in dependencies {
+            if self.ready.contains(dependency) {
+                continue;
+            }
+            match &dependency.ready_when {
+                PaneReadiness::ExitedSuccessfully => {},
+                PaneReadiness::OutputMatches(pattern) => {
+                    match named_terminal_panes.get(&dependency.pane_name) {
+                        Some(terminal_id) => {
+                            self.output_watchers
+                                .entry(*terminal_id)
+                                .or_insert_with(|| OutputWatcher::new(&dependency.pane_name))
+                                .watch_for(pattern);
+                        },
+                        None => {
+                            log::error!(
+                                "Cannot watch the output of pane {:?}: no such pane in tab",
+                                dependency.pane_name
+                            );
+                        },
+                    }
+                },
+                PaneReadiness::PortOpen(port) => {
+                    let port_probe = self
+                        .port_probes
+                        .entry(*port)
+                        .or_insert_with(|| PortProbe::start(*port, senders.clone()));
+                    if port_probe.is_open() {
+                        self.ready.insert(dependency.clone());
+                        continue;
+                    }
+                },
+            }
+            pending.push(dependency.clone());
+        }
+        pending
+    }
+    /// Stops watching conditions none of the given dependencies are waiting for
+    pub fn retain(&mut self, pending_dependencies: &[PaneDependency]) {
+        self.output_watchers.retain(|_, output_watcher| {
+            output_watcher.retain_patterns(pending_dependencies);
+            output_watcher.has_patterns()
+        });
+        self.port_probes.retain(|port, _| {
+            pending_dependencies
+                .iter()
+                .any(|d| d.ready_when == PaneReadiness::PortOpen(*port))
+        });
+    }
+}
+
+struct OutputWatcher {
+    vte_parser: vte::Parser,
+    line_matcher: LineMatcher,
+}
+
+impl OutputWatcher {
+    pub fn new(pane_name: &str) -> Self {
+        OutputWatcher {
+            vte_parser: vte::Parser::new(),
+            line_matcher: LineMatcher {
+                pane_name: pane_name.to_owned(),
+                ..Default::default()
+            },
+        }
+    }
+    pub fn watch_for(&mut self, pattern: &str) {
+        if self.line_matcher.patterns.iter().any(|(p, _)| p == pattern) {
+            return;
+        }
+        let regex = Regex::new(pattern).unwrap_or_else(|e| {
+            log::error!(
+                "Invalid depends_on output pattern {:?}, matching it literally: {}",
+                pattern,
+                e
+            );
+            // the escaped pattern is always a valid regex
+            Regex::new(&regex::escape(pattern)).unwrap()
+        });
+        self.line_matcher.patterns.push((pattern.to_owned(), regex));
+    }
+    pub fn watch(&mut self, bytes: &[u8]) -> Vec<PaneDependency> {
+        for byte in bytes {
+            self.vte_parser.advance(&mut self.line_matcher, *byte);
+        }
+        // also match the line currently being written, eg. a prompt that does not end in a newline
+        self.line_matcher.match_current_line();
+        self.line_matcher.matched.drain(..).collect()
+    }
+    pub fn retain_patterns(&mut self, pending_dependencies: &[PaneDependency]) {
+        let pane_name = &self.line_matcher.pane_name;
+        self.line_matcher.patterns.retain(|(pattern, _)| {
+            pending_dependencies.iter().any(|d| {
+                &d.pane_name == pane_name
+                    && d.ready_when == PaneReadiness::OutputMatches(pattern.clone())
+            })
+        });
+    }
+    pub fn has_patterns(&self) -> bool {
+        !self.line_matcher.patterns.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct LineMatcher {
+    pane_name: String,
+    patterns: Vec<(String, Regex)>,
+    current_line: String,
+    matched: Vec<PaneDependency>,
+}
+
+impl LineMatcher {
+    fn match_current_line(&mut self) {
+        let current_line = &self.current_line;
+        let pane_name = &self.pane_name;
+        let matched = &mut self.matched;
+        self.patterns.retain(|(pattern, regex)| {
+            if regex.is_match(current_line) {
+                matched.push(PaneDependency {
+                    pane_name: pane_name.clone(),
+                    ready_when: PaneReadiness::OutputMatches(pattern.clone()),
+                });
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl vte::Perform for LineMatcher {
+    fn print(&mut self, c: char) {
+        if self.current_line.len() < MAX_WATCHED_LINE_LENGTH {
+            self.current_line.push(c);
+        }
+    }
+    fn execute(&mut self, byte: u8) {
+        if matches!(byte, b'\n' | b'\r') {
+            self.match_current_line();
+            self.current_line.clear();
+        }
+    }
+}
+
+/// Repeatedly tries to connect to a local port in a background thread until it succeeds,
+/// letting the screen know when it does
+struct PortProbe {
+    is_open: Arc<AtomicBool>,
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl PortProbe {
+    pub fn start(port: u16, senders: ThreadSenders) -> Self {
+        let is_open = Arc::new(AtomicBool::new(false));
+        let is_cancelled = Arc::new(AtomicBool::new(false));
+        thread::Builder::new()
+            .name(format!("port_probe_{}", port))
+            .spawn({
+                let is_open = is_open.clone();
+                let is_cancelled = is_cancelled.clone();
+                move || {
+                    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+                    while !is_cancelled.load(Ordering::Relaxed) {
+                        if TcpStream::connect_timeout(&address, PORT_PROBE_INTERVAL).is_ok() {
+                            is_open.store(true, Ordering::Relaxed);
+                            let _ =
+                                senders.send_to_screen(ScreenInstruction::CheckPaneDependencies);
+                            break;
+                        }
+                        thread::sleep(PORT_PROBE_INTERVAL);
+                    }
+                }
+            })
+            .map_err(|e| log::error!("Failed to start probing port {}: {}", port, e))
+            .ok();
+        PortProbe {
+            is_open,
+            is_cancelled,
+        }
+    }
+    pub fn is_open(&self) -> bool {
+        self.is_open.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for PortProbe {
+    fn drop(&mut self) {
+        self.is_cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/pane_dependencies_tests.rs"]
+mod pane_dependencies_tests;
@@ -0,0 +1,94 @@
+use super::PaneDependencies;
+use crate::thread_bus::ThreadSenders;
+use std::collections::HashMap;
+use zellij_utils::input::layout::{PaneDependency, PaneReadiness};
+
+fn dependency(pane_name: &str, ready_when: PaneReadiness) -> PaneDependency {
+    PaneDependency {
+        pane_name: pane_name.to_owned(),
+        ready_when,
+    }
+}
+
+fn named_terminal_panes() -> HashMap<String, u32> {
+    HashMap::from([("db".to_owned(), 1), ("api".to_owned(), 2)])
+}
+
+#[test]
+fn exited_dependency_is_pending_until_pane_exits_successfully() {
+    let mut pane_dependencies = PaneDependencies::default();
+    let dependencies = vec![dependency("db", PaneReadiness::ExitedSuccessfully)];
+    let senders = ThreadSenders::default();
+    assert_eq!(
+        pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders),
+        dependencies
+    );
+    pane_dependencies.pane_exited_successfully("api".to_owned());
+    assert_eq!(
+        pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders),
+        dependencies
+    );
+    pane_dependencies.pane_exited_successfully("db".to_owned());
+    assert!(pane_dependencies
+        .pending(&dependencies, &named_terminal_panes(), &senders)
+        .is_empty());
+}
+
+#[test]
+fn output_dependency_matches_output_of_named_pane_only() {
+    let mut pane_dependencies = PaneDependencies::default();
+    let dependencies = vec![dependency(
+        "db",
+        PaneReadiness::OutputMatches("ready to accept connections on port \\d+".to_owned()),
+    )];
+    let senders = ThreadSenders::default();
+    pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders);
+    assert!(!pane_dependencies.handle_pty_bytes(2, b"ready to accept connections on port 5432\n"));
+    assert!(!pane_dependencies.handle_pty_bytes(1, b"starting up...\r\n"));
+    assert!(pane_dependencies.handle_pty_bytes(
+        1,
+        b"\x1b[32mready\x1b[0m to accept connections on port 5432\r\n"
+    ));
+    assert!(pane_dependencies
+        .pending(&dependencies, &named_terminal_panes(), &senders)
+        .is_empty());
+}
+
+#[test]
+fn output_dependency_matches_lines_split_across_reads() {
+    let mut pane_dependencies = PaneDependencies::default();
+    let dependencies = vec![dependency(
+        "api",
+        PaneReadiness::OutputMatches("listening".to_owned()),
+    )];
+    let senders = ThreadSenders::default();
+    pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders);
+    assert!(!pane_dependencies.handle_pty_bytes(2, b"server list"));
+    assert!(pane_dependencies.handle_pty_bytes(2, b"ening"));
+}
+
+#[test]
+fn invalid_output_pattern_is_matched_literally() {
+    let mut pane_dependencies = PaneDependencies::default();
+    let dependencies = vec![dependency(
+        "api",
+        PaneReadiness::OutputMatches("[ready".to_owned()),
+    )];
+    let senders = ThreadSenders::default();
+    pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders);
+    assert!(!pane_dependencies.handle_pty_bytes(2, b"ready\n"));
+    assert!(pane_dependencies.handle_pty_bytes(2, b"[ready\n"));
+}
+
+#[test]
+fn output_is_not_watched_once_no_pane_depends_on_it() {
+    let mut pane_dependencies = PaneDependencies::default();
+    let dependencies = vec![dependency(
+        "db",
+        PaneReadiness::OutputMatches("ready".to_owned()),
+    )];
+    let senders = ThreadSenders::default();
+    pane_dependencies.pending(&dependencies, &named_terminal_panes(), &senders);
+    pane_dependencies.retain(&[]);
+    assert!(!pane_dependencies.handle_pty_bytes(1, b"ready\n"));
+}
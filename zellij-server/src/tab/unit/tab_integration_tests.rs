@@ -17,6 +17,7 @@ use std::sync::Mutex;
 
 use zellij_utils::channels::Receiver;
 use zellij_utils::data::Direction;
+use zellij_utils::data::DumpScreenFormat;
 use zellij_utils::data::Resize;
 use zellij_utils::data::ResizeStrategy;
 use zellij_utils::data::WebSharing;
@@ -1295,8 +1296,13 @@ fn dump_screen() {
     tab.handle_pty_bytes(2, Vec::from("scratch".as_bytes()))
         .unwrap();
     let file = "/tmp/log.sh";
-    tab.dump_active_terminal_screen(Some(file.to_string()), client_id, false)
-        .unwrap();
+    tab.dump_active_terminal_screen(
+        Some(file.to_string()),
+        client_id,
+        false,
+        DumpScreenFormat::Text,
+    )
+    .unwrap();
     assert_eq!(
         map.lock().unwrap().get(file).unwrap(),
         "scratch",
@@ -1333,8 +1339,13 @@ fn clear_screen() {
         .unwrap();
     let file = "/tmp/log-clear-screen.sh";
     tab.clear_active_terminal_screen(client_id).unwrap();
-    tab.dump_active_terminal_screen(Some(file.to_string()), client_id, false)
-        .unwrap();
+    tab.dump_active_terminal_screen(
+        Some(file.to_string()),
+        client_id,
+        false,
+        DumpScreenFormat::Text,
+    )
+    .unwrap();
     assert_eq!(
         map.lock().unwrap().get(file).unwrap(),
         "",
@@ -10,7 +10,9 @@ use crate::{
 };
 use std::net::{IpAddr, Ipv4Addr};
 use std::path::PathBuf;
-use zellij_utils::data::{Direction, NewPanePlacement, Resize, ResizeStrategy, WebSharing};
+use zellij_utils::data::{
+    Direction, NewPanePlacement, Resize, ResizeStrategy, TilingMode, WebSharing,
+};
 use zellij_utils::errors::prelude::*;
 use zellij_utils::input::layout::{SplitDirection, SplitSize, TiledPaneLayout};
 use zellij_utils::ipc::IpcReceiverWithContext;
@@ -828,6 +830,93 @@ fn split_largest_pane() {
     );
 }
 
+#[test]
+fn tiling_mode_rebalances_panes_on_open_and_close() {
+    let size = Size {
+        cols: 120,
+        rows: 20,
+    };
+    let stacked_resize = false;
+    let mut tab = create_new_tab(size, stacked_resize);
+    tab.set_tiling_mode(TilingMode::Grid).unwrap();
+    for i in 2..5 {
+        tab.new_pane(
+            PaneId::Terminal(i),
+            None,
+            None,
+            false,
+            true,
+            NewPanePlacement::default(),
+            Some(1),
+            None,
+        )
+        .unwrap();
+    }
+    let geoms = |tab: &Tab| -> Vec<(usize, usize, usize, usize)> {
+        tab.tiled_panes
+            .panes
+            .values()
+            .map(|p| {
+                let geom = p.position_and_size();
+                (geom.x, geom.y, geom.cols.as_usize(), geom.rows.as_usize())
+            })
+            .collect()
+    };
+    assert_eq!(
+        geoms(&tab),
+        vec![
+            (0, 0, 60, 10),
+            (60, 0, 60, 10),
+            (0, 10, 60, 10),
+            (60, 10, 60, 10)
+        ],
+        "four panes are arranged in a grid"
+    );
+    tab.close_pane(PaneId::Terminal(2), true, None);
+    assert_eq!(
+        geoms(&tab),
+        vec![(0, 0, 60, 20), (60, 0, 60, 10), (60, 10, 60, 10)],
+        "remaining panes are rebalanced after closing one"
+    );
+}
+
+#[test]
+fn tiling_mode_from_layout_rebalances_its_panes() {
+    let size = Size {
+        cols: 120,
+        rows: 20,
+    };
+    let layout = TiledPaneLayout {
+        children_split_direction: SplitDirection::Vertical,
+        children: vec![
+            TiledPaneLayout {
+                split_size: Some(SplitSize::Percent(80)),
+                ..Default::default()
+            },
+            TiledPaneLayout::default(),
+            TiledPaneLayout::default(),
+        ],
+        tiling_mode: Some(TilingMode::Grid),
+        ..Default::default()
+    };
+    let tab = create_new_tab_with_layout(size, layout);
+    assert_eq!(tab.tiling_mode(), TilingMode::Grid);
+    let geoms: Vec<(usize, usize, usize, usize)> = tab
+        .tiled_panes
+        .panes
+        .values()
+        .map(|p| {
+            let geom = p.position_and_size();
+            (geom.x, geom.y, geom.cols.as_usize(), geom.rows.as_usize())
+        })
+        .collect();
+    assert_eq!(
+        geoms,
+        vec![(0, 0, 60, 20), (60, 0, 60, 10), (60, 10, 60, 10)],
+        "the panes of the layout are arranged in a grid rather than by their sizes"
+    );
+}
+
 #[test]
 pub fn cannot_split_panes_vertically_when_active_pane_is_too_small() {
     let size = Size { cols: 8, rows: 20 };
@@ -1,8 +1,12 @@
 mod component_coordinates;
 mod nested_list;
+mod progress;
 mod ribbon;
+mod scroll_list;
+mod sparkline;
 mod table;
 mod text;
+mod text_input;
 
 use crate::panes::grid::Grid;
 use lazy_static::lazy_static;
@@ -13,9 +17,13 @@ use zellij_utils::errors::prelude::*;
 
 use component_coordinates::{is_too_high, is_too_wide, Coordinates};
 use nested_list::{nested_list, parse_nested_list_items};
+use progress::{progress_bar, spinner};
 use ribbon::ribbon;
+use scroll_list::{parse_list_text, parse_scroll_list_items, parse_tree_items, scroll_list};
+use sparkline::sparkline;
 use table::table;
 use text::{parse_text, parse_text_params, stringify_text, text, Text};
+use text_input::text_input;
 
 macro_rules! parse_next_param {
     ($next_param:expr, $type:ident, $component_name:expr, $item_name:expr) => {{
@@ -122,6 +130,61 @@ impl<'a> UiComponentParser<'a> {
             let encoded_text = text(stringified_params, &self.style, component_coordinates);
             parse_vte_bytes!(self, encoded_text);
             Ok(())
+        } else if component_name == &"text_input" {
+            let cursor = params_iter
+                .next()
+                .and_then(|cursor| cursor.parse::<usize>().ok());
+            let value = params_iter
+                .next()
+                .map(|value| parse_list_text(value).map_err(|e| anyhow!(e)))
+                .transpose()?
+                .context("a text input must have a value")?;
+            let placeholder = params_iter
+                .next()
+                .map(|placeholder| parse_list_text(placeholder).map_err(|e| anyhow!(e)))
+                .transpose()?;
+            let encoded_text_input = text_input(
+                value,
+                placeholder,
+                cursor,
+                &self.style,
+                component_coordinates,
+            );
+            parse_vte_bytes!(self, encoded_text_input);
+            Ok(())
+        } else if component_name == &"scroll_list" || component_name == &"tree" {
+            let total = parse_next_param!(params_iter.next(), usize, component_name, "a total");
+            let offset = parse_next_param!(params_iter.next(), usize, component_name, "an offset");
+            let items = if component_name == &"tree" {
+                parse_tree_items(params_iter)
+            } else {
+                parse_scroll_list_items(params_iter)
+            };
+            let encoded_scroll_list =
+                scroll_list(items, total, offset, &self.style, component_coordinates);
+            parse_vte_bytes!(self, encoded_scroll_list);
+            Ok(())
+        } else if component_name == &"progress_bar" {
+            let progress = parse_next_param!(params_iter.next(), f64, "progress_bar", "progress");
+            let label = parse_text_params(params_iter).into_iter().next();
+            let encoded_progress_bar =
+                progress_bar(progress, label, &self.style, component_coordinates);
+            parse_vte_bytes!(self, encoded_progress_bar);
+            Ok(())
+        } else if component_name == &"spinner" {
+            let frame = parse_next_param!(params_iter.next(), usize, "spinner", "a frame");
+            let label = parse_text_params(params_iter).into_iter().next();
+            let encoded_spinner = spinner(frame, label, &self.style, component_coordinates);
+            parse_vte_bytes!(self, encoded_spinner);
+            Ok(())
+        } else if component_name == &"sparkline" {
+            let max = params_iter.next().and_then(|max| max.parse::<f64>().ok());
+            let values = params_iter
+                .filter_map(|value| value.parse::<f64>().ok())
+                .collect();
+            let encoded_sparkline = sparkline(values, max, &self.style, component_coordinates);
+            parse_vte_bytes!(self, encoded_sparkline);
+            Ok(())
         } else {
             Err(anyhow!("Unknown component: {}", component_name))
         }
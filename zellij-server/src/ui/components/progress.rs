@@ -0,0 +1,121 @@
+use super::{stringify_text, Coordinates, Text};
+use crate::panes::terminal_character::{AnsiCode, CharacterStyles, RESET_STYLES};
+use zellij_utils::data::Style;
+
+use unicode_width::UnicodeWidthStr;
+
+static PROGRESS_BAR_PARTIAL_CELLS: [&str; 8] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+static PROGRESS_BAR_FULL_CELL: &str = "█";
+static SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const DEFAULT_PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Renders `label [█████▌    ]  55%`, the bar taking whatever width the label and percentage
+/// leave
+pub fn progress_bar(
+    progress: f64,
+    label: Option<Text>,
+    style: &Style,
+    coordinates: Option<Coordinates>,
+) -> Vec<u8> {
+    let progress = if progress.is_nan() {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0)
+    };
+    let declaration = style.colors.text_unselected;
+    let text_style = CharacterStyles::from(declaration).bold(Some(AnsiCode::On));
+    let fill_style = CharacterStyles::from(declaration)
+        .foreground(Some(style.colors.exit_code_success.base.into()));
+    let percentage = format!(" {:>3}%", (progress * 100.0).floor() as usize);
+
+    let label_width = label
+        .as_ref()
+        .map(|label| label.text.width() + 1)
+        .unwrap_or(0);
+    let width = coordinates
+        .as_ref()
+        .and_then(|c| c.width)
+        .unwrap_or(label_width + DEFAULT_PROGRESS_BAR_WIDTH + 2 + percentage.width());
+    let bar_width = width.saturating_sub(label_width + 2 + percentage.width());
+
+    let mut stringified = coordinates
+        .as_ref()
+        .map(|c| c.to_string())
+        .unwrap_or_default();
+    if let Some(label) = label {
+        // the label is truncated to the width of the component, but is not padded to it - the
+        // bar and percentage follow it
+        let label_coordinates = coordinates.as_ref().map(|c| Coordinates {
+            width: Some(label_width.saturating_sub(1).min(width)),
+            ..c.clone()
+        });
+        let (label, _label_width) = stringify_text(
+            &label,
+            None,
+            &label_coordinates,
+            &declaration,
+            &style.colors,
+            text_style,
+        );
+        stringified.push_str(&format!("{}{}{} ", text_style, label, text_style));
+    }
+    if bar_width > 0 {
+        stringified.push_str(&format!(
+            "{}[{}{}{}]",
+            text_style,
+            fill_style,
+            progress_bar_cells(progress, bar_width),
+            text_style
+        ));
+    }
+    if width >= label_width + percentage.width() {
+        stringified.push_str(&percentage);
+    }
+    stringified.push_str(&format!("{}", RESET_STYLES));
+    stringified.as_bytes().to_vec()
+}
+
+/// Renders a spinner at the given frame, followed by an optional label
+pub fn spinner(
+    frame: usize,
+    label: Option<Text>,
+    style: &Style,
+    coordinates: Option<Coordinates>,
+) -> Vec<u8> {
+    let declaration = style.colors.text_unselected;
+    let text_style = CharacterStyles::from(declaration).bold(Some(AnsiCode::On));
+    let spinner_style = text_style.foreground(Some(declaration.emphasis_0.into()));
+    let mut stringified = coordinates
+        .as_ref()
+        .map(|c| c.to_string())
+        .unwrap_or_default();
+    stringified.push_str(&format!(
+        "{}{}",
+        spinner_style,
+        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]
+    ));
+    if let Some(label) = label {
+        let (label, _label_width) = stringify_text(
+            &label,
+            Some(2),
+            &coordinates,
+            &declaration,
+            &style.colors,
+            text_style,
+        );
+        stringified.push_str(&format!("{} {}", text_style, label));
+    }
+    stringified.push_str(&format!("{}", RESET_STYLES));
+    stringified.as_bytes().to_vec()
+}
+
+pub fn progress_bar_cells(progress: f64, bar_width: usize) -> String {
+    let eighths = (progress * bar_width as f64 * 8.0).round() as usize;
+    let full_cells = (eighths / 8).min(bar_width);
+    let mut cells = PROGRESS_BAR_FULL_CELL.repeat(full_cells);
+    if full_cells < bar_width {
+        cells.push_str(PROGRESS_BAR_PARTIAL_CELLS[eighths % 8]);
+        cells.push_str(&" ".repeat(bar_width - full_cells - 1));
+    }
+    cells
+}
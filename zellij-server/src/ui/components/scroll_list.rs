@@ -0,0 +1,204 @@
+use super::{
+    is_too_high, parse_indices, parse_opaque, parse_selected, parse_text, stringify_text,
+    Coordinates, Text,
+};
+use crate::panes::terminal_character::{AnsiCode, CharacterStyles, RESET_STYLES};
+use zellij_utils::data::Style;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+static SCROLLBAR_TRACK: &str = "│";
+static SCROLLBAR_THUMB: &str = "┃";
+static TREE_EXPANDED: &str = "▾ ";
+static TREE_COLLAPSED: &str = "▸ ";
+static TREE_LEAF: &str = "  ";
+
+#[derive(Debug, Clone)]
+pub struct ScrollListItem {
+    pub text: Text,
+    // eg. the indentation and expansion marker of tree nodes
+    pub prefix: String,
+}
+
+/// Renders the visible window of a list that is `total` items long and scrolled down to
+/// `offset`, the plugin only sends the items in the window
+pub fn scroll_list(
+    items: Vec<ScrollListItem>,
+    total: usize,
+    offset: usize,
+    style: &Style,
+    coordinates: Option<Coordinates>,
+) -> Vec<u8> {
+    let visible_items = match coordinates.as_ref().and_then(|c| c.height) {
+        Some(height) => items.len().min(height),
+        None => items.len(),
+    };
+    let has_scrollbar = total > visible_items;
+    let max_width = coordinates
+        .as_ref()
+        .and_then(|c| c.width)
+        .unwrap_or_else(|| max_item_width(&items) + if has_scrollbar { 1 } else { 0 });
+    let content_width = if has_scrollbar {
+        max_width.saturating_sub(1)
+    } else {
+        max_width
+    };
+    // the items are truncated and padded to leave room for the scrollbar
+    let content_coordinates = coordinates.as_ref().map(|c| Coordinates {
+        width: Some(content_width),
+        ..c.clone()
+    });
+    let thumb = scrollbar_thumb(total, offset, visible_items);
+    let mut stringified = String::new();
+    for (line_index, item) in items.into_iter().enumerate() {
+        if is_too_high(line_index + 1, &coordinates) {
+            break;
+        }
+        let style_declaration = if item.text.selected {
+            style.colors.list_selected
+        } else {
+            style.colors.list_unselected
+        };
+        let text_style = if item.text.opaque || item.text.selected {
+            CharacterStyles::from(style_declaration)
+                .background(Some(style_declaration.background.into()))
+        } else {
+            CharacterStyles::from(style_declaration)
+        };
+        let prefix = truncate(&item.prefix, content_width);
+        let prefix_width = prefix.width();
+        let (text, text_width) = stringify_text(
+            &item.text,
+            Some(prefix_width),
+            &content_coordinates,
+            &style_declaration,
+            &style.colors,
+            text_style.bold(Some(AnsiCode::On)),
+        );
+        let end_padding = content_width.saturating_sub(prefix_width + text_width);
+        let go_to_row_instruction = coordinates
+            .as_ref()
+            .map(|c| c.stringify_with_y_offset(line_index))
+            .unwrap_or_else(|| {
+                if line_index != 0 {
+                    "\n\r".to_owned()
+                } else {
+                    "".to_owned()
+                }
+            });
+        stringified.push_str(&format!(
+            "{}{}{}{}{}{:end_padding$}{}",
+            go_to_row_instruction, text_style, prefix, text, text_style, "", RESET_STYLES
+        ));
+        if has_scrollbar {
+            let scrollbar_character = if thumb.contains(&line_index) {
+                SCROLLBAR_THUMB
+            } else {
+                SCROLLBAR_TRACK
+            };
+            let scrollbar_style = CharacterStyles::from(style.colors.list_unselected);
+            stringified.push_str(&format!(
+                "{}{}{}",
+                scrollbar_style, scrollbar_character, RESET_STYLES
+            ));
+        }
+    }
+    stringified.as_bytes().to_vec()
+}
+
+pub fn parse_scroll_list_items<'a>(
+    params_iter: impl Iterator<Item = &'a mut String>,
+) -> Vec<ScrollListItem> {
+    params_iter
+        .flat_map(|stringified| {
+            let text = parse_list_text(stringified)?;
+            Ok::<ScrollListItem, String>(ScrollListItem {
+                text,
+                prefix: String::new(),
+            })
+        })
+        .collect()
+}
+
+pub fn parse_tree_items<'a>(
+    params_iter: impl Iterator<Item = &'a mut String>,
+) -> Vec<ScrollListItem> {
+    params_iter
+        .flat_map(|stringified| {
+            let mut depth = 0;
+            while stringified.starts_with('|') {
+                stringified.remove(0);
+                depth += 1;
+            }
+            let marker = if stringified.starts_with('+') {
+                stringified.remove(0);
+                TREE_COLLAPSED
+            } else if stringified.starts_with('-') {
+                stringified.remove(0);
+                TREE_EXPANDED
+            } else {
+                TREE_LEAF
+            };
+            let text = parse_list_text(stringified)?;
+            Ok::<ScrollListItem, String>(ScrollListItem {
+                text,
+                prefix: format!("{:indentation$}{}", "", marker, indentation = depth * 2),
+            })
+        })
+        .collect()
+}
+
+pub fn parse_list_text(stringified: &mut String) -> Result<Text, String> {
+    let selected = parse_selected(stringified);
+    let opaque = parse_opaque(stringified);
+    let indices = parse_indices(stringified);
+    // an empty item has no bytes to parse
+    let text = if stringified.is_empty() {
+        String::new()
+    } else {
+        parse_text(stringified).map_err(|e| e.to_string())?
+    };
+    Ok(Text {
+        text,
+        opaque,
+        selected,
+        indices,
+    })
+}
+
+/// The rows of the window in which the scrollbar thumb is drawn
+pub fn scrollbar_thumb(
+    total: usize,
+    offset: usize,
+    visible_items: usize,
+) -> std::ops::Range<usize> {
+    if total == 0 || visible_items == 0 {
+        return 0..0;
+    }
+    let thumb_size = (visible_items * visible_items / total).max(1);
+    let thumb_start = if offset + visible_items >= total {
+        // make sure the end of the list is visibly the end, regardless of rounding
+        visible_items - thumb_size
+    } else {
+        (offset * visible_items / total).min(visible_items - thumb_size)
+    };
+    thumb_start..thumb_start + thumb_size
+}
+
+fn max_item_width(items: &[ScrollListItem]) -> usize {
+    items
+        .iter()
+        .map(|item| item.prefix.width() + item.text.text.width())
+        .max()
+        .unwrap_or(0)
+}
+
+fn truncate(text: &str, max_width: usize) -> String {
+    let mut width = 0;
+    text.chars()
+        .take_while(|character| {
+            width += character.width().unwrap_or(0);
+            width <= max_width
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use super::Coordinates;
+use crate::panes::terminal_character::{AnsiCode, CharacterStyles, RESET_STYLES};
+use zellij_utils::data::Style;
+
+static SPARKLINE_LEVELS: [&str; 8] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// Renders one cell per value, scaled so that `max` (or the largest value if there is none) is a
+/// full cell - if there are more values than fit in the width, the most recent (last) ones are
+/// shown
+pub fn sparkline(
+    values: Vec<f64>,
+    max: Option<f64>,
+    style: &Style,
+    coordinates: Option<Coordinates>,
+) -> Vec<u8> {
+    let declaration = style.colors.text_unselected;
+    let sparkline_style = CharacterStyles::from(declaration)
+        .foreground(Some(declaration.emphasis_0.into()))
+        .bold(Some(AnsiCode::On));
+    let width = coordinates
+        .as_ref()
+        .and_then(|c| c.width)
+        .unwrap_or(values.len());
+    let mut stringified = coordinates
+        .as_ref()
+        .map(|c| c.to_string())
+        .unwrap_or_default();
+    stringified.push_str(&format!(
+        "{}{}{}",
+        sparkline_style,
+        sparkline_cells(&values, max, width),
+        RESET_STYLES
+    ));
+    stringified.as_bytes().to_vec()
+}
+
+pub fn sparkline_cells(values: &[f64], max: Option<f64>, width: usize) -> String {
+    let values = &values[values.len().saturating_sub(width)..];
+    let max = max.unwrap_or_else(|| values.iter().cloned().fold(0.0, f64::max));
+    values
+        .iter()
+        .map(|value| {
+            let level = if max > 0.0 && value.is_finite() {
+                ((value / max).clamp(0.0, 1.0) * (SPARKLINE_LEVELS.len() - 1) as f64).round()
+                    as usize
+            } else {
+                0
+            };
+            SPARKLINE_LEVELS[level]
+        })
+        .collect()
+}
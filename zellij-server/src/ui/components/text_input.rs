@@ -0,0 +1,103 @@
+use super::{Coordinates, Text};
+use crate::panes::terminal_character::{AnsiCode, CharacterStyles, RESET_STYLES};
+use zellij_utils::data::Style;
+
+use unicode_width::UnicodeWidthChar;
+
+/// Renders a single line text input, scrolled horizontally so that the cursor is always visible
+///
+/// The cursor is given in characters and is not drawn if the input is not focused (ie. if it is
+/// None)
+pub fn text_input(
+    value: Text,
+    placeholder: Option<Text>,
+    cursor: Option<usize>,
+    style: &Style,
+    coordinates: Option<Coordinates>,
+) -> Vec<u8> {
+    let declaration = if value.selected {
+        style.colors.text_selected
+    } else {
+        style.colors.text_unselected
+    };
+    let base_style = if value.opaque || value.selected {
+        CharacterStyles::from(declaration).background(Some(declaration.background.into()))
+    } else {
+        CharacterStyles::from(declaration)
+    };
+    let cursor_style = base_style.reverse(Some(AnsiCode::On));
+    let (characters, characters_style) = match placeholder {
+        Some(placeholder) if value.text.is_empty() => (
+            placeholder.text.chars().collect::<Vec<_>>(),
+            base_style.dim(Some(AnsiCode::On)),
+        ),
+        _ => (value.text.chars().collect::<Vec<_>>(), base_style),
+    };
+    let cursor = cursor.map(|cursor| cursor.min(value.text.chars().count()));
+    // the cursor can sit right after the last character, where it takes a cell of its own
+    let content_width = characters
+        .iter()
+        .map(|c| c.width().unwrap_or(0))
+        .sum::<usize>()
+        + if cursor == Some(characters.len()) {
+            1
+        } else {
+            0
+        };
+    let width = coordinates
+        .as_ref()
+        .and_then(|c| c.width)
+        .unwrap_or(content_width);
+    let first_visible = first_visible_character(&characters, cursor, width);
+
+    let mut stringified = coordinates.map(|c| c.to_string()).unwrap_or_default();
+    let mut current_width = 0;
+    for (index, character) in characters.iter().enumerate().skip(first_visible) {
+        let character_width = character.width().unwrap_or(0);
+        if current_width + character_width > width {
+            break;
+        }
+        current_width += character_width;
+        let character_style = if cursor == Some(index) {
+            cursor_style
+        } else {
+            characters_style
+        };
+        stringified.push_str(&format!("{}{}", character_style, character));
+    }
+    if cursor == Some(characters.len()) && current_width < width {
+        stringified.push_str(&format!("{} ", cursor_style));
+        current_width += 1;
+    }
+    stringified.push_str(&format!(
+        "{}{:padding$}{}",
+        base_style,
+        "",
+        RESET_STYLES,
+        padding = width.saturating_sub(current_width)
+    ));
+    stringified.as_bytes().to_vec()
+}
+
+/// The index of the first character to draw so that the cursor fits in `width`
+fn first_visible_character(characters: &[char], cursor: Option<usize>, width: usize) -> usize {
+    let cursor = match cursor {
+        Some(cursor) => cursor,
+        None => return 0,
+    };
+    let mut visible_width = if cursor == characters.len() {
+        1
+    } else {
+        characters[cursor].width().unwrap_or(0)
+    };
+    let mut first_visible = cursor;
+    while first_visible > 0 {
+        let previous_width = characters[first_visible - 1].width().unwrap_or(0);
+        if visible_width + previous_width > width {
+            break;
+        }
+        visible_width += previous_width;
+        first_visible -= 1;
+    }
+    first_visible
+}
@@ -10,6 +10,7 @@ use zellij_utils::{
 pub struct LoadingIndication {
     pub ended: bool,
     error: Option<String>,
+    suspended: bool,
     animation_offset: usize,
     plugin_name: String,
     terminal_emulator_colors: Option<Styling>,
@@ -38,6 +39,7 @@ impl LoadingIndication {
         let current_animation_offset = self.animation_offset;
         let current_terminal_emulator_colors = self.terminal_emulator_colors.take();
         let mut current_error = self.error.take();
+        let current_suspended = self.suspended;
         let override_previous_error = other.override_previous_error;
         drop(std::mem::replace(self, other));
         self.animation_offset = current_animation_offset;
@@ -45,8 +47,10 @@ impl LoadingIndication {
         if let Some(current_error) = current_error.take() {
             // we do this so that only the first error (usually the root cause) will be shown
             // when plugins support scrolling, we might want to do an append here
-            if !override_previous_error {
+            // (a suspension always replaces the previous error, since it can be reloaded from)
+            if !override_previous_error && !self.suspended {
                 self.error = Some(current_error);
+                self.suspended = current_suspended;
             }
         }
     }
@@ -63,6 +67,13 @@ impl LoadingIndication {
     pub fn is_error(&self) -> bool {
         self.error.is_some()
     }
+    pub fn indicate_suspended(&mut self, error_text: String) {
+        self.error = Some(error_text);
+        self.suspended = true;
+    }
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
     pub fn override_previous_error(&mut self) {
         self.override_previous_error = true;
     }
@@ -106,13 +117,21 @@ impl Display for LoadingIndication {
                 red.bold().paint("ERROR: "),
                 error_text.replace('\n', "\n\r")
             ));
-            // we add this additional line explicitly to make it easier to realize when something
-            // is wrong in very small plugins (eg. the tab-bar and status-bar)
-            stringified.push_str(&format!(
-                "\n\r{}",
-                red.bold()
-                    .paint("ERROR IN PLUGIN - check logs for more info")
-            ));
+            if self.suspended {
+                stringified.push_str(&format!(
+                    "\n\r{} {}",
+                    "Press",
+                    cyan.paint("<ENTER> to reload")
+                ));
+            } else {
+                // we add this additional line explicitly to make it easier to realize when something
+                // is wrong in very small plugins (eg. the tab-bar and status-bar)
+                stringified.push_str(&format!(
+                    "\n\r{}",
+                    red.bold()
+                        .paint("ERROR IN PLUGIN - check logs for more info")
+                ));
+            }
         } else {
             let loading_text = "Loading";
             stringified.push_str(&format!("{} {}", loading_text, cyan.paint(plugin_name)));
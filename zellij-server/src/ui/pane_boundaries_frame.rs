@@ -80,6 +80,7 @@ pub struct PaneFrame {
     pub other_focused_clients: Vec<ClientId>,
     exit_status: Option<ExitStatus>,
     is_first_run: bool,
+    waiting_for: Option<String>,
     pane_is_stacked_over: bool,
     pane_is_stacked_under: bool,
     should_draw_pane_frames: bool,
@@ -109,6 +110,7 @@ impl PaneFrame {
             other_cursors_exist_in_session: frame_params.other_cursors_exist_in_session,
             exit_status: None,
             is_first_run: false,
+            waiting_for: None,
             pane_is_stacked_over: frame_params.pane_is_stacked_over,
             pane_is_stacked_under: frame_params.pane_is_stacked_under,
             should_draw_pane_frames: frame_params.should_draw_pane_frames,
@@ -132,6 +134,9 @@ impl PaneFrame {
     pub fn indicate_first_run(&mut self) {
         self.is_first_run = true;
     }
+    pub fn indicate_waiting_for(&mut self, pane_names: Vec<String>) {
+        self.waiting_for = Some(pane_names.join(", "));
+    }
     pub fn override_color(&mut self, color: PaletteColor) {
         self.color = Some(color);
     }
@@ -946,7 +951,29 @@ impl PaneFrame {
                     left_bracket.len() + exited_text.len() + right_bracket.len(),
                 )
             },
-            None => (foreground_color(boundary_type::HORIZONTAL, self.color), 1),
+            None => match &self.waiting_for {
+                Some(pane_names) => {
+                    let mut first_part = vec![];
+                    let left_bracket = " [ ";
+                    let waiting_text = "WAITING FOR: ";
+                    let right_bracket = " ] ";
+                    first_part.append(&mut foreground_color(left_bracket, self.color));
+                    first_part.append(&mut foreground_color(waiting_text, self.color));
+                    first_part.append(&mut foreground_color(
+                        pane_names,
+                        Some(self.style.colors.text_unselected.emphasis_0),
+                    ));
+                    first_part.append(&mut foreground_color(right_bracket, self.color));
+                    (
+                        first_part,
+                        left_bracket.len()
+                            + waiting_text.len()
+                            + pane_names.width()
+                            + right_bracket.len(),
+                    )
+                },
+                None => (foreground_color(boundary_type::HORIZONTAL, self.color), 1),
+            },
         }
     }
     fn second_held_title_part_full(&self) -> (Vec<TerminalCharacter>, usize) {
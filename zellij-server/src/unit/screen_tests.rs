@@ -11,7 +11,7 @@ use insta::assert_snapshot;
 use std::net::{IpAddr, Ipv4Addr};
 use std::path::PathBuf;
 use zellij_utils::cli::CliAction;
-use zellij_utils::data::{Event, Resize, Style, WebSharing};
+use zellij_utils::data::{DumpScreenFormat, Event, Resize, Style, WebSharing};
 use zellij_utils::errors::{prelude::*, ErrorContext};
 use zellij_utils::input::actions::Action;
 use zellij_utils::input::command::{RunCommand, TerminalAction};
@@ -932,6 +932,105 @@ fn move_focus_left_at_left_screen_edge_changes_tab() {
     );
 }
 
+#[test]
+fn focus_mark_switches_to_tab_of_marked_pane() {
+    let size = Size {
+        cols: 121,
+        rows: 20,
+    };
+    let mut screen = create_new_screen(size, true);
+
+    new_tab(&mut screen, 1, 1);
+    new_tab(&mut screen, 2, 2);
+    screen.switch_tab_prev(None, true, 1).expect("TEST");
+    screen.mark_focused_pane("api".to_owned(), 1).expect("TEST");
+    screen.switch_tab_next(None, true, 1).expect("TEST");
+    screen.mark_focused_pane("api".to_owned(), 1).expect("TEST");
+    screen.switch_tab_prev(None, true, 1).expect("TEST");
+
+    assert_eq!(
+        screen.get_active_tab(1).unwrap().pane_id_with_mark("api"),
+        None,
+        "Marking a new pane removes the mark from the previous one"
+    );
+    screen.focus_mark("api", 1).expect("TEST");
+    assert_eq!(
+        screen.get_active_tab(1).unwrap().position,
+        1,
+        "Active tab switched to the tab of the marked pane"
+    );
+}
+
+#[test]
+fn following_client_mirrors_tab_of_followed_client_until_it_stops() {
+    let size = Size {
+        cols: 121,
+        rows: 20,
+    };
+    let mut screen = create_new_screen(size, true);
+    screen.session_is_mirrored = false;
+
+    new_tab(&mut screen, 1, 1);
+    new_tab(&mut screen, 2, 2);
+    screen.add_client(2, false).expect("TEST");
+    screen.switch_tab_prev(None, true, 2).expect("TEST");
+
+    screen.follow_client(1, 2).expect("TEST");
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        1,
+        "Following client moved to the tab of the followed client"
+    );
+    assert_eq!(screen.mode_info.get(&2).unwrap().following_client, Some(1));
+
+    screen.switch_tab_prev(None, true, 1).expect("TEST");
+    screen.sync_following_clients().expect("TEST");
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        0,
+        "Following client switched tabs along with the followed client"
+    );
+
+    screen.stop_following(2).expect("TEST");
+    screen.switch_tab_next(None, true, 1).expect("TEST");
+    screen.sync_following_clients().expect("TEST");
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        0,
+        "Client stays in its tab once it stops following"
+    );
+    assert_eq!(screen.mode_info.get(&2).unwrap().following_client, None);
+}
+
+#[test]
+fn following_client_mode_info_includes_followed_client_name() {
+    let size = Size {
+        cols: 121,
+        rows: 20,
+    };
+    let mut screen = create_new_screen(size, true);
+    screen.session_is_mirrored = false;
+
+    new_tab(&mut screen, 1, 1);
+    screen.add_client(2, false).expect("TEST");
+    screen.add_client(3, false).expect("TEST");
+    screen.set_client_name(1, "alice".to_owned());
+
+    screen.follow_client(1, 2).expect("TEST");
+    screen.follow_client(2, 3).expect("TEST");
+    assert_eq!(
+        screen.mode_info.get(&2).unwrap().following_client_name,
+        Some("alice".to_owned()),
+        "Name of the followed client is in the mode info"
+    );
+    assert_eq!(
+        screen.mode_info.get(&3).unwrap().following_client_name,
+        None,
+        "No name when the followed client has none"
+    );
+    assert_eq!(screen.mode_info.get(&3).unwrap().following_client, Some(2));
+}
+
 #[test]
 fn basic_move_of_active_tab_to_left() {
     let mut screen = create_fixed_size_screen();
@@ -2305,6 +2404,7 @@ pub fn send_cli_dump_screen_action() {
     let cli_action = CliAction::DumpScreen {
         path: PathBuf::from("/tmp/foo"),
         full: true,
+        format: DumpScreenFormat::Text,
     };
     let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
         0,
@@ -2319,6 +2419,43 @@ pub fn send_cli_dump_screen_action() {
     ));
 }
 
+#[test]
+pub fn send_cli_dump_screen_action_with_html_format() {
+    let size = Size { cols: 80, rows: 20 };
+    let client_id = 10; // fake client id should not appear in the screen's state
+    let mut initial_layout = TiledPaneLayout::default();
+    initial_layout.children_split_direction = SplitDirection::Vertical;
+    initial_layout.children = vec![TiledPaneLayout::default(), TiledPaneLayout::default()];
+    let mut mock_screen = MockScreen::new(size);
+    let session_metadata = mock_screen.clone_session_metadata();
+    let screen_thread = mock_screen.run(Some(initial_layout), vec![]);
+    let received_server_instructions = Arc::new(Mutex::new(vec![]));
+    let server_receiver = mock_screen.server_receiver.take().unwrap();
+    let server_thread = log_actions_in_thread!(
+        received_server_instructions,
+        ServerInstruction::KillSession,
+        server_receiver
+    );
+    let cli_action = CliAction::DumpScreen {
+        path: PathBuf::from("/tmp/foo.html"),
+        full: true,
+        format: DumpScreenFormat::Html,
+    };
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        0,
+        "\u{1b}[1;31mred & bold\u{1b}[m plain".as_bytes().to_vec(),
+    ));
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    mock_screen.teardown(vec![server_thread, screen_thread]);
+    let fake_filesystem = mock_screen.os_input.fake_filesystem.lock().unwrap();
+    let dump = fake_filesystem.get("/tmp/foo.html").unwrap();
+    assert!(dump.starts_with("<!DOCTYPE html>"));
+    assert!(dump.contains(
+        "<span style=\"color:#800000;font-weight:bold\">red &amp; bold</span> plain</pre>"
+    ));
+}
+
 #[test]
 pub fn send_cli_edit_scrollback_action() {
     let size = Size { cols: 80, rows: 20 };
@@ -2872,6 +3009,9 @@ pub fn send_cli_new_pane_action_with_default_parameters() {
         command: vec![],
         plugin: None,
         cwd: None,
+        env: vec![],
+        restart: None,
+        max_restarts: None,
         floating: false,
         in_place: false,
         name: None,
@@ -2921,6 +3061,9 @@ pub fn send_cli_new_pane_action_with_split_direction() {
         command: vec![],
         plugin: None,
         cwd: None,
+        env: vec![],
+        restart: None,
+        max_restarts: None,
         floating: false,
         in_place: false,
         name: None,
@@ -2970,6 +3113,9 @@ pub fn send_cli_new_pane_action_with_command_and_cwd() {
         command: vec!["htop".into()],
         plugin: None,
         cwd: Some("/some/folder".into()),
+        env: vec![],
+        restart: None,
+        max_restarts: None,
         floating: false,
         in_place: false,
         name: None,
@@ -3030,6 +3176,9 @@ pub fn send_cli_new_pane_action_with_floating_pane_and_coordinates() {
         command: vec!["htop".into()],
         plugin: None,
         cwd: Some("/some/folder".into()),
+        env: vec![],
+        restart: None,
+        max_restarts: None,
         floating: true,
         in_place: false,
         name: None,
@@ -3354,6 +3503,7 @@ pub fn send_cli_new_tab_action_default_params() {
         name: None,
         layout: None,
         layout_dir: None,
+        params: vec![],
         cwd: None,
         initial_command: vec![],
         initial_plugin: None,
@@ -3395,6 +3545,7 @@ pub fn send_cli_new_tab_action_with_name_and_layout() {
         plugin_receiver
     );
     let new_tab_action = CliAction::NewTab {
+        params: vec![],
         name: Some("my-awesome-tab-name".into()),
         layout: Some(PathBuf::from(format!(
             "{}/src/unit/fixtures/layout-with-three-panes.kdl",
@@ -3702,6 +3853,137 @@ pub fn send_cli_query_tab_names_action() {
     assert_snapshot!(format!("{:#?}", log_tab_names_instruction));
 }
 
+#[test]
+pub fn send_cli_search_session_action() {
+    let size = Size { cols: 80, rows: 20 };
+    let client_id = 10; // fake client id should not appear in the screen's state
+    let mut initial_layout = TiledPaneLayout::default();
+    initial_layout.children_split_direction = SplitDirection::Vertical;
+    initial_layout.children = vec![TiledPaneLayout::default(), TiledPaneLayout::default()];
+    let mut mock_screen = MockScreen::new(size);
+    let session_metadata = mock_screen.clone_session_metadata();
+    let screen_thread = mock_screen.run(Some(initial_layout), vec![]);
+    let received_server_instructions = Arc::new(Mutex::new(vec![]));
+    let server_receiver = mock_screen.server_receiver.take().unwrap();
+    let server_thread = log_actions_in_thread!(
+        received_server_instructions,
+        ServerInstruction::KillSession,
+        server_receiver
+    );
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        0,
+        "all good\n\rstill good".as_bytes().to_vec(),
+    ));
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        1,
+        "starting\n\rError: connection refused\n\rretrying"
+            .as_bytes()
+            .to_vec(),
+    ));
+    let cli_action = CliAction::SearchSession {
+        needle: "error: \\w+".to_owned(),
+        regex: true,
+        case_insensitive: true,
+        whole_word: false,
+        context: 1,
+    };
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    mock_screen.teardown(vec![server_thread, screen_thread]);
+    let log_search_results_instruction = received_server_instructions
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|instruction| match instruction {
+            ServerInstruction::Log(..) => true,
+            _ => false,
+        })
+        .cloned();
+    assert_snapshot!(format!("{:#?}", log_search_results_instruction));
+}
+
+#[test]
+pub fn send_cli_log_pane_action() {
+    let size = Size { cols: 80, rows: 20 };
+    let client_id = 10; // fake client id should not appear in the screen's state
+    let log_dir = tempfile::tempdir().unwrap();
+    let log_path = log_dir.path().join("pane.log");
+    let mut mock_screen = MockScreen::new(size);
+    let session_metadata = mock_screen.clone_session_metadata();
+    let screen_thread = mock_screen.run(None, vec![]);
+    let _ = mock_screen
+        .to_screen
+        .send(ScreenInstruction::PtyBytes(0, "before".as_bytes().to_vec()));
+    let cli_action = CliAction::LogPane {
+        pane_id: "terminal_0".to_owned(),
+        path: Some(log_path.clone()),
+        strip_ansi: true,
+        append: false,
+        max_size: None,
+        max_files: 5,
+        stop: false,
+    };
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        0,
+        "\u{1b}[31mlogged\u{1b}[0m\n\r".as_bytes().to_vec(),
+    ));
+    let cli_action = CliAction::LogPane {
+        pane_id: "terminal_0".to_owned(),
+        path: None,
+        strip_ansi: false,
+        append: false,
+        max_size: None,
+        max_files: 5,
+        stop: true,
+    };
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    let _ = mock_screen
+        .to_screen
+        .send(ScreenInstruction::PtyBytes(0, "after".as_bytes().to_vec()));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    mock_screen.teardown(vec![screen_thread]);
+    assert_eq!(std::fs::read_to_string(&log_path).unwrap(), "logged\n\r");
+}
+
+#[test]
+pub fn send_cli_record_pane_action() {
+    let size = Size { cols: 80, rows: 20 };
+    let client_id = 10; // fake client id should not appear in the screen's state
+    let recording_dir = tempfile::tempdir().unwrap();
+    let recording_path = recording_dir.path().join("run.cast");
+    let mut mock_screen = MockScreen::new(size);
+    let session_metadata = mock_screen.clone_session_metadata();
+    let screen_thread = mock_screen.run(None, vec![]);
+    let cli_action = CliAction::Record {
+        pane_id: "terminal_0".to_owned(),
+        out: Some(recording_path.clone()),
+        stop: false,
+    };
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        0,
+        "recorded".as_bytes().to_vec(),
+    ));
+    let cli_action = CliAction::Record {
+        pane_id: "terminal_0".to_owned(),
+        out: None,
+        stop: true,
+    };
+    send_cli_action_to_server(&session_metadata, cli_action, client_id);
+    let _ = mock_screen.to_screen.send(ScreenInstruction::PtyBytes(
+        0,
+        "not recorded".as_bytes().to_vec(),
+    ));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    mock_screen.teardown(vec![screen_thread]);
+    let recording = std::fs::read_to_string(&recording_path).unwrap();
+    let (header, events) = zellij_utils::asciicast::parse_asciicast(&recording).unwrap();
+    assert_eq!((header.width, header.height), (78, 18)); // the pane's content area
+    let output: Vec<&str> = events.iter().map(|e| e.data.as_str()).collect();
+    assert_eq!(output, vec!["recorded"]);
+}
+
 #[test]
 pub fn send_cli_launch_or_focus_plugin_action() {
     let size = Size {
@@ -10,13 +10,20 @@
 //! - The list of [`Events`](prelude::Event) a plugin can subscribe to
 //! - The [`ZellijWorker`] trait for implementing background workers combined with the
 //! [`register_worker!`](register_worker) macro to register them
+//! - With the `testing` feature, a [harness](testing::PluginTestHarness) to unit test plugins
+//! natively
 //!
 //! ### Full Example and Development Environment
 //! For a working plugin example as well as a development environment, please see:
 //! [https://github.com/zellij-org/rust-plugin-example](https://github.com/zellij-org/rust-plugin-example)
 //!
+#[doc(hidden)]
+pub mod metadata;
+pub mod output;
 pub mod prelude;
 pub mod shim;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod ui_components;
 
 use serde::{Deserialize, Serialize};
@@ -96,9 +103,34 @@ Please refer to the documentation for further information:
 ///
 /// register_plugin!(MyPlugin);
 /// ```
+///
+/// This also embeds the plugin API version the plugin was built against in the plugin, so that
+/// incompatible plugins are refused when loading rather than misbehaving. Plugins can also
+/// declare the permissions they will [`request`](shim::request_permission), to be shown by
+/// `zellij plugin info`:
+/// ```rust,ignore
+/// register_plugin!(MyPlugin, permissions = [ReadApplicationState, RunCommands]);
+/// ```
+///
+/// Plugins rendering with the standard `print!` and `println!` can be tested with the
+/// [`testing`](crate::testing) harness, but what they print is only captured when the test
+/// runner does not capture it first (ie. with `cargo test -- --nocapture`). Importing
+/// [`output::print`] and [`output::println`] instead lifts this limitation.
 #[macro_export]
 macro_rules! register_plugin {
     ($t:ty) => {
+        $crate::__plugin_metadata!(None);
+        $crate::register_plugin!(@plugin $t);
+    };
+    ($t:ty, permissions = [$($permission:ident),* $(,)?]) => {
+        // fail to compile rather than embed permissions that do not exist
+        const _: () = {
+            $(let _ = $crate::prelude::PermissionType::$permission;)*
+        };
+        $crate::__plugin_metadata!(Some(&[$(stringify!($permission)),*]));
+        $crate::register_plugin!(@plugin $t);
+    };
+    (@plugin $t:ty) => {
         thread_local! {
             static STATE: std::cell::RefCell<$t> = std::cell::RefCell::new(Default::default());
         }
@@ -171,6 +203,21 @@ macro_rules! register_plugin {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __plugin_metadata {
+    ($permissions:expr) => {
+        const __ZELLIJ_PLUGIN_METADATA_LEN: usize =
+            $crate::metadata::plugin_metadata::<0>($permissions).size();
+        // the section name must match zellij_utils::plugin_metadata::PLUGIN_METADATA_SECTION
+        #[cfg_attr(target_family = "wasm", link_section = "zellij_plugin_metadata")]
+        #[used]
+        static __ZELLIJ_PLUGIN_METADATA: [u8; __ZELLIJ_PLUGIN_METADATA_LEN] =
+            $crate::metadata::plugin_metadata::<__ZELLIJ_PLUGIN_METADATA_LEN>($permissions)
+                .into_bytes();
+    };
+}
+
 /// Used to register a plugin worker implementing the [`ZellijWorker`] trait.
 ///
 /// eg.
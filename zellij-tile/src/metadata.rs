@@ -0,0 +1,77 @@
+//! The metadata [`register_plugin!`](crate::register_plugin) embeds in the plugin's wasm binary,
+//! see [`zellij_utils::plugin_metadata`] for its format. It is rendered at compile time so that it
+//! can be placed in a static in its own custom section.
+
+use zellij_utils::plugin_metadata::PLUGIN_API_VERSION;
+
+pub const ZELLIJ_TILE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Collects the metadata bytes, counting (but dropping) the ones that do not fit so that it can
+/// first be rendered with `N = 0` to find out the length of the static to render it into
+pub struct PluginMetadata<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> PluginMetadata<N> {
+    const fn push_str(mut self, string: &str) -> Self {
+        let string = string.as_bytes();
+        let mut i = 0;
+        while i < string.len() {
+            if self.len < N {
+                self.bytes[self.len] = string[i];
+            }
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+    const fn push_u32(mut self, number: u32) -> Self {
+        let mut divisor = 1;
+        while number / divisor >= 10 {
+            divisor *= 10;
+        }
+        while divisor > 0 {
+            if self.len < N {
+                self.bytes[self.len] = b'0' + (number / divisor % 10) as u8;
+            }
+            self.len += 1;
+            divisor /= 10;
+        }
+        self
+    }
+    pub const fn size(&self) -> usize {
+        self.len
+    }
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+/// `permissions` are the names of the [`PermissionType`](crate::prelude::PermissionType)s the
+/// plugin declared, or None if it did not declare them
+pub const fn plugin_metadata<const N: usize>(permissions: Option<&[&str]>) -> PluginMetadata<N> {
+    let mut metadata = PluginMetadata {
+        bytes: [0; N],
+        len: 0,
+    }
+    .push_str("api_version ")
+    .push_u32(PLUGIN_API_VERSION)
+    .push_str("\nzellij_tile_version ")
+    .push_str(ZELLIJ_TILE_VERSION)
+    .push_str("\n");
+    if let Some(permissions) = permissions {
+        metadata = metadata.push_str("permissions");
+        let mut i = 0;
+        while i < permissions.len() {
+            metadata = metadata.push_str(" ").push_str(permissions[i]);
+            i += 1;
+        }
+        metadata = metadata.push_str("\n");
+    }
+    metadata
+}
+
+#[cfg(test)]
+#[path = "./unit/metadata_tests.rs"]
+mod metadata_tests;
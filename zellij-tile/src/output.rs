@@ -0,0 +1,39 @@
+//! Replacements for `print!` and `println!` to render plugins with.
+//!
+//! They behave exactly like the standard macros when the plugin runs in Zellij, but unlike them
+//! are always captured by the [`testing`](crate::testing) harness (with the `testing` feature),
+//! even when the test runner captures the test's own output. The UI components of this crate use
+//! them already.
+//!
+//! ```rust
+//! use zellij_tile::output::{print, println};
+//! ```
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __zellij_tile_print {
+    ($($arg:tt)*) => {
+        $crate::output::write_fmt(format_args!($($arg)*))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __zellij_tile_println {
+    () => {
+        $crate::output::write_fmt(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::output::write_fmt(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub use crate::{__zellij_tile_print as print, __zellij_tile_println as println};
+
+#[doc(hidden)]
+pub fn write_fmt(args: std::fmt::Arguments) {
+    #[cfg(feature = "testing")]
+    crate::testing::write_output(args);
+    #[cfg(not(feature = "testing"))]
+    std::print!("{}", args);
+}
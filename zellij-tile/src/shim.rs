@@ -14,11 +14,14 @@ use zellij_utils::plugin_api::plugin_command::{
     parse_layout_response, CreateTokenResponse, ListTokensResponse, ProtobufDeleteLayoutResponse,
     ProtobufDumpLayoutResponse, ProtobufDumpSessionLayoutResponse, ProtobufEditLayoutResponse,
     ProtobufGenerateRandomNameResponse, ProtobufGetFocusedPaneInfoResponse,
-    ProtobufGetLayoutDirResponse, ProtobufGetPanePidResponse, ProtobufParseLayoutResponse,
-    ProtobufPluginCommand, ProtobufRenameLayoutResponse, ProtobufSaveLayoutResponse,
-    RenameWebTokenResponse, RevokeAllWebTokensResponse, RevokeTokenResponse,
+    ProtobufGetLayoutDirResponse, ProtobufGetPanePidResponse, ProtobufKvResponse,
+    ProtobufListGrantedPermissionsResponse, ProtobufParseLayoutResponse, ProtobufPluginCommand,
+    ProtobufRenameLayoutResponse, ProtobufSaveLayoutResponse, ProtobufSearchSessionResponse,
+    ProtobufSendRpcRequestResponse, RenameWebTokenResponse, RevokeAllWebTokensResponse,
+    RevokeTokenResponse,
 };
 use zellij_utils::plugin_api::plugin_ids::{ProtobufPluginIds, ProtobufZellijVersion};
+use zellij_utils::plugin_api::plugin_permission::ProtobufPermissionType;
 
 pub use super::ui_components::*;
 pub use prost::{self, *};
@@ -1346,6 +1349,313 @@ pub fn get_pane_pid(pane_id: PaneId) -> Result<i32, String> {
     }
 }
 
+/// Search the full scrollback of every terminal pane in the session
+///
+/// # Arguments
+/// * `query` - What to search for, and how
+///
+/// # Returns
+/// * `Ok(Vec<PaneSearchResults>)` - The matches, grouped by pane and ordered by tab position
+///   (panes without matches are left out)
+/// * `Err(String)` - An error message if the search pattern is invalid, timed out, or another
+///   error occurred
+pub fn search_session(query: SearchQuery) -> Result<Vec<PaneSearchResults>, String> {
+    let plugin_command = PluginCommand::SearchSession(query);
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+
+    // Read response from stdin
+    let response_bytes =
+        bytes_from_stdin().map_err(|e| format!("Failed to read response from stdin: {:?}", e))?;
+
+    // Decode protobuf response
+    let protobuf_response = ProtobufSearchSessionResponse::decode(response_bytes.as_slice())
+        .map_err(|e| format!("Failed to decode protobuf response: {}", e))?;
+
+    // Convert to Rust type
+    let response = SessionSearchResponse::try_from(protobuf_response)
+        .map_err(|e| format!("Failed to convert protobuf response: {}", e))?;
+
+    match response {
+        SessionSearchResponse::Ok(search_results) => Ok(search_results),
+        SessionSearchResponse::Err(error_msg) => Err(error_msg),
+    }
+}
+
+/// Focus the pane (switching to its tab if needed) and scroll it so that `line` of its
+/// scrollback is visible, `line` being a `SearchMatch::line` as returned by `search_session`
+pub fn jump_to_search_match(pane_id: PaneId, line: usize) {
+    let plugin_command = PluginCommand::JumpToSearchMatch { pane_id, line };
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Start receiving the output of a terminal pane as `Event::PaneOutput`, either raw or as
+/// completed lines with ANSI sequences stripped (requires the `ReadPaneContents` permission and
+/// a subscription to `EventType::PaneOutput`)
+///
+/// Output is batched on the server, so a busy pane results in at most a few events per second
+pub fn subscribe_to_pane_output(pane_id: PaneId, format: PaneOutputFormat) {
+    let plugin_command = PluginCommand::SubscribeToPaneOutput(pane_id, format);
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Stop receiving the output of a pane previously subscribed to with `subscribe_to_pane_output`
+pub fn unsubscribe_from_pane_output(pane_id: PaneId) {
+    let plugin_command = PluginCommand::UnsubscribeFromPaneOutput(pane_id);
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Read a value from this plugin's key/value store
+///
+/// # Returns
+/// * `Ok(Some(value))` - The value stored under `key`
+/// * `Ok(None)` - If nothing is stored under `key`
+/// * `Err(String)` - If the store could not be read
+pub fn kv_get<S: AsRef<str>>(scope: KvScope, key: S) -> Result<Option<Vec<u8>>, String> {
+    let plugin_command = PluginCommand::KvGet {
+        scope,
+        key: key.as_ref().to_owned(),
+    };
+    match run_kv_command(plugin_command)? {
+        KvResponse::Value(value) => Ok(value),
+        KvResponse::Err(error_msg) => Err(error_msg),
+        response => Err(format!("Unexpected response: {:?}", response)),
+    }
+}
+
+/// Store a value in this plugin's key/value store, replacing any previous value under `key`
+///
+/// Stores are limited in the length of their keys, the size of each value and their total size;
+/// a write that would go over any of these limits fails and leaves the store as it was
+pub fn kv_set<S: AsRef<str>>(scope: KvScope, key: S, value: Vec<u8>) -> Result<(), String> {
+    let plugin_command = PluginCommand::KvSet {
+        scope,
+        key: key.as_ref().to_owned(),
+        value,
+    };
+    match run_kv_command(plugin_command)? {
+        KvResponse::Done => Ok(()),
+        KvResponse::Err(error_msg) => Err(error_msg),
+        response => Err(format!("Unexpected response: {:?}", response)),
+    }
+}
+
+/// Remove `key` from this plugin's key/value store (removing a missing key is not an error)
+pub fn kv_delete<S: AsRef<str>>(scope: KvScope, key: S) -> Result<(), String> {
+    let plugin_command = PluginCommand::KvDelete {
+        scope,
+        key: key.as_ref().to_owned(),
+    };
+    match run_kv_command(plugin_command)? {
+        KvResponse::Done => Ok(()),
+        KvResponse::Err(error_msg) => Err(error_msg),
+        response => Err(format!("Unexpected response: {:?}", response)),
+    }
+}
+
+/// List the keys in this plugin's key/value store, sorted
+pub fn kv_list(scope: KvScope) -> Result<Vec<String>, String> {
+    let plugin_command = PluginCommand::KvList { scope };
+    match run_kv_command(plugin_command)? {
+        KvResponse::Keys(keys) => Ok(keys),
+        KvResponse::Err(error_msg) => Err(error_msg),
+        response => Err(format!("Unexpected response: {:?}", response)),
+    }
+}
+
+/// Read a value stored with `kv_set_json` and deserialize it
+pub fn kv_get_json<T: DeserializeOwned, S: AsRef<str>>(
+    scope: KvScope,
+    key: S,
+) -> Result<Option<T>, String> {
+    match kv_get(scope, key)? {
+        Some(value) => serde_json::from_slice(&value)
+            .map(Some)
+            .map_err(|e| format!("Failed to deserialize value: {}", e)),
+        None => Ok(None),
+    }
+}
+
+/// Serialize a value as JSON and store it in this plugin's key/value store
+pub fn kv_set_json<T: Serialize, S: AsRef<str>>(
+    scope: KvScope,
+    key: S,
+    value: &T,
+) -> Result<(), String> {
+    let value =
+        serde_json::to_vec(value).map_err(|e| format!("Failed to serialize value: {}", e))?;
+    kv_set(scope, key, value)
+}
+
+fn run_kv_command(plugin_command: PluginCommand) -> Result<KvResponse, String> {
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+
+    let response_bytes =
+        bytes_from_stdin().map_err(|e| format!("Failed to read response from stdin: {:?}", e))?;
+    let protobuf_response = ProtobufKvResponse::decode(response_bytes.as_slice())
+        .map_err(|e| format!("Failed to decode protobuf response: {}", e))?;
+    KvResponse::try_from(protobuf_response)
+        .map_err(|e| format!("Failed to convert protobuf response: {}", e))
+}
+
+/// Handle requests for `method` sent by other plugins with `send_rpc_request`, they arrive as
+/// `Event::RpcRequest` and must be answered with `respond_to_rpc_request`
+pub fn register_rpc_method<S: AsRef<str>>(method: S) {
+    let plugin_command = PluginCommand::RegisterRpcMethod(method.as_ref().to_owned());
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Stop handling requests for `method`
+pub fn unregister_rpc_method<S: AsRef<str>>(method: S) {
+    let plugin_command = PluginCommand::UnregisterRpcMethod(method.as_ref().to_owned());
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Call `method` on the plugin that registered it, with `payload` serialized as JSON
+///
+/// The response arrives later as `Event::RpcResponse` carrying the returned request id, it is an
+/// error if no plugin registered the method, the handling plugin was unloaded or it did not
+/// respond within `timeout_ms` (5 seconds by default)
+///
+/// # Arguments
+/// * `destination_plugin_id` - Only call the method on this plugin
+///
+/// # Returns
+/// * `Ok(u64)` - The id of the request
+/// * `Err(String)` - If the payload could not be serialized or the request could not be sent
+pub fn send_rpc_request<T: Serialize, S: AsRef<str>>(
+    method: S,
+    payload: &T,
+    destination_plugin_id: Option<u32>,
+    timeout_ms: Option<u64>,
+) -> Result<u64, String> {
+    let payload =
+        serde_json::to_vec(payload).map_err(|e| format!("Failed to serialize payload: {}", e))?;
+    let plugin_command = PluginCommand::SendRpcRequest {
+        method: method.as_ref().to_owned(),
+        payload,
+        destination_plugin_id,
+        timeout_ms,
+    };
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+
+    let response_bytes =
+        bytes_from_stdin().map_err(|e| format!("Failed to read response from stdin: {:?}", e))?;
+    let protobuf_response = ProtobufSendRpcRequestResponse::decode(response_bytes.as_slice())
+        .map_err(|e| format!("Failed to decode protobuf response: {}", e))?;
+    Ok(protobuf_response.request_id)
+}
+
+/// Answer a request received as `Event::RpcRequest`, serializing the result as JSON
+pub fn respond_to_rpc_request<T: Serialize>(
+    request_id: u64,
+    result: Result<&T, String>,
+) -> Result<(), String> {
+    let result = match result {
+        Ok(payload) => Ok(serde_json::to_vec(payload)
+            .map_err(|e| format!("Failed to serialize payload: {}", e))?),
+        Err(e) => Err(e),
+    };
+    let plugin_command = PluginCommand::RespondToRpcRequest { request_id, result };
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+    Ok(())
+}
+
+/// Deserialize the JSON payload of an `Event::RpcRequest` or `Event::RpcResponse`
+pub fn parse_rpc_payload<T: DeserializeOwned>(payload: &[u8]) -> Result<T, String> {
+    serde_json::from_slice(payload).map_err(|e| format!("Failed to deserialize payload: {}", e))
+}
+
+/// Serve the requests the Zellij web server receives under `/plugins/<route>/`, they arrive as
+/// `Event::HttpRequest` and must be answered with `respond_to_http_request`
+///
+/// Requests go through the web server's token authentication before they reach the plugin. A
+/// route name can only contain letters, digits, '-' and '_', and belongs to one plugin at a time.
+pub fn register_http_route<S: AsRef<str>>(route: S) {
+    let plugin_command = PluginCommand::RegisterHttpRoute(route.as_ref().to_owned());
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Stop serving requests under `/plugins/<route>/`
+pub fn unregister_http_route<S: AsRef<str>>(route: S) {
+    let plugin_command = PluginCommand::UnregisterHttpRoute(route.as_ref().to_owned());
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Answer a request received as `Event::HttpRequest`, requests that are not answered within 30
+/// seconds get a 504 response
+pub fn respond_to_http_request(request_id: u64, response: PluginHttpResponse) {
+    let plugin_command = PluginCommand::RespondToHttpRequest {
+        request_id,
+        response,
+    };
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
+/// Returns the permissions granted to each plugin, keyed by plugin url (requires the
+/// ReadApplicationState permission)
+pub fn list_granted_permissions() -> Result<BTreeMap<String, Vec<PermissionType>>, String> {
+    let plugin_command = PluginCommand::ListGrantedPermissions;
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+    let response =
+        ProtobufListGrantedPermissionsResponse::decode(bytes_from_stdin().unwrap().as_slice())
+            .unwrap();
+    if let Some(error) = response.error {
+        return Err(error);
+    }
+    Ok(response
+        .granted
+        .into_iter()
+        .map(|granted| {
+            let permissions = granted
+                .permissions
+                .iter()
+                .filter_map(|p| ProtobufPermissionType::from_i32(*p))
+                .filter_map(|p| PermissionType::try_from(p).ok())
+                .collect();
+            (granted.plugin_url, permissions)
+        })
+        .collect())
+}
+
+/// Revokes permissions granted to a plugin (all of them if `permissions` is empty), running
+/// instances of the plugin lose them right away and it will have to ask for them again the next
+/// time it loads (requires the ChangeApplicationState permission)
+pub fn revoke_plugin_permissions(plugin_url: &str, permissions: &[PermissionType]) {
+    let plugin_command = PluginCommand::RevokePluginPermissions {
+        plugin_url: plugin_url.to_owned(),
+        permissions: permissions.into(),
+    };
+    let protobuf_plugin_command: ProtobufPluginCommand = plugin_command.try_into().unwrap();
+    object_to_stdout(&protobuf_plugin_command.encode_to_vec());
+    unsafe { host_run_plugin_command() };
+}
+
 /// Save a layout to the user's layout directory
 ///
 /// # Arguments
@@ -2043,6 +2353,7 @@ pub fn object_from_stdin<T: DeserializeOwned>() -> Result<T> {
     serde_json::from_str(&json).with_context(err_context)
 }
 
+#[cfg(not(feature = "testing"))]
 #[doc(hidden)]
 pub fn bytes_from_stdin() -> Result<Vec<u8>> {
     let err_context = || "failed to deserialize bytes from stdin".to_string();
@@ -2051,12 +2362,25 @@ pub fn bytes_from_stdin() -> Result<Vec<u8>> {
     serde_json::from_str(&json).with_context(err_context)
 }
 
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub fn bytes_from_stdin() -> Result<Vec<u8>> {
+    crate::testing::read_response()
+}
+
+#[cfg(not(feature = "testing"))]
 #[doc(hidden)]
 pub fn object_to_stdout(object: &impl Serialize) {
     // TODO: no crashy
     println!("{}", serde_json::to_string(object).unwrap());
 }
 
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub fn object_to_stdout(object: &impl Serialize) {
+    crate::testing::write_command(serde_json::to_string(object).unwrap());
+}
+
 /// Post a message to a worker of this plugin, for more information please see [Plugin Workers](https://zellij.dev/documentation/plugin-api-workers.md)
 pub fn post_message_to(plugin_message: PluginMessage) {
     let plugin_command = PluginCommand::PostMessageTo(plugin_message);
@@ -2081,7 +2405,13 @@ pub fn run_action(action: Action, context: BTreeMap<String, String>) {
     unsafe { host_run_plugin_command() };
 }
 
+#[cfg(not(feature = "testing"))]
 #[link(wasm_import_module = "zellij")]
 extern "C" {
     fn host_run_plugin_command();
 }
+
+#[cfg(feature = "testing")]
+unsafe fn host_run_plugin_command() {
+    crate::testing::run_plugin_command();
+}
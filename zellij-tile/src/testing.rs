@@ -0,0 +1,294 @@
+//! Run plugins natively in unit tests, against a fake host.
+//!
+//! With the `testing` feature the [commands](crate::shim) a plugin issues are recorded instead
+//! of being sent to Zellij, so a test can drive a [`ZellijPlugin`] through a
+//! [`PluginTestHarness`] without wasm or a running server:
+//!
+//! ```rust,ignore
+//! use zellij_tile::prelude::*;
+//! use zellij_tile::testing::PluginTestHarness;
+//!
+//! #[test]
+//! fn shows_the_tab_count() {
+//!     let mut harness = PluginTestHarness::<MyPlugin>::new();
+//!     harness.load(BTreeMap::new());
+//!     assert!(harness.commands().contains(&PluginCommand::Subscribe(
+//!         [EventType::TabUpdate].into()
+//!     )));
+//!     harness.update(Event::TabUpdate(vec![TabInfo::default()]));
+//!     insta::assert_snapshot!(harness.render(10, 80));
+//! }
+//! ```
+//!
+//! What a plugin writes to stdout while rendering is captured (on unix), as is what it prints
+//! with the [`print!`](crate::output::print) and [`println!`](crate::output::println) macros of
+//! this crate or its UI components. The standard `print!` and `println!` are the exception when
+//! the test's output is captured by the test runner, as it is by default with `cargo test`: what
+//! they print never reaches stdout, so it is only captured with `--nocapture`. Plugins that
+//! import the macros of this crate instead are tested the same way regardless:
+//!
+//! ```rust
+//! use zellij_tile::prelude::*;
+//! use zellij_tile::output::{print, println};
+//! ```
+//!
+//! The fake host is kept per thread, so tests can run in parallel as long as each of them uses a
+//! single harness.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::{TryFrom, TryInto};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use prost::Message;
+use zellij_utils::data::{Event, EventType, PipeMessage, PluginCommand};
+use zellij_utils::errors::prelude::*;
+use zellij_utils::plugin_api::event::ProtobufEvent;
+use zellij_utils::plugin_api::pipe_message::ProtobufPipeMessage;
+use zellij_utils::plugin_api::plugin_command::ProtobufPluginCommand;
+
+use crate::ZellijPlugin;
+
+type Responder = Box<dyn FnMut(&PluginCommand) -> Option<Vec<u8>>>;
+
+#[derive(Default)]
+struct FakeHost {
+    // the last object the plugin wrote to its "stdout", ie. the command it is about to run
+    pending_command: Option<String>,
+    // the response to the last command, read by the plugin from its "stdin"
+    pending_response: Option<Vec<u8>>,
+    commands: Vec<PluginCommand>,
+    responders: Vec<Responder>,
+    subscriptions: HashSet<EventType>,
+    output: String,
+    // stdout is redirected to be captured, see `capture_stdout`
+    capturing_stdout: bool,
+}
+
+thread_local! {
+    static HOST: RefCell<FakeHost> = RefCell::new(FakeHost::default());
+}
+
+pub(crate) fn write_command(serialized_command: String) {
+    HOST.with(|host| host.borrow_mut().pending_command = Some(serialized_command));
+}
+
+pub(crate) fn run_plugin_command() {
+    let command = HOST
+        .with(|host| host.borrow_mut().pending_command.take())
+        .and_then(|serialized_command| serde_json::from_str::<Vec<u8>>(&serialized_command).ok())
+        .and_then(|bytes| ProtobufPluginCommand::decode(bytes.as_slice()).ok())
+        .and_then(|protobuf_command| PluginCommand::try_from(protobuf_command).ok())
+        .expect("plugin ran a command that cannot be deserialized");
+    // responders are taken out while they run so that the host is not borrowed when they do
+    let mut responders = HOST.with(|host| std::mem::take(&mut host.borrow_mut().responders));
+    let response = responders
+        .iter_mut()
+        .find_map(|responder| responder(&command));
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        host.responders = responders;
+        match &command {
+            PluginCommand::Subscribe(event_types) => {
+                host.subscriptions.extend(event_types.iter().cloned())
+            },
+            PluginCommand::Unsubscribe(event_types) => host
+                .subscriptions
+                .retain(|event_type| !event_types.contains(event_type)),
+            _ => {},
+        }
+        host.pending_response = response;
+        host.commands.push(command);
+    });
+}
+
+pub(crate) fn read_response() -> Result<Vec<u8>> {
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        match host.pending_response.take() {
+            Some(response) => Ok(response),
+            None => Err(anyhow!(
+                "no response was set up for {:?}, see PluginTestHarness::on_command",
+                host.commands.last()
+            )),
+        }
+    })
+}
+
+pub(crate) fn write_output(args: fmt::Arguments) {
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        if host.capturing_stdout {
+            // written to stdout as well so that it stays in order with what the plugin writes
+            // there directly
+            let _ = std::io::Write::write_fmt(&mut std::io::stdout(), args);
+        } else {
+            let _ = host.output.write_fmt(args);
+        }
+    });
+}
+
+/// Runs `f` with stdout redirected to a temporary file, returning what was written to it
+///
+/// Stdout is shared by the whole process, so tests rendering in parallel take turns.
+#[cfg(unix)]
+fn capture_stdout(f: impl FnOnce()) -> String {
+    use std::io::{Read, Seek, SeekFrom, Write};
+    use std::os::unix::io::{AsRawFd, RawFd};
+    use std::sync::Mutex;
+
+    struct RedirectedStdout {
+        original_stdout: RawFd,
+    }
+
+    impl Drop for RedirectedStdout {
+        fn drop(&mut self) {
+            let _ = std::io::stdout().flush();
+            unsafe {
+                libc::dup2(self.original_stdout, libc::STDOUT_FILENO);
+                libc::close(self.original_stdout);
+            }
+            HOST.with(|host| {
+                if let Ok(mut host) = host.try_borrow_mut() {
+                    host.capturing_stdout = false;
+                }
+            });
+        }
+    }
+
+    static STDOUT: Mutex<()> = Mutex::new(());
+    let _stdout = STDOUT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut captured = match tempfile::tempfile() {
+        Ok(captured) => captured,
+        Err(_) => {
+            f();
+            return String::new();
+        },
+    };
+    let _ = std::io::stdout().flush();
+    let original_stdout = unsafe { libc::dup(libc::STDOUT_FILENO) };
+    if original_stdout < 0 {
+        f();
+        return String::new();
+    }
+    if unsafe { libc::dup2(captured.as_raw_fd(), libc::STDOUT_FILENO) } < 0 {
+        unsafe { libc::close(original_stdout) };
+        f();
+        return String::new();
+    }
+    {
+        let _redirected_stdout = RedirectedStdout { original_stdout };
+        HOST.with(|host| host.borrow_mut().capturing_stdout = true);
+        f();
+    }
+    let mut output = String::new();
+    let _ = captured.seek(SeekFrom::Start(0));
+    let _ = captured.read_to_string(&mut output);
+    output
+}
+
+#[cfg(not(unix))]
+fn capture_stdout(f: impl FnOnce()) -> String {
+    f();
+    String::new()
+}
+
+/// Drives a plugin the way Zellij would, recording the commands it issues and capturing what it
+/// renders
+///
+/// Events and pipe messages are serialized and deserialized on their way to the plugin, and
+/// events it did not subscribe to are not delivered, as they would be by Zellij.
+pub struct PluginTestHarness<P: ZellijPlugin> {
+    plugin: P,
+}
+
+impl<P: ZellijPlugin> Default for PluginTestHarness<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: ZellijPlugin> PluginTestHarness<P> {
+    /// Creates a harness for a default instance of the plugin, resetting this thread's fake host
+    pub fn new() -> Self {
+        PluginTestHarness::with_plugin(P::default())
+    }
+    /// Creates a harness for an already constructed plugin, resetting this thread's fake host
+    pub fn with_plugin(plugin: P) -> Self {
+        HOST.with(|host| *host.borrow_mut() = FakeHost::default());
+        PluginTestHarness { plugin }
+    }
+    /// Answers the commands that expect a response from Zellij (eg. `get_plugin_ids`,
+    /// `kv_get`) with the protobuf encoded response returned by `responder`
+    ///
+    /// Responders are tried in the order they were added until one of them returns a response.
+    /// A plugin waiting for a response nobody provided fails the way it would if Zellij sent it
+    /// garbage, which usually means a panic.
+    pub fn on_command(
+        &mut self,
+        responder: impl FnMut(&PluginCommand) -> Option<Vec<u8>> + 'static,
+    ) -> &mut Self {
+        HOST.with(|host| host.borrow_mut().responders.push(Box::new(responder)));
+        self
+    }
+    pub fn load(&mut self, configuration: BTreeMap<String, String>) {
+        self.plugin.load(configuration);
+    }
+    /// Sends the event to the plugin if it subscribed to it, returns whether it asked to render
+    pub fn update(&mut self, event: Event) -> bool {
+        let event_type = EventType::from_str(&event.to_string())
+            .expect("every event has an event type of the same name");
+        if !self.is_subscribed(event_type) {
+            return false;
+        }
+        let protobuf_event: ProtobufEvent = event.try_into().expect("event can be sent to plugins");
+        let event = ProtobufEvent::decode(protobuf_event.encode_to_vec().as_slice())
+            .ok()
+            .and_then(|protobuf_event| Event::try_from(protobuf_event).ok())
+            .expect("event can be received by plugins");
+        self.plugin.update(event)
+    }
+    /// Sends the pipe message to the plugin, returns whether it asked to render
+    pub fn pipe(&mut self, pipe_message: PipeMessage) -> bool {
+        let protobuf_pipe_message: ProtobufPipeMessage = pipe_message
+            .try_into()
+            .expect("pipe message can be sent to plugins");
+        let pipe_message =
+            ProtobufPipeMessage::decode(protobuf_pipe_message.encode_to_vec().as_slice())
+                .ok()
+                .and_then(|protobuf_pipe_message| PipeMessage::try_from(protobuf_pipe_message).ok())
+                .expect("pipe message can be received by plugins");
+        self.plugin.pipe(pipe_message)
+    }
+    /// Renders the plugin at the given size, returning everything it printed
+    pub fn render(&mut self, rows: usize, cols: usize) -> String {
+        HOST.with(|host| host.borrow_mut().output.clear());
+        let plugin = &mut self.plugin;
+        let stdout = capture_stdout(|| plugin.render(rows, cols));
+        HOST.with(|host| std::mem::take(&mut host.borrow_mut().output) + &stdout)
+    }
+    /// All the commands the plugin issued so far
+    pub fn commands(&self) -> Vec<PluginCommand> {
+        HOST.with(|host| host.borrow().commands.clone())
+    }
+    /// The commands the plugin issued since the last call to this method
+    pub fn take_commands(&mut self) -> Vec<PluginCommand> {
+        HOST.with(|host| std::mem::take(&mut host.borrow_mut().commands))
+    }
+    pub fn is_subscribed(&self, event_type: EventType) -> bool {
+        HOST.with(|host| host.borrow().subscriptions.contains(&event_type))
+    }
+    pub fn plugin(&self) -> &P {
+        &self.plugin
+    }
+    pub fn plugin_mut(&mut self) -> &mut P {
+        &mut self.plugin
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/testing_tests.rs"]
+mod testing_tests;
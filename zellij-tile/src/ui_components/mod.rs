@@ -1,12 +1,22 @@
 mod nested_list;
+mod progress;
 mod ribbon;
+mod scroll_list;
+mod sparkline;
 mod table;
 mod text;
+mod text_input;
+mod tree;
 
 pub use prost::{self, *};
 pub use zellij_utils::plugin_api;
 
 pub use nested_list::*;
+pub use progress::*;
 pub use ribbon::*;
+pub use scroll_list::*;
+pub use sparkline::*;
 pub use table::*;
 pub use text::*;
+pub use text_input::*;
+pub use tree::*;
@@ -1,4 +1,5 @@
 use super::Text;
+use crate::output::print;
 use std::borrow::Borrow;
 use std::ops::RangeBounds;
 
@@ -0,0 +1,144 @@
+use super::Text;
+use crate::output::print;
+
+#[derive(Debug, Default, Clone)]
+pub struct ProgressBar {
+    progress: f64,
+    label: Option<Text>,
+}
+
+impl ProgressBar {
+    /// `progress` is between 0.0 and 1.0, values outside this range are clamped by the host
+    pub fn new(progress: f64) -> Self {
+        ProgressBar {
+            progress,
+            label: None,
+        }
+    }
+    pub fn with_label(mut self, label: Text) -> Self {
+        self.label = Some(label);
+        self
+    }
+    pub fn set_progress(&mut self, progress: f64) {
+        self.progress = progress;
+    }
+    pub fn progress(&self) -> f64 {
+        self.progress
+    }
+    pub fn serialize(&self) -> String {
+        format!("{};{}", self.progress, serialize_label(&self.label))
+    }
+}
+
+/// A spinner that moves one frame every time it is ticked, usually on a timer
+#[derive(Debug, Default, Clone)]
+pub struct Spinner {
+    frame: usize,
+    label: Option<Text>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Spinner::default()
+    }
+    pub fn with_label(mut self, label: Text) -> Self {
+        self.label = Some(label);
+        self
+    }
+    pub fn set_label(&mut self, label: Option<Text>) {
+        self.label = label;
+    }
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+    pub fn serialize(&self) -> String {
+        format!("{};{}", self.frame, serialize_label(&self.label))
+    }
+}
+
+fn serialize_label(label: &Option<Text>) -> String {
+    label.as_ref().map(|l| l.serialize()).unwrap_or_default()
+}
+
+pub fn print_progress_bar(progress_bar: &ProgressBar) {
+    print!("{}", serialize_progress_bar(progress_bar))
+}
+
+pub fn print_progress_bar_with_coordinates(
+    progress_bar: &ProgressBar,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_progress_bar_with_coordinates(progress_bar, x, y, width, height)
+    )
+}
+
+pub fn serialize_progress_bar(progress_bar: &ProgressBar) -> String {
+    format!("\u{1b}Pzprogress_bar;{}\u{1b}\\", progress_bar.serialize())
+}
+
+pub fn serialize_progress_bar_with_coordinates(
+    progress_bar: &ProgressBar,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pzprogress_bar;{}/{}/{}/{};{}\u{1b}\\",
+        x,
+        y,
+        width,
+        height,
+        progress_bar.serialize()
+    )
+}
+
+pub fn print_spinner(spinner: &Spinner) {
+    print!("{}", serialize_spinner(spinner))
+}
+
+pub fn print_spinner_with_coordinates(
+    spinner: &Spinner,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_spinner_with_coordinates(spinner, x, y, width, height)
+    )
+}
+
+pub fn serialize_spinner(spinner: &Spinner) -> String {
+    format!("\u{1b}Pzspinner;{}\u{1b}\\", spinner.serialize())
+}
+
+pub fn serialize_spinner_with_coordinates(
+    spinner: &Spinner,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pzspinner;{}/{}/{}/{};{}\u{1b}\\",
+        x,
+        y,
+        width,
+        height,
+        spinner.serialize()
+    )
+}
@@ -0,0 +1,173 @@
+use super::Text;
+use crate::output::print;
+
+/// A list with a selected item that scrolls to keep it in view
+///
+/// Only the items that fit in the height it is rendered at are sent to the host, so it can hold
+/// any number of items. The host draws a scrollbar if some of them are not visible.
+#[derive(Debug, Default, Clone)]
+pub struct ScrollList {
+    items: Vec<Text>,
+    selected: Option<usize>,
+    offset: usize,
+}
+
+impl ScrollList {
+    pub fn new(items: Vec<Text>) -> Self {
+        ScrollList {
+            items,
+            ..Default::default()
+        }
+    }
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.select(index);
+        self
+    }
+    /// Replaces the items, keeping the selection and scroll position where possible
+    pub fn set_items(&mut self, items: Vec<Text>) {
+        self.items = items;
+        self.selected = match self.selected {
+            Some(_) if self.items.is_empty() => None,
+            Some(selected) => Some(selected.min(self.items.len() - 1)),
+            None => None,
+        };
+        self.offset = self.offset.min(self.items.len().saturating_sub(1));
+    }
+    pub fn items(&self) -> &[Text] {
+        &self.items
+    }
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+    pub fn selected_item(&self) -> Option<&Text> {
+        self.selected.and_then(|selected| self.items.get(selected))
+    }
+    pub fn select(&mut self, index: usize) {
+        if !self.items.is_empty() {
+            self.selected = Some(index.min(self.items.len() - 1));
+        }
+    }
+    pub fn clear_selection(&mut self) {
+        self.selected = None;
+    }
+    /// Selects the next item, or the first one if none is selected
+    pub fn select_next(&mut self) {
+        match self.selected {
+            Some(selected) => self.select(selected + 1),
+            None => self.select(0),
+        }
+    }
+    /// Selects the previous item, or the first one if none is selected
+    pub fn select_previous(&mut self) {
+        match self.selected {
+            Some(selected) => self.select(selected.saturating_sub(1)),
+            None => self.select(0),
+        }
+    }
+    pub fn select_first(&mut self) {
+        self.select(0);
+    }
+    pub fn select_last(&mut self) {
+        self.select(self.items.len().saturating_sub(1));
+    }
+    pub fn page_down(&mut self, rows: usize) {
+        let selected = self.selected.unwrap_or(0);
+        self.select(selected + rows.max(1));
+    }
+    pub fn page_up(&mut self, rows: usize) {
+        let selected = self.selected.unwrap_or(0);
+        self.select(selected.saturating_sub(rows.max(1)));
+    }
+    /// The index of the first visible item
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Serializes the items that fit in `rows` (or all of them if it is None), scrolling the
+    /// list first if the selected item would not be visible
+    pub fn serialize(&mut self, rows: Option<usize>) -> String {
+        let rows = rows.unwrap_or(self.items.len());
+        self.offset = scroll_offset(self.offset, self.selected, self.items.len(), rows);
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(rows)
+            .map(|(index, item)| {
+                if Some(index) == self.selected {
+                    item.clone().selected().serialize()
+                } else {
+                    item.serialize()
+                }
+            })
+            .collect::<Vec<_>>();
+        serialize_window(self.items.len(), self.offset, items)
+    }
+}
+
+/// The smallest change to `offset` that keeps `selected` visible in a window of `rows`
+pub(crate) fn scroll_offset(
+    offset: usize,
+    selected: Option<usize>,
+    total: usize,
+    rows: usize,
+) -> usize {
+    let max_offset = total.saturating_sub(rows);
+    let offset = match selected {
+        Some(selected) if selected < offset => selected,
+        Some(selected) if rows > 0 && selected >= offset + rows => selected + 1 - rows,
+        _ => offset,
+    };
+    offset.min(max_offset)
+}
+
+pub(crate) fn serialize_window(total: usize, offset: usize, items: Vec<String>) -> String {
+    if items.is_empty() {
+        format!("{};{}", total, offset)
+    } else {
+        format!("{};{};{}", total, offset, items.join(";"))
+    }
+}
+
+pub fn print_scroll_list(list: &mut ScrollList) {
+    print!("{}", serialize_scroll_list(list))
+}
+
+pub fn print_scroll_list_with_coordinates(
+    list: &mut ScrollList,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_scroll_list_with_coordinates(list, x, y, width, height)
+    )
+}
+
+pub fn serialize_scroll_list(list: &mut ScrollList) -> String {
+    format!("\u{1b}Pzscroll_list;{}\u{1b}\\", list.serialize(None))
+}
+
+pub fn serialize_scroll_list_with_coordinates(
+    list: &mut ScrollList,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let serialized = list.serialize(height);
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pzscroll_list;{}/{}/{}/{};{}\u{1b}\\",
+        x, y, width, height, serialized
+    )
+}
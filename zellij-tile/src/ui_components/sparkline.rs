@@ -0,0 +1,85 @@
+use crate::output::print;
+
+/// A one line chart of a series of values, one cell per value
+///
+/// The values are scaled so that `max` fills a whole cell, if it is not set the largest value is
+/// used. Only the most recent values that fit in the width are shown.
+#[derive(Debug, Default, Clone)]
+pub struct Sparkline {
+    values: Vec<f64>,
+    max: Option<f64>,
+}
+
+impl Sparkline {
+    pub fn new(values: Vec<f64>) -> Self {
+        Sparkline { values, max: None }
+    }
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+    pub fn push(&mut self, value: f64) {
+        self.values.push(value);
+    }
+    /// Drops the oldest values so that at most `len` remain
+    pub fn truncate_front(&mut self, len: usize) {
+        let excess = self.values.len().saturating_sub(len);
+        self.values.drain(..excess);
+    }
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+    pub fn serialize(&self) -> String {
+        let max = self.max.map(|m| m.to_string()).unwrap_or_default();
+        let values = self
+            .values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            max
+        } else {
+            format!("{};{}", max, values.join(";"))
+        }
+    }
+}
+
+pub fn print_sparkline(sparkline: &Sparkline) {
+    print!("{}", serialize_sparkline(sparkline))
+}
+
+pub fn print_sparkline_with_coordinates(
+    sparkline: &Sparkline,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_sparkline_with_coordinates(sparkline, x, y, width, height)
+    )
+}
+
+pub fn serialize_sparkline(sparkline: &Sparkline) -> String {
+    format!("\u{1b}Pzsparkline;{}\u{1b}\\", sparkline.serialize())
+}
+
+pub fn serialize_sparkline_with_coordinates(
+    sparkline: &Sparkline,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pzsparkline;{}/{}/{}/{};{}\u{1b}\\",
+        x,
+        y,
+        width,
+        height,
+        sparkline.serialize()
+    )
+}
@@ -1,4 +1,5 @@
 use super::Text;
+use crate::output::print;
 
 /// render a table with arbitrary data
 #[derive(Debug, Clone)]
@@ -1,3 +1,4 @@
+use crate::output::print;
 use std::ops::Bound;
 use std::ops::RangeBounds;
 
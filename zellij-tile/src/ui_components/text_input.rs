@@ -1,7 +1,6 @@
-// This is a duplicate of the same file in the sequence (and possibly other) plugins
-// Right now there's no good place to put them for shared logic except the zellij-tile-utils crate
-// (which I'd rather eliminate), hence the duplication.
-use zellij_tile::prelude::*;
+use super::Text;
+use crate::output::print;
+use zellij_utils::data::{BareKey, KeyModifier, KeyWithModifier};
 
 const MAX_UNDO_STACK_SIZE: usize = 100;
 
@@ -22,6 +21,9 @@ pub enum InputAction {
 }
 
 /// A reusable text input component with cursor support and standard editing keybindings
+///
+/// Feed it key events with `handle_key` and render it with `print_text_input`, the host scrolls
+/// it horizontally to keep the cursor in view
 #[derive(Debug, Clone)]
 pub struct TextInput {
     buffer: String,
@@ -29,6 +31,8 @@ pub struct TextInput {
     undo_stack: Vec<(String, usize)>, // (buffer, cursor) snapshots
     redo_stack: Vec<(String, usize)>,
     last_edit_was_insert: bool, // For coalescing consecutive inserts
+    placeholder: Option<String>,
+    focused: bool,
 }
 
 impl TextInput {
@@ -42,9 +46,26 @@ impl TextInput {
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
             last_edit_was_insert: false,
+            placeholder: None,
+            focused: true,
         }
     }
 
+    /// Text shown (dimmed) while the input is empty
+    pub fn with_placeholder<S: ToString>(mut self, placeholder: S) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Unfocused inputs are rendered without a cursor
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
     /// Create an empty TextInput
     pub fn empty() -> Self {
         Self::new(String::new())
@@ -66,19 +87,16 @@ impl TextInput {
     }
 
     /// Get a shorthand for cursor_position
-    #[allow(unused)]
     pub fn cursor_position(&self) -> usize {
         self.cursor_position
     }
 
     /// Get mutable access to the underlying buffer for direct manipulation
-    #[allow(unused)]
     pub fn get_text_mut(&mut self) -> &mut String {
         &mut self.buffer
     }
 
     /// Set the text and move cursor to the end
-    #[allow(unused)]
     pub fn set_text(&mut self, text: String) {
         self.break_coalescing();
         self.save_undo_state();
@@ -87,7 +105,6 @@ impl TextInput {
     }
 
     /// Set cursor position (clamped to text length)
-    #[allow(unused)]
     pub fn set_cursor_position(&mut self, pos: usize) {
         let text_len = self.buffer.chars().count();
         self.cursor_position = pos.min(text_len);
@@ -442,27 +459,79 @@ impl TextInput {
     }
 
     /// Check if undo is available
-    #[allow(unused)]
     pub fn can_undo(&self) -> bool {
         !self.undo_stack.is_empty()
     }
 
     /// Check if redo is available
-    #[allow(unused)]
     pub fn can_redo(&self) -> bool {
         !self.redo_stack.is_empty()
     }
 
-    #[allow(unused)]
     pub fn drain_text(&mut self) -> String {
         self.cursor_position = 0;
         self.buffer.drain(..).collect()
     }
+
+    pub fn serialize(&self) -> String {
+        let cursor = if self.focused {
+            self.cursor_position.to_string()
+        } else {
+            String::new()
+        };
+        let value = Text::new(&self.buffer).serialize();
+        match &self.placeholder {
+            Some(placeholder) => format!(
+                "{};{};{}",
+                cursor,
+                value,
+                Text::new(placeholder).serialize()
+            ),
+            None => format!("{};{}", cursor, value),
+        }
+    }
+}
+
+pub fn print_text_input(text_input: &TextInput) {
+    print!("{}", serialize_text_input(text_input))
+}
+
+pub fn print_text_input_with_coordinates(
+    text_input: &TextInput,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_text_input_with_coordinates(text_input, x, y, width, height)
+    )
+}
+
+pub fn serialize_text_input(text_input: &TextInput) -> String {
+    format!("\u{1b}Pztext_input;{}\u{1b}\\", text_input.serialize())
+}
+
+pub fn serialize_text_input_with_coordinates(
+    text_input: &TextInput,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pztext_input;{}/{}/{}/{};{}\u{1b}\\",
+        x,
+        y,
+        width,
+        height,
+        text_input.serialize()
+    )
 }
 
-// run with:
-// cargo test --lib --target x86_64-unknown-linux-gnu
-//
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,242 @@
+use super::scroll_list::{scroll_offset, serialize_window};
+use super::Text;
+use crate::output::print;
+
+#[derive(Debug, Default, Clone)]
+pub struct TreeNode {
+    pub content: Text,
+    pub children: Vec<TreeNode>,
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    pub fn new(content: Text) -> Self {
+        TreeNode {
+            content,
+            ..Default::default()
+        }
+    }
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+    pub fn expanded(mut self) -> Self {
+        self.expanded = true;
+        self
+    }
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// A scrollable tree of collapsible nodes, with a selected node
+///
+/// The selection is an index into the currently visible (expanded) nodes, use `selected_path` to
+/// find the node it points to.
+#[derive(Debug, Default, Clone)]
+pub struct Tree {
+    roots: Vec<TreeNode>,
+    selected: Option<usize>,
+    offset: usize,
+}
+
+struct VisibleNode<'a> {
+    node: &'a TreeNode,
+    depth: usize,
+    path: Vec<usize>,
+}
+
+impl Tree {
+    pub fn new(roots: Vec<TreeNode>) -> Self {
+        Tree {
+            roots,
+            ..Default::default()
+        }
+    }
+    pub fn roots(&self) -> &[TreeNode] {
+        &self.roots
+    }
+    pub fn set_roots(&mut self, roots: Vec<TreeNode>) {
+        self.roots = roots;
+        self.clamp_selection();
+    }
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+    pub fn select(&mut self, index: usize) {
+        let visible_count = self.visible_nodes().len();
+        if visible_count > 0 {
+            self.selected = Some(index.min(visible_count - 1));
+        }
+    }
+    pub fn select_next(&mut self) {
+        match self.selected {
+            Some(selected) => self.select(selected + 1),
+            None => self.select(0),
+        }
+    }
+    pub fn select_previous(&mut self) {
+        match self.selected {
+            Some(selected) => self.select(selected.saturating_sub(1)),
+            None => self.select(0),
+        }
+    }
+    /// The indices of the selected node and its ancestors, starting from the root
+    pub fn selected_path(&self) -> Option<Vec<usize>> {
+        let selected = self.selected?;
+        self.visible_nodes()
+            .into_iter()
+            .nth(selected)
+            .map(|visible_node| visible_node.path)
+    }
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        let path = self.selected_path()?;
+        self.node_at(&path)
+    }
+    pub fn selected_node_mut(&mut self) -> Option<&mut TreeNode> {
+        let path = self.selected_path()?;
+        self.node_at_mut(&path)
+    }
+    pub fn expand_selected(&mut self) {
+        self.set_selected_expanded(true);
+    }
+    pub fn collapse_selected(&mut self) {
+        self.set_selected_expanded(false);
+    }
+    pub fn toggle_selected(&mut self) {
+        if let Some(node) = self.selected_node() {
+            let expanded = node.expanded;
+            self.set_selected_expanded(!expanded);
+        }
+    }
+    pub fn serialize(&mut self, rows: Option<usize>) -> String {
+        let (total, offset, items) = {
+            let visible_nodes = self.visible_nodes();
+            let total = visible_nodes.len();
+            let rows = rows.unwrap_or(total);
+            let offset = scroll_offset(self.offset, self.selected, total, rows);
+            let items = visible_nodes
+                .into_iter()
+                .enumerate()
+                .skip(offset)
+                .take(rows)
+                .map(|(index, visible_node)| {
+                    let marker = if visible_node.node.is_leaf() {
+                        ""
+                    } else if visible_node.node.expanded {
+                        "-"
+                    } else {
+                        "+"
+                    };
+                    let content = if Some(index) == self.selected {
+                        visible_node.node.content.clone().selected().serialize()
+                    } else {
+                        visible_node.node.content.serialize()
+                    };
+                    format!("{}{}{}", "|".repeat(visible_node.depth), marker, content)
+                })
+                .collect::<Vec<_>>();
+            (total, offset, items)
+        };
+        self.offset = offset;
+        serialize_window(total, offset, items)
+    }
+    fn set_selected_expanded(&mut self, expanded: bool) {
+        if let Some(node) = self.selected_node_mut() {
+            if !node.is_leaf() {
+                node.expanded = expanded;
+            }
+        }
+        self.clamp_selection();
+    }
+    fn clamp_selection(&mut self) {
+        let visible_count = self.visible_nodes().len();
+        self.selected = match self.selected {
+            Some(_) if visible_count == 0 => None,
+            Some(selected) => Some(selected.min(visible_count - 1)),
+            None => None,
+        };
+    }
+    fn visible_nodes(&self) -> Vec<VisibleNode<'_>> {
+        let mut visible_nodes = vec![];
+        collect_visible_nodes(&self.roots, 0, &mut vec![], &mut visible_nodes);
+        visible_nodes
+    }
+    fn node_at(&self, path: &[usize]) -> Option<&TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get(*first)?;
+        for index in rest {
+            node = node.children.get(*index)?;
+        }
+        Some(node)
+    }
+    fn node_at_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get_mut(*first)?;
+        for index in rest {
+            node = node.children.get_mut(*index)?;
+        }
+        Some(node)
+    }
+}
+
+fn collect_visible_nodes<'a>(
+    nodes: &'a [TreeNode],
+    depth: usize,
+    path: &mut Vec<usize>,
+    visible_nodes: &mut Vec<VisibleNode<'a>>,
+) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        visible_nodes.push(VisibleNode {
+            node,
+            depth,
+            path: path.clone(),
+        });
+        if node.expanded {
+            collect_visible_nodes(&node.children, depth + 1, path, visible_nodes);
+        }
+        path.pop();
+    }
+}
+
+pub fn print_tree(tree: &mut Tree) {
+    print!("{}", serialize_tree(tree))
+}
+
+pub fn print_tree_with_coordinates(
+    tree: &mut Tree,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) {
+    print!(
+        "{}",
+        serialize_tree_with_coordinates(tree, x, y, width, height)
+    )
+}
+
+pub fn serialize_tree(tree: &mut Tree) -> String {
+    format!("\u{1b}Pztree;{}\u{1b}\\", tree.serialize(None))
+}
+
+pub fn serialize_tree_with_coordinates(
+    tree: &mut Tree,
+    x: usize,
+    y: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+) -> String {
+    let serialized = tree.serialize(height);
+    let width = width.map(|w| w.to_string()).unwrap_or_default();
+    let height = height.map(|h| h.to_string()).unwrap_or_default();
+    format!(
+        "\u{1b}Pztree;{}/{}/{}/{};{}\u{1b}\\",
+        x, y, width, height, serialized
+    )
+}
+
+#[cfg(test)]
+#[path = "./unit/tree_tests.rs"]
+mod tree_tests;
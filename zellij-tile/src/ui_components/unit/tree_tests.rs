@@ -0,0 +1,108 @@
+use super::*;
+use crate::ui_components::{ScrollList, Text};
+
+fn tree() -> Tree {
+    Tree::new(vec![
+        TreeNode::new(Text::new("src")).with_children(vec![
+            TreeNode::new(Text::new("main.rs")),
+            TreeNode::new(Text::new("lib.rs")),
+        ]),
+        TreeNode::new(Text::new("Cargo.toml")),
+    ])
+}
+
+#[test]
+fn collapsed_nodes_hide_their_children() {
+    let mut tree = tree();
+    tree.select(0);
+    assert_eq!(
+        tree.serialize(None),
+        format!(
+            "2;0;+{};{}",
+            Text::new("src").selected().serialize(),
+            Text::new("Cargo.toml").serialize()
+        )
+    );
+}
+
+#[test]
+fn expanded_nodes_show_their_children_indented() {
+    let mut tree = tree();
+    tree.select(0);
+    tree.expand_selected();
+    tree.select_next();
+    assert_eq!(tree.selected_path(), Some(vec![0, 0]));
+    assert_eq!(
+        tree.serialize(None),
+        format!(
+            "4;0;-{};|{};|{};{}",
+            Text::new("src").serialize(),
+            Text::new("main.rs").selected().serialize(),
+            Text::new("lib.rs").serialize(),
+            Text::new("Cargo.toml").serialize()
+        )
+    );
+}
+
+#[test]
+fn collapsing_clamps_the_selection() {
+    let mut tree = tree();
+    tree.select(0);
+    tree.expand_selected();
+    tree.select(3);
+    assert_eq!(tree.selected_path(), Some(vec![1]));
+    tree.select(0);
+    tree.collapse_selected();
+    tree.select(3);
+    assert_eq!(tree.selected(), Some(1));
+    assert_eq!(tree.selected_path(), Some(vec![1]));
+}
+
+#[test]
+fn tree_scrolls_to_keep_the_selection_visible() {
+    let mut tree = tree();
+    tree.select(0);
+    tree.toggle_selected();
+    tree.select(3);
+    assert_eq!(
+        tree.serialize(Some(2)),
+        format!(
+            "4;2;|{};{}",
+            Text::new("lib.rs").serialize(),
+            Text::new("Cargo.toml").selected().serialize()
+        )
+    );
+    tree.select(1);
+    assert!(tree.serialize(Some(2)).starts_with("4;1;"));
+}
+
+#[test]
+fn scroll_list_only_serializes_the_visible_window() {
+    let items = (0..10).map(|i| Text::new(i.to_string())).collect();
+    let mut list = ScrollList::new(items).with_selected(5);
+    assert_eq!(
+        list.serialize(Some(3)),
+        format!(
+            "10;3;{};{};{}",
+            Text::new("3").serialize(),
+            Text::new("4").serialize(),
+            Text::new("5").selected().serialize()
+        )
+    );
+    list.page_down(3);
+    list.select_last();
+    assert!(list.serialize(Some(3)).starts_with("10;7;"));
+    list.select_first();
+    assert!(list.serialize(Some(3)).starts_with("10;0;"));
+}
+
+#[test]
+fn scroll_list_keeps_selection_when_items_shrink() {
+    let items = (0..10).map(|i| Text::new(i.to_string())).collect();
+    let mut list = ScrollList::new(items).with_selected(9);
+    list.set_items(vec![Text::new("a"), Text::new("b")]);
+    assert_eq!(list.selected(), Some(1));
+    list.set_items(vec![]);
+    assert_eq!(list.selected(), None);
+    assert_eq!(list.serialize(Some(3)), "0;0");
+}
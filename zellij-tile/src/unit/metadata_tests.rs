@@ -0,0 +1,35 @@
+use super::{plugin_metadata, ZELLIJ_TILE_VERSION};
+use zellij_utils::data::PermissionType;
+use zellij_utils::plugin_metadata::{PluginMetadata, PLUGIN_API_VERSION};
+
+const PERMISSIONS: &[&str] = &["ReadApplicationState", "RunCommands"];
+const METADATA_LEN: usize = plugin_metadata::<0>(Some(PERMISSIONS)).size();
+static METADATA: [u8; METADATA_LEN] =
+    plugin_metadata::<METADATA_LEN>(Some(PERMISSIONS)).into_bytes();
+
+#[test]
+fn metadata_rendered_at_compile_time_can_be_read_by_the_host() {
+    let section = std::str::from_utf8(&METADATA).unwrap();
+    assert_eq!(
+        PluginMetadata::from_section(section),
+        Ok(PluginMetadata {
+            api_version: PLUGIN_API_VERSION,
+            zellij_tile_version: Some(ZELLIJ_TILE_VERSION.to_owned()),
+            permissions: Some(vec![
+                PermissionType::ReadApplicationState,
+                PermissionType::RunCommands
+            ]),
+        })
+    );
+}
+
+#[test]
+fn undeclared_permissions() {
+    const LEN: usize = plugin_metadata::<0>(None).size();
+    let metadata = plugin_metadata::<LEN>(None).into_bytes();
+    let section = std::str::from_utf8(&metadata).unwrap();
+    assert_eq!(
+        PluginMetadata::from_section(section).unwrap().permissions,
+        None
+    );
+}
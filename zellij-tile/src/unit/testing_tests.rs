@@ -0,0 +1,130 @@
+use super::PluginTestHarness;
+use crate::output::println;
+use crate::prelude::*;
+use std::path::PathBuf;
+use zellij_utils::plugin_api::plugin_ids::ProtobufPluginIds;
+
+#[derive(Default)]
+struct CounterPlugin {
+    count: usize,
+    plugin_id: Option<u32>,
+}
+
+impl ZellijPlugin for CounterPlugin {
+    fn load(&mut self, _configuration: BTreeMap<String, String>) {
+        subscribe(&[EventType::Key]);
+    }
+    fn update(&mut self, event: Event) -> bool {
+        match event {
+            Event::Key(key) if key.bare_key == BareKey::Char('q') => {
+                hide_self();
+                false
+            },
+            Event::Key(_) => {
+                self.count += 1;
+                true
+            },
+            _ => false,
+        }
+    }
+    fn pipe(&mut self, pipe_message: PipeMessage) -> bool {
+        if pipe_message.name == "whoami" {
+            self.plugin_id = Some(get_plugin_ids().plugin_id);
+        }
+        true
+    }
+    fn render(&mut self, rows: usize, cols: usize) {
+        println!("{}x{}", rows, cols);
+        print_text(Text::new(format!("count: {}", self.count)).color_range(0, ..5));
+    }
+}
+
+fn key(character: char) -> Event {
+    Event::Key(KeyWithModifier::new(BareKey::Char(character)))
+}
+
+#[test]
+fn records_commands() {
+    let mut harness = PluginTestHarness::<CounterPlugin>::new();
+    harness.load(BTreeMap::new());
+    assert_eq!(
+        harness.take_commands(),
+        vec![PluginCommand::Subscribe([EventType::Key].into())]
+    );
+    harness.update(key('q'));
+    assert_eq!(harness.take_commands(), vec![PluginCommand::HideSelf]);
+    assert_eq!(harness.commands(), vec![]);
+}
+
+#[test]
+fn delivers_only_subscribed_events() {
+    let mut harness = PluginTestHarness::<CounterPlugin>::new();
+    assert!(!harness.update(key('a')), "not subscribed before load");
+    harness.load(BTreeMap::new());
+    assert!(harness.update(key('a')));
+    assert!(!harness.update(Event::Visible(true)));
+    assert_eq!(harness.plugin().count, 1);
+}
+
+#[test]
+fn answers_commands_with_responders() {
+    let mut harness = PluginTestHarness::<CounterPlugin>::new();
+    harness.on_command(|command| match command {
+        PluginCommand::GetPluginIds => {
+            let plugin_ids = PluginIds {
+                plugin_id: 7,
+                zellij_pid: 1,
+                initial_cwd: PathBuf::from("/tmp"),
+                client_id: 1,
+            };
+            let protobuf_plugin_ids: ProtobufPluginIds = plugin_ids.try_into().unwrap();
+            Some(protobuf_plugin_ids.encode_to_vec())
+        },
+        _ => None,
+    });
+    harness.pipe(PipeMessage::new(
+        PipeSource::Keybind,
+        "whoami",
+        &None,
+        &None,
+        false,
+    ));
+    assert_eq!(harness.plugin().plugin_id, Some(7));
+}
+
+#[test]
+fn captures_render_output() {
+    let mut harness = PluginTestHarness::<CounterPlugin>::new();
+    harness.load(BTreeMap::new());
+    harness.update(key('a'));
+    harness.update(key('b'));
+    let text = Text::new("count: 2").color_range(0, ..5);
+    assert_eq!(
+        harness.render(10, 80),
+        format!("10x80\n{}", serialize_text(&text))
+    );
+    assert_eq!(
+        harness.render(1, 1),
+        format!("1x1\n{}", serialize_text(&text))
+    );
+}
+
+#[derive(Default)]
+struct StdoutPlugin;
+
+impl ZellijPlugin for StdoutPlugin {
+    fn render(&mut self, rows: usize, _cols: usize) {
+        use std::io::Write;
+        let mut stdout = std::io::stdout();
+        let _ = write!(stdout, "rows: ");
+        println!("{}", rows);
+        let _ = writeln!(stdout, "done");
+    }
+}
+
+#[test]
+fn captures_stdout_in_order_with_render_output() {
+    let mut harness = PluginTestHarness::<StdoutPlugin>::new();
+    assert_eq!(harness.render(3, 80), "rows: 3\ndone\n");
+    assert_eq!(harness.render(4, 80), "rows: 4\ndone\n");
+}
@@ -157,6 +157,14 @@ pub struct TiledPaneLayout {
     pub hide_floating_panes: bool,
     #[prost(string, optional, tag="13")]
     pub pane_initial_contents: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="14")]
+    pub log_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="15")]
+    pub depends_on: ::prost::alloc::vec::Vec<PaneDependency>,
+    #[prost(string, optional, tag="16")]
+    pub mark: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="17")]
+    pub tiling_mode: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -185,6 +193,33 @@ pub struct FloatingPaneLayout {
     pub logical_position: ::core::option::Option<u32>,
     #[prost(bool, optional, tag="12")]
     pub borderless: ::core::option::Option<bool>,
+    #[prost(string, optional, tag="13")]
+    pub log_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="14")]
+    pub depends_on: ::prost::alloc::vec::Vec<PaneDependency>,
+    #[prost(string, optional, tag="15")]
+    pub mark: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneDependency {
+    #[prost(string, tag="1")]
+    pub pane_name: ::prost::alloc::string::String,
+    #[prost(oneof="pane_dependency::ReadyWhen", tags="2, 3, 4")]
+    pub ready_when: ::core::option::Option<pane_dependency::ReadyWhen>,
+}
+/// Nested message and enum types in `PaneDependency`.
+pub mod pane_dependency {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ReadyWhen {
+        #[prost(bool, tag="2")]
+        ExitedSuccessfully(bool),
+        #[prost(string, tag="3")]
+        OutputMatches(::prost::alloc::string::String),
+        #[prost(uint32, tag="4")]
+        PortOpen(u32),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -577,6 +612,8 @@ pub struct DumpScreenPayload {
     pub file_path: ::prost::alloc::string::String,
     #[prost(bool, tag="2")]
     pub include_scrollback: bool,
+    #[prost(enumeration="DumpScreenFormat", optional, tag="3")]
+    pub format: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -911,6 +948,7 @@ pub enum SearchOption {
     CaseSensitivity = 0,
     WholeWord = 1,
     Wrap = 2,
+    Regex = 3,
 }
 impl SearchOption {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -922,6 +960,7 @@ impl SearchOption {
             SearchOption::CaseSensitivity => "CaseSensitivity",
             SearchOption::WholeWord => "WholeWord",
             SearchOption::Wrap => "Wrap",
+            SearchOption::Regex => "Regex",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -930,6 +969,7 @@ impl SearchOption {
             "CaseSensitivity" => Some(Self::CaseSensitivity),
             "WholeWord" => Some(Self::WholeWord),
             "Wrap" => Some(Self::Wrap),
+            "Regex" => Some(Self::Regex),
             _ => None,
         }
     }
@@ -962,6 +1002,38 @@ impl MoveTabDirection {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum DumpScreenFormat {
+    Text = 0,
+    Ansi = 1,
+    Html = 2,
+    Svg = 3,
+}
+impl DumpScreenFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DumpScreenFormat::Text => "Text",
+            DumpScreenFormat::Ansi => "Ansi",
+            DumpScreenFormat::Html => "Html",
+            DumpScreenFormat::Svg => "Svg",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Text" => Some(Self::Text),
+            "Ansi" => Some(Self::Ansi),
+            "Html" => Some(Self::Html),
+            "Svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ActionName {
     Quit = 0,
     Write = 1,
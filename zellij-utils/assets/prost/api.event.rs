@@ -9,7 +9,7 @@ pub struct EventNameList {
 pub struct Event {
     #[prost(enumeration="EventType", tag="1")]
     pub name: i32,
-    #[prost(oneof="event::Payload", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34")]
+    #[prost(oneof="event::Payload", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38")]
     pub payload: ::core::option::Option<event::Payload>,
 }
 /// Nested message and enum types in `Event`.
@@ -83,6 +83,14 @@ pub mod event {
         CwdChangedPayload(super::CwdChangedPayload),
         #[prost(message, tag="34")]
         AvailableLayoutInfoPayload(super::AvailableLayoutInfoPayload),
+        #[prost(message, tag="35")]
+        PaneOutputPayload(super::PaneOutputPayload),
+        #[prost(message, tag="36")]
+        RpcRequestPayload(super::RpcRequestPayload),
+        #[prost(message, tag="37")]
+        RpcResponsePayload(super::RpcResponsePayload),
+        #[prost(message, tag="38")]
+        HttpRequestPayload(super::HttpRequestPayload),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -97,6 +105,80 @@ pub struct CwdChangedPayload {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneOutputPayload {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(oneof="pane_output_payload::Output", tags="2, 3")]
+    pub output: ::core::option::Option<pane_output_payload::Output>,
+}
+/// Nested message and enum types in `PaneOutputPayload`.
+pub mod pane_output_payload {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Output {
+        #[prost(bytes, tag="2")]
+        Raw(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag="3")]
+        Lines(super::PaneOutputLines),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneOutputLines {
+    #[prost(string, repeated, tag="1")]
+    pub lines: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RpcRequestPayload {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="4")]
+    pub caller_plugin_id: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RpcResponsePayload {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(oneof="rpc_response_payload::Result", tags="2, 3")]
+    pub result: ::core::option::Option<rpc_response_payload::Result>,
+}
+/// Nested message and enum types in `RpcResponsePayload`.
+pub mod rpc_response_payload {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(bytes, tag="2")]
+        Payload(::prost::alloc::vec::Vec<u8>),
+        #[prost(string, tag="3")]
+        Error(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HttpRequestPayload {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(string, tag="2")]
+    pub route: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="6")]
+    pub headers: ::prost::alloc::vec::Vec<Header>,
+    #[prost(bytes="vec", tag="7")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FailedToStartWebServerPayload {
     #[prost(string, tag="1")]
     pub error: ::prost::alloc::string::String,
@@ -393,6 +475,8 @@ pub struct PluginInfo {
     pub plugin_url: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="3")]
     pub plugin_config: ::prost::alloc::vec::Vec<ContextItem>,
+    #[prost(string, optional, tag="4")]
+    pub suspended: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -465,6 +549,20 @@ pub struct LayoutMetadata {
     pub creation_time: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub update_time: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="4")]
+    pub params: ::prost::alloc::vec::Vec<LayoutParam>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LayoutParam {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub default: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="3")]
+    pub required: bool,
+    #[prost(string, optional, tag="4")]
+    pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -619,6 +717,10 @@ pub struct ModeUpdatePayload {
     pub web_server_port: ::core::option::Option<u32>,
     #[prost(bool, optional, tag="15")]
     pub web_server_capability: ::core::option::Option<bool>,
+    #[prost(uint32, optional, tag="16")]
+    pub following_client: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="17")]
+    pub following_client_name: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -767,6 +869,10 @@ pub enum EventType {
     ActionComplete = 38,
     CwdChanged = 39,
     AvailableLayoutInfo = 40,
+    PaneOutput = 41,
+    RpcRequest = 42,
+    RpcResponse = 43,
+    HttpRequest = 44,
 }
 impl EventType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -815,6 +921,10 @@ impl EventType {
             EventType::ActionComplete => "ActionComplete",
             EventType::CwdChanged => "CwdChanged",
             EventType::AvailableLayoutInfo => "AvailableLayoutInfo",
+            EventType::PaneOutput => "PaneOutput",
+            EventType::RpcRequest => "RpcRequest",
+            EventType::RpcResponse => "RpcResponse",
+            EventType::HttpRequest => "HttpRequest",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -860,6 +970,10 @@ impl EventType {
             "ActionComplete" => Some(Self::ActionComplete),
             "CwdChanged" => Some(Self::CwdChanged),
             "AvailableLayoutInfo" => Some(Self::AvailableLayoutInfo),
+            "PaneOutput" => Some(Self::PaneOutput),
+            "RpcRequest" => Some(Self::RpcRequest),
+            "RpcResponse" => Some(Self::RpcResponse),
+            "HttpRequest" => Some(Self::HttpRequest),
             _ => None,
         }
     }
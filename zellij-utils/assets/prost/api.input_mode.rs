@@ -37,6 +37,10 @@ pub enum InputMode {
     Prompt = 12,
     /// / `Tmux` mode allows for basic tmux keybindings functionality
     Tmux = 13,
+    /// / `MarkPane` mode allows marking the focused pane with a single key.
+    MarkPane = 14,
+    /// / `FocusMark` mode allows jumping to a marked pane with a single key.
+    FocusMark = 15,
 }
 impl InputMode {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -59,6 +63,8 @@ impl InputMode {
             InputMode::Move => "Move",
             InputMode::Prompt => "Prompt",
             InputMode::Tmux => "Tmux",
+            InputMode::MarkPane => "MarkPane",
+            InputMode::FocusMark => "FocusMark",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -78,6 +84,8 @@ impl InputMode {
             "Move" => Some(Self::Move),
             "Prompt" => Some(Self::Prompt),
             "Tmux" => Some(Self::Tmux),
+            "MarkPane" => Some(Self::MarkPane),
+            "FocusMark" => Some(Self::FocusMark),
             _ => None,
         }
     }
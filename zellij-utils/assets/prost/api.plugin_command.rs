@@ -3,7 +3,7 @@
 pub struct PluginCommand {
     #[prost(enumeration="CommandName", tag="1")]
     pub name: i32,
-    #[prost(oneof="plugin_command::Payload", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136")]
+    #[prost(oneof="plugin_command::Payload", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152")]
     pub payload: ::core::option::Option<plugin_command::Payload>,
 }
 /// Nested message and enum types in `PluginCommand`.
@@ -255,6 +255,38 @@ pub mod plugin_command {
         TogglePaneBorderlessPayload(super::TogglePaneBorderlessPayload),
         #[prost(message, tag="136")]
         SetPaneBorderlessPayload(super::SetPaneBorderlessPayload),
+        #[prost(message, tag="137")]
+        SearchSessionPayload(super::SearchSessionPayload),
+        #[prost(message, tag="138")]
+        JumpToSearchMatchPayload(super::JumpToSearchMatchPayload),
+        #[prost(message, tag="139")]
+        SubscribeToPaneOutputPayload(super::SubscribeToPaneOutputPayload),
+        #[prost(message, tag="140")]
+        UnsubscribeFromPaneOutputPayload(super::PaneId),
+        #[prost(message, tag="141")]
+        KvGetPayload(super::KvPayload),
+        #[prost(message, tag="142")]
+        KvSetPayload(super::KvPayload),
+        #[prost(message, tag="143")]
+        KvDeletePayload(super::KvPayload),
+        #[prost(message, tag="144")]
+        KvListPayload(super::KvPayload),
+        #[prost(string, tag="145")]
+        RegisterRpcMethodPayload(::prost::alloc::string::String),
+        #[prost(string, tag="146")]
+        UnregisterRpcMethodPayload(::prost::alloc::string::String),
+        #[prost(message, tag="147")]
+        SendRpcRequestPayload(super::SendRpcRequestPayload),
+        #[prost(message, tag="148")]
+        RespondToRpcRequestPayload(super::RespondToRpcRequestPayload),
+        #[prost(string, tag="149")]
+        RegisterHttpRoutePayload(::prost::alloc::string::String),
+        #[prost(string, tag="150")]
+        UnregisterHttpRoutePayload(::prost::alloc::string::String),
+        #[prost(message, tag="151")]
+        RespondToHttpRequestPayload(super::RespondToHttpRequestPayload),
+        #[prost(message, tag="152")]
+        RevokePluginPermissionsPayload(super::RevokePluginPermissionsPayload),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1246,6 +1278,206 @@ pub struct FocusedPaneInfo {
     #[prost(message, optional, tag="2")]
     pub focused_pane_id: ::core::option::Option<PaneId>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchSessionPayload {
+    #[prost(string, tag="1")]
+    pub needle: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub regex: bool,
+    #[prost(bool, tag="3")]
+    pub case_insensitive: bool,
+    #[prost(bool, tag="4")]
+    pub whole_word: bool,
+    #[prost(uint32, tag="5")]
+    pub context_lines: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchMatch {
+    #[prost(uint64, tag="1")]
+    pub line: u64,
+    #[prost(uint64, tag="2")]
+    pub start_column: u64,
+    #[prost(uint64, tag="3")]
+    pub end_column: u64,
+    #[prost(string, tag="4")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="5")]
+    pub context_before: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="6")]
+    pub context_after: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneSearchResults {
+    #[prost(uint32, tag="1")]
+    pub tab_position: u32,
+    #[prost(string, tag="2")]
+    pub tab_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(string, tag="4")]
+    pub pane_title: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="5")]
+    pub matches: ::prost::alloc::vec::Vec<SearchMatch>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionSearchResults {
+    #[prost(message, repeated, tag="1")]
+    pub panes: ::prost::alloc::vec::Vec<PaneSearchResults>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchSessionResponse {
+    #[prost(oneof="search_session_response::Result", tags="1, 2")]
+    pub result: ::core::option::Option<search_session_response::Result>,
+}
+/// Nested message and enum types in `SearchSessionResponse`.
+pub mod search_session_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag="1")]
+        Results(super::SessionSearchResults),
+        #[prost(string, tag="2")]
+        Error(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JumpToSearchMatchPayload {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(uint64, tag="2")]
+    pub line: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeToPaneOutputPayload {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(enumeration="PaneOutputFormat", tag="2")]
+    pub format: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KvPayload {
+    #[prost(enumeration="KvScope", tag="1")]
+    pub scope: i32,
+    #[prost(string, tag="2")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KvResponse {
+    #[prost(oneof="kv_response::Result", tags="1, 2, 3, 4")]
+    pub result: ::core::option::Option<kv_response::Result>,
+}
+/// Nested message and enum types in `KvResponse`.
+pub mod kv_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(bool, tag="1")]
+        Done(bool),
+        #[prost(message, tag="2")]
+        Value(super::KvValue),
+        #[prost(message, tag="3")]
+        Keys(super::KvKeys),
+        #[prost(string, tag="4")]
+        Error(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KvValue {
+    #[prost(bytes="vec", optional, tag="1")]
+    pub value: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KvKeys {
+    #[prost(string, repeated, tag="1")]
+    pub keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendRpcRequestPayload {
+    #[prost(string, tag="1")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, optional, tag="3")]
+    pub destination_plugin_id: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag="4")]
+    pub timeout_ms: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendRpcRequestResponse {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RespondToRpcRequestPayload {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(oneof="respond_to_rpc_request_payload::Result", tags="2, 3")]
+    pub result: ::core::option::Option<respond_to_rpc_request_payload::Result>,
+}
+/// Nested message and enum types in `RespondToRpcRequestPayload`.
+pub mod respond_to_rpc_request_payload {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(bytes, tag="2")]
+        Payload(::prost::alloc::vec::Vec<u8>),
+        #[prost(string, tag="3")]
+        Error(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RespondToHttpRequestPayload {
+    #[prost(uint64, tag="1")]
+    pub request_id: u64,
+    #[prost(uint32, tag="2")]
+    pub status: u32,
+    #[prost(message, repeated, tag="3")]
+    pub headers: ::prost::alloc::vec::Vec<super::event::Header>,
+    #[prost(bytes="vec", tag="4")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokePluginPermissionsPayload {
+    #[prost(string, tag="1")]
+    pub plugin_url: ::prost::alloc::string::String,
+    /// revokes all of the plugin's permissions if empty
+    #[prost(enumeration="super::plugin_permission::PermissionType", repeated, tag="2")]
+    pub permissions: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GrantedPluginPermissions {
+    #[prost(string, tag="1")]
+    pub plugin_url: ::prost::alloc::string::String,
+    #[prost(enumeration="super::plugin_permission::PermissionType", repeated, tag="2")]
+    pub permissions: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListGrantedPermissionsResponse {
+    #[prost(message, repeated, tag="1")]
+    pub granted: ::prost::alloc::vec::Vec<GrantedPluginPermissions>,
+    #[prost(string, optional, tag="2")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CommandName {
@@ -1414,6 +1646,23 @@ pub enum CommandName {
     GetFocusedPaneInfo = 183,
     TogglePaneBorderless = 184,
     SetPaneBorderless = 185,
+    SearchSession = 186,
+    JumpToSearchMatch = 187,
+    SubscribeToPaneOutput = 188,
+    UnsubscribeFromPaneOutput = 189,
+    KvGet = 190,
+    KvSet = 191,
+    KvDelete = 192,
+    KvList = 193,
+    RegisterRpcMethod = 194,
+    UnregisterRpcMethod = 195,
+    SendRpcRequest = 196,
+    RespondToRpcRequest = 197,
+    RegisterHttpRoute = 198,
+    UnregisterHttpRoute = 199,
+    RespondToHttpRequest = 200,
+    ListGrantedPermissions = 201,
+    RevokePluginPermissions = 202,
 }
 impl CommandName {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1587,6 +1836,23 @@ impl CommandName {
             CommandName::GetFocusedPaneInfo => "GetFocusedPaneInfo",
             CommandName::TogglePaneBorderless => "TogglePaneBorderless",
             CommandName::SetPaneBorderless => "SetPaneBorderless",
+            CommandName::SearchSession => "SearchSession",
+            CommandName::JumpToSearchMatch => "JumpToSearchMatch",
+            CommandName::SubscribeToPaneOutput => "SubscribeToPaneOutput",
+            CommandName::UnsubscribeFromPaneOutput => "UnsubscribeFromPaneOutput",
+            CommandName::KvGet => "KvGet",
+            CommandName::KvSet => "KvSet",
+            CommandName::KvDelete => "KvDelete",
+            CommandName::KvList => "KvList",
+            CommandName::RegisterRpcMethod => "RegisterRpcMethod",
+            CommandName::UnregisterRpcMethod => "UnregisterRpcMethod",
+            CommandName::SendRpcRequest => "SendRpcRequest",
+            CommandName::RespondToRpcRequest => "RespondToRpcRequest",
+            CommandName::RegisterHttpRoute => "RegisterHttpRoute",
+            CommandName::UnregisterHttpRoute => "UnregisterHttpRoute",
+            CommandName::RespondToHttpRequest => "RespondToHttpRequest",
+            CommandName::ListGrantedPermissions => "ListGrantedPermissions",
+            CommandName::RevokePluginPermissions => "RevokePluginPermissions",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1757,6 +2023,23 @@ impl CommandName {
             "GetFocusedPaneInfo" => Some(Self::GetFocusedPaneInfo),
             "TogglePaneBorderless" => Some(Self::TogglePaneBorderless),
             "SetPaneBorderless" => Some(Self::SetPaneBorderless),
+            "SearchSession" => Some(Self::SearchSession),
+            "JumpToSearchMatch" => Some(Self::JumpToSearchMatch),
+            "SubscribeToPaneOutput" => Some(Self::SubscribeToPaneOutput),
+            "UnsubscribeFromPaneOutput" => Some(Self::UnsubscribeFromPaneOutput),
+            "KvGet" => Some(Self::KvGet),
+            "KvSet" => Some(Self::KvSet),
+            "KvDelete" => Some(Self::KvDelete),
+            "KvList" => Some(Self::KvList),
+            "RegisterRpcMethod" => Some(Self::RegisterRpcMethod),
+            "UnregisterRpcMethod" => Some(Self::UnregisterRpcMethod),
+            "SendRpcRequest" => Some(Self::SendRpcRequest),
+            "RespondToRpcRequest" => Some(Self::RespondToRpcRequest),
+            "RegisterHttpRoute" => Some(Self::RegisterHttpRoute),
+            "UnregisterHttpRoute" => Some(Self::UnregisterHttpRoute),
+            "RespondToHttpRequest" => Some(Self::RespondToHttpRequest),
+            "ListGrantedPermissions" => Some(Self::ListGrantedPermissions),
+            "RevokePluginPermissions" => Some(Self::RevokePluginPermissions),
             _ => None,
         }
     }
@@ -1845,3 +2128,55 @@ impl FixedOrPercent {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PaneOutputFormat {
+    Raw = 0,
+    Lines = 1,
+}
+impl PaneOutputFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PaneOutputFormat::Raw => "Raw",
+            PaneOutputFormat::Lines => "Lines",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Raw" => Some(Self::Raw),
+            "Lines" => Some(Self::Lines),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum KvScope {
+    Session = 0,
+    Global = 1,
+}
+impl KvScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            KvScope::Session => "Session",
+            KvScope::Global => "Global",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Session" => Some(Self::Session),
+            "Global" => Some(Self::Global),
+            _ => None,
+        }
+    }
+}
@@ -17,6 +17,7 @@ pub enum PermissionType {
     ReadPaneContents = 13,
     RunActionsAsUser = 14,
     WriteToClipboard = 15,
+    ServeHttpRoutes = 16,
 }
 impl PermissionType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -41,6 +42,7 @@ impl PermissionType {
             PermissionType::ReadPaneContents => "ReadPaneContents",
             PermissionType::RunActionsAsUser => "RunActionsAsUser",
             PermissionType::WriteToClipboard => "WriteToClipboard",
+            PermissionType::ServeHttpRoutes => "ServeHttpRoutes",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -62,6 +64,7 @@ impl PermissionType {
             "ReadPaneContents" => Some(Self::ReadPaneContents),
             "RunActionsAsUser" => Some(Self::RunActionsAsUser),
             "WriteToClipboard" => Some(Self::WriteToClipboard),
+            "ServeHttpRoutes" => Some(Self::ServeHttpRoutes),
             _ => None,
         }
     }
@@ -119,7 +119,7 @@ pub struct RgbColor {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Action {
-    #[prost(oneof="action::ActionType", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 95, 96, 90, 91, 92, 93, 94")]
+    #[prost(oneof="action::ActionType", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 95, 96, 90, 91, 92, 93, 94, 97, 98, 99, 100, 101, 102, 103, 104, 105")]
     pub action_type: ::core::option::Option<action::ActionType>,
 }
 /// Nested message and enum types in `Action`.
@@ -319,6 +319,24 @@ pub mod action {
         NewBlockingPane(super::NewBlockingPaneAction),
         #[prost(message, tag="94")]
         OverrideLayout(super::OverrideLayoutAction),
+        #[prost(message, tag="97")]
+        SearchPane(super::SearchPaneAction),
+        #[prost(message, tag="98")]
+        SearchSession(super::SearchSessionAction),
+        #[prost(message, tag="99")]
+        JumpToSearchMatch(super::JumpToSearchMatchAction),
+        #[prost(message, tag="100")]
+        LogPane(super::LogPaneAction),
+        #[prost(message, tag="101")]
+        RecordPane(super::RecordPaneAction),
+        #[prost(message, tag="102")]
+        SetTilingMode(super::SetTilingModeAction),
+        #[prost(message, tag="103")]
+        MarkPane(super::MarkPaneAction),
+        #[prost(message, tag="104")]
+        FocusMark(super::FocusMarkAction),
+        #[prost(message, tag="105")]
+        Follow(super::FollowAction),
     }
 }
 // Action message definitions (all 92 variants)
@@ -590,6 +608,8 @@ pub struct DumpScreenAction {
     pub file_path: ::prost::alloc::string::String,
     #[prost(bool, tag="2")]
     pub include_scrollback: bool,
+    #[prost(enumeration="DumpScreenFormat", tag="3")]
+    pub format: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1007,6 +1027,102 @@ pub struct SetPaneBorderlessAction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchPaneAction {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(string, tag="2")]
+    pub needle: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub regex: bool,
+    #[prost(bool, tag="4")]
+    pub case_insensitive: bool,
+    #[prost(bool, tag="5")]
+    pub whole_word: bool,
+    #[prost(bool, tag="6")]
+    pub json: bool,
+    #[prost(uint32, tag="7")]
+    pub context_lines: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchSessionAction {
+    #[prost(string, tag="1")]
+    pub needle: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub regex: bool,
+    #[prost(bool, tag="3")]
+    pub case_insensitive: bool,
+    #[prost(bool, tag="4")]
+    pub whole_word: bool,
+    #[prost(uint32, tag="5")]
+    pub context_lines: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JumpToSearchMatchAction {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    #[prost(uint64, tag="2")]
+    pub line: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogPaneAction {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    /// when missing, logging is stopped
+    #[prost(message, optional, tag="2")]
+    pub log_options: ::core::option::Option<PaneLogOptions>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecordPaneAction {
+    #[prost(message, optional, tag="1")]
+    pub pane_id: ::core::option::Option<PaneId>,
+    /// when missing, recording is stopped
+    #[prost(string, optional, tag="2")]
+    pub out: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetTilingModeAction {
+    #[prost(enumeration="TilingMode", tag="1")]
+    pub tiling_mode: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarkPaneAction {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FocusMarkAction {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FollowAction {
+    #[prost(uint32, tag="1")]
+    pub client_id: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneLogOptions {
+    #[prost(string, tag="1")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub strip_ansi: bool,
+    #[prost(bool, tag="3")]
+    pub append: bool,
+    #[prost(uint64, optional, tag="4")]
+    pub max_size: ::core::option::Option<u64>,
+    #[prost(uint32, tag="5")]
+    pub max_files: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Position {
     /// Changed from uint32 to int32 to support negative line numbers
     #[prost(int32, tag="1")]
@@ -1040,6 +1156,8 @@ pub struct CliAssets {
     pub force_run_layout_commands: bool,
     #[prost(string, optional, tag="11")]
     pub cwd: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="12")]
+    pub client_name: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1073,6 +1191,20 @@ pub struct LayoutMetadata {
     pub creation_time: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub update_time: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="4")]
+    pub params: ::prost::alloc::vec::Vec<LayoutParam>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LayoutParam {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub default: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag="3")]
+    pub required: bool,
+    #[prost(string, optional, tag="4")]
+    pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1261,6 +1393,12 @@ pub struct RunCommandAction {
     /// Added missing use_terminal_title field
     #[prost(bool, tag="8")]
     pub use_terminal_title: bool,
+    #[prost(map="string, string", tag="9")]
+    pub env: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(enumeration="RestartPolicy", tag="10")]
+    pub restart: i32,
+    #[prost(uint32, optional, tag="11")]
+    pub max_restarts: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1289,9 +1427,17 @@ pub struct TiledPaneLayout {
     pub is_expanded_in_stack: bool,
     #[prost(bool, tag="12")]
     pub hide_floating_panes: bool,
-    /// NOTE: run_instructions_to_ignore is not represented here because it's a field used only inside the server itself and not part of the server/client contract
     #[prost(string, optional, tag="13")]
     pub pane_initial_contents: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="14")]
+    pub log_to: ::core::option::Option<::prost::alloc::string::String>,
+    /// NOTE: run_instructions_to_ignore is not represented here because it's a field used only inside the server itself and not part of the server/client contract
+    #[prost(message, repeated, tag="15")]
+    pub depends_on: ::prost::alloc::vec::Vec<PaneDependency>,
+    #[prost(string, optional, tag="16")]
+    pub mark: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="TilingMode", optional, tag="17")]
+    pub tiling_mode: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1320,6 +1466,33 @@ pub struct FloatingPaneLayout {
     pub logical_position: ::core::option::Option<u32>,
     #[prost(bool, optional, tag="12")]
     pub borderless: ::core::option::Option<bool>,
+    #[prost(string, optional, tag="13")]
+    pub log_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="14")]
+    pub depends_on: ::prost::alloc::vec::Vec<PaneDependency>,
+    #[prost(string, optional, tag="15")]
+    pub mark: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaneDependency {
+    #[prost(string, tag="1")]
+    pub pane_name: ::prost::alloc::string::String,
+    #[prost(oneof="pane_dependency::ReadyWhen", tags="2, 3, 4")]
+    pub ready_when: ::core::option::Option<pane_dependency::ReadyWhen>,
+}
+/// Nested message and enum types in `PaneDependency`.
+pub mod pane_dependency {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum ReadyWhen {
+        #[prost(bool, tag="2")]
+        ExitedSuccessfully(bool),
+        #[prost(string, tag="3")]
+        OutputMatches(::prost::alloc::string::String),
+        #[prost(uint32, tag="4")]
+        PortOpen(u32),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1663,6 +1836,8 @@ pub struct Options {
     pub post_command_discovery_hook: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(bool, optional, tag="40")]
     pub osc8_hyperlinks: ::core::option::Option<bool>,
+    #[prost(uint64, optional, tag="41")]
+    pub plugin_execution_budget: ::core::option::Option<u64>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -1911,6 +2086,8 @@ pub enum InputMode {
     Move = 12,
     Prompt = 13,
     Tmux = 14,
+    MarkPane = 15,
+    FocusMark = 16,
 }
 impl InputMode {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1934,6 +2111,8 @@ impl InputMode {
             InputMode::Move => "INPUT_MODE_MOVE",
             InputMode::Prompt => "INPUT_MODE_PROMPT",
             InputMode::Tmux => "INPUT_MODE_TMUX",
+            InputMode::MarkPane => "INPUT_MODE_MARK_PANE",
+            InputMode::FocusMark => "INPUT_MODE_FOCUS_MARK",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1954,6 +2133,8 @@ impl InputMode {
             "INPUT_MODE_MOVE" => Some(Self::Move),
             "INPUT_MODE_PROMPT" => Some(Self::Prompt),
             "INPUT_MODE_TMUX" => Some(Self::Tmux),
+            "INPUT_MODE_MARK_PANE" => Some(Self::MarkPane),
+            "INPUT_MODE_FOCUS_MARK" => Some(Self::FocusMark),
             _ => None,
         }
     }
@@ -2103,6 +2284,35 @@ impl ExitReason {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum RestartPolicy {
+    Never = 0,
+    OnFailure = 1,
+    Always = 2,
+}
+impl RestartPolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RestartPolicy::Never => "RESTART_POLICY_NEVER",
+            RestartPolicy::OnFailure => "RESTART_POLICY_ON_FAILURE",
+            RestartPolicy::Always => "RESTART_POLICY_ALWAYS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RESTART_POLICY_NEVER" => Some(Self::Never),
+            "RESTART_POLICY_ON_FAILURE" => Some(Self::OnFailure),
+            "RESTART_POLICY_ALWAYS" => Some(Self::Always),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum MouseEventType {
     Unspecified = 0,
     Press = 1,
@@ -2177,6 +2387,7 @@ pub enum SearchOption {
     WholeWord = 2,
     /// Unchanged - already matches
     Wrap = 3,
+    Regex = 4,
 }
 impl SearchOption {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2189,6 +2400,7 @@ impl SearchOption {
             SearchOption::CaseSensitivity => "SEARCH_OPTION_CASE_SENSITIVITY",
             SearchOption::WholeWord => "SEARCH_OPTION_WHOLE_WORD",
             SearchOption::Wrap => "SEARCH_OPTION_WRAP",
+            SearchOption::Regex => "SEARCH_OPTION_REGEX",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2198,6 +2410,7 @@ impl SearchOption {
             "SEARCH_OPTION_CASE_SENSITIVITY" => Some(Self::CaseSensitivity),
             "SEARCH_OPTION_WHOLE_WORD" => Some(Self::WholeWord),
             "SEARCH_OPTION_WRAP" => Some(Self::Wrap),
+            "SEARCH_OPTION_REGEX" => Some(Self::Regex),
             _ => None,
         }
     }
@@ -2389,6 +2602,76 @@ impl StackDirection {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum DumpScreenFormat {
+    Unspecified = 0,
+    Text = 1,
+    Ansi = 2,
+    Html = 3,
+    Svg = 4,
+}
+impl DumpScreenFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DumpScreenFormat::Unspecified => "DUMP_SCREEN_FORMAT_UNSPECIFIED",
+            DumpScreenFormat::Text => "DUMP_SCREEN_FORMAT_TEXT",
+            DumpScreenFormat::Ansi => "DUMP_SCREEN_FORMAT_ANSI",
+            DumpScreenFormat::Html => "DUMP_SCREEN_FORMAT_HTML",
+            DumpScreenFormat::Svg => "DUMP_SCREEN_FORMAT_SVG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DUMP_SCREEN_FORMAT_UNSPECIFIED" => Some(Self::Unspecified),
+            "DUMP_SCREEN_FORMAT_TEXT" => Some(Self::Text),
+            "DUMP_SCREEN_FORMAT_ANSI" => Some(Self::Ansi),
+            "DUMP_SCREEN_FORMAT_HTML" => Some(Self::Html),
+            "DUMP_SCREEN_FORMAT_SVG" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TilingMode {
+    Unspecified = 0,
+    Manual = 1,
+    Bsp = 2,
+    MasterStack = 3,
+    Grid = 4,
+}
+impl TilingMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TilingMode::Unspecified => "TILING_MODE_UNSPECIFIED",
+            TilingMode::Manual => "TILING_MODE_MANUAL",
+            TilingMode::Bsp => "TILING_MODE_BSP",
+            TilingMode::MasterStack => "TILING_MODE_MASTER_STACK",
+            TilingMode::Grid => "TILING_MODE_GRID",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TILING_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TILING_MODE_MANUAL" => Some(Self::Manual),
+            "TILING_MODE_BSP" => Some(Self::Bsp),
+            "TILING_MODE_MASTER_STACK" => Some(Self::MasterStack),
+            "TILING_MODE_GRID" => Some(Self::Grid),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum WebSharing {
     Unspecified = 0,
     On = 1,
@@ -2422,7 +2705,7 @@ impl WebSharing {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerToClientMsg {
-    #[prost(oneof="server_to_client_msg::Message", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
+    #[prost(oneof="server_to_client_msg::Message", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
     pub message: ::core::option::Option<server_to_client_msg::Message>,
 }
 /// Nested message and enum types in `ServerToClientMsg`.
@@ -2456,6 +2739,8 @@ pub mod server_to_client_msg {
         RenamedSession(super::RenamedSessionMsg),
         #[prost(message, tag="13")]
         ConfigFileUpdated(super::ConfigFileUpdatedMsg),
+        #[prost(message, tag="14")]
+        PluginHttpResponse(super::PluginHttpResponseMsg),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2537,8 +2822,25 @@ pub struct ConfigFileUpdatedMsg {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PluginHttpResponseMsg {
+    /// unset if no plugin in the session serves the route
+    #[prost(message, optional, tag="1")]
+    pub response: ::core::option::Option<PluginHttpResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PluginHttpResponse {
+    #[prost(uint32, tag="1")]
+    pub status: u32,
+    #[prost(map="string, string", tag="2")]
+    pub headers: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(bytes="vec", tag="3")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientToServerMsg {
-    #[prost(oneof="client_to_server_msg::Message", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16")]
+    #[prost(oneof="client_to_server_msg::Message", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17")]
     pub message: ::core::option::Option<client_to_server_msg::Message>,
 }
 /// Nested message and enum types in `ClientToServerMsg`.
@@ -2578,6 +2880,8 @@ pub mod client_to_server_msg {
         FailedToStartWebServer(super::FailedToStartWebServerMsg),
         #[prost(message, tag="16")]
         AttachWatcherClient(super::AttachWatcherClientMsg),
+        #[prost(message, tag="17")]
+        PluginHttpRequest(super::PluginHttpRequestMsg),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2693,3 +2997,19 @@ pub struct FailedToStartWebServerMsg {
     #[prost(string, tag="1")]
     pub error: ::prost::alloc::string::String,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PluginHttpRequestMsg {
+    #[prost(string, tag="1")]
+    pub route: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(map="string, string", tag="5")]
+    pub headers: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(bytes="vec", tag="6")]
+    pub body: ::prost::alloc::vec::Vec<u8>,
+}
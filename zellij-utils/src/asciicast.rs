@@ -0,0 +1,174 @@
+//! Reading and writing of asciicast v2 recordings, as produced and consumed by asciinema
+//! (https://docs.asciinema.org/manual/asciicast/v2/)
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const ASCIICAST_VERSION: u8 = 2;
+pub const OUTPUT_EVENT: &str = "o";
+pub const RESIZE_EVENT: &str = "r";
+
+/// The first line of an asciicast file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsciicastHeader {
+    pub version: u8,
+    pub width: usize,
+    pub height: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_time_limit: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl AsciicastHeader {
+    pub fn new(width: usize, height: usize) -> Self {
+        AsciicastHeader {
+            version: ASCIICAST_VERSION,
+            width,
+            height,
+            timestamp: None,
+            idle_time_limit: None,
+            title: None,
+        }
+    }
+}
+
+/// Every line after the header, serialized as `[time, code, data]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsciicastEvent {
+    // seconds since the start of the recording
+    pub time: f64,
+    // "o" for output, "r" for resize, etc.
+    pub code: String,
+    pub data: String,
+}
+
+impl AsciicastEvent {
+    pub fn output(time: f64, data: String) -> Self {
+        AsciicastEvent {
+            time,
+            code: OUTPUT_EVENT.to_owned(),
+            data,
+        }
+    }
+    pub fn resize(time: f64, width: usize, height: usize) -> Self {
+        AsciicastEvent {
+            time,
+            code: RESIZE_EVENT.to_owned(),
+            data: format!("{}x{}", width, height),
+        }
+    }
+    pub fn to_line(&self) -> String {
+        serde_json::to_string(&(self.time, &self.code, &self.data)).unwrap_or_default()
+    }
+    pub fn from_line(line: &str) -> Result<Self, String> {
+        let (time, code, data): (f64, String, String) =
+            serde_json::from_str(line).map_err(|e| format!("Malformed event {}: {}", line, e))?;
+        Ok(AsciicastEvent { time, code, data })
+    }
+}
+
+pub fn parse_asciicast(contents: &str) -> Result<(AsciicastHeader, Vec<AsciicastEvent>), String> {
+    let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+    let header: AsciicastHeader = lines
+        .next()
+        .ok_or_else(|| "Empty recording".to_owned())
+        .and_then(|l| serde_json::from_str(l).map_err(|e| format!("Malformed header: {}", e)))?;
+    if header.version != ASCIICAST_VERSION {
+        return Err(format!(
+            "Unsupported asciicast version: {}, only version {} is supported",
+            header.version, ASCIICAST_VERSION
+        ));
+    }
+    let events = lines
+        .map(AsciicastEvent::from_line)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((header, events))
+}
+
+/// The output events of a recording along with how long to wait before writing each of them,
+/// with the recorded delays divided by `speed` and (optionally) capped at `idle_time_limit`
+/// seconds
+pub fn playback_schedule(
+    events: &[AsciicastEvent],
+    speed: f64,
+    idle_time_limit: Option<f64>,
+) -> Vec<(Duration, &str)> {
+    let mut previous_time = 0.0;
+    events
+        .iter()
+        .filter(|e| e.code == OUTPUT_EVENT)
+        .map(|e| {
+            let mut delay = (e.time - previous_time).max(0.0);
+            previous_time = e.time;
+            if let Some(idle_time_limit) = idle_time_limit {
+                delay = delay.min(idle_time_limit);
+            }
+            let delay = if speed > 0.0 { delay / speed } else { 0.0 };
+            (Duration::from_secs_f64(delay), e.data.as_str())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_roundtrip_through_json_lines() {
+        let event = AsciicastEvent::output(1.5, "hi \"there\"\r\n\u{1b}[0m".to_owned());
+        let line = event.to_line();
+        assert_eq!(line, r#"[1.5,"o","hi \"there\"\r\n\u001b[0m"]"#);
+        assert_eq!(AsciicastEvent::from_line(&line), Ok(event));
+    }
+
+    #[test]
+    fn parses_recording() {
+        let recording = concat!(
+            r#"{"version": 2, "width": 80, "height": 24, "timestamp": 1504467315}"#,
+            "\n",
+            r#"[0.248848, "o", "foo"]"#,
+            "\n",
+            r#"[1.001376, "r", "100x30"]"#,
+            "\n",
+        );
+        let (header, events) = parse_asciicast(recording).unwrap();
+        assert_eq!(header.width, 80);
+        assert_eq!(header.height, 24);
+        assert_eq!(header.timestamp, Some(1504467315));
+        assert_eq!(
+            events,
+            vec![
+                AsciicastEvent::output(0.248848, "foo".to_owned()),
+                AsciicastEvent::resize(1.001376, 100, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let recording = r#"{"version": 1, "width": 80, "height": 24}"#;
+        assert!(parse_asciicast(recording).is_err());
+    }
+
+    #[test]
+    fn playback_schedule_respects_speed_and_idle_time_limit() {
+        let events = vec![
+            AsciicastEvent::output(1.0, "a".to_owned()),
+            AsciicastEvent::resize(2.0, 100, 30),
+            AsciicastEvent::output(11.0, "b".to_owned()),
+            AsciicastEvent::output(12.0, "c".to_owned()),
+        ];
+        let schedule = playback_schedule(&events, 2.0, Some(4.0));
+        assert_eq!(
+            schedule,
+            vec![
+                (Duration::from_millis(500), "a"),
+                (Duration::from_secs(2), "b"),
+                (Duration::from_millis(500), "c"),
+            ]
+        );
+    }
+}
@@ -1,13 +1,20 @@
-use crate::data::{Direction, InputMode, Resize, UnblockCondition};
+use crate::data::{
+    Direction, DumpScreenFormat, InputMode, PermissionType, Resize, TilingMode, UnblockCondition,
+};
 use crate::setup::Setup;
 use crate::{
     consts::{ZELLIJ_CONFIG_DIR_ENV, ZELLIJ_CONFIG_FILE_ENV},
-    input::{layout::PluginUserConfiguration, options::Options},
+    input::{
+        command::{parse_env_var, RestartPolicy},
+        layout::{parse_layout_param, PluginUserConfiguration},
+        options::Options,
+    },
 };
 use clap::{Args, Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 use std::path::PathBuf;
+use std::str::FromStr;
 use url::Url;
 
 fn validate_session(name: &str) -> Result<String, String> {
@@ -59,6 +66,10 @@ pub struct CliArgs {
     #[clap(short, long, value_parser, overrides_with = "layout")]
     pub layout: Option<PathBuf>,
 
+    /// Set a parameter of the layout (eg. --param project=perth), can be repeated
+    #[clap(long = "param", value_parser = parse_layout_param, value_name = "NAME=VALUE")]
+    pub params: Vec<(String, String)>,
+
     /// Name of a predefined layout inside the layout directory or the path to a layout file
     /// Will always start a new session, even if inside an existing session
     #[clap(short, long, value_parser, overrides_with = "new_session_with_layout")]
@@ -218,6 +229,86 @@ pub enum SessionCommand {
     Options(Options),
 }
 
+#[derive(Debug, Subcommand, Clone, Serialize, Deserialize)]
+pub enum LayoutCommand {
+    /// Check a layout file for errors without starting a session with it
+    Validate {
+        /// The layout file to validate
+        file: PathBuf,
+        /// Set a parameter of the layout (eg. --param project=perth), required parameters without
+        /// a value are given placeholders
+        #[clap(long = "param", value_parser = parse_layout_param, value_name = "NAME=VALUE")]
+        params: Vec<(String, String)>,
+        /// Print the diagnostics as JSON (eg. for CI or editor integrations)
+        #[clap(long, value_parser)]
+        json: bool,
+    },
+}
+
+#[derive(Debug, Subcommand, Clone, Serialize, Deserialize)]
+pub enum PluginSubcommand {
+    /// Inspect and revoke the permissions granted to plugins
+    #[clap(subcommand)]
+    Permissions(PluginPermissionsCommand),
+    /// Show the plugin API version a plugin was built against, whether it can be loaded and the
+    /// permissions it declares it will request
+    Info {
+        /// The plugin's .wasm file
+        file: PathBuf,
+    },
+}
+
+#[derive(Debug, Subcommand, Clone, Serialize, Deserialize)]
+pub enum PluginPermissionsCommand {
+    /// List the permissions granted to plugins
+    List {
+        /// Only list the permissions of this plugin
+        plugin_url: Option<String>,
+    },
+    /// Revoke permissions granted to a plugin, it will have to ask for them again the next time
+    /// it is loaded
+    Revoke {
+        /// The url of the plugin, as it was loaded (eg. file:/path/to/plugin.wasm)
+        plugin_url: String,
+        /// The permission to revoke (eg. RunCommands), all of them if not specified
+        #[clap(value_parser)]
+        permission: Option<PermissionType>,
+    },
+    /// Show the recorded uses of sensitive permissions (RunCommands, WriteToStdin and
+    /// RunActionsAsUser)
+    Audit {
+        /// Only show the uses by this plugin
+        plugin_url: Option<String>,
+    },
+}
+
+/// The format of a layout file to convert to KDL
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutSourceFormat {
+    /// The legacy Zellij YAML layout
+    #[default]
+    Zellij,
+    /// A tmuxinator project file
+    Tmuxinator,
+    /// A tmuxp session file
+    Tmuxp,
+}
+
+impl FromStr for LayoutSourceFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Zellij" | "zellij" | "yaml" => Ok(LayoutSourceFormat::Zellij),
+            "Tmuxinator" | "tmuxinator" => Ok(LayoutSourceFormat::Tmuxinator),
+            "Tmuxp" | "tmuxp" => Ok(LayoutSourceFormat::Tmuxp),
+            _ => Err(format!(
+                "Failed to parse LayoutSourceFormat. Unknown format: {} (expected one of: zellij, tmuxinator, tmuxp)",
+                s
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Subcommand, Clone, Serialize, Deserialize)]
 pub enum Sessions {
     /// List active sessions
@@ -332,9 +423,18 @@ pub enum Sessions {
     #[clap(visible_alias = "r")]
     Run {
         /// Command to run
-        #[clap(last(true), required(true))]
+        #[clap(last(true), required_unless_present("playback"))]
         command: Vec<String>,
 
+        /// Replay an asciicast recording (eg. one made with `zellij action record`) in the new
+        /// pane instead of running a command
+        #[clap(long, value_parser, conflicts_with("command"))]
+        playback: Option<PathBuf>,
+
+        /// The speed to replay the --playback recording at, eg. 2 to replay twice as fast
+        #[clap(long, value_parser, requires("playback"))]
+        playback_speed: Option<f64>,
+
         /// Direction to open the new pane in
         #[clap(short, long, value_parser, conflicts_with("floating"))]
         direction: Option<Direction>,
@@ -343,6 +443,18 @@ pub enum Sessions {
         #[clap(long, value_parser)]
         cwd: Option<PathBuf>,
 
+        /// Set an environment variable for the command (eg. --env KEY=VALUE), can be repeated
+        #[clap(long = "env", value_parser = parse_env_var, value_name = "KEY=VALUE")]
+        env: Vec<(String, String)>,
+
+        /// Restart the command when it exits [possible values: never, on-failure, always]
+        #[clap(long, value_parser, value_name = "POLICY")]
+        restart: Option<RestartPolicy>,
+
+        /// The maximum number of consecutive restarts before giving up (default: 3)
+        #[clap(long, value_parser, requires("restart"))]
+        max_restarts: Option<usize>,
+
         /// Open the new pane in floating mode
         #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
         floating: bool,
@@ -444,11 +556,18 @@ pub enum Sessions {
         borderless: Option<bool>,
     },
     /// Load a plugin
-    #[clap(visible_alias = "p")]
+    #[clap(
+        visible_alias = "p",
+        subcommand_negates_reqs(true),
+        args_conflicts_with_subcommands(true)
+    )]
     Plugin {
         /// Plugin URL, can either start with http(s), file: or zellij:
         #[clap(last(true), required(true))]
-        url: String,
+        url: Option<String>,
+
+        #[clap(subcommand)]
+        command: Option<PluginSubcommand>,
 
         /// Plugin configuration
         #[clap(short, long, value_parser)]
@@ -472,6 +591,10 @@ pub enum Sessions {
         /// Skip the memory and HD cache and force recompile of the plugin (good for development)
         #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
         skip_plugin_cache: bool,
+        /// Reload the plugin in place whenever its file changes (implies --skip-plugin-cache, only
+        /// for file: plugins)
+        #[clap(long, value_parser, default_value("false"), takes_value(false))]
+        dev: bool,
         /// The x coordinates if the pane is floating as a bare integer (eg. 1) or percent (eg. 10%)
         #[clap(short, long, requires("floating"))]
         x: Option<String>,
@@ -550,12 +673,33 @@ pub enum Sessions {
     ConvertConfig {
         old_config_file: PathBuf,
     },
+    /// Convert a layout file from another format to a KDL layout printed to stdout
     ConvertLayout {
         old_layout_file: PathBuf,
+        /// The format of the layout file [zellij|tmuxinator|tmuxp], zellij being the legacy
+        /// YAML layouts
+        #[clap(long, value_parser, default_value("zellij"))]
+        from: LayoutSourceFormat,
     },
     ConvertTheme {
         old_theme_file: PathBuf,
     },
+    /// Replay an asciicast v2 recording (eg. one made with `zellij action record`) to the
+    /// terminal, eg. in a new pane with: zellij run --playback run.cast
+    Playback {
+        /// The asciicast file to replay
+        file: PathBuf,
+        /// Playback speed, eg. 2 to replay twice as fast
+        #[clap(short, long, value_parser, default_value("1.0"))]
+        speed: f64,
+        /// Cap pauses in the recording to this many seconds (defaults to the idle_time_limit of
+        /// the recording, if any)
+        #[clap(short, long, value_parser)]
+        idle_time_limit: Option<f64>,
+    },
+    /// Work with layout files
+    #[clap(subcommand)]
+    Layout(LayoutCommand),
     /// Send data to one or more plugins, launch them if they are not running.
     #[clap(override_usage(
 r#"
@@ -639,6 +783,11 @@ pub enum CliAction {
         /// Dump the pane with full scrollback
         #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
         full: bool,
+
+        /// The format of the dump [text|ansi|html|svg], all formats other than text preserve
+        /// colors, styles and hyperlinks
+        #[clap(long, value_parser, default_value("text"))]
+        format: DumpScreenFormat,
     },
     /// Dump current layout to stdout
     DumpLayout,
@@ -683,6 +832,23 @@ pub enum CliAction {
         #[clap(long, value_parser)]
         cwd: Option<PathBuf>,
 
+        /// Set an environment variable for the command (eg. --env KEY=VALUE), can be repeated
+        #[clap(
+            long = "env",
+            value_parser = parse_env_var,
+            value_name = "KEY=VALUE",
+            requires("command")
+        )]
+        env: Vec<(String, String)>,
+
+        /// Restart the command when it exits [possible values: never, on-failure, always]
+        #[clap(long, value_parser, value_name = "POLICY", requires("command"))]
+        restart: Option<RestartPolicy>,
+
+        /// The maximum number of consecutive restarts before giving up (default: 3)
+        #[clap(long, value_parser, requires("restart"))]
+        max_restarts: Option<usize>,
+
         /// Open the new pane in floating mode
         #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
         floating: bool,
@@ -836,6 +1002,16 @@ pub enum CliAction {
     },
     /// Remove a previously set pane name
     UndoRenamePane,
+    /// Mark the focused pane, so that it can later be focused from any tab with `focus-mark`.
+    /// A mark belongs to a single pane, marking another pane with it moves the mark
+    MarkPane {
+        #[clap(short, long, value_parser)]
+        name: String,
+    },
+    /// Focus the pane with the given mark, switching to its tab if needed
+    FocusMark {
+        name: String,
+    },
     /// Go to the next tab.
     GoToNextTab,
     /// Go to the previous tab.
@@ -869,6 +1045,15 @@ pub enum CliAction {
         #[clap(long, value_parser, requires("layout"))]
         layout_dir: Option<PathBuf>,
 
+        /// Set a parameter of the layout (eg. --param project=perth), can be repeated
+        #[clap(
+            long = "param",
+            value_parser = parse_layout_param,
+            value_name = "NAME=VALUE",
+            requires("layout")
+        )]
+        params: Vec<(String, String)>,
+
         /// Name of the new tab
         #[clap(short, long, value_parser)]
         name: Option<String>,
@@ -953,6 +1138,11 @@ pub enum CliAction {
     },
     PreviousSwapLayout,
     NextSwapLayout,
+    /// Set how the tiled panes of the active tab are arranged when panes are opened or closed
+    /// [manual|bsp|master-stack|grid]
+    SetTilingMode {
+        tiling_mode: TilingMode,
+    },
     /// Override the layout of the active tab
     OverrideLayout {
         /// Path to the layout file
@@ -1148,6 +1338,129 @@ tail -f /tmp/my-live-logfile | zellij action pipe --name logs --plugin https://e
         #[clap(short, long, value_parser)]
         message: String,
     },
+    /// Search the full scrollback of a pane, printing the position and text of every match
+    ///
+    /// Lines are logical lines (soft-wrapped lines count as one) numbered from the top of the
+    /// scrollback, columns are character offsets within that line
+    Search {
+        /// The pane_id of the pane, eg. terminal_1, plugin_2 or 3 (equivalent to terminal_3)
+        #[clap(short, long, value_parser)]
+        pane_id: String,
+        /// The text to search for (a regular expression if --regex is specified)
+        needle: String,
+        /// Interpret the needle as a regular expression
+        #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
+        regex: bool,
+        /// Ignore case when matching
+        #[clap(
+            short('i'),
+            long,
+            value_parser,
+            default_value("false"),
+            takes_value(false)
+        )]
+        case_insensitive: bool,
+        /// Only match whole words
+        #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
+        whole_word: bool,
+        /// Number of lines of context to print before and after every match
+        #[clap(short('C'), long, value_parser, default_value("0"))]
+        context: usize,
+        /// Print the matches as JSON
+        #[clap(long, value_parser, default_value("false"), takes_value(false))]
+        json: bool,
+    },
+    /// Search the full scrollback of all terminal panes in the session, printing the matches as
+    /// JSON grouped by tab and pane
+    SearchSession {
+        /// The text to search for (a regular expression if --regex is specified)
+        needle: String,
+        /// Interpret the needle as a regular expression
+        #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
+        regex: bool,
+        /// Ignore case when matching
+        #[clap(
+            short('i'),
+            long,
+            value_parser,
+            default_value("false"),
+            takes_value(false)
+        )]
+        case_insensitive: bool,
+        /// Only match whole words
+        #[clap(short, long, value_parser, default_value("false"), takes_value(false))]
+        whole_word: bool,
+        /// Number of lines of context to include before and after every match
+        #[clap(short('C'), long, value_parser, default_value("0"))]
+        context: usize,
+    },
+    /// Focus a pane and scroll it so that the given line of its scrollback (as reported by
+    /// search-session) is visible
+    JumpToSearchMatch {
+        /// The pane_id of the pane, eg. terminal_1, plugin_2 or 3 (equivalent to terminal_3)
+        #[clap(short, long, value_parser)]
+        pane_id: String,
+        /// The logical line number, counted from the top of the scrollback
+        #[clap(short, long, value_parser)]
+        line: usize,
+    },
+    /// Continuously write the output of a pane to a file, until the pane closes or logging is
+    /// stopped with --stop
+    LogPane {
+        /// The pane_id of the pane, eg. terminal_1 or 3 (equivalent to terminal_3)
+        #[clap(short, long, value_parser)]
+        pane_id: String,
+        /// The file to write the output to
+        #[clap(long, value_parser, required_unless_present("stop"))]
+        path: Option<PathBuf>,
+        /// Strip ANSI escape sequences (colors, cursor movements, etc.) from the output
+        #[clap(long, value_parser, default_value("false"), takes_value(false))]
+        strip_ansi: bool,
+        /// Append to the file if it already exists rather than truncating it
+        #[clap(long, value_parser, default_value("false"), takes_value(false))]
+        append: bool,
+        /// Rotate the file once it grows beyond this many bytes
+        #[clap(long, value_parser)]
+        max_size: Option<u64>,
+        /// How many rotated files (<path>.1, <path>.2, ...) to keep
+        #[clap(long, value_parser, default_value("5"))]
+        max_files: usize,
+        /// Stop writing the output of this pane to a file
+        #[clap(
+            long,
+            value_parser,
+            default_value("false"),
+            takes_value(false),
+            conflicts_with("path")
+        )]
+        stop: bool,
+    },
+    /// Record the output of a pane along with its timing into an asciicast v2 file, until the
+    /// pane closes or the recording is stopped with --stop
+    Record {
+        /// The pane_id of the pane, eg. terminal_1 or 3 (equivalent to terminal_3)
+        #[clap(short, long, value_parser)]
+        pane_id: String,
+        /// The file to record to (eg. run.cast)
+        #[clap(short, long, value_parser, required_unless_present("stop"))]
+        out: Option<PathBuf>,
+        /// Stop recording this pane
+        #[clap(
+            long,
+            value_parser,
+            default_value("false"),
+            takes_value(false),
+            conflicts_with("out")
+        )]
+        stop: bool,
+    },
+    /// Make the focused client mirror the tab and pane focus of another client, until the next
+    /// keypress
+    Follow {
+        /// The id of the client to follow (see list-clients)
+        #[clap(short, long, value_parser)]
+        client: u16,
+    },
     /// Detach from the current session
     Detach,
     /// Switch to a different session
@@ -1011,6 +1011,17 @@ pub enum Event {
     ActionComplete(Action, Option<PaneId>, BTreeMap<String, String>), // Action, pane_id, context
     CwdChanged(PaneId, PathBuf, Vec<ClientId>), // pane_id, cwd, focused_client_ids
     AvailableLayoutInfo(Vec<LayoutInfo>, Vec<LayoutWithError>),
+    /// New output from a pane the plugin subscribed to with `SubscribeToPaneOutput` (requires
+    /// ReadPaneContents permission)
+    PaneOutput(PaneId, PaneOutput),
+    /// Another plugin called one of the methods this plugin registered with `RegisterRpcMethod`,
+    /// it should be answered with `RespondToRpcRequest`
+    RpcRequest(u64, String, Vec<u8>, u32), // request id, method, payload, caller plugin id
+    /// The answer to a request sent with `SendRpcRequest`, or the reason it failed
+    RpcResponse(u64, Result<Vec<u8>, String>), // request id, payload or error
+    /// A request to one of the routes this plugin registered with `RegisterHttpRoute`, it should
+    /// be answered with `RespondToHttpRequest` (requires ServeHttpRoutes permission)
+    HttpRequest(u64, PluginHttpRequest), // request id, request
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumDiscriminants, Display, Serialize, Deserialize)]
@@ -1054,6 +1065,7 @@ pub enum Permission {
     ReadPaneContents,
     RunActionsAsUser,
     WriteToClipboard,
+    ServeHttpRoutes,
 }
 
 impl PermissionType {
@@ -1085,6 +1097,9 @@ impl PermissionType {
             },
             PermissionType::RunActionsAsUser => "Execute actions as the user".to_owned(),
             PermissionType::WriteToClipboard => "Write to clipboard".to_owned(),
+            PermissionType::ServeHttpRoutes => {
+                "Serve HTTP routes through the Zellij web server".to_owned()
+            },
         }
     }
 }
@@ -1161,6 +1176,12 @@ pub enum InputMode {
     /// `Tmux` mode allows for basic tmux keybindings functionality
     #[serde(alias = "tmux")]
     Tmux,
+    /// `MarkPane` mode allows marking the focused pane with a single key.
+    #[serde(alias = "markpane")]
+    MarkPane,
+    /// `FocusMark` mode allows jumping to a marked pane with a single key.
+    #[serde(alias = "focusmark")]
+    FocusMark,
 }
 
 impl Default for InputMode {
@@ -1251,6 +1272,8 @@ impl FromStr for InputMode {
             "move" | "Move" => Ok(InputMode::Move),
             "prompt" | "Prompt" => Ok(InputMode::Prompt),
             "tmux" | "Tmux" => Ok(InputMode::Tmux),
+            "markpane" | "MarkPane" => Ok(InputMode::MarkPane),
+            "focusmark" | "FocusMark" => Ok(InputMode::FocusMark),
             "entersearch" | "Entersearch" | "EnterSearch" => Ok(InputMode::EnterSearch),
             e => Err(ConversionError::UnknownInputMode(e.into())),
         }
@@ -1657,6 +1680,10 @@ pub struct ModeInfo {
     pub web_server_ip: Option<IpAddr>,
     pub web_server_port: Option<u16>,
     pub web_server_capability: Option<bool>,
+    /// The client whose tab and pane focus this client is currently mirroring
+    pub following_client: Option<ClientId>,
+    /// The display name of the followed client, if it has one
+    pub following_client_name: Option<String>,
 }
 
 impl ModeInfo {
@@ -1718,6 +1745,8 @@ pub struct SessionInfo {
 pub struct PluginInfo {
     pub location: String,
     pub configuration: BTreeMap<String, String>,
+    /// The reason this plugin was suspended (eg. it exceeded its execution budget), if it was
+    pub suspended: Option<String>,
 }
 
 impl From<RunPlugin> for PluginInfo {
@@ -1725,6 +1754,7 @@ impl From<RunPlugin> for PluginInfo {
         PluginInfo {
             location: run_plugin.location.display(),
             configuration: run_plugin.configuration.inner().clone(),
+            suspended: None,
         }
     }
 }
@@ -1764,13 +1794,32 @@ pub struct LayoutMetadata {
     pub tabs: Vec<TabMetadata>,
     pub creation_time: String,
     pub update_time: String,
+    pub params: Vec<LayoutParam>,
+}
+
+/// A parameter declared in the `params` block of a layout, interpolated with `${name}`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LayoutParam {
+    pub name: String,
+    pub default: Option<String>,
+    pub required: bool,
+    /// The value to open the layout with (eg. given with `--param` or prompted for)
+    pub value: Option<String>,
 }
 
 impl From<&PathBuf> for LayoutMetadata {
     fn from(path: &PathBuf) -> LayoutMetadata {
         match Layout::stringified_from_path(path) {
             Ok((path_str, stringified_layout, _swap_layouts)) => {
-                match Layout::from_kdl(&stringified_layout, Some(path_str), None, None) {
+                let params = Layout::params_from_kdl(&stringified_layout).unwrap_or_default();
+                match Layout::from_kdl_with_params(
+                    &stringified_layout,
+                    Some(path_str),
+                    None,
+                    None,
+                    LayoutMetadata::placeholder_param_values(&params),
+                    None,
+                ) {
                     Ok(layout) => {
                         let layout_tabs = layout.tabs();
                         let tabs = if layout_tabs.is_empty() {
@@ -1796,6 +1845,7 @@ impl From<&PathBuf> for LayoutMetadata {
                             tabs,
                             creation_time,
                             update_time,
+                            params,
                         }
                     },
                     Err(e) => {
@@ -1813,6 +1863,29 @@ impl From<&PathBuf> for LayoutMetadata {
 }
 
 impl LayoutMetadata {
+    /// The values of the parameters of the layout that were given (see [`LayoutParam::value`])
+    pub fn param_values(&self) -> BTreeMap<String, String> {
+        self.params
+            .iter()
+            .filter_map(|p| p.value.clone().map(|value| (p.name.clone(), value)))
+            .collect()
+    }
+    /// The required parameters of the layout that were not given a value
+    pub fn missing_params(&self) -> Vec<&LayoutParam> {
+        self.params
+            .iter()
+            .filter(|p| p.required && p.value.is_none())
+            .collect()
+    }
+    /// Values for the parameters of a layout good enough to parse it without the real ones, eg.
+    /// to describe its tabs and panes before the user was asked for them
+    pub fn placeholder_param_values(params: &[LayoutParam]) -> BTreeMap<String, String> {
+        params
+            .iter()
+            .filter(|p| p.required)
+            .map(|p| (p.name.clone(), format!("<{}>", p.name)))
+            .collect()
+    }
     fn creation_and_update_times(path: &PathBuf) -> (String, String) {
         // (creation_time, update_time) returns stringified unix epoch
         match std::fs::metadata(path) {
@@ -2004,6 +2077,25 @@ impl LayoutInfo {
             LayoutInfo::Stringified(_stringified) => false,
         }
     }
+    /// Sets the values of the parameters to open this layout with, only layout files can have
+    /// parameters
+    pub fn with_param_values(mut self, param_values: &BTreeMap<String, String>) -> Self {
+        if let LayoutInfo::File(_, layout_metadata) = &mut self {
+            for (name, value) in param_values {
+                match layout_metadata.params.iter_mut().find(|p| &p.name == name) {
+                    Some(param) => param.value = Some(value.clone()),
+                    // the layout will fail to parse with an unknown parameter, letting the user
+                    // know about it
+                    None => layout_metadata.params.push(LayoutParam {
+                        name: name.clone(),
+                        value: Some(value.clone()),
+                        ..Default::default()
+                    }),
+                }
+            }
+        }
+        self
+    }
     pub fn from_config(
         layout_dir: &Option<PathBuf>,
         layout_path: &Option<PathBuf>,
@@ -2067,6 +2159,14 @@ impl SessionInfo {
         }
         self.plugins = plugin_list;
     }
+    pub fn populate_suspended_plugins(&mut self, suspended_plugins: BTreeMap<u32, String>) {
+        // u32 - plugin_id, String - the reason the plugin was suspended
+        for (plugin_id, reason) in suspended_plugins {
+            if let Some(plugin_info) = self.plugins.get_mut(&plugin_id) {
+                plugin_info.suspended = Some(reason);
+            }
+        }
+    }
 }
 
 /// Contains all the information for a currently opened tab.
@@ -2218,6 +2318,170 @@ impl PaneRenderReport {
     }
 }
 
+/// What to look for when searching the scrollback of a pane
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchQuery {
+    pub needle: String,
+    // interpret the needle as a regular expression rather than a literal string
+    pub regex: bool,
+    pub case_insensitive: bool,
+    pub whole_word: bool,
+    // how many lines before and after every match to include with it
+    pub context_lines: usize,
+}
+
+/// A single search hit inside a pane's scrollback
+///
+/// Lines are logical lines (a line soft-wrapped by the terminal counts as one) numbered from the
+/// top of the scrollback, columns are character offsets inside that line, `end_column` is
+/// exclusive
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchMatch {
+    pub line: usize,
+    pub start_column: usize,
+    pub end_column: usize,
+    pub text: String,
+    pub context_before: Vec<String>,
+    pub context_after: Vec<String>,
+}
+
+/// How a plugin subscribed to the output of a pane wants to receive it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaneOutputFormat {
+    /// The bytes exactly as the program in the pane wrote them, ANSI sequences included
+    #[default]
+    Raw,
+    /// Completed lines of text, with ANSI sequences stripped
+    Lines,
+}
+
+/// A batch of output from a pane, as delivered by the `PaneOutput` event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaneOutput {
+    Raw(Vec<u8>),
+    Lines(Vec<String>),
+}
+
+/// All the search hits inside one pane, as part of a session-wide search
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaneSearchResults {
+    pub tab_position: usize,
+    pub tab_name: String,
+    pub pane_id: PaneId,
+    pub pane_title: String,
+    pub matches: Vec<SearchMatch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SessionSearchResponse {
+    Ok(Vec<PaneSearchResults>),
+    Err(String),
+}
+
+/// How to continuously write the output of a pane to a file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaneLogOptions {
+    pub path: PathBuf,
+    // write the output without ANSI escape sequences (colors, cursor movements, etc.)
+    pub strip_ansi: bool,
+    // append to the file if it already exists rather than truncating it
+    pub append: bool,
+    // rotate the file once it grows beyond this many bytes
+    pub max_size: Option<u64>,
+    // how many rotated files (path.1, path.2, ...) to keep around
+    pub max_files: usize,
+}
+
+impl PaneLogOptions {
+    pub const DEFAULT_MAX_FILES: usize = 5;
+    pub fn new(path: PathBuf) -> Self {
+        PaneLogOptions {
+            path,
+            strip_ansi: false,
+            append: true,
+            max_size: None,
+            max_files: Self::DEFAULT_MAX_FILES,
+        }
+    }
+}
+
+/// The format in which to dump the contents of a pane to a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DumpScreenFormat {
+    /// Plain text, all styling is dropped
+    #[default]
+    Text,
+    /// Text with ANSI escape sequences for colors, styles and hyperlinks
+    Ansi,
+    /// A standalone HTML document
+    Html,
+    /// A standalone SVG image
+    Svg,
+}
+
+impl FromStr for DumpScreenFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Text" | "text" | "txt" => Ok(DumpScreenFormat::Text),
+            "Ansi" | "ansi" => Ok(DumpScreenFormat::Ansi),
+            "Html" | "html" => Ok(DumpScreenFormat::Html),
+            "Svg" | "svg" => Ok(DumpScreenFormat::Svg),
+            _ => Err(format!(
+                "Failed to parse DumpScreenFormat. Unknown format: {} (expected one of: text, ansi, html, svg)",
+                s
+            )),
+        }
+    }
+}
+
+/// How the tiled panes of a tab are arranged when panes are opened or closed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TilingMode {
+    /// New panes split the largest pane (or follow the swap layouts), nothing is rebalanced
+    #[default]
+    Manual,
+    /// Binary space partitioning, each new pane splits the space left over by the previous one
+    Bsp,
+    /// The first pane takes half the tab, all the others are stacked next to it
+    MasterStack,
+    /// All panes are arranged in an even grid
+    Grid,
+}
+
+impl TilingMode {
+    pub fn is_manual(&self) -> bool {
+        self == &TilingMode::Manual
+    }
+}
+
+impl fmt::Display for TilingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TilingMode::Manual => write!(f, "manual"),
+            TilingMode::Bsp => write!(f, "bsp"),
+            TilingMode::MasterStack => write!(f, "master-stack"),
+            TilingMode::Grid => write!(f, "grid"),
+        }
+    }
+}
+
+impl FromStr for TilingMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Manual" | "manual" => Ok(TilingMode::Manual),
+            "Bsp" | "bsp" => Ok(TilingMode::Bsp),
+            "MasterStack" | "master-stack" | "master_stack" => Ok(TilingMode::MasterStack),
+            "Grid" | "grid" => Ok(TilingMode::Grid),
+            _ => Err(format!(
+                "Failed to parse TilingMode. Unknown mode: {} (expected one of: manual, bsp, master-stack, grid)",
+                s
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PaneContents {
     // NOTE: both lines_above_viewport and lines_below_viewport are only populated if explicitly
@@ -2379,6 +2643,58 @@ pub enum GetPanePidResponse {
     Err(String),
 }
 
+/// Which of its key/value stores a plugin is reading from or writing to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KvScope {
+    /// Shared by all instances of the plugin in this session, gone once the session ends
+    #[default]
+    Session,
+    /// Shared by all instances of the plugin in all sessions, kept across restarts
+    Global,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KvResponse {
+    Done,
+    Value(Option<Vec<u8>>),
+    Keys(Vec<String>),
+    Err(String),
+}
+
+/// A request the web server received under `/plugins/<route>/...`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginHttpRequest {
+    pub route: String,
+    pub method: String,
+    // the rest of the path after the route, starting with a slash (eg. "/status")
+    pub path: String,
+    // the raw query string, without the leading question mark
+    pub query: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginHttpResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl PluginHttpResponse {
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        PluginHttpResponse {
+            status,
+            headers: BTreeMap::new(),
+            body: body.into(),
+        }
+    }
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum GetFocusedPaneInfoResponse {
     Ok { tab_index: usize, pane_id: PaneId },
@@ -2505,7 +2821,7 @@ pub enum PermissionStatus {
     Denied,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct FileToOpen {
     pub path: PathBuf,
     pub line_number: Option<usize>,
@@ -2529,11 +2845,12 @@ impl FileToOpen {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct CommandToRun {
     pub path: PathBuf,
     pub args: Vec<String>,
     pub cwd: Option<PathBuf>,
+    pub env: BTreeMap<String, String>,
 }
 
 impl CommandToRun {
@@ -2550,9 +2867,13 @@ impl CommandToRun {
             ..Default::default()
         }
     }
+    pub fn with_env(mut self, env: BTreeMap<String, String>) -> Self {
+        self.env = env;
+        self
+    }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct MessageToPlugin {
     pub plugin_url: Option<String>,
     pub destination_plugin_id: Option<u32>,
@@ -2566,7 +2887,7 @@ pub struct MessageToPlugin {
     pub floating_pane_coordinates: Option<FloatingPaneCoordinates>,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct NewPluginArgs {
     pub should_float: Option<bool>,
     pub pane_id_to_replace: Option<PaneId>,
@@ -2695,7 +3016,7 @@ impl ConnectToSession {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct PluginMessage {
     pub name: String,
     pub payload: String,
@@ -3075,7 +3396,7 @@ impl NewPanePlacement {
 
 type Context = BTreeMap<String, String>;
 
-#[derive(Debug, Clone, EnumDiscriminants, Display)]
+#[derive(Debug, Clone, PartialEq, EnumDiscriminants, Display)]
 #[strum_discriminants(derive(EnumString, Hash, Serialize, Deserialize))]
 #[strum_discriminants(name(CommandType))]
 pub enum PluginCommand {
@@ -3301,4 +3622,51 @@ pub enum PluginCommand {
     ParseLayout(String), // String contains raw KDL layout
     GetLayoutDir,
     GetFocusedPaneInfo,
+    SearchSession(SearchQuery),
+    JumpToSearchMatch {
+        pane_id: PaneId,
+        line: usize,
+    },
+    SubscribeToPaneOutput(PaneId, PaneOutputFormat),
+    UnsubscribeFromPaneOutput(PaneId),
+    KvGet {
+        scope: KvScope,
+        key: String,
+    },
+    KvSet {
+        scope: KvScope,
+        key: String,
+        value: Vec<u8>,
+    },
+    KvDelete {
+        scope: KvScope,
+        key: String,
+    },
+    KvList {
+        scope: KvScope,
+    },
+    RegisterRpcMethod(String),
+    UnregisterRpcMethod(String),
+    SendRpcRequest {
+        method: String,
+        payload: Vec<u8>,
+        // only send the request to this plugin, rather than to any plugin serving the method
+        destination_plugin_id: Option<u32>,
+        timeout_ms: Option<u64>,
+    },
+    RespondToRpcRequest {
+        request_id: u64,
+        result: Result<Vec<u8>, String>,
+    },
+    RegisterHttpRoute(String),
+    UnregisterHttpRoute(String),
+    RespondToHttpRequest {
+        request_id: u64,
+        response: PluginHttpResponse,
+    },
+    ListGrantedPermissions,
+    RevokePluginPermissions {
+        plugin_url: String,
+        permissions: Vec<PermissionType>, // revokes all of them if empty
+    },
 }
@@ -285,8 +285,14 @@ pub enum ScreenContext {
     SetFixedWidth,
     ClosePane,
     HoldPane,
+    CheckPaneDependencies,
+    UpdatePaneRestarts,
     UpdatePaneName,
     UndoRenamePane,
+    MarkPane,
+    FocusMark,
+    Follow,
+    StopFollowing,
     NewTab,
     ApplyLayout,
     SwitchTabNext,
@@ -316,16 +322,19 @@ pub enum ScreenContext {
     ToggleTab,
     AddClient,
     RemoveClient,
+    SetClientName,
     UpdateSearch,
     SearchDown,
     SearchUp,
     SearchToggleCaseSensitivity,
     SearchToggleWholeWord,
     SearchToggleWrap,
+    SearchToggleRegex,
     AddRedPaneFrameColorOverride,
     ClearPaneFrameColorOverride,
     PreviousSwapLayout,
     NextSwapLayout,
+    SetTilingMode,
     OverrideLayout,
     OverrideLayoutComplete,
     QueryTabNames,
@@ -400,7 +409,17 @@ pub enum ScreenContext {
     RemoveWatcherClient,
     SetFollowedClient,
     WatcherTerminalResize, // NEW
-    Notify, // Perth STORY-003
+    Notify,                // Perth STORY-003
+    SearchPane,
+    SearchSession,
+    GetSessionSearchResults,
+    JumpToSearchMatch,
+    LogPane,
+    RecordPane,
+    SubscribeToPaneOutput,
+    UnsubscribeFromPaneOutput,
+    ClearPaneOutputSubscriptions,
+    FlushPaneOutput,
 }
 
 /// Stack call representations corresponding to the different types of [`PtyInstruction`]s.
@@ -416,6 +435,7 @@ pub enum PtyContext {
     OverrideLayout,
     ClosePane,
     CloseTab,
+    CommandExited,
     ReRunCommandInPane,
     DropToShellInPane,
     SpawnInPlaceTerminal,
@@ -477,6 +497,18 @@ pub enum PluginContext {
     UserInput,
     LayoutListUpdate,
     RequestStateUpdateForPlugin,
+    RegisterRpcMethod,
+    UnregisterRpcMethod,
+    RpcRequest,
+    RpcResponse,
+    RpcTimeout,
+    DevModePluginFilesChanged,
+    RegisterHttpRoute,
+    UnregisterHttpRoute,
+    HttpRequest,
+    HttpResponse,
+    HttpRequestTimeout,
+    RevokePluginPermissions,
 }
 
 /// Stack call representations corresponding to the different types of [`ClientInstruction`]s.
@@ -503,6 +535,7 @@ pub enum ClientContext {
     StartWebServer,
     RenamedSession,
     ConfigFileUpdated,
+    PluginHttpResponse,
 }
 
 /// Stack call representations corresponding to the different types of [`ServerInstruction`]s.
@@ -537,6 +570,8 @@ pub enum ServerContext {
     WebServerStarted,
     FailedToStartWebServer,
     SendWebClientsForbidden,
+    PluginHttpRequest,
+    PluginHttpResponse,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -559,6 +594,9 @@ pub enum BackgroundJobContext {
     RunCommand,
     WebRequest,
     ReportPluginList,
+    ReportPluginSuspended,
+    RecordPermissionUse,
+    FlushPaneOutput,
     ListWebSessions,
     RenderToClients,
     HighlightPanesWithMessage,
@@ -7,8 +7,9 @@ use super::layout::{
 };
 use crate::cli::CliAction;
 use crate::data::{
-    CommandOrPlugin, Direction, KeyWithModifier, LayoutInfo, NewPanePlacement, OriginatingPlugin,
-    PaneId, Resize, UnblockCondition,
+    ClientId, CommandOrPlugin, Direction, DumpScreenFormat, KeyWithModifier, LayoutInfo,
+    NewPanePlacement, OriginatingPlugin, PaneId, PaneLogOptions, Resize, SearchQuery, TilingMode,
+    UnblockCondition,
 };
 use crate::data::{FloatingPaneCoordinates, InputMode};
 use crate::home::{find_default_config_dir, get_layout_dir};
@@ -78,6 +79,7 @@ pub enum SearchOption {
     CaseSensitivity,
     WholeWord,
     Wrap,
+    Regex,
 }
 
 impl FromStr for SearchOption {
@@ -89,6 +91,7 @@ impl FromStr for SearchOption {
             },
             "WholeWord" | "wholeword" | "Wholeword" => Ok(SearchOption::WholeWord),
             "Wrap" | "wrap" => Ok(SearchOption::Wrap),
+            "Regex" | "regex" => Ok(SearchOption::Regex),
             _ => Err(format!(
                 "Failed to parse SearchOption. Unknown SearchOption: {}",
                 s
@@ -163,6 +166,7 @@ pub enum Action {
     DumpScreen {
         file_path: String,
         include_scrollback: bool,
+        format: DumpScreenFormat,
     },
     /// Dumps
     DumpLayout,
@@ -259,6 +263,14 @@ pub enum Action {
         input: Vec<u8>,
     },
     UndoRenamePane,
+    /// Mark the focused pane, moving the mark from any other pane that has it
+    MarkPane {
+        name: String,
+    },
+    /// Focus the pane with the given mark, in whichever tab it is
+    FocusMark {
+        name: String,
+    },
     /// Create a new tab, optionally with a specified tab layout.
     NewTab {
         tiled_layout: Option<TiledPaneLayout>,
@@ -350,6 +362,10 @@ pub enum Action {
     ToggleMouseMode,
     PreviousSwapLayout,
     NextSwapLayout,
+    /// Set the tiling mode of the active tab, rebalancing its tiled panes
+    SetTilingMode {
+        tiling_mode: TilingMode,
+    },
     /// Override the layout of the active tab
     OverrideLayout {
         tabs: Vec<TabLayoutInfo>,
@@ -466,6 +482,36 @@ pub enum Action {
     },
     TogglePaneInGroup,
     ToggleGroupMarking,
+    /// Search the full scrollback of a pane and report the matches
+    SearchPane {
+        pane_id: PaneId,
+        query: SearchQuery,
+        json: bool,
+    },
+    /// Search the full scrollback of every terminal pane in the session and report the matches
+    /// as JSON
+    SearchSession {
+        query: SearchQuery,
+    },
+    /// Focus a pane and scroll it to a line of its scrollback
+    JumpToSearchMatch {
+        pane_id: PaneId,
+        line: usize,
+    },
+    /// Start writing the output of a pane to a file, or stop if `log_options` is None
+    LogPane {
+        pane_id: PaneId,
+        log_options: Option<PaneLogOptions>,
+    },
+    /// Start recording a pane into an asciicast file, or stop if `out` is None
+    RecordPane {
+        pane_id: PaneId,
+        out: Option<PathBuf>,
+    },
+    /// Mirror the tab and pane focus of another client until the next keypress
+    Follow {
+        client_id: ClientId,
+    },
 }
 
 impl Default for Action {
@@ -523,9 +569,10 @@ impl Action {
             CliAction::MovePaneBackwards => Ok(vec![Action::MovePaneBackwards]),
             CliAction::MoveTab { direction } => Ok(vec![Action::MoveTab { direction }]),
             CliAction::Clear => Ok(vec![Action::ClearScreen]),
-            CliAction::DumpScreen { path, full } => Ok(vec![Action::DumpScreen {
+            CliAction::DumpScreen { path, full, format } => Ok(vec![Action::DumpScreen {
                 file_path: path.as_os_str().to_string_lossy().into(),
                 include_scrollback: full,
+                format,
             }]),
             CliAction::DumpLayout => Ok(vec![Action::DumpLayout]),
             CliAction::EditScrollback => Ok(vec![Action::EditScrollback]),
@@ -545,6 +592,9 @@ impl Action {
                 command,
                 plugin,
                 cwd,
+                env,
+                restart,
+                max_restarts,
                 floating,
                 in_place,
                 name,
@@ -588,6 +638,9 @@ impl Action {
                             direction,
                             hold_on_close,
                             hold_on_start,
+                            env: env.into_iter().collect(),
+                            restart: restart.unwrap_or_default(),
+                            max_restarts,
                             ..Default::default()
                         })
                     } else {
@@ -688,6 +741,9 @@ impl Action {
                         direction,
                         hold_on_close,
                         hold_on_start,
+                        env: env.into_iter().collect(),
+                        restart: restart.unwrap_or_default(),
+                        max_restarts,
                         ..Default::default()
                     };
                     if floating {
@@ -806,6 +862,8 @@ impl Action {
                 },
             ]),
             CliAction::UndoRenamePane => Ok(vec![Action::UndoRenamePane]),
+            CliAction::MarkPane { name } => Ok(vec![Action::MarkPane { name }]),
+            CliAction::FocusMark { name } => Ok(vec![Action::FocusMark { name }]),
             CliAction::GoToNextTab => Ok(vec![Action::GoToNextTab]),
             CliAction::GoToPreviousTab => Ok(vec![Action::GoToPreviousTab]),
             CliAction::CloseTab => Ok(vec![Action::CloseTab]),
@@ -824,6 +882,7 @@ impl Action {
                 name,
                 layout,
                 layout_dir,
+                params,
                 cwd,
                 initial_command,
                 initial_plugin,
@@ -909,10 +968,10 @@ impl Action {
                             None,
                         )
                     } else {
-                        Layout::stringified_from_path_or_default(Some(&layout_path), layout_dir)
+                        Layout::stringified_from_path_or_default(Some(&layout_path), layout_dir.clone())
                             .map_err(|e| format!("Failed to load layout: {}", e))?
                     };
-                    let mut layout = Layout::from_str(&raw_layout, path_to_raw_layout, swap_layouts.as_ref().map(|(f, p)| (f.as_str(), p.as_str())), cwd).map_err(|e| {
+                    let mut layout = Layout::from_kdl_with_params(&raw_layout, Some(path_to_raw_layout), swap_layouts.as_ref().map(|(f, p)| (f.as_str(), p.as_str())), cwd, params.into_iter().collect(), layout_dir).map_err(|e| {
                         let stringified_error = match e {
                             ConfigError::KdlError(kdl_error) => {
                                 let error = kdl_error.add_src(layout_path.as_path().as_os_str().to_string_lossy().to_string(), String::from(raw_layout));
@@ -1013,6 +1072,9 @@ impl Action {
             },
             CliAction::PreviousSwapLayout => Ok(vec![Action::PreviousSwapLayout]),
             CliAction::NextSwapLayout => Ok(vec![Action::NextSwapLayout]),
+            CliAction::SetTilingMode { tiling_mode } => {
+                Ok(vec![Action::SetTilingMode { tiling_mode }])
+            },
             CliAction::OverrideLayout {
                 layout,
                 layout_dir,
@@ -1326,6 +1388,92 @@ impl Action {
                     }
                 }
             },
+            CliAction::Search {
+                pane_id,
+                needle,
+                regex,
+                case_insensitive,
+                whole_word,
+                context,
+                json,
+            } => match PaneId::from_str(&pane_id) {
+                Ok(pane_id) => Ok(vec![Action::SearchPane {
+                    pane_id,
+                    query: SearchQuery {
+                        needle,
+                        regex,
+                        case_insensitive,
+                        whole_word,
+                        context_lines: context,
+                    },
+                    json,
+                }]),
+                Err(_e) => Err(format!(
+                    "Malformed pane id: {}, expecting either a bare integer (eg. 1), a terminal pane id (eg. terminal_1) or a plugin pane id (eg. plugin_1)",
+                    pane_id
+                )),
+            },
+            CliAction::SearchSession {
+                needle,
+                regex,
+                case_insensitive,
+                whole_word,
+                context,
+            } => Ok(vec![Action::SearchSession {
+                query: SearchQuery {
+                    needle,
+                    regex,
+                    case_insensitive,
+                    whole_word,
+                    context_lines: context,
+                },
+            }]),
+            CliAction::JumpToSearchMatch { pane_id, line } => match PaneId::from_str(&pane_id) {
+                Ok(pane_id) => Ok(vec![Action::JumpToSearchMatch { pane_id, line }]),
+                Err(_e) => Err(format!(
+                    "Malformed pane id: {}, expecting either a bare integer (eg. 1), a terminal pane id (eg. terminal_1) or a plugin pane id (eg. plugin_1)",
+                    pane_id
+                )),
+            },
+            CliAction::LogPane {
+                pane_id,
+                path,
+                strip_ansi,
+                append,
+                max_size,
+                max_files,
+                stop,
+            } => {
+                let pane_id = PaneId::from_str(&pane_id).map_err(|_e| format!(
+                    "Malformed pane id: {}, expecting either a bare integer (eg. 1), a terminal pane id (eg. terminal_1) or a plugin pane id (eg. plugin_1)",
+                    pane_id
+                ))?;
+                let log_options = match path {
+                    Some(path) if !stop => Some(PaneLogOptions {
+                        path: get_current_dir().join(path),
+                        strip_ansi,
+                        append,
+                        max_size,
+                        max_files,
+                    }),
+                    _ => None,
+                };
+                Ok(vec![Action::LogPane {
+                    pane_id,
+                    log_options,
+                }])
+            },
+            CliAction::Record { pane_id, out, stop } => {
+                let pane_id = PaneId::from_str(&pane_id).map_err(|_e| format!(
+                    "Malformed pane id: {}, expecting either a bare integer (eg. 1), a terminal pane id (eg. terminal_1) or a plugin pane id (eg. plugin_1)",
+                    pane_id
+                ))?;
+                let out = out
+                    .filter(|_| !stop)
+                    .map(|out| get_current_dir().join(out));
+                Ok(vec![Action::RecordPane { pane_id, out }])
+            },
+            CliAction::Follow { client } => Ok(vec![Action::Follow { client_id: client }]),
             CliAction::Detach => Ok(vec![Action::Detach]),
             CliAction::SwitchSession {
                 name,
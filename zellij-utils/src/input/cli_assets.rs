@@ -22,6 +22,8 @@ pub struct CliAssets {
     pub max_panes: Option<usize>,
     pub force_run_layout_commands: bool,
     pub cwd: Option<PathBuf>,
+    /// How this client is shown to other clients, eg. when they follow it
+    pub client_name: Option<String>,
 }
 
 impl CliAssets {
@@ -1,7 +1,10 @@
 //! Trigger a command
 use crate::data::{Direction, OriginatingPlugin};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub enum TerminalAction {
@@ -72,6 +75,13 @@ pub struct RunCommand {
     pub originating_plugin: Option<OriginatingPlugin>,
     #[serde(default)]
     pub use_terminal_title: bool,
+    // environment variables set for this command only, on top of the ones it inherits
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub restart: RestartPolicy,
+    #[serde(default)]
+    pub max_restarts: Option<usize>,
 }
 
 impl std::fmt::Display for RunCommand {
@@ -109,6 +119,12 @@ pub struct RunCommandAction {
     pub originating_plugin: Option<OriginatingPlugin>,
     #[serde(default)]
     pub use_terminal_title: bool,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub restart: RestartPolicy,
+    #[serde(default)]
+    pub max_restarts: Option<usize>,
 }
 
 impl From<RunCommandAction> for RunCommand {
@@ -121,6 +137,9 @@ impl From<RunCommandAction> for RunCommand {
             hold_on_start: action.hold_on_start,
             originating_plugin: action.originating_plugin,
             use_terminal_title: action.use_terminal_title,
+            env: action.env,
+            restart: action.restart,
+            max_restarts: action.max_restarts,
         }
     }
 }
@@ -136,6 +155,9 @@ impl From<RunCommand> for RunCommandAction {
             hold_on_start: run_command.hold_on_start,
             originating_plugin: run_command.originating_plugin,
             use_terminal_title: run_command.use_terminal_title,
+            env: run_command.env,
+            restart: run_command.restart,
+            max_restarts: run_command.max_restarts,
         }
     }
 }
@@ -168,4 +190,95 @@ impl RunCommand {
         self.cwd = Some(cwd);
         self
     }
+    pub fn with_env(mut self, env: BTreeMap<String, String>) -> Self {
+        self.env = env;
+        self
+    }
+    pub fn max_restarts(&self) -> usize {
+        self.max_restarts.unwrap_or(DEFAULT_MAX_RESTARTS)
+    }
+    /// Replay an asciicast recording (eg. one made with `zellij action record`) in a terminal
+    /// pane, by running our own `playback` command in it
+    pub fn playback(file: PathBuf, speed: Option<f64>) -> Self {
+        let command = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("zellij"));
+        let mut args = vec!["playback".to_owned(), file.display().to_string()];
+        if let Some(speed) = speed {
+            args.push("--speed".to_owned());
+            args.push(speed.to_string());
+        }
+        RunCommand {
+            command,
+            args,
+            hold_on_close: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// How many times a command pane with a restart policy is restarted in a row before giving up,
+/// unless specified otherwise
+pub const DEFAULT_MAX_RESTARTS: usize = 3;
+
+/// When to automatically restart the command of a command pane once it exits
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum RestartPolicy {
+    /// The pane is held (or closed) as usual
+    #[default]
+    Never,
+    /// Restart the command if it exited with a non-zero exit status or was killed
+    OnFailure,
+    /// Restart the command whenever it exits
+    Always,
+}
+
+impl RestartPolicy {
+    pub fn should_restart(&self, exit_status: Option<i32>) -> bool {
+        match self {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure => exit_status != Some(0),
+            RestartPolicy::Always => true,
+        }
+    }
+    /// The time to wait before the given restart (1-based), doubling with each attempt: 1s, 2s,
+    /// 4s and 8s from then on
+    pub fn backoff(restart_count: usize) -> Duration {
+        let secs = 1u64 << restart_count.saturating_sub(1).min(3);
+        Duration::from_secs(secs)
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(RestartPolicy::Never),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "always" => Ok(RestartPolicy::Always),
+            _ => Err(format!(
+                "Unknown restart policy: {} (expected one of: never, on-failure, always)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestartPolicy::Never => write!(f, "never"),
+            RestartPolicy::OnFailure => write!(f, "on-failure"),
+            RestartPolicy::Always => write!(f, "always"),
+        }
+    }
+}
+
+/// Parses a `KEY=VALUE` pair, as given to the `--env` flag of commands
+pub fn parse_env_var(env_var: &str) -> Result<(String, String), String> {
+    match env_var.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!(
+            "Invalid environment variable: {}, expected KEY=VALUE",
+            env_var
+        )),
+    }
 }
@@ -51,6 +51,7 @@ impl Keybinds {
                     mode_keybindings.get(key_with_modifier).cloned()
                 }
             })
+            .or_else(|| mark_actions_for_key(mode, key_with_modifier, default_input_mode))
             .unwrap_or_else(|| {
                 vec![self.default_action_for_mode(
                     mode,
@@ -116,6 +117,30 @@ impl Keybinds {
     }
 }
 
+// in the mark modes every unbound character is the name of a mark, so that setting or jumping to
+// a mark takes a single keypress after which we return to the default mode
+fn mark_actions_for_key(
+    mode: &InputMode,
+    key_with_modifier: &KeyWithModifier,
+    default_input_mode: InputMode,
+) -> Option<Vec<Action>> {
+    let name = match key_with_modifier.bare_key {
+        BareKey::Char(c) if key_with_modifier.key_modifiers.is_empty() => c.to_string(),
+        _ => return None,
+    };
+    let mark_action = match mode {
+        InputMode::MarkPane => Action::MarkPane { name },
+        InputMode::FocusMark => Action::FocusMark { name },
+        _ => return None,
+    };
+    Some(vec![
+        mark_action,
+        Action::SwitchToMode {
+            input_mode: default_input_mode,
+        },
+    ])
+}
+
 // we need to do this because [10] in standard STDIN, [10] is both Enter (without a carriage
 // return) and ctrl-j - so here, if ctrl-j is bound we return its bound action, and otherwise we
 // just write the raw bytes to the terminal and let whichever program is there decide what they are
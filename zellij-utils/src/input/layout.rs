@@ -11,10 +11,12 @@
 #[cfg(not(target_family = "wasm"))]
 use crate::downloader::Downloader;
 use crate::{
-    data::{Direction, LayoutInfo, LayoutMetadata, LayoutParsingError, LayoutWithError},
+    data::{
+        Direction, LayoutInfo, LayoutMetadata, LayoutParsingError, LayoutWithError, TilingMode,
+    },
     home::{default_layout_dir, find_default_config_dir},
     input::{
-        command::RunCommand,
+        command::{RestartPolicy, RunCommand},
         config::{Config, ConfigError},
     },
     pane_size::{Constraint, Dimension, PaneGeom},
@@ -279,6 +281,18 @@ impl Run {
                 if merged.args.is_empty() && !base_run_command.args.is_empty() {
                     merged.args = base_run_command.args.clone();
                 }
+                for (key, value) in &base_run_command.env {
+                    merged
+                        .env
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+                if merged.restart == RestartPolicy::Never {
+                    merged.restart = base_run_command.restart;
+                }
+                if merged.max_restarts.is_none() {
+                    merged.max_restarts = base_run_command.max_restarts;
+                }
                 Some(Run::Command(merged))
             },
             (Some(Run::Command(base_run_command)), Some(Run::Cwd(other_cwd))) => {
@@ -362,6 +376,14 @@ impl Run {
             }
         }
     }
+    pub fn add_env(&mut self, env: Option<BTreeMap<String, String>>) {
+        // adds to (and overrides) the env of a Run::Command if it is Some
+        if let Some(env) = env {
+            if let Run::Command(run_command) = self {
+                run_command.env.extend(env);
+            }
+        }
+    }
     pub fn add_close_on_exit(&mut self, close_on_exit: Option<bool>) {
         // overrides the hold_on_close of a Run::Command if it is Some
         // and not empty
@@ -380,6 +402,17 @@ impl Run {
             }
         }
     }
+    pub fn add_restart(&mut self, restart: Option<RestartPolicy>, max_restarts: Option<usize>) {
+        // overrides the restart policy of a Run::Command if they are Some
+        if let Run::Command(run_command) = self {
+            if let Some(restart) = restart {
+                run_command.restart = restart;
+            }
+            if let Some(max_restarts) = max_restarts {
+                run_command.max_restarts = Some(max_restarts);
+            }
+        }
+    }
     pub fn is_same_category(first: &Option<Run>, second: &Option<Run>) -> bool {
         match (first, second) {
             (Some(Run::Plugin(..)), Some(Run::Plugin(..))) => true,
@@ -782,6 +815,41 @@ impl FromStr for PercentOrFixed {
     }
 }
 
+/// The condition a pane needs to meet for the command panes depending on it to be started
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Default)]
+pub enum PaneReadiness {
+    /// The command of the pane exited with status 0
+    #[default]
+    ExitedSuccessfully,
+    /// A line matching this regex appeared in the output of the pane
+    OutputMatches(String),
+    /// A TCP port on localhost accepts connections
+    PortOpen(u16),
+}
+
+/// A pane (by name) in the same layout that needs to be ready before this pane's command starts
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PaneDependency {
+    pub pane_name: String,
+    pub ready_when: PaneReadiness,
+}
+
+fn run_instruction_with_dependencies(
+    run: &Option<Run>,
+    depends_on: &[PaneDependency],
+) -> Option<Run> {
+    // command panes with dependencies are started suspended, they are only run once their
+    // dependencies are ready
+    match run {
+        Some(Run::Command(run_command)) if !depends_on.is_empty() => {
+            let mut run_command = run_command.clone();
+            run_command.hold_on_start = true;
+            Some(Run::Command(run_command))
+        },
+        _ => run.clone(),
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct FloatingPaneLayout {
     pub name: Option<String>,
@@ -796,6 +864,9 @@ pub struct FloatingPaneLayout {
     pub already_running: bool,
     pub pane_initial_contents: Option<String>,
     pub logical_position: Option<usize>,
+    pub log_to: Option<PathBuf>,
+    pub depends_on: Vec<PaneDependency>,
+    pub mark: Option<String>,
 }
 
 impl FloatingPaneLayout {
@@ -813,6 +884,9 @@ impl FloatingPaneLayout {
             already_running: false,
             pane_initial_contents: None,
             logical_position: None,
+            log_to: None,
+            depends_on: vec![],
+            mark: None,
         }
     }
     pub fn add_cwd_to_layout(&mut self, cwd: &PathBuf) {
@@ -828,6 +902,9 @@ impl FloatingPaneLayout {
             run.add_start_suspended(start_suspended);
         }
     }
+    pub fn run_instruction(&self) -> Option<Run> {
+        run_instruction_with_dependencies(&self.run, &self.depends_on)
+    }
 }
 
 impl From<&TiledPaneLayout> for FloatingPaneLayout {
@@ -836,6 +913,9 @@ impl From<&TiledPaneLayout> for FloatingPaneLayout {
             name: pane_layout.name.clone(),
             run: pane_layout.run.clone(),
             focus: pane_layout.focus,
+            log_to: pane_layout.log_to.clone(),
+            depends_on: pane_layout.depends_on.clone(),
+            mark: pane_layout.mark.clone(),
             ..Default::default()
         }
     }
@@ -857,6 +937,10 @@ pub struct TiledPaneLayout {
     pub run_instructions_to_ignore: Vec<Option<Run>>,
     pub hide_floating_panes: bool, // only relevant if this is the base layout
     pub pane_initial_contents: Option<String>,
+    pub log_to: Option<PathBuf>,
+    pub depends_on: Vec<PaneDependency>,
+    pub mark: Option<String>,
+    pub tiling_mode: Option<TilingMode>, // only relevant if this is the base layout
 }
 
 impl TiledPaneLayout {
@@ -990,7 +1074,10 @@ impl TiledPaneLayout {
         // as the order of the "flattened" layout panes received from eg. position_panes_in_space
         let mut run_instructions = vec![];
         if self.children.is_empty() {
-            run_instructions.push(self.run.clone());
+            run_instructions.push(run_instruction_with_dependencies(
+                &self.run,
+                &self.depends_on,
+            ));
         }
         let mut run_instructions_of_children = vec![];
         for child in &self.children {
@@ -1229,6 +1316,17 @@ impl Default for LayoutParts {
     }
 }
 
+/// Parses the value of a layout parameter given on the command line, eg. `--param project=perth`
+pub fn parse_layout_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!(
+            "Invalid layout parameter: {}, expected NAME=VALUE",
+            param
+        )),
+    }
+}
+
 impl Layout {
     pub fn list_available_layouts(
         layout_dir: Option<PathBuf>,
@@ -1324,14 +1422,16 @@ impl Layout {
         layout_info: LayoutInfo,
     ) -> Result<Layout, ConfigError> {
         let mut should_start_layout_commands_suspended = false;
+        let mut params = BTreeMap::new();
         let (path_to_raw_layout, raw_layout, raw_swap_layouts) = match layout_info {
-            LayoutInfo::File(layout_name_without_extension, _layout_metadata) => {
+            LayoutInfo::File(layout_name_without_extension, layout_metadata) => {
                 let layout_dir = layout_dir.clone().or_else(|| default_layout_dir());
                 let (path_to_layout, stringified_layout, swap_layouts) =
                     Self::stringified_from_dir(
                         &PathBuf::from(layout_name_without_extension),
                         layout_dir.as_ref(),
                     )?;
+                params = layout_metadata.param_values();
                 (Some(path_to_layout), stringified_layout, swap_layouts)
             },
             LayoutInfo::BuiltIn(layout_name) => {
@@ -1345,13 +1445,15 @@ impl Layout {
             },
             LayoutInfo::Stringified(stringified_layout) => (None, stringified_layout, None),
         };
-        let mut layout = Layout::from_kdl(
+        let mut layout = Layout::from_kdl_with_params(
             &raw_layout,
             path_to_raw_layout,
             raw_swap_layouts
                 .as_ref()
                 .map(|(r, f)| (r.as_str(), f.as_str())),
             None,
+            params,
+            layout_dir.clone().or_else(default_layout_dir),
         );
         if should_start_layout_commands_suspended {
             layout
@@ -1367,14 +1469,16 @@ impl Layout {
         config: Option<Config>,
     ) -> Result<(Layout, Config), ConfigError> {
         let mut should_start_layout_commands_suspended = false;
+        let mut params = BTreeMap::new();
         let (path_to_raw_layout, raw_layout, raw_swap_layouts) = match layout_info {
-            LayoutInfo::File(layout_name_without_extension, _layout_metadata) => {
+            LayoutInfo::File(layout_name_without_extension, layout_metadata) => {
                 let layout_dir = layout_dir.clone().or_else(|| default_layout_dir());
                 let (path_to_layout, stringified_layout, swap_layouts) =
                     Self::stringified_from_dir(
                         &PathBuf::from(layout_name_without_extension),
                         layout_dir.as_ref(),
                     )?;
+                params = layout_metadata.param_values();
                 (Some(path_to_layout), stringified_layout, swap_layouts)
             },
             LayoutInfo::BuiltIn(layout_name) => {
@@ -1388,13 +1492,15 @@ impl Layout {
             },
             LayoutInfo::Stringified(stringified_layout) => (None, stringified_layout.clone(), None),
         };
-        let mut layout = Layout::from_kdl(
+        let mut layout = Layout::from_kdl_with_params(
             &raw_layout,
             path_to_raw_layout,
             raw_swap_layouts
                 .as_ref()
                 .map(|(r, f)| (r.as_str(), f.as_str())),
             None,
+            params,
+            layout_dir.clone().or_else(default_layout_dir),
         );
         if should_start_layout_commands_suspended {
             layout
@@ -1463,16 +1569,19 @@ impl Layout {
         layout_path: Option<&PathBuf>,
         layout_dir: Option<PathBuf>,
         config: Config,
+        params: BTreeMap<String, String>,
     ) -> Result<(Layout, Config), ConfigError> {
         let (path_to_raw_layout, raw_layout, raw_swap_layouts) =
-            Layout::stringified_from_path_or_default(layout_path, layout_dir)?;
-        let layout = Layout::from_kdl(
+            Layout::stringified_from_path_or_default(layout_path, layout_dir.clone())?;
+        let layout = Layout::from_kdl_with_params(
             &raw_layout,
             Some(path_to_raw_layout),
             raw_swap_layouts
                 .as_ref()
                 .map(|(r, f)| (r.as_str(), f.as_str())),
             None,
+            params,
+            layout_dir,
         )?;
         let config = Config::from_kdl(&raw_layout, Some(config))?; // this merges the two config, with
         Ok((layout, config))
@@ -1510,14 +1619,18 @@ impl Layout {
         layout_dir: Option<PathBuf>,
     ) -> Result<Layout, ConfigError> {
         let (path_to_raw_layout, raw_layout, raw_swap_layouts) =
-            Layout::stringified_from_path_or_default(layout_path, layout_dir)?;
-        let layout = Layout::from_kdl(
+            Layout::stringified_from_path_or_default(layout_path, layout_dir.clone())?;
+        // this is used to list the available layouts, before we have values for their parameters
+        let params = Layout::params_from_kdl(&raw_layout)?;
+        let layout = Layout::from_kdl_with_params(
             &raw_layout,
             Some(path_to_raw_layout),
             raw_swap_layouts
                 .as_ref()
                 .map(|(r, f)| (r.as_str(), f.as_str())),
             None,
+            LayoutMetadata::placeholder_param_values(&params),
+            layout_dir,
         )?;
         Ok(layout)
     }
@@ -0,0 +1,426 @@
+//! Checks a layout file for mistakes that would otherwise only surface once a session is started
+//! with it (`zellij layout validate`)
+use crate::consts::ASSET_MAP;
+use crate::data::LayoutMetadata;
+use crate::home::default_layout_dir;
+use crate::input::command::RunCommand;
+use crate::input::config::{Config, ConfigError};
+use crate::input::layout::{
+    FloatingPaneLayout, Layout, LayoutConstraint, Run, RunPluginLocation, RunPluginOrAlias,
+    SplitSize, TiledPaneLayout,
+};
+
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode, SourceSpan};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutDiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Error, Debug, Serialize)]
+#[error("{message}")]
+pub struct LayoutDiagnostic {
+    pub severity: LayoutDiagnosticSeverity,
+    pub message: String,
+    pub file: String,
+    pub line: Option<usize>,   // 1 based
+    pub column: Option<usize>, // 1 based
+    pub offset: Option<usize>,
+    pub length: Option<usize>,
+    pub help: Option<String>,
+    #[serde(skip)]
+    src: Option<NamedSource>,
+}
+
+impl LayoutDiagnostic {
+    fn error(file: &str, message: String) -> Self {
+        LayoutDiagnostic {
+            severity: LayoutDiagnosticSeverity::Error,
+            message,
+            file: file.to_owned(),
+            line: None,
+            column: None,
+            offset: None,
+            length: None,
+            help: None,
+            src: None,
+        }
+    }
+    fn warning(file: &str, message: String) -> Self {
+        LayoutDiagnostic {
+            severity: LayoutDiagnosticSeverity::Warning,
+            ..LayoutDiagnostic::error(file, message)
+        }
+    }
+    fn with_help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_owned());
+        self
+    }
+    fn from_config_error(file: &str, config_error: ConfigError) -> Self {
+        match config_error {
+            ConfigError::KdlError(kdl_error) => {
+                let mut diagnostic = LayoutDiagnostic::error(file, kdl_error.error_message);
+                diagnostic.help = kdl_error.help_message;
+                if let Some(src) = kdl_error.src {
+                    diagnostic.file = src.name().to_owned();
+                    if let (Some(offset), Some(length)) = (kdl_error.offset, kdl_error.len) {
+                        let span = SourceSpan::from((offset, length));
+                        if let Ok(span_contents) = src.read_span(&span, 0, 0) {
+                            diagnostic.line = Some(span_contents.line() + 1);
+                            diagnostic.column = Some(span_contents.column() + 1);
+                        }
+                        diagnostic.offset = Some(offset);
+                        diagnostic.length = Some(length);
+                    }
+                    diagnostic.src = Some(src);
+                }
+                diagnostic
+            },
+            config_error => LayoutDiagnostic::error(file, config_error.to_string()),
+        }
+    }
+    pub fn is_error(&self) -> bool {
+        self.severity == LayoutDiagnosticSeverity::Error
+    }
+}
+
+impl Diagnostic for LayoutDiagnostic {
+    fn severity(&self) -> Option<miette::Severity> {
+        match self.severity {
+            LayoutDiagnosticSeverity::Error => Some(miette::Severity::Error),
+            LayoutDiagnosticSeverity::Warning => Some(miette::Severity::Warning),
+        }
+    }
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.src.as_ref().map(|src| src as &dyn SourceCode)
+    }
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match (&self.src, self.offset, self.length) {
+            (Some(_), Some(offset), Some(length)) => Some(Box::new(std::iter::once(
+                LabeledSpan::new(Some(self.message.clone()), offset, length),
+            ))),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LayoutValidationReport {
+    pub file: String,
+    pub valid: bool,
+    pub diagnostics: Vec<LayoutDiagnostic>,
+}
+
+impl LayoutValidationReport {
+    pub fn error_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.is_error()).count()
+    }
+    pub fn warning_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| !d.is_error()).count()
+    }
+}
+
+/// Parses the layout file with the given configuration and checks the result for problems we
+/// can find without starting a session with it. Required layout parameters that were not given a
+/// value are filled in with placeholders.
+pub fn validate_layout_file(
+    layout_path: &Path,
+    config: Config,
+    params: BTreeMap<String, String>,
+) -> LayoutValidationReport {
+    let file = layout_path.display().to_string();
+    let diagnostics = match parse_layout_file(layout_path, config, params) {
+        Ok((layout, config)) => LayoutValidator::new(&file, &config).validate(&layout),
+        Err(config_error) => vec![LayoutDiagnostic::from_config_error(&file, config_error)],
+    };
+    LayoutValidationReport {
+        valid: !diagnostics.iter().any(|d| d.is_error()),
+        file,
+        diagnostics,
+    }
+}
+
+fn parse_layout_file(
+    layout_path: &Path,
+    config: Config,
+    params: BTreeMap<String, String>,
+) -> Result<(Layout, Config), ConfigError> {
+    let (path_to_raw_layout, raw_layout, raw_swap_layouts) =
+        Layout::stringified_from_path(layout_path)?;
+    let declared_params = Layout::params_from_kdl(&raw_layout)?;
+    let mut param_values = LayoutMetadata::placeholder_param_values(&declared_params);
+    param_values.extend(params);
+    let layout_dir = config
+        .options
+        .layout_dir
+        .clone()
+        .or_else(default_layout_dir);
+    let layout = Layout::from_kdl_with_params(
+        &raw_layout,
+        Some(path_to_raw_layout),
+        raw_swap_layouts
+            .as_ref()
+            .map(|(r, f)| (r.as_str(), f.as_str())),
+        None,
+        param_values,
+        layout_dir,
+    )?;
+    let config = Config::from_kdl(&raw_layout, Some(config))?;
+    Ok((layout, config))
+}
+
+struct LayoutValidator<'a> {
+    file: &'a str,
+    config: &'a Config,
+    diagnostics: Vec<LayoutDiagnostic>,
+}
+
+impl<'a> LayoutValidator<'a> {
+    fn new(file: &'a str, config: &'a Config) -> Self {
+        LayoutValidator {
+            file,
+            config,
+            diagnostics: vec![],
+        }
+    }
+    fn validate(mut self, layout: &Layout) -> Vec<LayoutDiagnostic> {
+        for (i, (tab_name, tiled_panes, floating_panes)) in layout.tabs.iter().enumerate() {
+            let context = match tab_name {
+                Some(tab_name) => format!("tab \"{}\"", tab_name),
+                None => format!("tab #{}", i + 1),
+            };
+            self.check_tiled_panes(tiled_panes, &context);
+            self.check_floating_panes(floating_panes, &context);
+        }
+        if let Some((tiled_panes, floating_panes)) = &layout.template {
+            self.check_tiled_panes(tiled_panes, "the new tab template");
+            self.check_floating_panes(floating_panes, "the new tab template");
+        }
+        for (swap_tiled_layouts, name) in &layout.swap_tiled_layouts {
+            let swap_layout_name = swap_layout_name("swap_tiled_layout", name);
+            for (constraint, tiled_panes) in swap_tiled_layouts {
+                let context = format!("{} ({})", swap_layout_name, constraint_name(constraint));
+                self.check_tiled_panes(tiled_panes, &context);
+            }
+            let constraints: Vec<&LayoutConstraint> = swap_tiled_layouts.keys().collect();
+            self.check_swap_layout_constraints(&constraints, &swap_layout_name, 1);
+        }
+        for (swap_floating_layouts, name) in &layout.swap_floating_layouts {
+            let swap_layout_name = swap_layout_name("swap_floating_layout", name);
+            for (constraint, floating_panes) in swap_floating_layouts {
+                let context = format!("{} ({})", swap_layout_name, constraint_name(constraint));
+                self.check_floating_panes(floating_panes, &context);
+            }
+            let constraints: Vec<&LayoutConstraint> = swap_floating_layouts.keys().collect();
+            self.check_swap_layout_constraints(&constraints, &swap_layout_name, 0);
+        }
+        self.diagnostics
+    }
+    fn check_tiled_panes(&mut self, pane: &TiledPaneLayout, context: &str) {
+        if let Some(run) = &pane.run {
+            self.check_run(run, context);
+        }
+        let percent_total: usize = pane
+            .children
+            .iter()
+            .filter_map(|c| match c.split_size {
+                Some(SplitSize::Percent(percent)) => Some(percent),
+                _ => None,
+            })
+            .sum();
+        if percent_total > 100 {
+            self.diagnostics.push(
+                LayoutDiagnostic::error(
+                    self.file,
+                    format!(
+                        "The percentage sizes of sibling panes in {} add up to {}%",
+                        context, percent_total
+                    ),
+                )
+                .with_help("Percentage sizes are shares of the space left by the fixed size panes of the same split, they should not add up to more than 100%"),
+            );
+        }
+        for child in &pane.children {
+            self.check_tiled_panes(child, context);
+        }
+    }
+    fn check_floating_panes(&mut self, floating_panes: &[FloatingPaneLayout], context: &str) {
+        for floating_pane in floating_panes {
+            if let Some(run) = &floating_pane.run {
+                self.check_run(run, context);
+            }
+        }
+    }
+    fn check_run(&mut self, run: &Run, context: &str) {
+        match run {
+            Run::Plugin(RunPluginOrAlias::RunPlugin(run_plugin)) => {
+                self.check_plugin_location(&run_plugin.location, context)
+            },
+            Run::Plugin(RunPluginOrAlias::Alias(plugin_alias)) => {
+                let run_plugin = plugin_alias
+                    .run_plugin
+                    .as_ref()
+                    .or_else(|| self.config.plugins.aliases.get(&plugin_alias.name));
+                match run_plugin {
+                    Some(run_plugin) => self.check_plugin_location(&run_plugin.location, context),
+                    None => self.diagnostics.push(
+                        LayoutDiagnostic::error(
+                            self.file,
+                            format!(
+                                "Unknown plugin alias \"{}\" in {}",
+                                plugin_alias.name, context
+                            ),
+                        )
+                        .with_help("Define the alias in the plugins block of the configuration, or use a plugin url (eg. file:/path/to/plugin.wasm)"),
+                    ),
+                }
+            },
+            Run::Command(run_command) => self.check_command(run_command, context),
+            Run::EditFile(..) | Run::Cwd(_) => {},
+        }
+    }
+    fn check_plugin_location(&mut self, location: &RunPluginLocation, context: &str) {
+        match location {
+            RunPluginLocation::Zellij(plugin_tag) => {
+                let plugin_name = String::from(plugin_tag.clone());
+                let asset_path = PathBuf::from("plugins")
+                    .join(&plugin_name)
+                    .with_extension("wasm");
+                if !ASSET_MAP.contains_key(&asset_path) {
+                    self.diagnostics.push(LayoutDiagnostic::error(
+                        self.file,
+                        format!(
+                            "Unknown built-in plugin \"zellij:{}\" in {}",
+                            plugin_name, context
+                        ),
+                    ));
+                }
+            },
+            RunPluginLocation::File(path) => {
+                if !path.exists() && !path.with_extension("wasm").exists() {
+                    self.diagnostics.push(LayoutDiagnostic::error(
+                        self.file,
+                        format!(
+                            "Plugin file \"{}\" in {} does not exist",
+                            path.display(),
+                            context
+                        ),
+                    ));
+                }
+            },
+            RunPluginLocation::Remote(_) => {},
+        }
+    }
+    fn check_command(&mut self, run_command: &RunCommand, context: &str) {
+        let command = &run_command.command;
+        let command_exists = if command.components().count() > 1 {
+            match &run_command.cwd {
+                Some(cwd) => cwd.join(command).exists(),
+                None => command.exists(),
+            }
+        } else {
+            std::env::var_os("PATH")
+                .map(|path| std::env::split_paths(&path).any(|dir| dir.join(command).is_file()))
+                .unwrap_or(false)
+        };
+        if !command_exists {
+            self.diagnostics.push(LayoutDiagnostic::warning(
+                self.file,
+                format!(
+                    "Command \"{}\" in {} was not found on this machine's PATH",
+                    command.display(),
+                    context
+                ),
+            ));
+        }
+    }
+    /// Swap layout constraints are tried in order, warn about those that can never be chosen
+    /// because all the pane counts they match are matched by the ones before them
+    fn check_swap_layout_constraints(
+        &mut self,
+        constraints: &[&LayoutConstraint],
+        swap_layout_name: &str,
+        min_pane_count: usize,
+    ) {
+        // counts above the largest one mentioned are all matched by the same constraints
+        let max_pane_count = constraints
+            .iter()
+            .map(|c| match c {
+                LayoutConstraint::MaxPanes(count)
+                | LayoutConstraint::MinPanes(count)
+                | LayoutConstraint::ExactPanes(count) => *count,
+                LayoutConstraint::NoConstraint => 0,
+            })
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let mut matched_pane_counts = BTreeSet::new();
+        for constraint in constraints {
+            let pane_counts: BTreeSet<usize> = (min_pane_count..=max_pane_count)
+                .filter(|count| constraint_matches(constraint, *count))
+                .collect();
+            if pane_counts.is_empty() {
+                self.diagnostics.push(LayoutDiagnostic::warning(
+                    self.file,
+                    format!(
+                        "The layout for {} in {} can never be used, it matches no pane count",
+                        constraint_name(constraint),
+                        swap_layout_name
+                    ),
+                ));
+            } else if pane_counts.is_subset(&matched_pane_counts) {
+                self.diagnostics.push(
+                    LayoutDiagnostic::warning(
+                        self.file,
+                        format!(
+                            "The layout for {} in {} is never used, the pane counts it matches are matched by the layouts before it",
+                            constraint_name(constraint),
+                            swap_layout_name
+                        ),
+                    )
+                    .with_help("Constraints are tried in this order: max_panes, min_panes, exact_panes, then no constraint"),
+                );
+            }
+            matched_pane_counts.extend(pane_counts);
+        }
+    }
+}
+
+fn constraint_matches(constraint: &LayoutConstraint, pane_count: usize) -> bool {
+    match constraint {
+        LayoutConstraint::MaxPanes(max_panes) => pane_count <= *max_panes,
+        LayoutConstraint::MinPanes(min_panes) => pane_count >= *min_panes,
+        LayoutConstraint::ExactPanes(exact_panes) => pane_count == *exact_panes,
+        LayoutConstraint::NoConstraint => true,
+    }
+}
+
+fn constraint_name(constraint: &LayoutConstraint) -> String {
+    match constraint {
+        LayoutConstraint::NoConstraint => "no constraint".to_owned(),
+        constraint => constraint.to_string(),
+    }
+}
+
+fn swap_layout_name(node_name: &str, name: &Option<String>) -> String {
+    match name {
+        Some(name) => format!("{} \"{}\"", node_name, name),
+        None => node_name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/layout_validation_test.rs"]
+mod layout_validation_test;
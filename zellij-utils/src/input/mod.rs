@@ -4,6 +4,8 @@ pub mod command;
 pub mod config;
 pub mod keybinds;
 pub mod layout;
+#[cfg(not(target_family = "wasm"))]
+pub mod layout_validation;
 pub mod mouse;
 pub mod options;
 pub mod permission;
@@ -54,6 +56,8 @@ mod not_wasm {
             web_server_ip: None,
             web_server_port: None,
             web_server_capability: None,
+            following_client: None,
+            following_client_name: None,
         }
     }
 
@@ -236,6 +236,11 @@ pub struct Options {
     /// of manipulating the command (eg. with a regex) before it gets serialized
     #[clap(long, value_parser)]
     pub post_command_discovery_hook: Option<String>,
+
+    /// The amount of fuel (roughly one unit per wasm instruction) a plugin may consume in a single
+    /// call into it before it is suspended
+    #[clap(long, value_parser)]
+    pub plugin_execution_budget: Option<u64>,
 }
 
 #[derive(ArgEnum, Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
@@ -333,6 +338,9 @@ impl Options {
         let post_command_discovery_hook = other
             .post_command_discovery_hook
             .or(self.post_command_discovery_hook.clone());
+        let plugin_execution_budget = other
+            .plugin_execution_budget
+            .or(self.plugin_execution_budget);
 
         Options {
             simplified_ui,
@@ -375,6 +383,7 @@ impl Options {
             web_server_key,
             enforce_https_for_localhost,
             post_command_discovery_hook,
+            plugin_execution_budget,
         }
     }
 
@@ -452,6 +461,9 @@ impl Options {
         let post_command_discovery_hook = other
             .post_command_discovery_hook
             .or_else(|| self.post_command_discovery_hook.clone());
+        let plugin_execution_budget = other
+            .plugin_execution_budget
+            .or(self.plugin_execution_budget);
 
         Options {
             simplified_ui,
@@ -494,6 +506,7 @@ impl Options {
             web_server_key,
             enforce_https_for_localhost,
             post_command_discovery_hook,
+            plugin_execution_budget,
         }
     }
 
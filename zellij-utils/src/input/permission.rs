@@ -7,6 +7,9 @@ use std::{
 
 use crate::{consts::ZELLIJ_PLUGIN_PERMISSIONS_CACHE, data::PermissionType};
 
+#[cfg(not(target_family = "wasm"))]
+pub use audit_log::*;
+
 pub type GrantedPermission = HashMap<String, Vec<PermissionType>>;
 
 #[derive(Default, Debug)]
@@ -24,6 +27,29 @@ impl PermissionCache {
         self.granted.get(&plugin_name)
     }
 
+    pub fn granted(&self) -> &GrantedPermission {
+        &self.granted
+    }
+
+    /// Revokes one permission of a plugin, or all of them if `permission` is None, so that the
+    /// plugin has to ask for it again the next time it is loaded. Returns false if there was
+    /// nothing to revoke.
+    pub fn revoke(&mut self, plugin_name: &str, permission: Option<PermissionType>) -> bool {
+        match permission {
+            Some(permission) => match self.granted.get_mut(plugin_name) {
+                Some(permissions) if permissions.contains(&permission) => {
+                    permissions.retain(|p| p != &permission);
+                    if permissions.is_empty() {
+                        self.granted.remove(plugin_name);
+                    }
+                    true
+                },
+                _ => false,
+            },
+            None => self.granted.remove(plugin_name).is_some(),
+        }
+    }
+
     pub fn check_permissions(
         &self,
         plugin_name: String,
@@ -65,3 +91,131 @@ impl PermissionCache {
         Ok(())
     }
 }
+
+/// Permissions that let a plugin act as the user, every use of these is recorded in the audit log
+pub const AUDITED_PERMISSIONS: &[PermissionType] = &[
+    PermissionType::RunCommands,
+    PermissionType::WriteToStdin,
+    PermissionType::RunActionsAsUser,
+];
+
+// The audit log is written by the server and read by the CLI, plugins cannot reach it
+#[cfg(not(target_family = "wasm"))]
+mod audit_log {
+    use std::{
+        fs::{self, File, OpenOptions},
+        io::Write,
+        path::PathBuf,
+        str::FromStr,
+        time::SystemTime,
+    };
+
+    use crate::{consts::ZELLIJ_PLUGIN_AUDIT_LOG, data::PermissionType};
+
+    /// Once the audit log grows past this size it is moved aside (replacing the previous one) and a
+    /// new one is started
+    const AUDIT_LOG_MAX_SIZE: u64 = 1024 * 1024;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct AuditLogEntry {
+        pub timestamp: String,
+        pub plugin_name: String,
+        pub permission: PermissionType,
+        pub command: String,
+    }
+
+    impl AuditLogEntry {
+        /// Only the name of the command is recorded and not its arguments, since these can contain
+        /// anything the plugin typed into a terminal
+        pub fn new(plugin_name: String, permission: PermissionType, command: String) -> Self {
+            AuditLogEntry {
+                timestamp: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(),
+                plugin_name,
+                permission,
+                command,
+            }
+        }
+        fn to_line(&self) -> String {
+            format!(
+                "{}\t{}\t{}\t{}",
+                self.timestamp, self.permission, self.command, self.plugin_name
+            )
+        }
+        fn from_line(line: &str) -> Option<Self> {
+            let mut fields = line.splitn(4, '\t');
+            let timestamp = fields.next()?.to_owned();
+            let permission = PermissionType::from_str(fields.next()?).ok()?;
+            let command = fields.next()?.to_owned();
+            let plugin_name = fields.next()?.to_owned();
+            Some(AuditLogEntry {
+                timestamp,
+                plugin_name,
+                permission,
+                command,
+            })
+        }
+    }
+
+    /// The log file is opened on the first record and kept open afterwards, so that recording does
+    /// not cost more than a write
+    #[derive(Debug)]
+    pub struct PermissionAuditLog {
+        path: PathBuf,
+        file: Option<File>,
+        size: u64,
+    }
+
+    impl PermissionAuditLog {
+        pub fn from_path_or_default(log_path: Option<PathBuf>) -> Self {
+            PermissionAuditLog {
+                path: log_path.unwrap_or(ZELLIJ_PLUGIN_AUDIT_LOG.to_path_buf()),
+                file: None,
+                size: 0,
+            }
+        }
+
+        pub fn record(&mut self, entry: &AuditLogEntry) -> std::io::Result<()> {
+            if self.size > AUDIT_LOG_MAX_SIZE {
+                self.file = None;
+                self.size = 0;
+                fs::rename(&self.path, self.path.with_extension("log.old"))?;
+            }
+            let file = match self.file.as_mut() {
+                Some(file) => file,
+                None => {
+                    if let Some(parent) = self.path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    let file = OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&self.path)?;
+                    self.size = file.metadata()?.len();
+                    self.file.insert(file)
+                },
+            };
+            let line = format!("{}\n", entry.to_line());
+            file.write_all(line.as_bytes())?;
+            self.size += line.len() as u64;
+            Ok(())
+        }
+
+        /// The recorded entries, oldest first, optionally only those of one plugin
+        pub fn entries(&self, plugin_name: Option<&str>) -> std::io::Result<Vec<AuditLogEntry>> {
+            let raw_log = match fs::read_to_string(&self.path) {
+                Ok(raw_log) => raw_log,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                Err(e) => return Err(e),
+            };
+            Ok(raw_log
+                .lines()
+                .filter_map(AuditLogEntry::from_line)
+                .filter(|entry| plugin_name.map(|p| entry.plugin_name == p).unwrap_or(true))
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/permission_test.rs"]
+mod permission_test;
@@ -568,6 +568,58 @@ fn keybindings_unbinds_happen_after_binds() {
     assert_eq!(z_in_pane_mode, None, "Key was ultimately unbound");
 }
 
+#[test]
+fn unbound_characters_set_and_focus_marks_in_mark_modes() {
+    let config = Config::from_kdl("", None).unwrap();
+    let actions_for_key = |mode: InputMode, key: KeyWithModifier| {
+        config
+            .keybinds
+            .get_actions_for_key_in_mode_or_default_action(
+                &mode,
+                &key,
+                vec![],
+                InputMode::Normal,
+                false,
+            )
+    };
+    assert_eq!(
+        actions_for_key(
+            InputMode::MarkPane,
+            KeyWithModifier::new(BareKey::Char('a'))
+        ),
+        vec![
+            Action::MarkPane {
+                name: "a".to_owned()
+            },
+            Action::SwitchToMode {
+                input_mode: InputMode::Normal
+            },
+        ],
+    );
+    assert_eq!(
+        actions_for_key(
+            InputMode::FocusMark,
+            KeyWithModifier::new(BareKey::Char('a'))
+        ),
+        vec![
+            Action::FocusMark {
+                name: "a".to_owned()
+            },
+            Action::SwitchToMode {
+                input_mode: InputMode::Normal
+            },
+        ],
+    );
+    assert_eq!(
+        actions_for_key(
+            InputMode::FocusMark,
+            KeyWithModifier::new(BareKey::Char('a')).with_alt_modifier()
+        ),
+        vec![Action::NoOp],
+        "modified keys are not marks"
+    );
+}
+
 #[test]
 fn error_received_on_unknown_input_mode() {
     let config_contents = r#"
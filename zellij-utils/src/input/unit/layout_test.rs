@@ -1,4 +1,5 @@
 use super::super::layout::*;
+use crate::data::{LayoutParam, TilingMode};
 use insta::assert_snapshot;
 
 #[test]
@@ -511,6 +512,373 @@ fn layout_with_command_panes_and_close_on_exit() {
     assert_snapshot!(format!("{:#?}", layout));
 }
 
+#[test]
+fn layout_with_command_panes_and_env() {
+    let kdl_layout = r#"
+        layout {
+            pane command="agent" {
+                env {
+                    AGENT_ID 7
+                    PERTH_PANE_ROLE "reviewer"
+                }
+            }
+            floating_panes {
+                pane command="agent" {
+                    env {
+                        PERTH_PANE_ROLE "planner"
+                    }
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn layout_with_pane_dependencies() {
+    let kdl_layout = r#"
+        layout {
+            pane name="db" command="postgres"
+            pane name="migrations" command="migrate" {
+                depends_on "db" output="ready to accept connections"
+            }
+            pane command="server" {
+                depends_on "migrations"
+                depends_on "cache" port=6379
+            }
+            floating_panes {
+                pane name="cache" command="redis-server"
+                pane command="worker" {
+                    depends_on "migrations"
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn pane_dependencies_start_command_panes_suspended() {
+    let kdl_layout = r#"
+        layout {
+            pane name="db" command="postgres"
+            pane command="server" {
+                depends_on "db" port=5432
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    let run_instructions = layout.new_tab().0.extract_run_instructions();
+    let holds_on_start: Vec<bool> = run_instructions
+        .iter()
+        .map(|r| match r {
+            Some(Run::Command(run_command)) => run_command.hold_on_start,
+            _ => panic!("expected a command pane"),
+        })
+        .collect();
+    assert_eq!(holds_on_start, vec![false, true]);
+}
+
+#[test]
+fn pane_dependencies_in_pane_template() {
+    let kdl_layout = r#"
+        layout {
+            pane_template name="service" {
+                command "service"
+                depends_on "db"
+            }
+            pane name="db" command="postgres"
+            service name="api"
+            service name="worker" {
+                depends_on "api" output="listening"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn layout_with_command_panes_and_restart_policies() {
+    let kdl_layout = r#"
+        layout {
+            pane command="dev-server" {
+                restart "on-failure"
+            }
+            pane command="agent" {
+                restart "always"
+                max_restarts 5
+            }
+            floating_panes {
+                pane command="watcher" {
+                    restart "on-failure"
+                    max_restarts 1
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn layout_with_params() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project required=true
+                agent default="claude"
+                branch
+            }
+            cwd "/projects/${project}"
+            tab name="${project}" {
+                pane command="${agent}" name="${agent} on ${project}" {
+                    args "--branch" "${branch}" "--home" "${HOME}"
+                    env {
+                        PERTH_PROJECT "${project}"
+                    }
+                }
+            }
+        }
+    "#;
+    let params = [("project".to_owned(), "perth".to_owned())].into();
+    let layout = Layout::from_kdl_with_params(
+        kdl_layout,
+        Some("layout_file_name".into()),
+        None,
+        None,
+        params,
+        None,
+    )
+    .unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn layout_params_override_defaults() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                agent default="claude"
+            }
+            pane command="${agent}"
+        }
+    "#;
+    let params = [("agent".to_owned(), "codex".to_owned())].into();
+    let layout = Layout::from_kdl_with_params(
+        kdl_layout,
+        Some("layout_file_name".into()),
+        None,
+        None,
+        params,
+        None,
+    )
+    .unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+#[test]
+fn layout_params_are_parsed() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project required=true
+                agent default="claude"
+            }
+            pane cwd="${project}"
+        }
+    "#;
+    let params = Layout::params_from_kdl(kdl_layout).unwrap();
+    assert_eq!(
+        params,
+        vec![
+            LayoutParam {
+                name: "project".to_owned(),
+                required: true,
+                ..Default::default()
+            },
+            LayoutParam {
+                name: "agent".to_owned(),
+                default: Some("claude".to_owned()),
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn tab_tiling_mode() {
+    let kdl_layout = r#"
+        layout {
+            tab_template name="agents" tiling_mode="grid" {
+                children
+            }
+            tab tiling_mode="master-stack" {
+                pane
+                pane
+            }
+            agents
+            agents tiling_mode="bsp"
+            tab
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    let tiling_modes: Vec<Option<TilingMode>> = layout
+        .tabs()
+        .iter()
+        .map(|(_tab_name, tiled_layout, _floating_layouts)| tiled_layout.tiling_mode)
+        .collect();
+    assert_eq!(
+        tiling_modes,
+        vec![
+            Some(TilingMode::MasterStack),
+            Some(TilingMode::Grid),
+            Some(TilingMode::Bsp),
+            None
+        ]
+    );
+}
+
+#[test]
+fn error_on_unknown_tab_tiling_mode() {
+    let kdl_layout = r#"
+        layout {
+            tab tiling_mode="spiral"
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+fn layout_fixture(file_name: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root.join("src/input/unit/fixtures/layouts").join(file_name)
+}
+
+#[test]
+fn layout_with_included_templates() {
+    let layout = Layout::from_path_without_config(&layout_fixture("with-includes.kdl")).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
+fn pane_command_args(pane: &TiledPaneLayout) -> Vec<Vec<String>> {
+    let mut command_args = vec![];
+    if let Some(Run::Command(run_command)) = &pane.run {
+        command_args.push(run_command.args.clone());
+    }
+    for child in &pane.children {
+        command_args.append(&mut pane_command_args(child));
+    }
+    command_args
+}
+
+#[test]
+fn included_layouts_are_looked_up_in_the_layout_dir() {
+    // the layout is outside of the layout_dir, next to it there is no common/agents.kdl
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let layout_path =
+        root.join("src/input/unit/fixtures/project/with-includes-from-layout-dir.kdl");
+    let layout =
+        Layout::from_path_or_default_without_config(Some(&layout_path), Some(layout_fixture("")))
+            .unwrap();
+    let (tab_name, tab_layout, _floating_panes) = &layout.tabs()[0];
+    assert_eq!(tab_name.as_deref(), Some("agents"));
+    assert_eq!(
+        pane_command_args(tab_layout),
+        vec![vec!["--continue".to_owned()], vec!["--review".to_owned()]]
+    );
+    let layout_without_layout_dir = Layout::from_path_without_config(&layout_path);
+    assert!(layout_without_layout_dir.is_err(), "error provided");
+}
+
+#[test]
+fn nested_layout_include_is_relative_to_the_including_file() {
+    // common/agents.kdl includes reviewer.kdl, which is next to it rather than in the layout_dir
+    let layout = Layout::from_path_or_default_without_config(
+        Some(&layout_fixture("with-includes.kdl")),
+        Some(layout_fixture("")),
+    )
+    .unwrap();
+    let (_tab_name, tab_layout, _floating_panes) = &layout.tabs()[0];
+    assert_eq!(
+        pane_command_args(tab_layout),
+        vec![vec!["--continue".to_owned()], vec!["--review".to_owned()]]
+    );
+}
+
+#[test]
+fn error_on_circular_layout_include() {
+    let layout = Layout::from_path_without_config(&layout_fixture("include-cycle.kdl"));
+    match layout {
+        Err(ConfigError::KdlError(kdl_error)) => {
+            assert!(
+                kdl_error
+                    .error_message
+                    .starts_with("Circular layout include"),
+                "{}",
+                kdl_error.error_message
+            );
+        },
+        _ => panic!("expected a kdl error"),
+    }
+}
+
+#[test]
+fn error_on_missing_included_layout() {
+    let layout = Layout::from_path_without_config(&layout_fixture("include-missing.kdl"));
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_in_included_layout_points_at_included_file() {
+    let layout = Layout::from_path_without_config(&layout_fixture("include-with-error.kdl"));
+    match layout {
+        Err(ConfigError::KdlError(kdl_error)) => {
+            let src = kdl_error.src.expect("error has source");
+            assert!(src.name().ends_with("common/broken.kdl"), "{}", src.name());
+            let raw_included_layout =
+                std::fs::read_to_string(layout_fixture("common/broken.kdl")).unwrap();
+            let error_offset = kdl_error.offset.unwrap();
+            assert!(
+                raw_included_layout[error_offset..].starts_with("pane_template name=\"broken\"")
+            );
+        },
+        _ => panic!("expected a kdl error"),
+    }
+}
+
+#[test]
+fn error_on_include_in_layout_not_loaded_from_a_file() {
+    let kdl_layout = r#"
+        layout {
+            include "common/agents.kdl"
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn restart_policy_in_pane_template() {
+    let kdl_layout = r#"
+        layout {
+            pane_template name="service" {
+                command "service"
+                restart "on-failure"
+                max_restarts 10
+            }
+            service
+            service {
+                restart "always"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
 #[test]
 fn layout_with_command_panes_and_start_suspended() {
     let kdl_layout = r#"
@@ -613,6 +981,38 @@ fn layout_with_borderless_panes() {
     assert_eq!(layout, expected_layout);
 }
 
+#[test]
+fn layout_with_logged_panes() {
+    let kdl_layout = r#"
+        layout {
+            pane log_to="/tmp/tiled.log"
+            floating_panes {
+                pane {
+                    log_to "floating.log"
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    let expected_layout = Layout {
+        template: Some((
+            TiledPaneLayout {
+                children: vec![TiledPaneLayout {
+                    log_to: Some(PathBuf::from("/tmp/tiled.log")),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            vec![FloatingPaneLayout {
+                log_to: Some(PathBuf::from("floating.log")),
+                ..Default::default()
+            }],
+        )),
+        ..Default::default()
+    };
+    assert_eq!(layout, expected_layout);
+}
+
 #[test]
 fn layout_with_focused_panes() {
     let kdl_layout = r#"
@@ -1393,6 +1793,29 @@ fn close_on_exit_overrides_close_on_exit_in_template() {
     assert_snapshot!(format!("{:#?}", layout));
 }
 
+#[test]
+fn env_merged_with_env_in_template() {
+    let kdl_layout = r#"
+        layout {
+            pane_template name="agent" {
+                command "agent"
+                env {
+                    AGENT_ID "default"
+                    PERTH_PANE_ROLE "worker"
+                }
+            }
+            agent
+            agent {
+                env {
+                    AGENT_ID "7"
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    assert_snapshot!(format!("{:#?}", layout));
+}
+
 #[test]
 fn args_added_to_args_in_template() {
     let kdl_layout = r#"
@@ -1515,6 +1938,215 @@ fn error_on_bare_close_on_exit_without_command() {
     assert!(layout.is_err(), "error provided");
 }
 
+#[test]
+fn error_on_bare_env_without_command() {
+    let kdl_layout = r#"
+        layout {
+            pane {
+                env {
+                    AGENT_ID "7"
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_depends_on_without_command() {
+    let kdl_layout = r#"
+        layout {
+            pane name="db" command="postgres"
+            pane {
+                depends_on "db"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_restart_without_command() {
+    let kdl_layout = r#"
+        layout {
+            pane {
+                restart "always"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_unknown_restart_policy() {
+    let kdl_layout = r#"
+        layout {
+            pane command="dev-server" {
+                restart "sometimes"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn layout_with_playback_panes() {
+    let kdl_layout = r#"
+        layout {
+            pane playback="session.cast" playback_speed=2.5
+            floating_panes {
+                pane playback="other.cast" close_on_exit=true
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None).unwrap();
+    let (tiled_panes, floating_panes) = layout.new_tab();
+    assert_eq!(
+        tiled_panes.children[0].run,
+        Some(Run::Command(RunCommand::playback(
+            PathBuf::from("session.cast"),
+            Some(2.5)
+        ))),
+        "Playback pane replays the recording at the given speed"
+    );
+    assert_eq!(
+        floating_panes[0].run,
+        Some(Run::Command(RunCommand {
+            hold_on_close: false,
+            ..RunCommand::playback(PathBuf::from("other.cast"), None)
+        })),
+        "Command pane properties apply to playback panes"
+    );
+}
+
+#[test]
+fn error_on_playback_with_command() {
+    let kdl_layout = r#"
+        layout {
+            pane playback="session.cast" command="htop"
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_playback_speed_without_playback() {
+    let kdl_layout = r#"
+        layout {
+            pane command="htop" playback_speed=2
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_missing_required_layout_param() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project required=true
+            }
+            pane cwd="${project}"
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_unknown_layout_param() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project default="perth"
+            }
+            pane cwd="${project}"
+        }
+    "#;
+    let params = [("projcet".to_owned(), "zellij".to_owned())].into();
+    let layout = Layout::from_kdl_with_params(
+        kdl_layout,
+        Some("layout_file_name".into()),
+        None,
+        None,
+        params,
+        None,
+    );
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_required_layout_param_with_default() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project required=true default="perth"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_duplicate_layout_param() {
+    let kdl_layout = r#"
+        layout {
+            params {
+                project default="perth"
+                project default="zellij"
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_depends_on_without_pane_name() {
+    let kdl_layout = r#"
+        layout {
+            pane command="server" {
+                depends_on port=5432
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_depends_on_with_multiple_conditions() {
+    let kdl_layout = r#"
+        layout {
+            pane command="server" {
+                depends_on "db" output="ready" port=5432
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
+#[test]
+fn error_on_depends_on_with_unknown_condition() {
+    let kdl_layout = r#"
+        layout {
+            pane command="server" {
+                depends_on "db" healthy=true
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
 #[test]
 fn error_on_bare_args_in_template_without_command() {
     let kdl_layout = r#"
@@ -1543,6 +2175,22 @@ fn error_on_bare_close_on_exit_in_template_without_command() {
     assert!(layout.is_err(), "error provided");
 }
 
+#[test]
+fn error_on_bare_env_in_template_without_command() {
+    let kdl_layout = r#"
+        layout {
+            pane_template name="my_template"
+            my_template {
+                env {
+                    AGENT_ID "7"
+                }
+            }
+        }
+    "#;
+    let layout = Layout::from_kdl(kdl_layout, Some("layout_file_name".into()), None, None);
+    assert!(layout.is_err(), "error provided");
+}
+
 #[test]
 fn pane_template_command_with_cwd_overriden_by_its_consumers_command_cwd() {
     let kdl_layout = r#"
@@ -0,0 +1,67 @@
+use super::*;
+use insta::assert_snapshot;
+
+fn layout_fixture(file_name: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    root.join("src/input/unit/fixtures/layouts").join(file_name)
+}
+
+fn format_diagnostics(report: &LayoutValidationReport) -> String {
+    report
+        .diagnostics
+        .iter()
+        .map(|d| format!("{:?}: {}", d.severity, d.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn valid_layout_has_no_diagnostics() {
+    let report = validate_layout_file(
+        &layout_fixture("validate-valid.kdl"),
+        Config::from_default_assets().unwrap(),
+        BTreeMap::new(),
+    );
+    assert!(report.valid);
+    assert_eq!(format_diagnostics(&report), "");
+}
+
+#[test]
+fn semantic_problems_in_layout_are_reported() {
+    let report = validate_layout_file(
+        &layout_fixture("validate-problems.kdl"),
+        Config::from_default_assets().unwrap(),
+        BTreeMap::new(),
+    );
+    assert!(!report.valid);
+    assert_eq!(report.error_count(), 3);
+    assert_eq!(report.warning_count(), 2);
+    assert_snapshot!(format_diagnostics(&report));
+}
+
+#[test]
+fn parse_errors_are_reported_with_their_location() {
+    let report = validate_layout_file(
+        &layout_fixture("validate-syntax-error.kdl"),
+        Config::from_default_assets().unwrap(),
+        BTreeMap::new(),
+    );
+    assert!(!report.valid);
+    assert_eq!(report.diagnostics.len(), 1);
+    let diagnostic = &report.diagnostics[0];
+    assert!(diagnostic.file.ends_with("validate-syntax-error.kdl"));
+    assert_eq!((diagnostic.line, diagnostic.column), (Some(3), Some(9)));
+}
+
+#[test]
+fn report_is_serialized_to_json() {
+    let report = validate_layout_file(
+        &layout_fixture("validate-syntax-error.kdl"),
+        Config::from_default_assets().unwrap(),
+        BTreeMap::new(),
+    );
+    let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["diagnostics"][0]["severity"], "error");
+    assert_eq!(json["diagnostics"][0]["line"], 3);
+}
@@ -0,0 +1,114 @@
+use super::super::permission::*;
+use crate::data::PermissionType;
+use tempfile::tempdir;
+
+#[test]
+fn revoke_single_permission() {
+    let mut cache = PermissionCache::default();
+    cache.cache(
+        "file:/path/to/plugin.wasm".to_owned(),
+        vec![PermissionType::RunCommands, PermissionType::WriteToStdin],
+    );
+    assert!(cache.revoke(
+        "file:/path/to/plugin.wasm",
+        Some(PermissionType::RunCommands)
+    ));
+    assert_eq!(
+        cache.get_permissions("file:/path/to/plugin.wasm".to_owned()),
+        Some(&vec![PermissionType::WriteToStdin])
+    );
+    assert!(
+        !cache.revoke(
+            "file:/path/to/plugin.wasm",
+            Some(PermissionType::RunCommands)
+        ),
+        "a permission that is not granted cannot be revoked"
+    );
+}
+
+#[test]
+fn revoking_the_last_permission_forgets_the_plugin() {
+    let mut cache = PermissionCache::default();
+    cache.cache(
+        "file:/path/to/plugin.wasm".to_owned(),
+        vec![PermissionType::RunCommands],
+    );
+    assert!(cache.revoke(
+        "file:/path/to/plugin.wasm",
+        Some(PermissionType::RunCommands)
+    ));
+    assert!(cache.granted().is_empty());
+}
+
+#[test]
+fn revoke_all_permissions() {
+    let mut cache = PermissionCache::default();
+    cache.cache(
+        "file:/path/to/plugin.wasm".to_owned(),
+        vec![PermissionType::RunCommands, PermissionType::WriteToStdin],
+    );
+    cache.cache(
+        "https://example.com/other.wasm".to_owned(),
+        vec![PermissionType::ReadApplicationState],
+    );
+    assert!(cache.revoke("file:/path/to/plugin.wasm", None));
+    assert!(!cache.revoke("file:/path/to/plugin.wasm", None));
+    assert_eq!(cache.granted().len(), 1);
+}
+
+#[test]
+fn revoked_permissions_are_written_to_file() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("permissions.kdl");
+    let mut cache = PermissionCache::from_path_or_default(Some(cache_path.clone()));
+    cache.cache(
+        "file:/path/to/plugin.wasm".to_owned(),
+        vec![PermissionType::RunCommands, PermissionType::WriteToStdin],
+    );
+    cache.write_to_file().unwrap();
+
+    let mut cache = PermissionCache::from_path_or_default(Some(cache_path.clone()));
+    cache.revoke(
+        "file:/path/to/plugin.wasm",
+        Some(PermissionType::WriteToStdin),
+    );
+    cache.write_to_file().unwrap();
+
+    let cache = PermissionCache::from_path_or_default(Some(cache_path));
+    assert_eq!(
+        cache.get_permissions("file:/path/to/plugin.wasm".to_owned()),
+        Some(&vec![PermissionType::RunCommands])
+    );
+}
+
+#[test]
+fn audit_log_records_and_filters_entries() {
+    let dir = tempdir().unwrap();
+    let mut audit_log =
+        PermissionAuditLog::from_path_or_default(Some(dir.path().join("audit.log")));
+    assert_eq!(audit_log.entries(None).unwrap(), vec![]);
+
+    let first_entry = AuditLogEntry::new(
+        "file:/path/to/plugin.wasm".to_owned(),
+        PermissionType::RunCommands,
+        "RunCommand".to_owned(),
+    );
+    let second_entry = AuditLogEntry::new(
+        "https://example.com/other plugin.wasm".to_owned(),
+        PermissionType::WriteToStdin,
+        "WriteChars".to_owned(),
+    );
+    audit_log.record(&first_entry).unwrap();
+    audit_log.record(&second_entry).unwrap();
+
+    assert_eq!(
+        audit_log.entries(None).unwrap(),
+        vec![first_entry.clone(), second_entry.clone()]
+    );
+    assert_eq!(
+        audit_log
+            .entries(Some("https://example.com/other plugin.wasm"))
+            .unwrap(),
+        vec![second_entry]
+    );
+}
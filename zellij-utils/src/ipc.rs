@@ -1,6 +1,8 @@
 //! IPC stuff for starting to split things into a client and server model.
 use crate::{
-    data::{ClientId, ConnectToSession, KeyWithModifier, Style},
+    data::{
+        ClientId, ConnectToSession, KeyWithModifier, PluginHttpRequest, PluginHttpResponse, Style,
+    },
     errors::{prelude::*, ErrorContext},
     input::{actions::Action, cli_assets::CliAssets},
     pane_size::{Size, SizeInPixels},
@@ -138,6 +140,9 @@ pub enum ClientToServerMsg {
     FailedToStartWebServer {
         error: String,
     },
+    PluginHttpRequest {
+        request: PluginHttpRequest,
+    },
 }
 
 // Types of messages sent from the server to the client
@@ -173,6 +178,9 @@ pub enum ServerToClientMsg {
         name: String,
     },
     ConfigFileUpdated,
+    PluginHttpResponse {
+        response: Option<PluginHttpResponse>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
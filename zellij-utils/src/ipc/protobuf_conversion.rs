@@ -6,13 +6,14 @@ use crate::{
         ConnStatusMsg, ConnectedMsg, DetachSessionMsg, ExitMsg, ExitReason as ProtoExitReason,
         FailedToStartWebServerMsg, FirstClientConnectedMsg, ForegroundColorMsg,
         InputMode as ProtoInputMode, KeyMsg, KillSessionMsg, LayoutMetadata as ProtoLayoutMetadata,
-        LogErrorMsg, LogMsg, PaneMetadata as ProtoPaneMetadata, QueryTerminalSizeMsg,
-        RenamedSessionMsg, RenderMsg, ServerToClientMsg as ProtoServerToClientMsg,
-        StartWebServerMsg, SwitchSessionMsg, TabMetadata as ProtoTabMetadata,
-        TerminalPixelDimensionsMsg, TerminalResizeMsg, UnblockCliPipeInputMsg,
-        UnblockInputThreadMsg, WebServerStartedMsg,
+        LayoutParam as ProtoLayoutParam, LogErrorMsg, LogMsg, PaneMetadata as ProtoPaneMetadata,
+        PluginHttpRequestMsg, PluginHttpResponse as ProtoPluginHttpResponse, PluginHttpResponseMsg,
+        QueryTerminalSizeMsg, RenamedSessionMsg, RenderMsg,
+        ServerToClientMsg as ProtoServerToClientMsg, StartWebServerMsg, SwitchSessionMsg,
+        TabMetadata as ProtoTabMetadata, TerminalPixelDimensionsMsg, TerminalResizeMsg,
+        UnblockCliPipeInputMsg, UnblockInputThreadMsg, WebServerStartedMsg,
     },
-    data::InputMode,
+    data::{InputMode, PluginHttpRequest, PluginHttpResponse},
     errors::prelude::*,
     ipc::{
         ClientToServerMsg, ColorRegister, ExitReason, PaneReference, PixelDimensions,
@@ -114,6 +115,16 @@ impl From<ClientToServerMsg> for ProtoClientToServerMsg {
                     error,
                 })
             },
+            ClientToServerMsg::PluginHttpRequest { request } => {
+                client_to_server_msg::Message::PluginHttpRequest(PluginHttpRequestMsg {
+                    route: request.route,
+                    method: request.method,
+                    path: request.path,
+                    query: request.query,
+                    headers: request.headers.into_iter().collect(),
+                    body: request.body,
+                })
+            },
         };
 
         ProtoClientToServerMsg {
@@ -228,6 +239,18 @@ impl TryFrom<ProtoClientToServerMsg> for ClientToServerMsg {
                     error: failed.error,
                 })
             },
+            Some(client_to_server_msg::Message::PluginHttpRequest(request)) => {
+                Ok(ClientToServerMsg::PluginHttpRequest {
+                    request: PluginHttpRequest {
+                        route: request.route,
+                        method: request.method,
+                        path: request.path,
+                        query: request.query,
+                        headers: request.headers.into_iter().collect(),
+                        body: request.body,
+                    },
+                })
+            },
             None => Err(anyhow!("Empty ClientToServerMsg message")),
         }
     }
@@ -290,6 +313,15 @@ impl From<ServerToClientMsg> for ProtoServerToClientMsg {
             ServerToClientMsg::ConfigFileUpdated => {
                 server_to_client_msg::Message::ConfigFileUpdated(ConfigFileUpdatedMsg {})
             },
+            ServerToClientMsg::PluginHttpResponse { response } => {
+                server_to_client_msg::Message::PluginHttpResponse(PluginHttpResponseMsg {
+                    response: response.map(|response| ProtoPluginHttpResponse {
+                        status: response.status as u32,
+                        headers: response.headers.into_iter().collect(),
+                        body: response.body,
+                    }),
+                })
+            },
         };
 
         ProtoServerToClientMsg {
@@ -372,6 +404,18 @@ impl TryFrom<ProtoServerToClientMsg> for ServerToClientMsg {
             Some(server_to_client_msg::Message::ConfigFileUpdated(_)) => {
                 Ok(ServerToClientMsg::ConfigFileUpdated)
             },
+            Some(server_to_client_msg::Message::PluginHttpResponse(plugin_http_response)) => {
+                let response = match plugin_http_response.response {
+                    Some(response) => Some(PluginHttpResponse {
+                        status: u16::try_from(response.status)
+                            .map_err(|_| anyhow!("Invalid HTTP status {}", response.status))?,
+                        headers: response.headers.into_iter().collect(),
+                        body: response.body,
+                    }),
+                    None => None,
+                };
+                Ok(ServerToClientMsg::PluginHttpResponse { response })
+            },
             None => Err(anyhow!("Empty ServerToClientMsg message")),
         }
     }
@@ -510,6 +554,7 @@ impl From<crate::input::cli_assets::CliAssets>
             max_panes: cli_assets.max_panes.map(|m| m as u32),
             force_run_layout_commands: cli_assets.force_run_layout_commands,
             cwd: cli_assets.cwd.map(|p| p.to_string_lossy().to_string()),
+            client_name: cli_assets.client_name,
         }
     }
 }
@@ -539,6 +584,7 @@ impl TryFrom<crate::client_server_contract::client_server_contract::CliAssets>
             max_panes: cli_assets.max_panes.map(|m| m as usize),
             force_run_layout_commands: cli_assets.force_run_layout_commands,
             cwd: cli_assets.cwd.map(PathBuf::from),
+            client_name: cli_assets.client_name,
         })
     }
 }
@@ -613,6 +659,7 @@ impl From<crate::input::options::Options>
                 .map(|p| p.to_string_lossy().to_string()),
             enforce_https_for_localhost: options.enforce_https_for_localhost,
             post_command_discovery_hook: options.post_command_discovery_hook,
+            plugin_execution_budget: options.plugin_execution_budget,
         }
     }
 }
@@ -703,6 +750,7 @@ impl TryFrom<crate::client_server_contract::client_server_contract::Options>
             web_server_key: options.web_server_key.map(std::path::PathBuf::from),
             enforce_https_for_localhost: options.enforce_https_for_localhost,
             post_command_discovery_hook: options.post_command_discovery_hook,
+            plugin_execution_budget: options.plugin_execution_budget,
         })
     }
 }
@@ -717,22 +765,24 @@ impl From<crate::input::actions::Action>
             ChangeFloatingPaneCoordinatesAction, ClearScreenAction, CliPipeAction,
             CloseFocusAction, ClosePluginPaneAction, CloseTabAction, CloseTerminalPaneAction,
             ConfirmAction, CopyAction, DenyAction, DetachAction, DumpLayoutAction,
-            DumpScreenAction, EditFileAction, EditScrollbackAction, FocusNextPaneAction,
-            FocusPluginPaneWithIdAction, FocusPreviousPaneAction, FocusTerminalPaneWithIdAction,
-            GoToNextTabAction, GoToPreviousTabAction, GoToTabAction, GoToTabNameAction,
-            HalfPageScrollDownAction, HalfPageScrollUpAction, KeybindPipeAction,
-            LaunchOrFocusPluginAction, LaunchPluginAction, ListClientsAction, MouseEventAction,
+            DumpScreenAction, EditFileAction, EditScrollbackAction, FocusMarkAction,
+            FocusNextPaneAction, FocusPluginPaneWithIdAction, FocusPreviousPaneAction,
+            FocusTerminalPaneWithIdAction, FollowAction, GoToNextTabAction, GoToPreviousTabAction,
+            GoToTabAction, GoToTabNameAction, HalfPageScrollDownAction, HalfPageScrollUpAction,
+            JumpToSearchMatchAction, KeybindPipeAction, LaunchOrFocusPluginAction,
+            LaunchPluginAction, ListClientsAction, LogPaneAction, MarkPaneAction, MouseEventAction,
             MoveFocusAction, MoveFocusOrTabAction, MovePaneAction, MovePaneBackwardsAction,
             MoveTabAction, NewBlockingPaneAction, NewFloatingPaneAction,
             NewFloatingPluginPaneAction, NewInPlacePaneAction, NewInPlacePluginPaneAction,
             NewPaneAction, NewStackedPaneAction, NewTabAction, NewTiledPaneAction,
             NewTiledPluginPaneAction, NextSwapLayoutAction, NoOpAction, OverrideLayoutAction,
             PageScrollDownAction, PageScrollUpAction, PaneIdWithPlugin, PaneNameInputAction,
-            PreviousSwapLayoutAction, QueryTabNamesAction, QuitAction, RenamePluginPaneAction,
-            RenameSessionAction, RenameTabAction, RenameTerminalPaneAction, ResizeAction,
-            RunAction, ScrollDownAction, ScrollDownAtAction, ScrollToBottomAction,
+            PreviousSwapLayoutAction, QueryTabNamesAction, QuitAction, RecordPaneAction,
+            RenamePluginPaneAction, RenameSessionAction, RenameTabAction, RenameTerminalPaneAction,
+            ResizeAction, RunAction, ScrollDownAction, ScrollDownAtAction, ScrollToBottomAction,
             ScrollToTopAction, ScrollUpAction, ScrollUpAtAction, SearchAction, SearchInputAction,
-            SearchToggleOptionAction, SetPaneBorderlessAction, SkipConfirmAction, StackPanesAction,
+            SearchPaneAction, SearchSessionAction, SearchToggleOptionAction,
+            SetPaneBorderlessAction, SetTilingModeAction, SkipConfirmAction, StackPanesAction,
             StartOrReloadPluginAction, SwitchFocusAction, SwitchModeForAllClientsAction,
             SwitchSessionAction, SwitchToModeAction, TabNameInputAction, ToggleActiveSyncTabAction,
             ToggleFloatingPanesAction, ToggleFocusFullscreenAction, ToggleGroupMarkingAction,
@@ -806,9 +856,11 @@ impl From<crate::input::actions::Action>
             crate::input::actions::Action::DumpScreen {
                 file_path,
                 include_scrollback,
+                format,
             } => ActionType::DumpScreen(DumpScreenAction {
                 file_path,
                 include_scrollback,
+                format: dump_screen_format_to_proto_i32(format),
             }),
             crate::input::actions::Action::DumpLayout => {
                 ActionType::DumpLayout(DumpLayoutAction {})
@@ -1312,6 +1364,63 @@ impl From<crate::input::actions::Action>
             crate::input::actions::Action::ToggleGroupMarking => {
                 ActionType::ToggleGroupMarking(ToggleGroupMarkingAction {})
             },
+            crate::input::actions::Action::SearchPane {
+                pane_id,
+                query,
+                json,
+            } => ActionType::SearchPane(SearchPaneAction {
+                pane_id: Some(pane_id.into()),
+                needle: query.needle,
+                regex: query.regex,
+                case_insensitive: query.case_insensitive,
+                whole_word: query.whole_word,
+                json,
+                context_lines: query.context_lines as u32,
+            }),
+            crate::input::actions::Action::SearchSession { query } => {
+                ActionType::SearchSession(SearchSessionAction {
+                    needle: query.needle,
+                    regex: query.regex,
+                    case_insensitive: query.case_insensitive,
+                    whole_word: query.whole_word,
+                    context_lines: query.context_lines as u32,
+                })
+            },
+            crate::input::actions::Action::JumpToSearchMatch { pane_id, line } => {
+                ActionType::JumpToSearchMatch(JumpToSearchMatchAction {
+                    pane_id: Some(pane_id.into()),
+                    line: line as u64,
+                })
+            },
+            crate::input::actions::Action::LogPane {
+                pane_id,
+                log_options,
+            } => ActionType::LogPane(LogPaneAction {
+                pane_id: Some(pane_id.into()),
+                log_options: log_options.map(|log_options| log_options.into()),
+            }),
+            crate::input::actions::Action::RecordPane { pane_id, out } => {
+                ActionType::RecordPane(RecordPaneAction {
+                    pane_id: Some(pane_id.into()),
+                    out: out.map(|out| out.to_string_lossy().to_string()),
+                })
+            },
+            crate::input::actions::Action::SetTilingMode { tiling_mode } => {
+                ActionType::SetTilingMode(SetTilingModeAction {
+                    tiling_mode: tiling_mode_to_proto_i32(tiling_mode),
+                })
+            },
+            crate::input::actions::Action::MarkPane { name } => {
+                ActionType::MarkPane(MarkPaneAction { name })
+            },
+            crate::input::actions::Action::FocusMark { name } => {
+                ActionType::FocusMark(FocusMarkAction { name })
+            },
+            crate::input::actions::Action::Follow { client_id } => {
+                ActionType::Follow(FollowAction {
+                    client_id: client_id as u32,
+                })
+            },
         };
 
         Self {
@@ -1394,6 +1503,7 @@ impl TryFrom<crate::client_server_contract::client_server_contract::Action>
                 Ok(crate::input::actions::Action::DumpScreen {
                     file_path: dump_screen_action.file_path,
                     include_scrollback: dump_screen_action.include_scrollback,
+                    format: proto_i32_to_dump_screen_format(dump_screen_action.format)?,
                 })
             },
             ActionType::DumpLayout(_) => Ok(crate::input::actions::Action::DumpLayout),
@@ -1924,6 +2034,106 @@ impl TryFrom<crate::client_server_contract::client_server_contract::Action>
             ActionType::ToggleGroupMarking(_) => {
                 Ok(crate::input::actions::Action::ToggleGroupMarking)
             },
+            ActionType::SearchPane(search_pane_action) => {
+                Ok(crate::input::actions::Action::SearchPane {
+                    pane_id: search_pane_action
+                        .pane_id
+                        .ok_or_else(|| anyhow!("SearchPane missing pane_id"))?
+                        .try_into()?,
+                    query: crate::data::SearchQuery {
+                        needle: search_pane_action.needle,
+                        regex: search_pane_action.regex,
+                        case_insensitive: search_pane_action.case_insensitive,
+                        whole_word: search_pane_action.whole_word,
+                        context_lines: search_pane_action.context_lines as usize,
+                    },
+                    json: search_pane_action.json,
+                })
+            },
+            ActionType::SearchSession(search_session_action) => {
+                Ok(crate::input::actions::Action::SearchSession {
+                    query: crate::data::SearchQuery {
+                        needle: search_session_action.needle,
+                        regex: search_session_action.regex,
+                        case_insensitive: search_session_action.case_insensitive,
+                        whole_word: search_session_action.whole_word,
+                        context_lines: search_session_action.context_lines as usize,
+                    },
+                })
+            },
+            ActionType::JumpToSearchMatch(jump_to_search_match_action) => {
+                Ok(crate::input::actions::Action::JumpToSearchMatch {
+                    pane_id: jump_to_search_match_action
+                        .pane_id
+                        .ok_or_else(|| anyhow!("JumpToSearchMatch missing pane_id"))?
+                        .try_into()?,
+                    line: jump_to_search_match_action.line as usize,
+                })
+            },
+            ActionType::LogPane(log_pane_action) => Ok(crate::input::actions::Action::LogPane {
+                pane_id: log_pane_action
+                    .pane_id
+                    .ok_or_else(|| anyhow!("LogPane missing pane_id"))?
+                    .try_into()?,
+                log_options: log_pane_action
+                    .log_options
+                    .map(|log_options| log_options.into()),
+            }),
+            ActionType::RecordPane(record_pane_action) => {
+                Ok(crate::input::actions::Action::RecordPane {
+                    pane_id: record_pane_action
+                        .pane_id
+                        .ok_or_else(|| anyhow!("RecordPane missing pane_id"))?
+                        .try_into()?,
+                    out: record_pane_action.out.map(PathBuf::from),
+                })
+            },
+            ActionType::SetTilingMode(set_tiling_mode_action) => {
+                Ok(crate::input::actions::Action::SetTilingMode {
+                    tiling_mode: proto_i32_to_tiling_mode(set_tiling_mode_action.tiling_mode)?,
+                })
+            },
+            ActionType::MarkPane(mark_pane_action) => Ok(crate::input::actions::Action::MarkPane {
+                name: mark_pane_action.name,
+            }),
+            ActionType::FocusMark(focus_mark_action) => {
+                Ok(crate::input::actions::Action::FocusMark {
+                    name: focus_mark_action.name,
+                })
+            },
+            ActionType::Follow(follow_action) => Ok(crate::input::actions::Action::Follow {
+                client_id: follow_action.client_id as u16,
+            }),
+        }
+    }
+}
+
+impl From<crate::data::PaneLogOptions>
+    for crate::client_server_contract::client_server_contract::PaneLogOptions
+{
+    fn from(log_options: crate::data::PaneLogOptions) -> Self {
+        Self {
+            path: log_options.path.to_string_lossy().to_string(),
+            strip_ansi: log_options.strip_ansi,
+            append: log_options.append,
+            max_size: log_options.max_size,
+            max_files: log_options.max_files as u32,
+        }
+    }
+}
+
+impl From<crate::client_server_contract::client_server_contract::PaneLogOptions>
+    for crate::data::PaneLogOptions
+{
+    fn from(
+        log_options: crate::client_server_contract::client_server_contract::PaneLogOptions,
+    ) -> Self {
+        Self {
+            path: PathBuf::from(log_options.path),
+            strip_ansi: log_options.strip_ansi,
+            append: log_options.append,
+            max_size: log_options.max_size,
+            max_files: log_options.max_files as usize,
         }
     }
 }
@@ -2085,6 +2295,29 @@ impl From<crate::data::LayoutMetadata> for ProtoLayoutMetadata {
             tabs: metadata.tabs.into_iter().map(|t| t.into()).collect(),
             creation_time: metadata.creation_time,
             update_time: metadata.update_time,
+            params: metadata.params.into_iter().map(|p| p.into()).collect(),
+        }
+    }
+}
+
+impl From<crate::data::LayoutParam> for ProtoLayoutParam {
+    fn from(param: crate::data::LayoutParam) -> Self {
+        ProtoLayoutParam {
+            name: param.name,
+            default: param.default,
+            required: param.required,
+            value: param.value,
+        }
+    }
+}
+
+impl From<ProtoLayoutParam> for crate::data::LayoutParam {
+    fn from(proto_param: ProtoLayoutParam) -> Self {
+        crate::data::LayoutParam {
+            name: proto_param.name,
+            default: proto_param.default,
+            required: proto_param.required,
+            value: proto_param.value,
         }
     }
 }
@@ -2101,6 +2334,11 @@ impl TryFrom<ProtoLayoutMetadata> for crate::data::LayoutMetadata {
             tabs,
             creation_time: proto_metadata.creation_time,
             update_time: proto_metadata.update_time,
+            params: proto_metadata
+                .params
+                .into_iter()
+                .map(|p| p.into())
+                .collect(),
         })
     }
 }
@@ -2199,6 +2437,8 @@ fn input_mode_to_proto_i32(mode: InputMode) -> i32 {
         InputMode::Move => ProtoInputMode::Move as i32,
         InputMode::Prompt => ProtoInputMode::Prompt as i32,
         InputMode::Tmux => ProtoInputMode::Tmux as i32,
+        InputMode::MarkPane => ProtoInputMode::MarkPane as i32,
+        InputMode::FocusMark => ProtoInputMode::FocusMark as i32,
     }
 }
 
@@ -2218,6 +2458,8 @@ fn proto_i32_to_input_mode(i: i32) -> Result<InputMode> {
         Some(ProtoInputMode::Move) => Ok(InputMode::Move),
         Some(ProtoInputMode::Prompt) => Ok(InputMode::Prompt),
         Some(ProtoInputMode::Tmux) => Ok(InputMode::Tmux),
+        Some(ProtoInputMode::MarkPane) => Ok(InputMode::MarkPane),
+        Some(ProtoInputMode::FocusMark) => Ok(InputMode::FocusMark),
         _ => Err(anyhow!("Invalid InputMode value: {}", i)),
     }
 }
@@ -2257,6 +2499,7 @@ fn search_option_to_proto_i32(option: crate::input::actions::SearchOption) -> i3
         },
         crate::input::actions::SearchOption::Wrap => ProtoSearchOption::Wrap as i32,
         crate::input::actions::SearchOption::WholeWord => ProtoSearchOption::WholeWord as i32,
+        crate::input::actions::SearchOption::Regex => ProtoSearchOption::Regex as i32,
     }
 }
 
@@ -2269,6 +2512,35 @@ fn unblock_condition_to_proto_i32(condition: crate::data::UnblockCondition) -> i
     }
 }
 
+fn dump_screen_format_to_proto_i32(format: crate::data::DumpScreenFormat) -> i32 {
+    use crate::client_server_contract::client_server_contract::DumpScreenFormat as ProtoDumpScreenFormat;
+    match format {
+        crate::data::DumpScreenFormat::Text => ProtoDumpScreenFormat::Text as i32,
+        crate::data::DumpScreenFormat::Ansi => ProtoDumpScreenFormat::Ansi as i32,
+        crate::data::DumpScreenFormat::Html => ProtoDumpScreenFormat::Html as i32,
+        crate::data::DumpScreenFormat::Svg => ProtoDumpScreenFormat::Svg as i32,
+    }
+}
+
+fn tiling_mode_to_proto_i32(tiling_mode: crate::data::TilingMode) -> i32 {
+    use crate::client_server_contract::client_server_contract::TilingMode as ProtoTilingMode;
+    match tiling_mode {
+        crate::data::TilingMode::Manual => ProtoTilingMode::Manual as i32,
+        crate::data::TilingMode::Bsp => ProtoTilingMode::Bsp as i32,
+        crate::data::TilingMode::MasterStack => ProtoTilingMode::MasterStack as i32,
+        crate::data::TilingMode::Grid => ProtoTilingMode::Grid as i32,
+    }
+}
+
+fn restart_policy_to_proto_i32(restart: crate::input::command::RestartPolicy) -> i32 {
+    use crate::client_server_contract::client_server_contract::RestartPolicy as ProtoRestartPolicy;
+    match restart {
+        crate::input::command::RestartPolicy::Never => ProtoRestartPolicy::Never as i32,
+        crate::input::command::RestartPolicy::OnFailure => ProtoRestartPolicy::OnFailure as i32,
+        crate::input::command::RestartPolicy::Always => ProtoRestartPolicy::Always as i32,
+    }
+}
+
 // Reverse helper functions for Action conversion
 
 fn proto_i32_to_resize(resize: i32) -> Result<crate::data::Resize> {
@@ -2323,6 +2595,7 @@ fn proto_i32_to_search_option(option: i32) -> Result<crate::input::actions::Sear
         x if x == ProtoSearchOption::CaseSensitivity as i32 => ProtoSearchOption::CaseSensitivity,
         x if x == ProtoSearchOption::WholeWord as i32 => ProtoSearchOption::WholeWord,
         x if x == ProtoSearchOption::Wrap as i32 => ProtoSearchOption::Wrap,
+        x if x == ProtoSearchOption::Regex as i32 => ProtoSearchOption::Regex,
         _ => return Err(anyhow!("Invalid SearchOption: {}", option)),
     };
     match proto_option {
@@ -2331,6 +2604,7 @@ fn proto_i32_to_search_option(option: i32) -> Result<crate::input::actions::Sear
         },
         ProtoSearchOption::Wrap => Ok(crate::input::actions::SearchOption::Wrap),
         ProtoSearchOption::WholeWord => Ok(crate::input::actions::SearchOption::WholeWord),
+        ProtoSearchOption::Regex => Ok(crate::input::actions::SearchOption::Regex),
         ProtoSearchOption::Unspecified => Err(anyhow!("Unspecified search option")),
     }
 }
@@ -2355,7 +2629,58 @@ fn proto_i32_to_unblock_condition(condition: i32) -> Result<crate::data::Unblock
     }
 }
 
-// Position conversion
+fn proto_i32_to_restart_policy(restart: i32) -> Result<crate::input::command::RestartPolicy> {
+    use crate::client_server_contract::client_server_contract::RestartPolicy as ProtoRestartPolicy;
+    match restart {
+        x if x == ProtoRestartPolicy::Never as i32 => {
+            Ok(crate::input::command::RestartPolicy::Never)
+        },
+        x if x == ProtoRestartPolicy::OnFailure as i32 => {
+            Ok(crate::input::command::RestartPolicy::OnFailure)
+        },
+        x if x == ProtoRestartPolicy::Always as i32 => {
+            Ok(crate::input::command::RestartPolicy::Always)
+        },
+        _ => Err(anyhow!("Invalid RestartPolicy: {}", restart)),
+    }
+}
+
+fn proto_i32_to_dump_screen_format(format: i32) -> Result<crate::data::DumpScreenFormat> {
+    use crate::client_server_contract::client_server_contract::DumpScreenFormat as ProtoDumpScreenFormat;
+    let proto_format = match format {
+        x if x == ProtoDumpScreenFormat::Text as i32 => ProtoDumpScreenFormat::Text,
+        x if x == ProtoDumpScreenFormat::Ansi as i32 => ProtoDumpScreenFormat::Ansi,
+        x if x == ProtoDumpScreenFormat::Html as i32 => ProtoDumpScreenFormat::Html,
+        x if x == ProtoDumpScreenFormat::Svg as i32 => ProtoDumpScreenFormat::Svg,
+        _ => return Err(anyhow!("Invalid DumpScreenFormat: {}", format)),
+    };
+    match proto_format {
+        ProtoDumpScreenFormat::Text => Ok(crate::data::DumpScreenFormat::Text),
+        ProtoDumpScreenFormat::Ansi => Ok(crate::data::DumpScreenFormat::Ansi),
+        ProtoDumpScreenFormat::Html => Ok(crate::data::DumpScreenFormat::Html),
+        ProtoDumpScreenFormat::Svg => Ok(crate::data::DumpScreenFormat::Svg),
+        ProtoDumpScreenFormat::Unspecified => Err(anyhow!("Unspecified dump screen format")),
+    }
+}
+
+fn proto_i32_to_tiling_mode(tiling_mode: i32) -> Result<crate::data::TilingMode> {
+    use crate::client_server_contract::client_server_contract::TilingMode as ProtoTilingMode;
+    let proto_tiling_mode = match tiling_mode {
+        x if x == ProtoTilingMode::Manual as i32 => ProtoTilingMode::Manual,
+        x if x == ProtoTilingMode::Bsp as i32 => ProtoTilingMode::Bsp,
+        x if x == ProtoTilingMode::MasterStack as i32 => ProtoTilingMode::MasterStack,
+        x if x == ProtoTilingMode::Grid as i32 => ProtoTilingMode::Grid,
+        _ => return Err(anyhow!("Invalid TilingMode: {}", tiling_mode)),
+    };
+    match proto_tiling_mode {
+        ProtoTilingMode::Manual => Ok(crate::data::TilingMode::Manual),
+        ProtoTilingMode::Bsp => Ok(crate::data::TilingMode::Bsp),
+        ProtoTilingMode::MasterStack => Ok(crate::data::TilingMode::MasterStack),
+        ProtoTilingMode::Grid => Ok(crate::data::TilingMode::Grid),
+        ProtoTilingMode::Unspecified => Err(anyhow!("Unspecified tiling mode")),
+    }
+}
+
 impl From<crate::position::Position>
     for crate::client_server_contract::client_server_contract::Position
 {
@@ -2753,6 +3078,9 @@ impl From<crate::input::command::RunCommandAction>
             hold_on_start: action.hold_on_start,
             originating_plugin: action.originating_plugin.map(|op| op.into()),
             use_terminal_title: action.use_terminal_title,
+            env: action.env.into_iter().collect(),
+            restart: restart_policy_to_proto_i32(action.restart),
+            max_restarts: action.max_restarts.map(|m| m as u32),
         }
     }
 }
@@ -2855,6 +3183,9 @@ impl From<crate::input::layout::Run>
                         hold_on_start: cmd.hold_on_start,
                         originating_plugin: cmd.originating_plugin.map(|op| op.into()),
                         use_terminal_title: cmd.use_terminal_title,
+                        env: cmd.env.into_iter().collect(),
+                        restart: restart_policy_to_proto_i32(cmd.restart),
+                        max_restarts: cmd.max_restarts.map(|m| m as u32),
                     },
                 )),
             },
@@ -2972,6 +3303,10 @@ impl From<crate::input::layout::TiledPaneLayout>
             is_expanded_in_stack: layout.is_expanded_in_stack,
             hide_floating_panes: layout.hide_floating_panes,
             pane_initial_contents: layout.pane_initial_contents,
+            log_to: layout.log_to.map(|p| p.to_string_lossy().to_string()),
+            depends_on: layout.depends_on.into_iter().map(|d| d.into()).collect(),
+            mark: layout.mark,
+            tiling_mode: layout.tiling_mode.map(tiling_mode_to_proto_i32),
         }
     }
 }
@@ -2993,6 +3328,9 @@ impl From<crate::input::layout::FloatingPaneLayout>
             pane_initial_contents: layout.pane_initial_contents,
             logical_position: layout.logical_position.map(|l| l as u32),
             borderless: layout.borderless,
+            log_to: layout.log_to.map(|p| p.to_string_lossy().to_string()),
+            depends_on: layout.depends_on.into_iter().map(|d| d.into()).collect(),
+            mark: layout.mark,
         }
     }
 }
@@ -3221,6 +3559,9 @@ impl TryFrom<crate::client_server_contract::client_server_contract::Run>
                         .map(|op| op.try_into())
                         .transpose()?,
                     use_terminal_title: cmd.use_terminal_title,
+                    env: cmd.env.into_iter().collect(),
+                    restart: proto_i32_to_restart_policy(cmd.restart)?,
+                    max_restarts: cmd.max_restarts.map(|m| m as usize),
                 },
             )),
             RunType::EditFile(edit) => Ok(crate::input::layout::Run::EditFile(
@@ -3328,6 +3669,9 @@ impl TryFrom<crate::client_server_contract::client_server_contract::RunCommandAc
                 .map(|op| op.try_into())
                 .transpose()?,
             use_terminal_title: action.use_terminal_title,
+            env: action.env.into_iter().collect(),
+            restart: proto_i32_to_restart_policy(action.restart)?,
+            max_restarts: action.max_restarts.map(|m| m as usize),
         })
     }
 }
@@ -3361,6 +3705,11 @@ impl TryFrom<crate::client_server_contract::client_server_contract::TiledPaneLay
 
         let children: Result<Vec<_>> = layout.children.into_iter().map(|c| c.try_into()).collect();
         let run = layout.run.map(|r| r.try_into()).transpose()?;
+        let depends_on = layout
+            .depends_on
+            .into_iter()
+            .map(|d| d.try_into())
+            .collect::<Result<Vec<_>>>()?;
 
         let split_size = layout.split_size.and_then(|size| {
             use crate::client_server_contract::client_server_contract::split_size::SizeType;
@@ -3386,6 +3735,13 @@ impl TryFrom<crate::client_server_contract::client_server_contract::TiledPaneLay
             run_instructions_to_ignore: vec![], // not represented in protobuf
             hide_floating_panes: layout.hide_floating_panes,
             pane_initial_contents: layout.pane_initial_contents,
+            log_to: layout.log_to.map(PathBuf::from),
+            depends_on,
+            mark: layout.mark,
+            tiling_mode: layout
+                .tiling_mode
+                .map(proto_i32_to_tiling_mode)
+                .transpose()?,
         })
     }
 }
@@ -3404,6 +3760,11 @@ impl TryFrom<crate::client_server_contract::client_server_contract::FloatingPane
         let width = layout.width.map(|w| w.try_into()).transpose()?;
         let x = layout.x.map(|x| x.try_into()).transpose()?;
         let y = layout.y.map(|y| y.try_into()).transpose()?;
+        let depends_on = layout
+            .depends_on
+            .into_iter()
+            .map(|d| d.try_into())
+            .collect::<Result<Vec<_>>>()?;
 
         Ok(crate::input::layout::FloatingPaneLayout {
             name: layout.name,
@@ -3418,6 +3779,54 @@ impl TryFrom<crate::client_server_contract::client_server_contract::FloatingPane
             pane_initial_contents: layout.pane_initial_contents,
             logical_position: layout.logical_position.map(|p| p as usize),
             borderless: layout.borderless,
+            log_to: layout.log_to.map(PathBuf::from),
+            depends_on,
+            mark: layout.mark,
+        })
+    }
+}
+
+// PaneDependency conversion
+impl From<crate::input::layout::PaneDependency>
+    for crate::client_server_contract::client_server_contract::PaneDependency
+{
+    fn from(dependency: crate::input::layout::PaneDependency) -> Self {
+        use crate::client_server_contract::client_server_contract::pane_dependency::ReadyWhen;
+        use crate::input::layout::PaneReadiness;
+        let ready_when = match dependency.ready_when {
+            PaneReadiness::ExitedSuccessfully => ReadyWhen::ExitedSuccessfully(true),
+            PaneReadiness::OutputMatches(regex) => ReadyWhen::OutputMatches(regex),
+            PaneReadiness::PortOpen(port) => ReadyWhen::PortOpen(port as u32),
+        };
+        Self {
+            pane_name: dependency.pane_name,
+            ready_when: Some(ready_when),
+        }
+    }
+}
+
+impl TryFrom<crate::client_server_contract::client_server_contract::PaneDependency>
+    for crate::input::layout::PaneDependency
+{
+    type Error = anyhow::Error;
+    fn try_from(
+        dependency: crate::client_server_contract::client_server_contract::PaneDependency,
+    ) -> Result<Self> {
+        use crate::client_server_contract::client_server_contract::pane_dependency::ReadyWhen;
+        use crate::input::layout::PaneReadiness;
+        let ready_when = match dependency
+            .ready_when
+            .ok_or_else(|| anyhow!("PaneDependency missing ready_when"))?
+        {
+            ReadyWhen::ExitedSuccessfully(_) => PaneReadiness::ExitedSuccessfully,
+            ReadyWhen::OutputMatches(regex) => PaneReadiness::OutputMatches(regex),
+            ReadyWhen::PortOpen(port) => PaneReadiness::PortOpen(
+                u16::try_from(port).map_err(|_| anyhow!("Invalid port: {}", port))?,
+            ),
+        };
+        Ok(Self {
+            pane_name: dependency.pane_name,
+            ready_when,
         })
     }
 }
@@ -1,16 +1,17 @@
 use super::test_framework::*;
 use crate::data::{
-    BareKey, CommandOrPlugin, ConnectToSession, Direction, FloatingPaneCoordinates, InputMode,
-    KeyModifier, KeyWithModifier, LayoutInfo, LayoutMetadata, OriginatingPlugin, PaneId, PluginTag,
-    Resize, WebSharing,
+    BareKey, CommandOrPlugin, ConnectToSession, Direction, DumpScreenFormat,
+    FloatingPaneCoordinates, InputMode, KeyModifier, KeyWithModifier, LayoutInfo, LayoutMetadata,
+    LayoutParam, OriginatingPlugin, PaneId, PaneLogOptions, PluginTag, Resize, SearchQuery,
+    TilingMode, WebSharing,
 };
 use crate::input::actions::{Action, SearchDirection, SearchOption};
 use crate::input::cli_assets::CliAssets;
-use crate::input::command::{OpenFilePayload, RunCommand, RunCommandAction};
+use crate::input::command::{OpenFilePayload, RestartPolicy, RunCommand, RunCommandAction};
 use crate::input::layout::{
-    FloatingPaneLayout, LayoutConstraint, PercentOrFixed, PluginAlias, PluginUserConfiguration,
-    Run, RunPlugin, RunPluginLocation, RunPluginOrAlias, SplitDirection, SplitSize,
-    TiledPaneLayout,
+    FloatingPaneLayout, LayoutConstraint, PaneDependency, PaneReadiness, PercentOrFixed,
+    PluginAlias, PluginUserConfiguration, Run, RunPlugin, RunPluginLocation, RunPluginOrAlias,
+    SplitDirection, SplitSize, TiledPaneLayout,
 };
 use crate::input::mouse::{MouseEvent, MouseEventType};
 use crate::input::options::{Clipboard, OnForceClose, Options};
@@ -406,6 +407,7 @@ fn test_client_messages() {
             max_panes: Some(4),
             force_run_layout_commands: true,
             cwd: Some(PathBuf::from("/path/to/cwd")),
+            client_name: Some("alice".to_owned()),
         },
         is_web_client: true,
     });
@@ -422,6 +424,7 @@ fn test_client_messages() {
             max_panes: Some(4),
             force_run_layout_commands: true,
             cwd: Some(PathBuf::from("/path/to/cwd")),
+            client_name: None,
         },
         is_web_client: true,
     });
@@ -471,6 +474,7 @@ fn test_client_messages() {
                 web_server_key: Some(PathBuf::from("web_server_key")),
                 enforce_https_for_localhost: Some(true),
                 post_command_discovery_hook: Some("post_command_discovery_hook".to_owned()),
+                plugin_execution_budget: Some(1_000_000),
             }),
             layout: None,
             terminal_window_size: Size { rows: 80, cols: 42 },
@@ -479,6 +483,7 @@ fn test_client_messages() {
             max_panes: Some(4),
             force_run_layout_commands: true,
             cwd: Some(PathBuf::from("/path/to/cwd")),
+            client_name: None,
         },
         is_web_client: true,
     });
@@ -622,6 +627,26 @@ fn test_client_messages() {
         },
         is_web_client: true,
     });
+    test_client_roundtrip!(ClientToServerMsg::FirstClientConnected {
+        cli_assets: CliAssets {
+            configuration_options: Some(Options {
+                default_mode: Some(InputMode::MarkPane),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        is_web_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::FirstClientConnected {
+        cli_assets: CliAssets {
+            configuration_options: Some(Options {
+                default_mode: Some(InputMode::FocusMark),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        is_web_client: true,
+    });
     test_client_roundtrip!(ClientToServerMsg::FirstClientConnected {
         cli_assets: CliAssets {
             configuration_options: Some(Options {
@@ -872,6 +897,7 @@ fn test_client_messages() {
         action: Action::DumpScreen {
             file_path: "/path/to/file".to_owned(),
             include_scrollback: false,
+            format: DumpScreenFormat::Text,
         },
         terminal_id: Some(1),
         client_id: Some(100),
@@ -881,6 +907,27 @@ fn test_client_messages() {
         action: Action::DumpScreen {
             file_path: "/path/to/file".to_owned(),
             include_scrollback: true,
+            format: DumpScreenFormat::Ansi,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::DumpScreen {
+            file_path: "/path/to/file.html".to_owned(),
+            include_scrollback: true,
+            format: DumpScreenFormat::Html,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::DumpScreen {
+            file_path: "/path/to/file.svg".to_owned(),
+            include_scrollback: false,
+            format: DumpScreenFormat::Svg,
         },
         terminal_id: Some(1),
         client_id: Some(100),
@@ -1238,6 +1285,9 @@ fn test_client_messages() {
                 hold_on_start: false,
                 originating_plugin: None,
                 use_terminal_title: false,
+                env: BTreeMap::from([("AGENT_ID".to_owned(), "7".to_owned())]),
+                restart: RestartPolicy::OnFailure,
+                max_restarts: Some(5),
             }),
             pane_name: Some("my_pane_name".to_owned()),
             coordinates: FloatingPaneCoordinates::new(
@@ -1269,6 +1319,9 @@ fn test_client_messages() {
                     context: demo_context.clone(),
                 }),
                 use_terminal_title: false,
+                env: BTreeMap::new(),
+                restart: RestartPolicy::Never,
+                max_restarts: None,
             }),
             pane_name: Some("my_pane_name".to_owned()),
             coordinates: FloatingPaneCoordinates::new(
@@ -1312,6 +1365,9 @@ fn test_client_messages() {
                     context: demo_context.clone(),
                 }),
                 use_terminal_title: false,
+                env: BTreeMap::new(),
+                restart: RestartPolicy::Never,
+                max_restarts: None,
             }),
             direction: Some(Direction::Right),
             pane_name: Some("my_pane_name".to_owned()),
@@ -1337,6 +1393,9 @@ fn test_client_messages() {
                     context: demo_context.clone(),
                 }),
                 use_terminal_title: false,
+                env: BTreeMap::new(),
+                restart: RestartPolicy::Never,
+                max_restarts: None,
             }),
             pane_name: Some("my_pane_name".to_owned()),
             near_current_pane: false,
@@ -1374,6 +1433,9 @@ fn test_client_messages() {
                     context: demo_context.clone(),
                 }),
                 use_terminal_title: false,
+                env: BTreeMap::new(),
+                restart: RestartPolicy::Never,
+                max_restarts: None,
             }),
             pane_name: Some("my_pane_name".to_owned()),
             near_current_pane: false,
@@ -1836,6 +1898,9 @@ fn test_client_messages() {
                     hold_on_start: false,
                     originating_plugin: None,
                     use_terminal_title: true,
+                    env: BTreeMap::from([("AGENT_ID".to_owned(), "7".to_owned())]),
+                    restart: RestartPolicy::Always,
+                    max_restarts: None,
                 })),
                 ..Default::default()
             }),
@@ -1867,6 +1932,9 @@ fn test_client_messages() {
                         context: empty_context.clone(),
                     }),
                     use_terminal_title: true,
+                    env: BTreeMap::new(),
+                    restart: RestartPolicy::Never,
+                    max_restarts: None,
                 })),
                 ..Default::default()
             }),
@@ -1965,6 +2033,22 @@ fn test_client_messages() {
                     already_running: true,
                     pane_initial_contents: Some("pane_initial_contents".to_owned()),
                     logical_position: Some(15),
+                    log_to: Some(PathBuf::from("/path/to/pane.log")),
+                    depends_on: vec![
+                        PaneDependency {
+                            pane_name: "db".to_owned(),
+                            ready_when: PaneReadiness::ExitedSuccessfully,
+                        },
+                        PaneDependency {
+                            pane_name: "api".to_owned(),
+                            ready_when: PaneReadiness::OutputMatches("listening".to_owned()),
+                        },
+                        PaneDependency {
+                            pane_name: "cache".to_owned(),
+                            ready_when: PaneReadiness::PortOpen(6379),
+                        },
+                    ],
+                    mark: Some("logs".to_owned()),
                 },
                 FloatingPaneLayout {
                     name: Some("third floating layout".to_owned()),
@@ -2059,6 +2143,9 @@ fn test_client_messages() {
                     hold_on_start: false,
                     originating_plugin: None,
                     use_terminal_title: false,
+                    env: BTreeMap::new(),
+                    restart: RestartPolicy::Never,
+                    max_restarts: None,
                 }),
                 CommandOrPlugin::Plugin(RunPluginOrAlias::RunPlugin(RunPlugin::default())),
             ]),
@@ -2167,6 +2254,9 @@ fn test_client_messages() {
                 hold_on_start: false,
                 originating_plugin: None,
                 use_terminal_title: false,
+                env: BTreeMap::new(),
+                restart: RestartPolicy::Never,
+                max_restarts: None,
             },
             near_current_pane: false,
         },
@@ -2363,6 +2453,149 @@ fn test_client_messages() {
         client_id: Some(100),
         is_cli_client: true,
     });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SearchToggleOption {
+            option: SearchOption::Regex,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SearchPane {
+            pane_id: PaneId::Terminal(3),
+            query: SearchQuery {
+                needle: "error: .*".to_string(),
+                regex: true,
+                case_insensitive: true,
+                whole_word: false,
+                context_lines: 2,
+            },
+            json: true,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SearchSession {
+            query: SearchQuery {
+                needle: "panicked at".to_string(),
+                regex: false,
+                case_insensitive: false,
+                whole_word: true,
+                context_lines: 0,
+            },
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::JumpToSearchMatch {
+            pane_id: PaneId::Terminal(3),
+            line: 1024,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::LogPane {
+            pane_id: PaneId::Terminal(3),
+            log_options: Some(PaneLogOptions {
+                path: PathBuf::from("/tmp/pane.log"),
+                strip_ansi: true,
+                append: false,
+                max_size: Some(1024 * 1024),
+                max_files: 3,
+            }),
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::LogPane {
+            pane_id: PaneId::Plugin(2),
+            log_options: None,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::RecordPane {
+            pane_id: PaneId::Terminal(3),
+            out: Some(PathBuf::from("/tmp/run.cast")),
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::RecordPane {
+            pane_id: PaneId::Terminal(3),
+            out: None,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SetTilingMode {
+            tiling_mode: TilingMode::Manual,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SetTilingMode {
+            tiling_mode: TilingMode::Bsp,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SetTilingMode {
+            tiling_mode: TilingMode::MasterStack,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::SetTilingMode {
+            tiling_mode: TilingMode::Grid,
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::MarkPane {
+            name: "api".to_owned(),
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::FocusMark {
+            name: "api".to_owned(),
+        },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
+    test_client_roundtrip!(ClientToServerMsg::Action {
+        action: Action::Follow { client_id: 2 },
+        terminal_id: Some(1),
+        client_id: Some(100),
+        is_cli_client: true,
+    });
     test_client_roundtrip!(ClientToServerMsg::Action {
         action: Action::ToggleMouseMode,
         terminal_id: Some(1),
@@ -3087,7 +3320,13 @@ fn test_server_messages() {
                 LayoutMetadata {
                     tabs: vec![],
                     creation_time: "0".to_owned(),
-                    update_time: "0".to_owned()
+                    update_time: "0".to_owned(),
+                    params: vec![LayoutParam {
+                        name: "project".to_owned(),
+                        default: Some("perth".to_owned()),
+                        required: false,
+                        value: Some("zellij".to_owned()),
+                    }],
                 }
             )),
             cwd: Some(PathBuf::from("/path/to/cwd")),
@@ -1,10 +1,11 @@
+use crate::data::{LayoutParam, TilingMode};
 use crate::input::{
-    command::RunCommand,
+    command::{RestartPolicy, RunCommand},
     config::ConfigError,
     layout::{
-        FloatingPaneLayout, Layout, LayoutConstraint, PercentOrFixed, PluginUserConfiguration, Run,
-        RunPluginOrAlias, SplitDirection, SplitSize, SwapFloatingLayout, SwapTiledLayout,
-        TiledPaneLayout,
+        FloatingPaneLayout, Layout, LayoutConstraint, PaneDependency, PaneReadiness,
+        PercentOrFixed, PluginUserConfiguration, Run, RunPluginOrAlias, SplitDirection, SplitSize,
+        SwapFloatingLayout, SwapTiledLayout, TiledPaneLayout,
     },
 };
 
@@ -16,8 +17,8 @@ use std::str::FromStr;
 use crate::{
     kdl_child_with_name, kdl_children_nodes, kdl_first_entry_as_bool, kdl_first_entry_as_i64,
     kdl_first_entry_as_string, kdl_get_bool_property_or_child_value,
-    kdl_get_bool_property_or_child_value_with_error, kdl_get_child,
-    kdl_get_int_property_or_child_value, kdl_get_property_or_child,
+    kdl_get_bool_property_or_child_value_with_error, kdl_get_child, kdl_get_int_entry,
+    kdl_get_int_property_or_child_value, kdl_get_property_or_child, kdl_get_string_entry,
     kdl_get_string_property_or_child_value, kdl_get_string_property_or_child_value_with_error,
     kdl_name, kdl_parsing_error, kdl_property_names, kdl_property_or_child_value_node,
     kdl_string_arguments,
@@ -41,6 +42,18 @@ pub struct KdlLayoutParser<'a> {
     default_tab_template: Option<(TiledPaneLayout, Vec<FloatingPaneLayout>, KdlNode)>,
     new_tab_template: Option<(TiledPaneLayout, Vec<FloatingPaneLayout>)>,
     file_name: Option<PathBuf>,
+    params: BTreeMap<String, String>, // the values given for the layout parameters
+    param_values: BTreeMap<String, String>, // all layout parameters, once resolved
+    layout_dir: Option<PathBuf>, // where the layout files included with `include` are looked up
+    included_layouts: Vec<IncludedLayout>,
+}
+
+/// A layout file included with `include`, the spans of its nodes are shifted by `offset` so that
+/// they do not overlap those of the including layout and errors can be traced back to it
+struct IncludedLayout {
+    file_name: String,
+    raw_layout: String,
+    offset: usize,
 }
 
 impl<'a> KdlLayoutParser<'a> {
@@ -48,7 +61,10 @@ impl<'a> KdlLayoutParser<'a> {
         raw_layout: &'a str,
         global_cwd: Option<PathBuf>,
         file_name: Option<String>,
+        params: BTreeMap<String, String>,
+        layout_dir: Option<PathBuf>,
     ) -> Self {
+        let file_name = file_name.map(PathBuf::from);
         KdlLayoutParser {
             raw_layout,
             tab_templates: HashMap::new(),
@@ -56,7 +72,11 @@ impl<'a> KdlLayoutParser<'a> {
             default_tab_template: None,
             new_tab_template: None,
             global_cwd,
-            file_name: file_name.map(|f| PathBuf::from(f)),
+            file_name,
+            params,
+            param_values: BTreeMap::new(),
+            layout_dir,
+            included_layouts: vec![],
         }
     }
     fn is_a_reserved_word(&self, word: &str) -> bool {
@@ -70,12 +90,16 @@ impl<'a> KdlLayoutParser<'a> {
             || word == "new_tab_template"
             || word == "command"
             || word == "edit"
+            || word == "playback"
+            || word == "playback_speed"
             || word == "plugin"
             || word == "children"
             || word == "tab"
             || word == "args"
             || word == "close_on_exit"
             || word == "start_suspended"
+            || word == "restart"
+            || word == "max_restarts"
             || word == "borderless"
             || word == "focus"
             || word == "name"
@@ -85,7 +109,13 @@ impl<'a> KdlLayoutParser<'a> {
             || word == "swap_tiled_layout"
             || word == "swap_floating_layout"
             || word == "hide_floating_panes"
+            || word == "tiling_mode"
             || word == "contents_file"
+            || word == "log_to"
+            || word == "mark"
+            || word == "depends_on"
+            || word == "params"
+            || word == "include"
     }
     fn is_a_valid_pane_property(&self, property_name: &str) -> bool {
         property_name == "borderless"
@@ -95,10 +125,15 @@ impl<'a> KdlLayoutParser<'a> {
             || property_name == "plugin"
             || property_name == "command"
             || property_name == "edit"
+            || property_name == "playback"
+            || property_name == "playback_speed"
             || property_name == "cwd"
             || property_name == "args"
+            || property_name == "env"
             || property_name == "close_on_exit"
             || property_name == "start_suspended"
+            || property_name == "restart"
+            || property_name == "max_restarts"
             || property_name == "split_direction"
             || property_name == "pane"
             || property_name == "children"
@@ -106,6 +141,9 @@ impl<'a> KdlLayoutParser<'a> {
             || property_name == "expanded"
             || property_name == "exclude_from_sync"
             || property_name == "contents_file"
+            || property_name == "log_to"
+            || property_name == "mark"
+            || property_name == "depends_on"
     }
     fn is_a_valid_floating_pane_property(&self, property_name: &str) -> bool {
         property_name == "borderless"
@@ -114,16 +152,24 @@ impl<'a> KdlLayoutParser<'a> {
             || property_name == "plugin"
             || property_name == "command"
             || property_name == "edit"
+            || property_name == "playback"
+            || property_name == "playback_speed"
             || property_name == "cwd"
             || property_name == "args"
+            || property_name == "env"
             || property_name == "close_on_exit"
             || property_name == "start_suspended"
+            || property_name == "restart"
+            || property_name == "max_restarts"
             || property_name == "x"
             || property_name == "y"
             || property_name == "width"
             || property_name == "height"
             || property_name == "pinned"
             || property_name == "contents_file"
+            || property_name == "log_to"
+            || property_name == "mark"
+            || property_name == "depends_on"
     }
     fn is_a_valid_tab_property(&self, property_name: &str) -> bool {
         property_name == "focus"
@@ -136,6 +182,7 @@ impl<'a> KdlLayoutParser<'a> {
             || property_name == "min_panes"
             || property_name == "exact_panes"
             || property_name == "hide_floating_panes"
+            || property_name == "tiling_mode"
     }
     pub fn is_a_reserved_plugin_property(property_name: &str) -> bool {
         property_name == "location"
@@ -403,6 +450,467 @@ impl<'a> KdlLayoutParser<'a> {
             None => Ok(None),
         }
     }
+    fn parse_env(
+        &self,
+        pane_node: &KdlNode,
+    ) -> Result<Option<BTreeMap<String, String>>, ConfigError> {
+        match kdl_get_child!(pane_node, "env") {
+            Some(kdl_env) => {
+                let env_vars = kdl_children_nodes!(kdl_env).ok_or(kdl_parsing_error!(
+                    format!("env cannot be empty and should contain one or more variables (eg. env {{ KEY \"value\"; }})"),
+                    kdl_env
+                ))?;
+                let mut env = BTreeMap::new();
+                for env_var in env_vars {
+                    let env_var_name = kdl_name!(env_var);
+                    let env_var_value = kdl_first_entry_as_string!(env_var)
+                        .map(|s| s.to_string())
+                        .or_else(|| kdl_first_entry_as_i64!(env_var).map(|i| i.to_string()))
+                        .ok_or(kdl_parsing_error!(
+                            format!("Failed to parse env var: {:?}", env_var_name),
+                            env_var
+                        ))?;
+                    env.insert(env_var_name.to_string(), env_var_value);
+                }
+                Ok(Some(env))
+            },
+            None => Ok(None),
+        }
+    }
+    /// Parses the parameters declared in the `params` block of a layout, eg.
+    /// `params { project required=true; agent default="claude"; }`
+    pub fn parse_params(params_node: &KdlNode) -> Result<Vec<LayoutParam>, ConfigError> {
+        let mut params: Vec<LayoutParam> = vec![];
+        for param_node in kdl_children_nodes!(params_node).unwrap_or(&[]) {
+            let name = kdl_name!(param_node);
+            if params.iter().any(|p| p.name == name) {
+                return Err(kdl_parsing_error!(
+                    format!("Duplicate layout parameter: {}", name),
+                    param_node
+                ));
+            }
+            let mut param = LayoutParam {
+                name: name.to_owned(),
+                ..Default::default()
+            };
+            for entry in param_node.entries() {
+                match (entry.name().map(|n| n.value()), entry.value()) {
+                    (Some("required"), KdlValue::Bool(required)) => param.required = *required,
+                    (Some("default"), KdlValue::String(default)) => {
+                        param.default = Some(default.to_owned())
+                    },
+                    _ => {
+                        return Err(kdl_parsing_error!(
+                            format!(
+                                "Invalid property for layout parameter {}, expected: required=true|false or default=\"value\"",
+                                name
+                            ),
+                            entry
+                        ));
+                    },
+                }
+            }
+            if param.required && param.default.is_some() {
+                return Err(kdl_parsing_error!(
+                    format!(
+                        "Layout parameter {} cannot be both required and have a default value",
+                        name
+                    ),
+                    param_node
+                ));
+            }
+            params.push(param);
+        }
+        Ok(params)
+    }
+    fn resolve_params(&mut self, layout_node: &KdlNode) -> Result<(), ConfigError> {
+        let params_node = kdl_get_child!(layout_node, "params");
+        let declared_params = match params_node {
+            Some(params_node) => KdlLayoutParser::parse_params(params_node)?,
+            None => vec![],
+        };
+        if let Some(unknown_param) = self
+            .params
+            .keys()
+            .find(|name| !declared_params.iter().any(|p| &&p.name == name))
+        {
+            let declared_names: Vec<&str> =
+                declared_params.iter().map(|p| p.name.as_str()).collect();
+            return Err(kdl_parsing_error!(
+                format!(
+                    "Unknown layout parameter: {} (this layout accepts: {})",
+                    unknown_param,
+                    if declared_names.is_empty() {
+                        "no parameters".to_owned()
+                    } else {
+                        declared_names.join(", ")
+                    }
+                ),
+                params_node.unwrap_or(layout_node)
+            ));
+        }
+        for param in declared_params {
+            let value = match self.params.get(&param.name).or(param.default.as_ref()) {
+                Some(value) => value.clone(),
+                None if param.required => {
+                    let param_node = params_node
+                        .and_then(|p| kdl_get_child!(p, param.name.as_str()))
+                        .unwrap_or(layout_node);
+                    return Err(kdl_parsing_error!(
+                        format!(
+                            "Missing value for required layout parameter: {} (eg. --param {}=<value>)",
+                            param.name, param.name
+                        ),
+                        param_node
+                    ));
+                },
+                None => String::new(),
+            };
+            self.param_values.insert(param.name, value);
+        }
+        Ok(())
+    }
+    /// Replaces `${name}` with the value of the layout parameter `name` in all the string values
+    /// of the document, leaving other `${...}` expressions (eg. environment variables) intact
+    fn interpolate_params(&self, kdl_document: &mut KdlDocument) {
+        if self.param_values.is_empty() {
+            return;
+        }
+        for node in kdl_document.nodes_mut() {
+            if kdl_name!(node) == "params" {
+                continue;
+            }
+            for entry in node.entries_mut() {
+                if let KdlValue::String(value) = entry.value() {
+                    if value.contains("${") {
+                        let interpolated = self.param_values.iter().fold(
+                            value.to_owned(),
+                            |value, (name, param_value)| {
+                                value.replace(&format!("${{{}}}", name), param_value)
+                            },
+                        );
+                        entry.set_value(interpolated);
+                    }
+                }
+            }
+            if let Some(children) = node.children_mut() {
+                self.interpolate_params(children);
+            }
+        }
+    }
+    /// Replaces the `include` nodes of the layout node with the nodes of the layout files they
+    /// point to, recursively
+    fn expand_includes(&mut self, kdl_layout: &mut KdlDocument) -> Result<(), ConfigError> {
+        let mut include_stack: Vec<PathBuf> = self
+            .file_name
+            .iter()
+            .filter_map(|f| {
+                f.canonicalize()
+                    .or(f.with_extension("kdl").canonicalize())
+                    .ok()
+            })
+            .collect();
+        for node in kdl_layout.nodes_mut() {
+            if kdl_name!(node) != "layout" {
+                continue;
+            }
+            if let Some(children) = node.children_mut() {
+                self.expand_includes_in_document(children, &mut include_stack)?;
+            }
+        }
+        Ok(())
+    }
+    fn expand_includes_in_document(
+        &mut self,
+        kdl_document: &mut KdlDocument,
+        include_stack: &mut Vec<PathBuf>,
+    ) -> Result<(), ConfigError> {
+        let mut expanded_nodes = vec![];
+        for node in kdl_document.nodes_mut().drain(..) {
+            if kdl_name!(node) == "include" {
+                expanded_nodes.append(&mut self.include_layout_file(&node, include_stack)?);
+            } else {
+                self.assert_no_nested_includes(&node)?;
+                expanded_nodes.push(node);
+            }
+        }
+        *kdl_document.nodes_mut() = expanded_nodes;
+        Ok(())
+    }
+    fn include_layout_file(
+        &mut self,
+        include_node: &KdlNode,
+        include_stack: &mut Vec<PathBuf>,
+    ) -> Result<Vec<KdlNode>, ConfigError> {
+        let include_path = match kdl_first_entry_as_string!(include_node) {
+            Some(include_path) => PathBuf::from(include_path),
+            None => {
+                return Err(ConfigError::new_layout_kdl_error(
+                    "include must be followed by the path of a layout file, eg. include \"common/agents.kdl\"".into(),
+                    include_node.span().offset(),
+                    include_node.span().len(),
+                ));
+            },
+        };
+        // included files are looked up in the layout_dir so that layouts anywhere can share the
+        // ones in it, and failing that next to the layout file including them
+        let including_file_dir = include_stack.last().and_then(|f| f.parent());
+        let candidate_paths: Vec<PathBuf> = self
+            .layout_dir
+            .as_deref()
+            .into_iter()
+            .chain(including_file_dir)
+            .map(|dir| dir.join(&include_path))
+            .collect();
+        if candidate_paths.is_empty() {
+            return Err(ConfigError::new_layout_kdl_error(
+                "Only layouts loaded from a file or with a layout_dir can include other layout files"
+                    .into(),
+                include_node.span().offset(),
+                include_node.span().len(),
+            ));
+        }
+        let included_path = candidate_paths
+            .iter()
+            .find_map(|candidate_path| candidate_path.canonicalize().ok())
+            .ok_or_else(|| {
+                let candidate_paths: Vec<String> = candidate_paths
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect();
+                ConfigError::new_layout_kdl_error(
+                    format!(
+                        "Failed to find included layout file {}",
+                        candidate_paths.join(" or ")
+                    ),
+                    include_node.span().offset(),
+                    include_node.span().len(),
+                )
+            })?;
+        if include_stack.contains(&included_path) {
+            let include_chain: Vec<String> = include_stack
+                .iter()
+                .chain(std::iter::once(&included_path))
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(ConfigError::new_layout_kdl_error(
+                format!("Circular layout include: {}", include_chain.join(" -> ")),
+                include_node.span().offset(),
+                include_node.span().len(),
+            ));
+        }
+        let raw_layout = std::fs::read_to_string(&included_path).map_err(|e| {
+            ConfigError::new_layout_kdl_error(
+                format!(
+                    "Failed to read included layout file {}: {}",
+                    included_path.display(),
+                    e
+                ),
+                include_node.span().offset(),
+                include_node.span().len(),
+            )
+        })?;
+        let file_name = included_path.display().to_string();
+        let mut included_layout: KdlDocument = raw_layout
+            .parse()
+            .map_err(|e| super::kdl_layout_error(e, file_name.clone(), &raw_layout))?;
+        let offset = self
+            .included_layouts
+            .last()
+            .map(|i| i.offset + i.raw_layout.len() + 1)
+            .unwrap_or(self.raw_layout.len() + 1);
+        for node in included_layout.nodes_mut() {
+            shift_spans(node, offset);
+        }
+        self.included_layouts.push(IncludedLayout {
+            file_name,
+            raw_layout,
+            offset,
+        });
+        // an included file can either be a full layout, or only the nodes that would go in one
+        let mut included_nodes = match included_layout
+            .nodes_mut()
+            .iter_mut()
+            .find(|n| kdl_name!(n) == "layout")
+        {
+            Some(layout_node) => layout_node.children_mut().take().unwrap_or_default(),
+            None => included_layout,
+        };
+        if let Some(params_node) = included_nodes
+            .nodes()
+            .iter()
+            .find(|n| kdl_name!(n) == "params")
+        {
+            return Err(ConfigError::new_layout_kdl_error(
+                "Layout parameters can only be declared in the main layout file".into(),
+                params_node.span().offset(),
+                params_node.span().len(),
+            ));
+        }
+        include_stack.push(included_path);
+        self.expand_includes_in_document(&mut included_nodes, include_stack)?;
+        include_stack.pop();
+        Ok(included_nodes.nodes_mut().drain(..).collect())
+    }
+    fn assert_no_nested_includes(&self, kdl_node: &KdlNode) -> Result<(), ConfigError> {
+        for child in kdl_node.children().iter().flat_map(|c| c.nodes()) {
+            if kdl_name!(child) == "include" {
+                return Err(ConfigError::new_layout_kdl_error(
+                    "include can only be used directly inside the layout node".into(),
+                    child.span().offset(),
+                    child.span().len(),
+                ));
+            }
+            self.assert_no_nested_includes(child)?;
+        }
+        Ok(())
+    }
+    /// Attaches the source code an error points to, which can be one of the included layout files
+    pub fn add_error_src(
+        &self,
+        error: ConfigError,
+        file_name: String,
+        raw_layout: &str,
+    ) -> ConfigError {
+        match error {
+            ConfigError::KdlError(kdl_error) if kdl_error.src.is_some() => {
+                ConfigError::KdlError(kdl_error)
+            },
+            ConfigError::KdlError(mut kdl_error) => {
+                let included_layout = kdl_error.offset.and_then(|error_offset| {
+                    self.included_layouts.iter().find(|i| {
+                        error_offset >= i.offset && error_offset <= i.offset + i.raw_layout.len()
+                    })
+                });
+                match included_layout {
+                    Some(included_layout) => {
+                        kdl_error.offset = kdl_error.offset.map(|o| o - included_layout.offset);
+                        ConfigError::KdlError(kdl_error.add_src(
+                            included_layout.file_name.clone(),
+                            included_layout.raw_layout.clone(),
+                        ))
+                    },
+                    None => {
+                        ConfigError::KdlError(kdl_error.add_src(file_name, raw_layout.to_owned()))
+                    },
+                }
+            },
+            ConfigError::KdlDeserializationError(kdl_error) => {
+                super::kdl_layout_error(kdl_error, file_name, raw_layout)
+            },
+            e => e,
+        }
+    }
+    fn parse_depends_on(&self, pane_node: &KdlNode) -> Result<Vec<PaneDependency>, ConfigError> {
+        let mut depends_on = vec![];
+        let Some(children) = kdl_children_nodes!(pane_node) else {
+            return Ok(depends_on);
+        };
+        for dependency in children.iter().filter(|c| kdl_name!(c) == "depends_on") {
+            let pane_name = dependency
+                .entries()
+                .first()
+                .filter(|e| e.name().is_none())
+                .and_then(|e| e.value().as_string())
+                .ok_or(kdl_parsing_error!(
+                    format!("depends_on must specify the name of a pane (eg. depends_on \"db\")"),
+                    dependency
+                ))?;
+            for entry in dependency.entries().iter().skip(1) {
+                match entry.name().map(|n| n.value()) {
+                    Some("output") | Some("port") => {},
+                    Some(unknown) => {
+                        return Err(kdl_parsing_error!(
+                            format!(
+                                "Unknown depends_on property: {}, expected one of: output, port",
+                                unknown
+                            ),
+                            dependency
+                        ));
+                    },
+                    None => {
+                        return Err(kdl_parsing_error!(
+                            format!("depends_on accepts a single pane name"),
+                            dependency
+                        ));
+                    },
+                }
+            }
+            let output = kdl_get_string_entry!(dependency, "output");
+            let port = kdl_get_int_entry!(dependency, "port");
+            let ready_when = match (output, port) {
+                (Some(_), Some(_)) => {
+                    return Err(kdl_parsing_error!(
+                        format!(
+                            "depends_on can have either an output or a port condition, not both"
+                        ),
+                        dependency
+                    ));
+                },
+                (Some(output), None) => PaneReadiness::OutputMatches(output.to_owned()),
+                (None, Some(port)) => {
+                    let port = u16::try_from(port).map_err(|_| {
+                        kdl_parsing_error!(format!("Invalid port: {}", port), dependency)
+                    })?;
+                    PaneReadiness::PortOpen(port)
+                },
+                (None, None) => {
+                    if dependency.get("output").is_some() || dependency.get("port").is_some() {
+                        return Err(kdl_parsing_error!(
+                            format!("depends_on output must be a string and port a number"),
+                            dependency
+                        ));
+                    }
+                    PaneReadiness::ExitedSuccessfully
+                },
+            };
+            depends_on.push(PaneDependency {
+                pane_name: pane_name.to_owned(),
+                ready_when,
+            });
+        }
+        Ok(depends_on)
+    }
+    fn parse_restart(
+        &self,
+        pane_node: &KdlNode,
+    ) -> Result<(Option<RestartPolicy>, Option<usize>), ConfigError> {
+        let restart = match kdl_get_string_property_or_child_value_with_error!(pane_node, "restart")
+        {
+            Some(restart) => Some(
+                RestartPolicy::from_str(restart).map_err(|e| kdl_parsing_error!(e, pane_node))?,
+            ),
+            None => None,
+        };
+        let max_restarts = match kdl_get_int_property_or_child_value!(pane_node, "max_restarts") {
+            Some(max_restarts) => Some(usize::try_from(max_restarts).map_err(|_| {
+                kdl_parsing_error!(
+                    format!(
+                        "max_restarts must be a positive number, found: {}",
+                        max_restarts
+                    ),
+                    pane_node
+                )
+            })?),
+            None => None,
+        };
+        Ok((restart, max_restarts))
+    }
+    fn parse_playback_speed(&self, pane_node: &KdlNode) -> Result<Option<f64>, ConfigError> {
+        match kdl_get_property_or_child!(pane_node, "playback_speed") {
+            Some(playback_speed) => {
+                let value = playback_speed.value();
+                match value.as_f64().or_else(|| value.as_i64().map(|s| s as f64)) {
+                    Some(speed) if speed > 0.0 => Ok(Some(speed)),
+                    _ => Err(kdl_parsing_error!(
+                        format!("playback_speed must be a positive number, found: {}", value),
+                        pane_node
+                    )),
+                }
+            },
+            None => Ok(None),
+        }
+    }
     fn cwd_prefix(&self, tab_cwd: Option<&PathBuf>) -> Result<Option<PathBuf>, ConfigError> {
         Ok(match (&self.global_cwd, tab_cwd) {
             (Some(global_cwd), Some(tab_cwd)) => Some(global_cwd.join(tab_cwd)),
@@ -431,20 +939,48 @@ impl<'a> KdlLayoutParser<'a> {
     ) -> Result<Option<Run>, ConfigError> {
         let command = self.parse_path(pane_node, "command")?;
         let edit = self.parse_path(pane_node, "edit")?;
+        let playback = self.parse_path(pane_node, "playback")?;
+        let playback_speed = self.parse_playback_speed(pane_node)?;
         let cwd = self.parse_path(pane_node, "cwd")?;
         let args = self.parse_args(pane_node)?;
+        // a playback pane is a command pane replaying the recording with our playback command
+        let (command, args) = match (playback, command, edit.is_some(), args) {
+            (Some(playback), None, false, None) => {
+                let playback_command = RunCommand::playback(playback, playback_speed);
+                (Some(playback_command.command), Some(playback_command.args))
+            },
+            (Some(_playback), ..) => {
+                return Err(ConfigError::new_layout_kdl_error(
+                    "cannot have a command, edit instruction or args for a playback pane".into(),
+                    pane_node.span().offset(),
+                    pane_node.span().len(),
+                ));
+            },
+            (None, ..) if playback_speed.is_some() => {
+                return Err(ConfigError::new_layout_kdl_error(
+                    "playback_speed can only be set for a playback pane".into(),
+                    pane_node.span().offset(),
+                    pane_node.span().len(),
+                ));
+            },
+            (None, command, _, args) => (command, args),
+        };
+        let env = self.parse_env(pane_node)?;
         let close_on_exit =
             kdl_get_bool_property_or_child_value_with_error!(pane_node, "close_on_exit");
         let start_suspended =
             kdl_get_bool_property_or_child_value_with_error!(pane_node, "start_suspended");
+        let (restart, max_restarts) = self.parse_restart(pane_node)?;
         if !is_template {
             self.assert_no_bare_attributes_in_pane_node(
                 &command,
                 &args,
+                &env,
                 &close_on_exit,
                 &start_suspended,
                 pane_node,
             )?;
+            self.assert_restart_has_command(command.is_some(), &restart, &max_restarts, pane_node)?;
         }
         let hold_on_close = close_on_exit.map(|c| !c).unwrap_or(true);
         let hold_on_start = start_suspended.map(|c| c).unwrap_or(false);
@@ -456,6 +992,9 @@ impl<'a> KdlLayoutParser<'a> {
                 cwd,
                 hold_on_close,
                 hold_on_start,
+                env: env.unwrap_or_default(),
+                restart: restart.unwrap_or_default(),
+                max_restarts,
                 ..Default::default()
             }))),
             (None, Some(edit), Some(cwd)) => {
@@ -534,8 +1073,14 @@ impl<'a> KdlLayoutParser<'a> {
             kdl_get_bool_property_or_child_value_with_error!(kdl_node, "exclude_from_sync");
         let contents_file =
             kdl_get_string_property_or_child_value_with_error!(kdl_node, "contents_file");
+        let log_to = kdl_get_string_property_or_child_value_with_error!(kdl_node, "log_to")
+            .map(PathBuf::from);
+        let mark = kdl_get_string_property_or_child_value_with_error!(kdl_node, "mark")
+            .map(|mark| mark.to_string());
         let split_size = self.parse_split_size(kdl_node)?;
         let run = self.parse_command_plugin_or_edit_block(kdl_node)?;
+        let depends_on = self.parse_depends_on(kdl_node)?;
+        self.assert_depends_on_has_command(&depends_on, &run, kdl_node)?;
         let children_split_direction = self.parse_split_direction(kdl_node)?;
         let (external_children_index, children) = match kdl_children_nodes!(kdl_node) {
             Some(children) => {
@@ -585,6 +1130,9 @@ impl<'a> KdlLayoutParser<'a> {
             children_are_stacked,
             is_expanded_in_stack,
             pane_initial_contents,
+            log_to,
+            depends_on,
+            mark,
             ..Default::default()
         })
     }
@@ -600,11 +1148,17 @@ impl<'a> KdlLayoutParser<'a> {
         let pinned = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "pinned");
         let borderless = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "borderless");
         let run = self.parse_command_plugin_or_edit_block(kdl_node)?;
+        let depends_on = self.parse_depends_on(kdl_node)?;
+        self.assert_depends_on_has_command(&depends_on, &run, kdl_node)?;
         let focus = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "focus");
         let name = kdl_get_string_property_or_child_value_with_error!(kdl_node, "name")
             .map(|name| name.to_string());
         let contents_file =
             kdl_get_string_property_or_child_value_with_error!(kdl_node, "contents_file");
+        let log_to = kdl_get_string_property_or_child_value_with_error!(kdl_node, "log_to")
+            .map(PathBuf::from);
+        let mark = kdl_get_string_property_or_child_value_with_error!(kdl_node, "mark")
+            .map(|mark| mark.to_string());
         self.assert_no_mixed_children_and_properties(kdl_node)?;
         let pane_initial_contents = contents_file.and_then(|contents_file| {
             self.file_name
@@ -625,6 +1179,9 @@ impl<'a> KdlLayoutParser<'a> {
             pinned,
             borderless,
             pane_initial_contents,
+            log_to,
+            depends_on,
+            mark,
             ..Default::default()
         })
     }
@@ -713,14 +1270,21 @@ impl<'a> KdlLayoutParser<'a> {
                 let is_expanded_in_stack =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "expanded");
                 let args = self.parse_args(kdl_node)?;
+                let env = self.parse_env(kdl_node)?;
                 let close_on_exit =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "close_on_exit");
                 let start_suspended =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "start_suspended");
+                let (restart, max_restarts) = self.parse_restart(kdl_node)?;
                 let split_size = self.parse_split_size(kdl_node)?;
                 let run = self.parse_command_plugin_or_edit_block_for_template(kdl_node)?;
+                let depends_on = self.parse_depends_on(kdl_node)?;
                 let exclude_from_sync =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "exclude_from_sync");
+                let log_to = kdl_get_string_property_or_child_value_with_error!(kdl_node, "log_to")
+                    .map(PathBuf::from);
+                let mark = kdl_get_string_property_or_child_value_with_error!(kdl_node, "mark")
+                    .map(|mark| mark.to_string());
 
                 let external_children_index = if should_mark_external_children_index {
                     self.populate_external_children_index(kdl_node)?
@@ -731,10 +1295,18 @@ impl<'a> KdlLayoutParser<'a> {
                     &run,
                     &pane_template.run,
                     &args,
+                    &env,
                     &close_on_exit,
                     &start_suspended,
                     kdl_node,
                 )?;
+                self.assert_restart_has_command(
+                    matches!(run, Some(Run::Command(_)))
+                        || matches!(pane_template.run, Some(Run::Command(_))),
+                    &restart,
+                    &max_restarts,
+                    kdl_node,
+                )?;
                 self.insert_children_to_pane_template(
                     kdl_node,
                     &mut pane_template,
@@ -745,9 +1317,19 @@ impl<'a> KdlLayoutParser<'a> {
                     // we need to do this because panes consuming a pane_template
                     // can have bare args without a command
                     pane_template_run_command.add_args(args);
+                    pane_template_run_command.add_env(env);
                     pane_template_run_command.add_close_on_exit(close_on_exit);
                     pane_template_run_command.add_start_suspended(start_suspended);
+                    pane_template_run_command.add_restart(restart, max_restarts);
                 };
+                if !depends_on.is_empty() {
+                    pane_template.depends_on = depends_on;
+                }
+                self.assert_depends_on_has_command(
+                    &pane_template.depends_on,
+                    &pane_template.run,
+                    kdl_node,
+                )?;
                 if let Some(borderless) = borderless {
                     pane_template.borderless = Some(borderless);
                 }
@@ -760,6 +1342,12 @@ impl<'a> KdlLayoutParser<'a> {
                 if let Some(exclude_from_sync) = exclude_from_sync {
                     pane_template.exclude_from_sync = Some(exclude_from_sync);
                 }
+                if let Some(log_to) = log_to {
+                    pane_template.log_to = Some(log_to);
+                }
+                if let Some(mark) = mark {
+                    pane_template.mark = Some(mark);
+                }
                 if let Some(split_size) = split_size {
                     pane_template.split_size = Some(split_size);
                 }
@@ -819,27 +1407,48 @@ impl<'a> KdlLayoutParser<'a> {
                 let name = kdl_get_string_property_or_child_value_with_error!(kdl_node, "name")
                     .map(|name| name.to_string());
                 let args = self.parse_args(kdl_node)?;
+                let env = self.parse_env(kdl_node)?;
                 let close_on_exit =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "close_on_exit");
                 let start_suspended =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "start_suspended");
+                let (restart, max_restarts) = self.parse_restart(kdl_node)?;
                 let run = self.parse_command_plugin_or_edit_block_for_template(kdl_node)?;
+                let depends_on = self.parse_depends_on(kdl_node)?;
                 self.assert_no_bare_attributes_in_pane_node_with_template(
                     &run,
                     &pane_template.run,
                     &args,
+                    &env,
                     &close_on_exit,
                     &start_suspended,
                     kdl_node,
                 )?;
+                self.assert_restart_has_command(
+                    matches!(run, Some(Run::Command(_)))
+                        || matches!(pane_template.run, Some(Run::Command(_))),
+                    &restart,
+                    &max_restarts,
+                    kdl_node,
+                )?;
                 pane_template.run = Run::merge(&pane_template.run, &run);
                 if let Some(pane_template_run_command) = pane_template.run.as_mut() {
                     // we need to do this because panes consuming a pane_template
                     // can have bare args without a command
                     pane_template_run_command.add_args(args);
+                    pane_template_run_command.add_env(env);
                     pane_template_run_command.add_close_on_exit(close_on_exit);
                     pane_template_run_command.add_start_suspended(start_suspended);
+                    pane_template_run_command.add_restart(restart, max_restarts);
                 };
+                if !depends_on.is_empty() {
+                    pane_template.depends_on = depends_on;
+                }
+                self.assert_depends_on_has_command(
+                    &pane_template.depends_on,
+                    &pane_template.run,
+                    kdl_node,
+                )?;
                 if let Some(focus) = focus {
                     pane_template.focus = Some(focus);
                 }
@@ -851,6 +1460,10 @@ impl<'a> KdlLayoutParser<'a> {
                 let x = self.parse_percent_or_fixed(kdl_node, "x", true)?;
                 let y = self.parse_percent_or_fixed(kdl_node, "y", true)?;
                 let pinned = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "pinned");
+                let log_to = kdl_get_string_property_or_child_value_with_error!(kdl_node, "log_to")
+                    .map(PathBuf::from);
+                let mark = kdl_get_string_property_or_child_value_with_error!(kdl_node, "mark")
+                    .map(|mark| mark.to_string());
                 if let Some(height) = height {
                     pane_template.height = Some(height);
                 }
@@ -866,6 +1479,12 @@ impl<'a> KdlLayoutParser<'a> {
                 if let Some(pinned) = pinned {
                     pane_template.pinned = Some(pinned);
                 }
+                if let Some(log_to) = log_to {
+                    pane_template.log_to = Some(log_to);
+                }
+                if let Some(mark) = mark {
+                    pane_template.mark = Some(mark);
+                }
                 Ok(pane_template)
             },
             PaneOrFloatingPane::Either(mut pane_template) => {
@@ -873,27 +1492,48 @@ impl<'a> KdlLayoutParser<'a> {
                 let name = kdl_get_string_property_or_child_value_with_error!(kdl_node, "name")
                     .map(|name| name.to_string());
                 let args = self.parse_args(kdl_node)?;
+                let env = self.parse_env(kdl_node)?;
                 let close_on_exit =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "close_on_exit");
                 let start_suspended =
                     kdl_get_bool_property_or_child_value_with_error!(kdl_node, "start_suspended");
+                let (restart, max_restarts) = self.parse_restart(kdl_node)?;
                 let run = self.parse_command_plugin_or_edit_block_for_template(kdl_node)?;
+                let depends_on = self.parse_depends_on(kdl_node)?;
                 self.assert_no_bare_attributes_in_pane_node_with_template(
                     &run,
                     &pane_template.run,
                     &args,
+                    &env,
                     &close_on_exit,
                     &start_suspended,
                     kdl_node,
                 )?;
+                self.assert_restart_has_command(
+                    matches!(run, Some(Run::Command(_)))
+                        || matches!(pane_template.run, Some(Run::Command(_))),
+                    &restart,
+                    &max_restarts,
+                    kdl_node,
+                )?;
                 pane_template.run = Run::merge(&pane_template.run, &run);
                 if let Some(pane_template_run_command) = pane_template.run.as_mut() {
                     // we need to do this because panes consuming a pane_template
                     // can have bare args without a command
                     pane_template_run_command.add_args(args);
+                    pane_template_run_command.add_env(env);
                     pane_template_run_command.add_close_on_exit(close_on_exit);
                     pane_template_run_command.add_start_suspended(start_suspended);
+                    pane_template_run_command.add_restart(restart, max_restarts);
                 };
+                if !depends_on.is_empty() {
+                    pane_template.depends_on = depends_on;
+                }
+                self.assert_depends_on_has_command(
+                    &pane_template.depends_on,
+                    &pane_template.run,
+                    kdl_node,
+                )?;
                 if let Some(focus) = focus {
                     pane_template.focus = Some(focus);
                 }
@@ -905,6 +1545,10 @@ impl<'a> KdlLayoutParser<'a> {
                 let x = self.parse_percent_or_fixed(kdl_node, "x", true)?;
                 let y = self.parse_percent_or_fixed(kdl_node, "y", true)?;
                 let pinned = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "pinned");
+                let log_to = kdl_get_string_property_or_child_value_with_error!(kdl_node, "log_to")
+                    .map(PathBuf::from);
+                let mark = kdl_get_string_property_or_child_value_with_error!(kdl_node, "mark")
+                    .map(|mark| mark.to_string());
                 let mut floating_pane = FloatingPaneLayout::from(&pane_template);
                 if let Some(height) = height {
                     floating_pane.height = Some(height);
@@ -921,6 +1565,12 @@ impl<'a> KdlLayoutParser<'a> {
                 if let Some(pinned) = pinned {
                     floating_pane.pinned = Some(pinned);
                 }
+                if let Some(log_to) = log_to {
+                    floating_pane.log_to = Some(log_to);
+                }
+                if let Some(mark) = mark {
+                    floating_pane.mark = Some(mark);
+                }
                 Ok(floating_pane)
             },
         }
@@ -940,6 +1590,15 @@ impl<'a> KdlLayoutParser<'a> {
             None => Ok(SplitDirection::default()),
         }
     }
+    fn parse_tiling_mode(&self, kdl_node: &KdlNode) -> Result<Option<TilingMode>, ConfigError> {
+        match kdl_get_string_property_or_child_value_with_error!(kdl_node, "tiling_mode") {
+            Some(tiling_mode) => match TilingMode::from_str(tiling_mode) {
+                Ok(tiling_mode) => Ok(Some(tiling_mode)),
+                Err(e) => Err(kdl_parsing_error!(e, kdl_node)),
+            },
+            None => Ok(None),
+        }
+    }
     fn has_only_neutral_pane_template_properties(
         &self,
         kdl_node: &KdlNode,
@@ -1072,6 +1731,7 @@ impl<'a> KdlLayoutParser<'a> {
         self.assert_legal_template_name(&template_name, kdl_node)?;
         let focus = kdl_get_bool_property_or_child_value_with_error!(kdl_node, "focus");
         let run = self.parse_command_plugin_or_edit_block(kdl_node)?;
+        let depends_on = self.parse_depends_on(kdl_node)?;
 
         let is_floating = self.differentiate_pane_and_floating_pane_template(&kdl_node)?;
         let can_be_either_floating_or_tiled =
@@ -1084,6 +1744,7 @@ impl<'a> KdlLayoutParser<'a> {
                     PaneOrFloatingPane::Either(TiledPaneLayout {
                         focus,
                         run,
+                        depends_on,
                         ..Default::default()
                     }),
                     kdl_node.clone(),
@@ -1103,6 +1764,7 @@ impl<'a> KdlLayoutParser<'a> {
                     PaneOrFloatingPane::FloatingPane(FloatingPaneLayout {
                         focus,
                         run,
+                        depends_on,
                         height,
                         width,
                         x,
@@ -1141,6 +1803,7 @@ impl<'a> KdlLayoutParser<'a> {
                         focus,
                         split_size,
                         run,
+                        depends_on,
                         children_split_direction,
                         external_children_index,
                         children: pane_parts,
@@ -1175,6 +1838,7 @@ impl<'a> KdlLayoutParser<'a> {
         let is_focused = kdl_get_bool_property_or_child_value!(kdl_node, "focus").unwrap_or(false);
         let hide_floating_panes =
             kdl_get_bool_property_or_child_value!(kdl_node, "hide_floating_panes").unwrap_or(false);
+        let tiling_mode = self.parse_tiling_mode(kdl_node)?;
         let children_split_direction = self.parse_split_direction(kdl_node)?;
         let mut child_floating_panes = vec![];
         let children = match kdl_children_nodes!(kdl_node) {
@@ -1192,6 +1856,7 @@ impl<'a> KdlLayoutParser<'a> {
             children_split_direction,
             children,
             hide_floating_panes,
+            tiling_mode,
             ..Default::default()
         };
         if let Some(cwd_prefix) = &self.cwd_prefix(tab_cwd.as_ref())? {
@@ -1327,6 +1992,7 @@ impl<'a> KdlLayoutParser<'a> {
         pane_run: &Option<Run>,
         pane_template_run: &Option<Run>,
         args: &Option<Vec<String>>,
+        env: &Option<BTreeMap<String, String>>,
         close_on_exit: &Option<bool>,
         start_suspended: &Option<bool>,
         pane_node: &KdlNode,
@@ -1337,6 +2003,12 @@ impl<'a> KdlLayoutParser<'a> {
                 pane_node
             ));
         }
+        if let (None, None, true) = (pane_run, pane_template_run, env.is_some()) {
+            return Err(kdl_parsing_error!(
+                format!("env can only be specified if a command was specified either in the pane_template or in the pane"),
+                pane_node
+            ));
+        }
         if let (None, None, true) = (pane_run, pane_template_run, close_on_exit.is_some()) {
             return Err(kdl_parsing_error!(
                 format!("close_on_exit can only be specified if a command was specified either in the pane_template or in the pane"),
@@ -1355,6 +2027,7 @@ impl<'a> KdlLayoutParser<'a> {
         &self,
         command: &Option<PathBuf>,
         args: &Option<Vec<String>>,
+        env: &Option<BTreeMap<String, String>>,
         close_on_exit: &Option<bool>,
         start_suspended: &Option<bool>,
         pane_node: &KdlNode,
@@ -1381,6 +2054,13 @@ impl<'a> KdlLayoutParser<'a> {
                     pane_node.span().len(),
                 ));
             }
+            if env.is_some() {
+                return Err(ConfigError::new_layout_kdl_error(
+                    "env can only be set if a command was specified".into(),
+                    pane_node.span().offset(),
+                    pane_node.span().len(),
+                ));
+            }
         }
         Ok(())
     }
@@ -1497,6 +2177,38 @@ impl<'a> KdlLayoutParser<'a> {
         }
         Ok(())
     }
+    fn assert_restart_has_command(
+        &self,
+        has_command: bool,
+        restart: &Option<RestartPolicy>,
+        max_restarts: &Option<usize>,
+        pane_node: &KdlNode,
+    ) -> Result<(), ConfigError> {
+        if !has_command && (restart.is_some() || max_restarts.is_some()) {
+            return Err(ConfigError::new_layout_kdl_error(
+                "restart and max_restarts can only be set if a command was specified".into(),
+                pane_node.span().offset(),
+                pane_node.span().len(),
+            ));
+        }
+        Ok(())
+    }
+    fn assert_depends_on_has_command(
+        &self,
+        depends_on: &[PaneDependency],
+        run: &Option<Run>,
+        kdl_node: &KdlNode,
+    ) -> Result<(), ConfigError> {
+        match run {
+            Some(Run::Command(_)) => Ok(()),
+            _ if depends_on.is_empty() => Ok(()),
+            _ => Err(ConfigError::new_layout_kdl_error(
+                "depends_on can only be set if a command was specified".into(),
+                kdl_node.span().offset(),
+                kdl_node.span().len(),
+            )),
+        }
+    }
     fn assert_no_mixed_children_and_properties(
         &self,
         kdl_node: &KdlNode,
@@ -1575,6 +2287,9 @@ impl<'a> KdlLayoutParser<'a> {
             kdl_get_string_property_or_child_value!(kdl_node, "name").map(|s| s.to_string());
         let tab_cwd = self.parse_path(kdl_node, "cwd")?;
         let is_focused = kdl_get_bool_property_or_child_value!(kdl_node, "focus").unwrap_or(false);
+        if let Some(tiling_mode) = self.parse_tiling_mode(kdl_node)? {
+            tab_layout.tiling_mode = Some(tiling_mode);
+        }
         let children_split_direction = self.parse_split_direction(kdl_node)?;
         match kdl_children_nodes!(kdl_node) {
             Some(children) => {
@@ -1718,11 +2433,13 @@ impl<'a> KdlLayoutParser<'a> {
                 }
             }
         }
+        let tiling_mode = self.parse_tiling_mode(kdl_node)?;
         Ok((
             TiledPaneLayout {
                 children_split_direction,
                 children: tab_children,
                 external_children_index,
+                tiling_mode,
                 ..Default::default()
             },
             tab_floating_children,
@@ -2381,7 +3098,8 @@ impl<'a> KdlLayoutParser<'a> {
         raw_swap_layouts: &str,
         mut existing_layout: Layout,
     ) -> Result<Layout, ConfigError> {
-        let kdl_swap_layout: KdlDocument = raw_swap_layouts.parse()?;
+        let mut kdl_swap_layout: KdlDocument = raw_swap_layouts.parse()?;
+        self.interpolate_params(&mut kdl_swap_layout);
         let mut swap_tiled_layouts = vec![];
         let mut swap_floating_layouts = vec![];
 
@@ -2425,7 +3143,13 @@ impl<'a> KdlLayoutParser<'a> {
         Ok(existing_layout)
     }
     pub fn parse(&mut self) -> Result<Layout, ConfigError> {
-        let kdl_layout: KdlDocument = self.raw_layout.parse()?;
+        let mut kdl_layout: KdlDocument = self.raw_layout.parse()?;
+        self.expand_includes(&mut kdl_layout)?;
+        if let Some(layout_node) = kdl_layout.nodes().iter().find(|n| kdl_name!(n) == "layout") {
+            self.resolve_params(layout_node)?;
+        }
+        self.interpolate_params(&mut kdl_layout);
+        let kdl_layout = kdl_layout;
         let layout_node = kdl_layout
             .nodes()
             .iter()
@@ -2569,3 +3293,23 @@ impl<'a> KdlLayoutParser<'a> {
         }
     }
 }
+
+fn shift_spans(kdl_node: &mut KdlNode, offset: usize) {
+    let span = *kdl_node.span();
+    kdl_node.set_span((span.offset() + offset, span.len()));
+    let name_span = *kdl_node.name().span();
+    kdl_node
+        .name_mut()
+        .set_span((name_span.offset() + offset, name_span.len()));
+    for entry in kdl_node.entries_mut() {
+        let entry_span = *entry.span();
+        entry.set_span((entry_span.offset() + offset, entry_span.len()));
+    }
+    if let Some(children) = kdl_node.children_mut() {
+        let children_span = *children.span();
+        children.set_span((children_span.offset() + offset, children_span.len()));
+        for child in children.nodes_mut() {
+            shift_spans(child, offset);
+        }
+    }
+}
@@ -1,9 +1,9 @@
 mod kdl_layout_parser;
 use crate::data::{
-    BareKey, Direction, FloatingPaneCoordinates, InputMode, KeyWithModifier, LayoutInfo,
-    LayoutMetadata, MultiplayerColors, Palette, PaletteColor, PaneId, PaneInfo, PaneManifest,
-    PermissionType, Resize, SessionInfo, StyleDeclaration, Styling, TabInfo, WebSharing,
-    DEFAULT_STYLES,
+    BareKey, Direction, DumpScreenFormat, FloatingPaneCoordinates, InputMode, KeyWithModifier,
+    LayoutInfo, LayoutMetadata, LayoutParam, MultiplayerColors, Palette, PaletteColor, PaneId,
+    PaneInfo, PaneManifest, PermissionType, Resize, SessionInfo, StyleDeclaration, Styling,
+    TabInfo, TilingMode, WebSharing, DEFAULT_STYLES,
 };
 use crate::envs::EnvironmentVariables;
 use crate::home::{find_default_config_dir, get_layout_dir};
@@ -481,6 +481,16 @@ impl Action {
                 let resize = resize.unwrap_or(Resize::Increase);
                 Ok(Action::Resize { resize, direction })
             },
+            "SetTilingMode" => {
+                let tiling_mode = TilingMode::from_str(string.as_str()).map_err(|_| {
+                    ConfigError::new_kdl_error(
+                        format!("Invalid tiling mode: '{}'", string),
+                        action_node.span().offset(),
+                        action_node.span().len(),
+                    )
+                })?;
+                Ok(Action::SetTilingMode { tiling_mode })
+            },
             "MoveFocus" => {
                 let direction = Direction::from_str(string.as_str()).map_err(|_| {
                     ConfigError::new_kdl_error(
@@ -539,6 +549,7 @@ impl Action {
             "DumpScreen" => Ok(Action::DumpScreen {
                 file_path: string,
                 include_scrollback: false,
+                format: DumpScreenFormat::Text,
             }),
             "DumpLayout" => Ok(Action::DumpLayout),
             "NewPane" => {
@@ -595,6 +606,8 @@ impl Action {
                 })
             },
             "RenameSession" => Ok(Action::RenameSession { name: string }),
+            "MarkPane" => Ok(Action::MarkPane { name: string }),
+            "FocusMark" => Ok(Action::FocusMark { name: string }),
             _ => Err(ConfigError::new_kdl_error(
                 format!("Unsupported action: {}", action_name),
                 action_node.span().offset(),
@@ -690,6 +703,7 @@ impl Action {
             Action::DumpScreen {
                 file_path: file,
                 include_scrollback: _,
+                format: _,
             } => {
                 let mut node = KdlNode::new("DumpScreen");
                 node.push(file.clone());
@@ -736,6 +750,16 @@ impl Action {
                 Some(node)
             },
             Action::UndoRenamePane => Some(KdlNode::new("UndoRenamePane")),
+            Action::MarkPane { name } => {
+                let mut node = KdlNode::new("MarkPane");
+                node.push(name.clone());
+                Some(node)
+            },
+            Action::FocusMark { name } => {
+                let mut node = KdlNode::new("FocusMark");
+                node.push(name.clone());
+                Some(node)
+            },
             Action::NewTab {
                 tiled_layout: _,
                 floating_layouts: _,
@@ -1181,6 +1205,11 @@ impl Action {
             Action::ToggleMouseMode => Some(KdlNode::new("ToggleMouseMode")),
             Action::PreviousSwapLayout => Some(KdlNode::new("PreviousSwapLayout")),
             Action::NextSwapLayout => Some(KdlNode::new("NextSwapLayout")),
+            Action::SetTilingMode { tiling_mode } => {
+                let mut node = KdlNode::new("SetTilingMode");
+                node.push(tiling_mode.to_string());
+                Some(node)
+            },
             Action::BreakPane => Some(KdlNode::new("BreakPane")),
             Action::BreakPaneRight => Some(KdlNode::new("BreakPaneRight")),
             Action::BreakPaneLeft => Some(KdlNode::new("BreakPaneLeft")),
@@ -1580,6 +1609,11 @@ impl TryFrom<(&KdlNode, &Options)> for Action {
                 action_arguments,
                 kdl_action
             ),
+            "SetTilingMode" => parse_kdl_action_char_or_string_arguments!(
+                action_name,
+                action_arguments,
+                kdl_action
+            ),
             "MoveFocus" => parse_kdl_action_char_or_string_arguments!(
                 action_name,
                 action_arguments,
@@ -2055,6 +2089,16 @@ impl TryFrom<(&KdlNode, &Options)> for Action {
                 action_arguments,
                 kdl_action
             ),
+            "MarkPane" => parse_kdl_action_char_or_string_arguments!(
+                action_name,
+                action_arguments,
+                kdl_action
+            ),
+            "FocusMark" => parse_kdl_action_char_or_string_arguments!(
+                action_name,
+                action_arguments,
+                kdl_action
+            ),
             "MessagePlugin" => {
                 let arguments = action_arguments.iter().copied();
                 let mut args = kdl_arguments_that_are_strings(arguments)?;
@@ -2700,6 +2744,9 @@ impl Options {
         let post_command_discovery_hook =
             kdl_property_first_arg_as_string_or_error!(kdl_options, "post_command_discovery_hook")
                 .map(|(hook, _entry)| hook.to_string());
+        let plugin_execution_budget =
+            kdl_property_first_arg_as_i64_or_error!(kdl_options, "plugin_execution_budget")
+                .map(|(plugin_execution_budget, _entry)| plugin_execution_budget as u64);
 
         Ok(Options {
             simplified_ui,
@@ -2742,6 +2789,7 @@ impl Options {
             web_server_key,
             enforce_https_for_localhost,
             post_command_discovery_hook,
+            plugin_execution_budget,
         })
     }
     pub fn from_string(stringified_keybindings: &String) -> Result<Self, ConfigError> {
@@ -3895,6 +3943,34 @@ impl Options {
             None
         }
     }
+    fn plugin_execution_budget_to_kdl(&self, add_comments: bool) -> Option<KdlNode> {
+        let comment_text = format!(
+            "{}\n{}\n{}\n{}",
+            " ",
+            "// The amount of fuel (roughly one unit per wasm instruction) a plugin may consume in a",
+            "// single call into it before it is suspended",
+            "// Default: 1000000000",
+        );
+
+        let create_node = |node_value: u64| -> KdlNode {
+            let mut node = KdlNode::new("plugin_execution_budget");
+            node.push(KdlValue::Base10(node_value as i64));
+            node
+        };
+        if let Some(plugin_execution_budget) = self.plugin_execution_budget {
+            let mut node = create_node(plugin_execution_budget);
+            if add_comments {
+                node.set_leading(format!("{}\n", comment_text));
+            }
+            Some(node)
+        } else if add_comments {
+            let mut node = create_node(1_000_000_000);
+            node.set_leading(format!("{}\n// ", comment_text));
+            Some(node)
+        } else {
+            None
+        }
+    }
     fn post_command_discovery_hook_to_kdl(&self, add_comments: bool) -> Option<KdlNode> {
         let comment_text = format!(
             "{}\n{}\n{}\n{}\n{}\n{}",
@@ -4055,29 +4131,58 @@ impl Options {
         {
             nodes.push(post_command_discovery_hook);
         }
+        if let Some(plugin_execution_budget) = self.plugin_execution_budget_to_kdl(add_comments) {
+            nodes.push(plugin_execution_budget);
+        }
         nodes
     }
 }
 
 impl Layout {
+    /// The parameters declared in the `params` block of a layout
+    pub fn params_from_kdl(raw_layout: &str) -> Result<Vec<LayoutParam>, ConfigError> {
+        let kdl_layout: KdlDocument = raw_layout.parse()?;
+        match kdl_layout
+            .nodes()
+            .iter()
+            .find(|n| kdl_name!(n) == "layout")
+            .and_then(|layout_node| kdl_get_child!(layout_node, "params"))
+        {
+            Some(params_node) => KdlLayoutParser::parse_params(params_node),
+            None => Ok(vec![]),
+        }
+    }
     pub fn from_kdl(
         raw_layout: &str,
         file_name: Option<String>,
         raw_swap_layouts: Option<(&str, &str)>, // raw_swap_layouts swap_layouts_file_name
         cwd: Option<PathBuf>,
     ) -> Result<Self, ConfigError> {
-        let mut kdl_layout_parser = KdlLayoutParser::new(raw_layout, cwd, file_name.clone());
-        let layout = kdl_layout_parser.parse().map_err(|e| match e {
-            ConfigError::KdlError(kdl_error) => ConfigError::KdlError(kdl_error.add_src(
-                file_name.unwrap_or_else(|| "N/A".to_owned()),
-                String::from(raw_layout),
-            )),
-            ConfigError::KdlDeserializationError(kdl_error) => kdl_layout_error(
-                kdl_error,
+        Layout::from_kdl_with_params(
+            raw_layout,
+            file_name,
+            raw_swap_layouts,
+            cwd,
+            BTreeMap::new(),
+            None,
+        )
+    }
+    pub fn from_kdl_with_params(
+        raw_layout: &str,
+        file_name: Option<String>,
+        raw_swap_layouts: Option<(&str, &str)>, // raw_swap_layouts swap_layouts_file_name
+        cwd: Option<PathBuf>,
+        params: BTreeMap<String, String>, // values for the parameters declared in the layout
+        layout_dir: Option<PathBuf>,      // where included layout files are looked up first
+    ) -> Result<Self, ConfigError> {
+        let mut kdl_layout_parser =
+            KdlLayoutParser::new(raw_layout, cwd, file_name.clone(), params, layout_dir);
+        let layout = kdl_layout_parser.parse().map_err(|e| {
+            kdl_layout_parser.add_error_src(
+                e,
                 file_name.unwrap_or_else(|| "N/A".to_owned()),
                 raw_layout,
-            ),
-            e => e,
+            )
         })?;
         match raw_swap_layouts {
             Some((raw_swap_layout_filename, raw_swap_layout)) => {
@@ -1,3 +1,4 @@
+pub mod asciicast;
 pub mod cli;
 pub mod client_server_contract;
 pub mod consts;
@@ -10,6 +11,7 @@ pub mod kdl;
 pub mod notification;
 pub mod pane_size;
 pub mod plugin_api;
+pub mod plugin_metadata;
 pub mod position;
 pub mod session_serialization;
 pub mod setup;
@@ -10,6 +10,7 @@ pub use super::generated_api::api::{
         BareKey as ProtobufBareKey,
         // New layout-related types
         CommandOrPlugin as ProtobufCommandOrPlugin,
+        DumpScreenFormat as ProtobufDumpScreenFormat,
         DumpScreenPayload,
         EditFilePayload,
         FloatingPaneCoordinates as ProtobufFloatingPaneCoordinates,
@@ -38,6 +39,7 @@ pub use super::generated_api::api::{
         NewTabPayload,
         NewTiledPanePayload,
         OverrideLayoutPayload,
+        PaneDependency as ProtobufPaneDependency,
         PaneId as ProtobufPaneId,
         PaneIdAndShouldFloat,
         PaneRun as ProtobufPaneRun,
@@ -73,8 +75,9 @@ pub use super::generated_api::api::{
     resize::{Resize as ProtobufResize, ResizeDirection as ProtobufResizeDirection},
 };
 use crate::data::{
-    CommandOrPlugin, Direction, FloatingPaneCoordinates, InputMode, KeyWithModifier,
-    NewPanePlacement, PaneId, PluginTag, ResizeStrategy, UnblockCondition,
+    CommandOrPlugin, Direction, DumpScreenFormat, FloatingPaneCoordinates, InputMode,
+    KeyWithModifier, NewPanePlacement, PaneId, PluginTag, ResizeStrategy, TilingMode,
+    UnblockCondition,
 };
 use crate::errors::prelude::*;
 use crate::input::actions::Action;
@@ -82,9 +85,9 @@ use crate::input::actions::{SearchDirection, SearchOption};
 use crate::input::command::{OpenFilePayload, RunCommandAction};
 use crate::input::layout::SplitSize;
 use crate::input::layout::{
-    FloatingPaneLayout, LayoutConstraint, PercentOrFixed, PluginAlias, PluginUserConfiguration,
-    Run, RunPlugin, RunPluginLocation, RunPluginOrAlias, SplitDirection, SwapFloatingLayout,
-    SwapTiledLayout, TabLayoutInfo, TiledPaneLayout,
+    FloatingPaneLayout, LayoutConstraint, PaneDependency, PaneReadiness, PercentOrFixed,
+    PluginAlias, PluginUserConfiguration, Run, RunPlugin, RunPluginLocation, RunPluginOrAlias,
+    SplitDirection, SwapFloatingLayout, SwapTiledLayout, TabLayoutInfo, TiledPaneLayout,
 };
 use crate::input::mouse::{MouseEvent, MouseEventType};
 use crate::position::Position;
@@ -92,6 +95,7 @@ use crate::position::Position;
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 impl TryFrom<ProtobufAction> for Action {
     type Error = &'static str;
@@ -210,9 +214,15 @@ impl TryFrom<ProtobufAction> for Action {
                 Some(OptionalPayload::DumpScreenPayload(payload)) => {
                     let file_path = payload.file_path;
                     let include_scrollback = payload.include_scrollback;
+                    let format = payload
+                        .format
+                        .and_then(ProtobufDumpScreenFormat::from_i32)
+                        .map(|f| f.into())
+                        .unwrap_or_default();
                     Ok(Action::DumpScreen {
                         file_path,
                         include_scrollback,
+                        format,
                     })
                 },
                 _ => Err("Wrong payload for Action::DumpScreen"),
@@ -1114,11 +1124,13 @@ impl TryFrom<Action> for ProtobufAction {
             Action::DumpScreen {
                 file_path,
                 include_scrollback,
+                format,
             } => Ok(ProtobufAction {
                 name: ProtobufActionName::DumpScreen as i32,
                 optional_payload: Some(OptionalPayload::DumpScreenPayload(DumpScreenPayload {
                     file_path,
                     include_scrollback,
+                    format: Some(ProtobufDumpScreenFormat::from(format) as i32),
                 })),
             }),
             Action::EditScrollback => Ok(ProtobufAction {
@@ -1777,6 +1789,15 @@ impl TryFrom<Action> for ProtobufAction {
             | Action::TogglePaneBorderless { pane_id: _ }
             | Action::SetPaneBorderless { .. }
             | Action::Notify { .. }
+            | Action::SearchPane { .. }
+            | Action::SearchSession { .. }
+            | Action::JumpToSearchMatch { .. }
+            | Action::LogPane { .. }
+            | Action::RecordPane { .. }
+            | Action::SetTilingMode { .. }
+            | Action::MarkPane { .. }
+            | Action::FocusMark { .. }
+            | Action::Follow { .. }
             | Action::SkipConfirm { action: _ }
             | Action::SwitchSession { .. } => Err("Unsupported action"),
         }
@@ -1790,6 +1811,7 @@ impl TryFrom<ProtobufSearchOption> for SearchOption {
             ProtobufSearchOption::CaseSensitivity => Ok(SearchOption::CaseSensitivity),
             ProtobufSearchOption::WholeWord => Ok(SearchOption::WholeWord),
             ProtobufSearchOption::Wrap => Ok(SearchOption::Wrap),
+            ProtobufSearchOption::Regex => Ok(SearchOption::Regex),
         }
     }
 }
@@ -1801,6 +1823,7 @@ impl TryFrom<SearchOption> for ProtobufSearchOption {
             SearchOption::CaseSensitivity => Ok(ProtobufSearchOption::CaseSensitivity),
             SearchOption::WholeWord => Ok(ProtobufSearchOption::WholeWord),
             SearchOption::Wrap => Ok(ProtobufSearchOption::Wrap),
+            SearchOption::Regex => Ok(ProtobufSearchOption::Regex),
         }
     }
 }
@@ -2133,6 +2156,29 @@ impl TryFrom<KeyWithModifier> for ProtobufKeyWithModifier {
     }
 }
 
+// DumpScreenFormat conversions
+impl From<ProtobufDumpScreenFormat> for DumpScreenFormat {
+    fn from(protobuf_format: ProtobufDumpScreenFormat) -> Self {
+        match protobuf_format {
+            ProtobufDumpScreenFormat::Text => DumpScreenFormat::Text,
+            ProtobufDumpScreenFormat::Ansi => DumpScreenFormat::Ansi,
+            ProtobufDumpScreenFormat::Html => DumpScreenFormat::Html,
+            ProtobufDumpScreenFormat::Svg => DumpScreenFormat::Svg,
+        }
+    }
+}
+
+impl From<DumpScreenFormat> for ProtobufDumpScreenFormat {
+    fn from(format: DumpScreenFormat) -> Self {
+        match format {
+            DumpScreenFormat::Text => ProtobufDumpScreenFormat::Text,
+            DumpScreenFormat::Ansi => ProtobufDumpScreenFormat::Ansi,
+            DumpScreenFormat::Html => ProtobufDumpScreenFormat::Html,
+            DumpScreenFormat::Svg => ProtobufDumpScreenFormat::Svg,
+        }
+    }
+}
+
 // UnblockCondition conversions
 impl TryFrom<ProtobufUnblockCondition> for UnblockCondition {
     type Error = &'static str;
@@ -2405,6 +2451,41 @@ impl TryFrom<PercentOrFixed> for ProtobufPercentOrFixed {
     }
 }
 
+impl TryFrom<ProtobufPaneDependency> for PaneDependency {
+    type Error = &'static str;
+    fn try_from(protobuf: ProtobufPaneDependency) -> Result<Self, Self::Error> {
+        use super::generated_api::api::action::pane_dependency::ReadyWhen;
+        let ready_when = match protobuf.ready_when {
+            Some(ReadyWhen::ExitedSuccessfully(_)) => PaneReadiness::ExitedSuccessfully,
+            Some(ReadyWhen::OutputMatches(regex)) => PaneReadiness::OutputMatches(regex),
+            Some(ReadyWhen::PortOpen(port)) => {
+                PaneReadiness::PortOpen(u16::try_from(port).map_err(|_| "Invalid port")?)
+            },
+            None => return Err("PaneDependency must have a ready_when"),
+        };
+        Ok(PaneDependency {
+            pane_name: protobuf.pane_name,
+            ready_when,
+        })
+    }
+}
+
+impl TryFrom<PaneDependency> for ProtobufPaneDependency {
+    type Error = &'static str;
+    fn try_from(internal: PaneDependency) -> Result<Self, Self::Error> {
+        use super::generated_api::api::action::pane_dependency::ReadyWhen;
+        let ready_when = match internal.ready_when {
+            PaneReadiness::ExitedSuccessfully => ReadyWhen::ExitedSuccessfully(true),
+            PaneReadiness::OutputMatches(regex) => ReadyWhen::OutputMatches(regex),
+            PaneReadiness::PortOpen(port) => ReadyWhen::PortOpen(port as u32),
+        };
+        Ok(ProtobufPaneDependency {
+            pane_name: internal.pane_name,
+            ready_when: Some(ready_when),
+        })
+    }
+}
+
 impl TryFrom<ProtobufSplitDirection> for SplitDirection {
     type Error = &'static str;
     fn try_from(protobuf: ProtobufSplitDirection) -> Result<Self, Self::Error> {
@@ -2845,6 +2926,18 @@ impl TryFrom<ProtobufTiledPaneLayout> for TiledPaneLayout {
             run_instructions_to_ignore,
             hide_floating_panes: protobuf.hide_floating_panes,
             pane_initial_contents: protobuf.pane_initial_contents,
+            log_to: protobuf.log_to.map(PathBuf::from),
+            depends_on: protobuf
+                .depends_on
+                .into_iter()
+                .map(|d| d.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            mark: protobuf.mark,
+            tiling_mode: protobuf
+                .tiling_mode
+                .map(|t| TilingMode::from_str(&t))
+                .transpose()
+                .map_err(|_| "Failed to parse tiling mode")?,
         })
     }
 }
@@ -2876,6 +2969,14 @@ impl TryFrom<TiledPaneLayout> for ProtobufTiledPaneLayout {
             exclude_from_sync: internal.exclude_from_sync,
             hide_floating_panes: internal.hide_floating_panes,
             pane_initial_contents: internal.pane_initial_contents,
+            log_to: internal.log_to.map(|p| p.to_string_lossy().to_string()),
+            depends_on: internal
+                .depends_on
+                .into_iter()
+                .map(|d| d.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            mark: internal.mark,
+            tiling_mode: internal.tiling_mode.map(|t| t.to_string()),
         })
     }
 }
@@ -2901,6 +3002,13 @@ impl TryFrom<ProtobufFloatingPaneLayout> for FloatingPaneLayout {
             pane_initial_contents: protobuf.pane_initial_contents,
             logical_position: protobuf.logical_position.map(|p| p as usize),
             borderless: protobuf.borderless,
+            log_to: protobuf.log_to.map(PathBuf::from),
+            depends_on: protobuf
+                .depends_on
+                .into_iter()
+                .map(|d| d.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            mark: protobuf.mark,
         })
     }
 }
@@ -2926,6 +3034,13 @@ impl TryFrom<FloatingPaneLayout> for ProtobufFloatingPaneLayout {
             pane_initial_contents: internal.pane_initial_contents,
             logical_position: internal.logical_position.map(|p| p as u32),
             borderless: internal.borderless,
+            log_to: internal.log_to.map(|p| p.to_string_lossy().to_string()),
+            depends_on: internal
+                .depends_on
+                .into_iter()
+                .map(|d| d.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            mark: internal.mark,
         })
     }
 }
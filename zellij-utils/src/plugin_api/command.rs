@@ -10,7 +10,13 @@ impl TryFrom<ProtobufCommand> for CommandToRun {
         let path = PathBuf::from(protobuf_command.path);
         let args = protobuf_command.args;
         let cwd = protobuf_command.cwd.map(|c| PathBuf::from(c));
-        Ok(CommandToRun { path, args, cwd })
+        let env = protobuf_command.env.into_iter().collect();
+        Ok(CommandToRun {
+            path,
+            args,
+            cwd,
+            env,
+        })
     }
 }
 
@@ -21,6 +27,7 @@ impl TryFrom<CommandToRun> for ProtobufCommand {
             path: command_to_run.path.display().to_string(),
             args: command_to_run.args,
             cwd: command_to_run.cwd.map(|c| c.display().to_string()),
+            env: command_to_run.env.into_iter().collect(),
         })
     }
 }
@@ -2,28 +2,33 @@ pub use super::generated_api::api::{
     action::{Action as ProtobufAction, Position as ProtobufPosition},
     event::{
         event::Payload as ProtobufEventPayload,
-        layout_parsing_error::ErrorType as ProtobufLayoutParsingErrorType,
-        pane_scrollback_response, ActionCompletePayload as ProtobufActionCompletePayload,
+        layout_parsing_error::ErrorType as ProtobufLayoutParsingErrorType, pane_output_payload,
+        pane_scrollback_response, rpc_response_payload,
+        ActionCompletePayload as ProtobufActionCompletePayload,
         AvailableLayoutInfoPayload as ProtobufAvailableLayoutInfoPayload,
         ClientInfo as ProtobufClientInfo, ClientPaneHistory as ProtobufClientPaneHistory,
         ClientTabHistory as ProtobufClientTabHistory, ContextItem as ProtobufContextItem,
         CopyDestination as ProtobufCopyDestination, CwdChangedPayload as ProtobufCwdChangedPayload,
         Event as ProtobufEvent, EventNameList as ProtobufEventNameList,
         EventType as ProtobufEventType, FileMetadata as ProtobufFileMetadata,
+        HttpRequestPayload as ProtobufHttpRequestPayload,
         InputModeKeybinds as ProtobufInputModeKeybinds, KdlError as ProtobufKdlError,
         KdlErrorVariant as ProtobufKdlErrorVariant, KeyBind as ProtobufKeyBind,
         LayoutInfo as ProtobufLayoutInfo, LayoutMetadata as ProtobufLayoutMetadata,
-        LayoutParsingError as ProtobufLayoutParsingError,
+        LayoutParam as ProtobufLayoutParam, LayoutParsingError as ProtobufLayoutParsingError,
         LayoutWithError as ProtobufLayoutWithError, ModeUpdatePayload as ProtobufModeUpdatePayload,
         PaneContents as ProtobufPaneContents, PaneContentsEntry as ProtobufPaneContentsEntry,
         PaneId as ProtobufPaneId, PaneInfo as ProtobufPaneInfo,
         PaneManifest as ProtobufPaneManifest, PaneMetadata as ProtobufPaneMetadata,
+        PaneOutputLines as ProtobufPaneOutputLines, PaneOutputPayload as ProtobufPaneOutputPayload,
         PaneRenderReportPayload as ProtobufPaneRenderReportPayload,
         PaneScrollbackResponse as ProtobufPaneScrollbackResponse, PaneType as ProtobufPaneType,
         PluginInfo as ProtobufPluginInfo, ResurrectableSession as ProtobufResurrectableSession,
-        SelectedText as ProtobufSelectedText, SessionManifest as ProtobufSessionManifest,
-        SyntaxError as ProtobufSyntaxError, TabInfo as ProtobufTabInfo,
-        TabMetadata as ProtobufTabMetadata, UserActionPayload as ProtobufUserActionPayload,
+        RpcRequestPayload as ProtobufRpcRequestPayload,
+        RpcResponsePayload as ProtobufRpcResponsePayload, SelectedText as ProtobufSelectedText,
+        SessionManifest as ProtobufSessionManifest, SyntaxError as ProtobufSyntaxError,
+        TabInfo as ProtobufTabInfo, TabMetadata as ProtobufTabMetadata,
+        UserActionPayload as ProtobufUserActionPayload,
         WebServerStatusPayload as ProtobufWebServerStatusPayload, WebSharing as ProtobufWebSharing,
         *,
     },
@@ -34,10 +39,10 @@ pub use super::generated_api::api::{
 #[allow(hidden_glob_reexports)]
 use crate::data::{
     ClientId, ClientInfo, CopyDestination, Event, EventType, FileMetadata, InputMode,
-    KeyWithModifier, LayoutInfo, LayoutMetadata, ModeInfo, Mouse, PaneContents, PaneId, PaneInfo,
-    PaneManifest, PaneMetadata, PaneScrollbackResponse, PermissionStatus, PluginCapabilities,
-    PluginInfo, SelectedText, SessionInfo, Style, TabInfo, TabMetadata, WebServerStatus,
-    WebSharing,
+    KeyWithModifier, LayoutInfo, LayoutMetadata, LayoutParam, ModeInfo, Mouse, PaneContents,
+    PaneId, PaneInfo, PaneManifest, PaneMetadata, PaneOutput, PaneScrollbackResponse,
+    PermissionStatus, PluginCapabilities, PluginHttpRequest, PluginInfo, SelectedText, SessionInfo,
+    Style, TabInfo, TabMetadata, WebServerStatus, WebSharing,
 };
 
 use crate::errors::prelude::*;
@@ -488,6 +493,66 @@ impl TryFrom<ProtobufEvent> for Event {
                 },
                 _ => Err("Malformed payload for the AvailableLayoutInfo Event"),
             },
+            Some(ProtobufEventType::PaneOutput) => match protobuf_event.payload {
+                Some(ProtobufEventPayload::PaneOutputPayload(protobuf_payload)) => {
+                    let pane_id: PaneId = protobuf_payload
+                        .pane_id
+                        .ok_or("Missing pane_id in PaneOutput payload")?
+                        .try_into()
+                        .map_err(|_| "Failed to convert PaneId in PaneOutput payload")?;
+                    let output = match protobuf_payload.output {
+                        Some(pane_output_payload::Output::Raw(bytes)) => PaneOutput::Raw(bytes),
+                        Some(pane_output_payload::Output::Lines(lines)) => {
+                            PaneOutput::Lines(lines.lines)
+                        },
+                        None => return Err("Missing output in PaneOutput payload"),
+                    };
+                    Ok(Event::PaneOutput(pane_id, output))
+                },
+                _ => Err("Malformed payload for the PaneOutput Event"),
+            },
+            Some(ProtobufEventType::RpcRequest) => match protobuf_event.payload {
+                Some(ProtobufEventPayload::RpcRequestPayload(protobuf_payload)) => {
+                    Ok(Event::RpcRequest(
+                        protobuf_payload.request_id,
+                        protobuf_payload.method,
+                        protobuf_payload.payload,
+                        protobuf_payload.caller_plugin_id,
+                    ))
+                },
+                _ => Err("Malformed payload for the RpcRequest Event"),
+            },
+            Some(ProtobufEventType::RpcResponse) => match protobuf_event.payload {
+                Some(ProtobufEventPayload::RpcResponsePayload(protobuf_payload)) => {
+                    let result = match protobuf_payload.result {
+                        Some(rpc_response_payload::Result::Payload(payload)) => Ok(payload),
+                        Some(rpc_response_payload::Result::Error(error)) => Err(error),
+                        None => return Err("Missing result in RpcResponse payload"),
+                    };
+                    Ok(Event::RpcResponse(protobuf_payload.request_id, result))
+                },
+                _ => Err("Malformed payload for the RpcResponse Event"),
+            },
+            Some(ProtobufEventType::HttpRequest) => match protobuf_event.payload {
+                Some(ProtobufEventPayload::HttpRequestPayload(protobuf_payload)) => {
+                    Ok(Event::HttpRequest(
+                        protobuf_payload.request_id,
+                        PluginHttpRequest {
+                            route: protobuf_payload.route,
+                            method: protobuf_payload.method,
+                            path: protobuf_payload.path,
+                            query: protobuf_payload.query,
+                            headers: protobuf_payload
+                                .headers
+                                .into_iter()
+                                .map(|header| (header.name, header.value))
+                                .collect(),
+                            body: protobuf_payload.body,
+                        },
+                    ))
+                },
+                _ => Err("Malformed payload for the HttpRequest Event"),
+            },
             None => Err("Unknown Protobuf Event"),
         }
     }
@@ -964,6 +1029,67 @@ impl TryFrom<Event> for ProtobufEvent {
                     )),
                 })
             },
+            Event::RpcRequest(request_id, method, payload, caller_plugin_id) => Ok(ProtobufEvent {
+                name: ProtobufEventType::RpcRequest as i32,
+                payload: Some(event::Payload::RpcRequestPayload(
+                    ProtobufRpcRequestPayload {
+                        request_id,
+                        method,
+                        payload,
+                        caller_plugin_id,
+                    },
+                )),
+            }),
+            Event::RpcResponse(request_id, result) => {
+                let result = match result {
+                    Ok(payload) => rpc_response_payload::Result::Payload(payload),
+                    Err(error) => rpc_response_payload::Result::Error(error),
+                };
+                Ok(ProtobufEvent {
+                    name: ProtobufEventType::RpcResponse as i32,
+                    payload: Some(event::Payload::RpcResponsePayload(
+                        ProtobufRpcResponsePayload {
+                            request_id,
+                            result: Some(result),
+                        },
+                    )),
+                })
+            },
+            Event::HttpRequest(request_id, request) => Ok(ProtobufEvent {
+                name: ProtobufEventType::HttpRequest as i32,
+                payload: Some(event::Payload::HttpRequestPayload(
+                    ProtobufHttpRequestPayload {
+                        request_id,
+                        route: request.route,
+                        method: request.method,
+                        path: request.path,
+                        query: request.query,
+                        headers: request
+                            .headers
+                            .into_iter()
+                            .map(|(name, value)| Header { name, value })
+                            .collect(),
+                        body: request.body,
+                    },
+                )),
+            }),
+            Event::PaneOutput(pane_id, output) => {
+                let output = match output {
+                    PaneOutput::Raw(bytes) => pane_output_payload::Output::Raw(bytes),
+                    PaneOutput::Lines(lines) => {
+                        pane_output_payload::Output::Lines(ProtobufPaneOutputLines { lines })
+                    },
+                };
+                Ok(ProtobufEvent {
+                    name: ProtobufEventType::PaneOutput as i32,
+                    payload: Some(event::Payload::PaneOutputPayload(
+                        ProtobufPaneOutputPayload {
+                            pane_id: Some(pane_id.try_into()?),
+                            output: Some(output),
+                        },
+                    )),
+                })
+            },
         }
     }
 }
@@ -1048,6 +1174,7 @@ impl From<(u32, PluginInfo)> for ProtobufPluginInfo {
                 .into_iter()
                 .map(|(name, value)| ContextItem { name, value })
                 .collect(),
+            suspended: plugin_info.suspended,
         }
     }
 }
@@ -1081,6 +1208,7 @@ impl TryFrom<ProtobufSessionManifest> for SessionInfo {
                 PluginInfo {
                     location: plugin_info.plugin_url,
                     configuration,
+                    suspended: plugin_info.suspended,
                 },
             );
         }
@@ -1188,6 +1316,16 @@ impl TryFrom<ProtobufLayoutMetadata> for LayoutMetadata {
             tabs,
             creation_time: protobuf_metadata.creation_time,
             update_time: protobuf_metadata.update_time,
+            params: protobuf_metadata
+                .params
+                .into_iter()
+                .map(|p| LayoutParam {
+                    name: p.name,
+                    default: p.default,
+                    required: p.required,
+                    value: p.value,
+                })
+                .collect(),
         })
     }
 }
@@ -1204,6 +1342,16 @@ impl TryFrom<LayoutMetadata> for ProtobufLayoutMetadata {
             tabs,
             creation_time: metadata.creation_time,
             update_time: metadata.update_time,
+            params: metadata
+                .params
+                .into_iter()
+                .map(|p| ProtobufLayoutParam {
+                    name: p.name,
+                    default: p.default,
+                    required: p.required,
+                    value: p.value,
+                })
+                .collect(),
         })
     }
 }
@@ -1705,6 +1853,12 @@ impl TryFrom<ProtobufModeUpdatePayload> for ModeInfo {
 
         let web_server_capability = protobuf_mode_update_payload.web_server_capability;
 
+        let following_client = protobuf_mode_update_payload
+            .following_client
+            .map(|c| c as ClientId);
+
+        let following_client_name = protobuf_mode_update_payload.following_client_name;
+
         let mode_info = ModeInfo {
             mode: current_mode,
             keybinds,
@@ -1721,6 +1875,8 @@ impl TryFrom<ProtobufModeUpdatePayload> for ModeInfo {
             web_server_ip,
             web_server_port,
             web_server_capability,
+            following_client,
+            following_client_name,
         };
         Ok(mode_info)
     }
@@ -1745,6 +1901,8 @@ impl TryFrom<ModeInfo> for ProtobufModeUpdatePayload {
         let web_server_ip = mode_info.web_server_ip.map(|i| format!("{}", i));
         let web_server_port = mode_info.web_server_port.map(|p| p as u32);
         let web_server_capability = mode_info.web_server_capability;
+        let following_client = mode_info.following_client.map(|c| c as u32);
+        let following_client_name = mode_info.following_client_name;
         let mut protobuf_input_mode_keybinds: Vec<ProtobufInputModeKeybinds> = vec![];
         for (input_mode, input_mode_keybinds) in mode_info.keybinds {
             let mode: ProtobufInputMode = input_mode.try_into()?;
@@ -1785,6 +1943,8 @@ impl TryFrom<ModeInfo> for ProtobufModeUpdatePayload {
             web_server_ip,
             web_server_port,
             web_server_capability,
+            following_client,
+            following_client_name,
         })
     }
 }
@@ -1863,6 +2023,10 @@ impl TryFrom<ProtobufEventType> for EventType {
             ProtobufEventType::ActionComplete => EventType::ActionComplete,
             ProtobufEventType::CwdChanged => EventType::CwdChanged,
             ProtobufEventType::AvailableLayoutInfo => EventType::AvailableLayoutInfo,
+            ProtobufEventType::PaneOutput => EventType::PaneOutput,
+            ProtobufEventType::RpcRequest => EventType::RpcRequest,
+            ProtobufEventType::RpcResponse => EventType::RpcResponse,
+            ProtobufEventType::HttpRequest => EventType::HttpRequest,
         })
     }
 }
@@ -1911,6 +2075,10 @@ impl TryFrom<EventType> for ProtobufEventType {
             EventType::ActionComplete => ProtobufEventType::ActionComplete,
             EventType::CwdChanged => ProtobufEventType::CwdChanged,
             EventType::AvailableLayoutInfo => ProtobufEventType::AvailableLayoutInfo,
+            EventType::PaneOutput => ProtobufEventType::PaneOutput,
+            EventType::RpcRequest => ProtobufEventType::RpcRequest,
+            EventType::RpcResponse => ProtobufEventType::RpcResponse,
+            EventType::HttpRequest => ProtobufEventType::HttpRequest,
         })
     }
 }
@@ -2070,6 +2238,8 @@ fn serialize_mode_update_event_with_non_default_values() {
         web_server_ip: IpAddr::from_str("127.0.0.1").ok(),
         web_server_port: Some(8082),
         web_server_capability: Some(true),
+        following_client: Some(2),
+        following_client_name: Some("alice".to_owned()),
     });
     let protobuf_event: ProtobufEvent = mode_update_event.clone().try_into().unwrap();
     let serialized_protobuf_event = protobuf_event.encode_to_vec();
@@ -2301,6 +2471,86 @@ fn serialize_custom_message_event() {
     );
 }
 
+#[test]
+fn serialize_pane_output_event() {
+    use prost::Message;
+    let raw_output_event = Event::PaneOutput(
+        PaneId::Terminal(1),
+        PaneOutput::Raw(b"\x1b[1mfoo\r\n".to_vec()),
+    );
+    let protobuf_event: ProtobufEvent = raw_output_event.clone().try_into().unwrap();
+    let serialized_protobuf_event = protobuf_event.encode_to_vec();
+    let deserialized_protobuf_event: ProtobufEvent =
+        Message::decode(serialized_protobuf_event.as_slice()).unwrap();
+    let deserialized_event: Event = deserialized_protobuf_event.try_into().unwrap();
+    assert_eq!(
+        raw_output_event, deserialized_event,
+        "Event properly serialized/deserialized without change"
+    );
+    let lines_output_event = Event::PaneOutput(
+        PaneId::Terminal(2),
+        PaneOutput::Lines(vec!["foo".to_owned(), "".to_owned(), "bar".to_owned()]),
+    );
+    let protobuf_event: ProtobufEvent = lines_output_event.clone().try_into().unwrap();
+    let serialized_protobuf_event = protobuf_event.encode_to_vec();
+    let deserialized_protobuf_event: ProtobufEvent =
+        Message::decode(serialized_protobuf_event.as_slice()).unwrap();
+    let deserialized_event: Event = deserialized_protobuf_event.try_into().unwrap();
+    assert_eq!(
+        lines_output_event, deserialized_event,
+        "Event properly serialized/deserialized without change"
+    );
+}
+
+#[test]
+fn serialize_rpc_events() {
+    use prost::Message;
+    let events = vec![
+        Event::RpcRequest(1, "list_issues".to_owned(), b"{\"page\":2}".to_vec(), 3),
+        Event::RpcResponse(1, Ok(b"[]".to_vec())),
+        Event::RpcResponse(2, Ok(vec![])),
+        Event::RpcResponse(3, Err("timed out".to_owned())),
+    ];
+    for rpc_event in events {
+        let protobuf_event: ProtobufEvent = rpc_event.clone().try_into().unwrap();
+        let serialized_protobuf_event = protobuf_event.encode_to_vec();
+        let deserialized_protobuf_event: ProtobufEvent =
+            Message::decode(serialized_protobuf_event.as_slice()).unwrap();
+        let deserialized_event: Event = deserialized_protobuf_event.try_into().unwrap();
+        assert_eq!(
+            rpc_event, deserialized_event,
+            "Event properly serialized/deserialized without change"
+        );
+    }
+}
+
+#[test]
+fn serialize_http_request_event() {
+    use prost::Message;
+    let mut headers = BTreeMap::new();
+    headers.insert("content-type".to_owned(), "application/json".to_owned());
+    let http_request_event = Event::HttpRequest(
+        7,
+        PluginHttpRequest {
+            route: "dashboard".to_owned(),
+            method: "POST".to_owned(),
+            path: "/api/status".to_owned(),
+            query: "verbose=true".to_owned(),
+            headers,
+            body: b"{}".to_vec(),
+        },
+    );
+    let protobuf_event: ProtobufEvent = http_request_event.clone().try_into().unwrap();
+    let serialized_protobuf_event = protobuf_event.encode_to_vec();
+    let deserialized_protobuf_event: ProtobufEvent =
+        Message::decode(serialized_protobuf_event.as_slice()).unwrap();
+    let deserialized_event: Event = deserialized_protobuf_event.try_into().unwrap();
+    assert_eq!(
+        http_request_event, deserialized_event,
+        "Event properly serialized/deserialized without change"
+    );
+}
+
 #[test]
 fn serialize_file_system_create_event() {
     use prost::Message;
@@ -2500,6 +2750,7 @@ fn serialize_session_update_event_with_non_default_values() {
         PluginInfo {
             location: "https://example.com/my-plugin.wasm".to_owned(),
             configuration: plugin_configuration,
+            suspended: Some("exceeded its execution budget".to_owned()),
         },
     );
     let mut tab_history = BTreeMap::new();
@@ -2518,6 +2769,19 @@ fn serialize_session_update_event_with_non_default_values() {
                     tabs: vec![],
                     creation_time: "0".to_owned(),
                     update_time: "0".to_owned(),
+                    params: vec![
+                        LayoutParam {
+                            name: "project".to_owned(),
+                            required: true,
+                            value: Some("perth".to_owned()),
+                            ..Default::default()
+                        },
+                        LayoutParam {
+                            name: "agent".to_owned(),
+                            default: Some("claude".to_owned()),
+                            ..Default::default()
+                        },
+                    ],
                 },
             ),
             LayoutInfo::BuiltIn("layout2".to_owned()),
@@ -2527,6 +2791,7 @@ fn serialize_session_update_event_with_non_default_values() {
                     tabs: vec![],
                     creation_time: "0".to_owned(),
                     update_time: "0".to_owned(),
+                    params: vec![],
                 },
             ),
         ],
@@ -2551,6 +2816,7 @@ fn serialize_session_update_event_with_non_default_values() {
                     tabs: vec![],
                     creation_time: "0".to_owned(),
                     update_time: "0".to_owned(),
+                    params: vec![],
                 },
             ),
             LayoutInfo::BuiltIn("layout2".to_owned()),
@@ -2560,6 +2826,7 @@ fn serialize_session_update_event_with_non_default_values() {
                     tabs: vec![],
                     creation_time: "0".to_owned(),
                     update_time: "0".to_owned(),
+                    params: vec![],
                 },
             ),
         ],
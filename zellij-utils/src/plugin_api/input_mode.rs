@@ -23,6 +23,8 @@ impl TryFrom<ProtobufInputMode> for InputMode {
             ProtobufInputMode::Move => Ok(InputMode::Move),
             ProtobufInputMode::Prompt => Ok(InputMode::Prompt),
             ProtobufInputMode::Tmux => Ok(InputMode::Tmux),
+            ProtobufInputMode::MarkPane => Ok(InputMode::MarkPane),
+            ProtobufInputMode::FocusMark => Ok(InputMode::FocusMark),
         }
     }
 }
@@ -45,6 +47,8 @@ impl TryFrom<InputMode> for ProtobufInputMode {
             InputMode::Move => ProtobufInputMode::Move,
             InputMode::Prompt => ProtobufInputMode::Prompt,
             InputMode::Tmux => ProtobufInputMode::Tmux,
+            InputMode::MarkPane => ProtobufInputMode::MarkPane,
+            InputMode::FocusMark => ProtobufInputMode::FocusMark,
         })
     }
 }
@@ -4,9 +4,10 @@ pub use super::generated_api::api::{
     input_mode::InputMode as ProtobufInputMode,
     plugin_command::{
         delete_layout_response, dump_layout_response, dump_session_layout_response,
-        edit_layout_response, get_focused_pane_info_response, get_pane_pid_response,
+        edit_layout_response, get_focused_pane_info_response, get_pane_pid_response, kv_response,
         parse_layout_response, plugin_command::Payload, rename_layout_response,
-        save_layout_response, BreakPanesToNewTabPayload, BreakPanesToTabWithIndexPayload,
+        respond_to_rpc_request_payload, save_layout_response, search_session_response,
+        BreakPanesToNewTabPayload, BreakPanesToTabWithIndexPayload,
         ChangeFloatingPanesCoordinatesPayload, ChangeHostFolderPayload,
         ClearScreenForPaneIdPayload, CliPipeOutputPayload, CloseMultiplePanesPayload,
         CloseTabWithIndexPayload, CommandName, ContextItem, CopyToClipboardPayload,
@@ -25,8 +26,11 @@ pub use super::generated_api::api::{
         GetFocusedPaneInfoResponse as ProtobufGetFocusedPaneInfoResponse, GetLayoutDirPayload,
         GetLayoutDirResponse as ProtobufGetLayoutDirResponse, GetPanePidPayload,
         GetPanePidResponse as ProtobufGetPanePidResponse, GetPaneScrollbackPayload,
-        GroupAndUngroupPanesPayload, HidePaneWithIdPayload, HighlightAndUnhighlightPanesPayload,
-        HttpVerb as ProtobufHttpVerb, IdAndNewName, KeyToRebind, KeyToUnbind, KillSessionsPayload,
+        GrantedPluginPermissions as ProtobufGrantedPluginPermissions, GroupAndUngroupPanesPayload,
+        HidePaneWithIdPayload, HighlightAndUnhighlightPanesPayload, HttpVerb as ProtobufHttpVerb,
+        IdAndNewName, JumpToSearchMatchPayload, KeyToRebind, KeyToUnbind, KillSessionsPayload,
+        KvKeys, KvPayload, KvResponse as ProtobufKvResponse, KvScope as ProtobufKvScope, KvValue,
+        ListGrantedPermissionsResponse as ProtobufListGrantedPermissionsResponse,
         ListTokensResponse, LoadNewPluginPayload, MessageToPluginPayload,
         MovePaneWithPaneIdInDirectionPayload, MovePaneWithPaneIdPayload, MovePayload,
         NewPluginArgs as ProtobufNewPluginArgs, NewTabPayload, NewTabsWithLayoutInfoPayload,
@@ -36,22 +40,28 @@ pub use super::generated_api::api::{
         OpenFileNearPluginPayload, OpenFilePayload, OpenTerminalFloatingNearPluginPayload,
         OpenTerminalInPlaceOfPluginPayload, OpenTerminalNearPluginPayload, OverrideLayoutPayload,
         PageScrollDownInPaneIdPayload, PageScrollUpInPaneIdPayload, PaneId as ProtobufPaneId,
-        PaneIdAndFloatingPaneCoordinates, PaneType as ProtobufPaneType, ParseLayoutPayload,
-        ParseLayoutResponse as ProtobufParseLayoutResponse, PluginCommand as ProtobufPluginCommand,
-        PluginMessagePayload, RebindKeysPayload, ReconfigurePayload, ReloadPluginPayload,
-        RenameLayoutPayload, RenameLayoutResponse as ProtobufRenameLayoutResponse,
-        RenameWebLoginTokenPayload, RenameWebTokenResponse, ReplacePaneWithExistingPanePayload,
-        RequestPluginPermissionPayload, RerunCommandPanePayload, ResizePaneIdWithDirectionPayload,
-        ResizePayload, RevokeAllWebTokensResponse, RevokeTokenResponse, RevokeWebLoginTokenPayload,
+        PaneIdAndFloatingPaneCoordinates, PaneOutputFormat as ProtobufPaneOutputFormat,
+        PaneSearchResults as ProtobufPaneSearchResults, PaneType as ProtobufPaneType,
+        ParseLayoutPayload, ParseLayoutResponse as ProtobufParseLayoutResponse,
+        PluginCommand as ProtobufPluginCommand, PluginMessagePayload, RebindKeysPayload,
+        ReconfigurePayload, ReloadPluginPayload, RenameLayoutPayload,
+        RenameLayoutResponse as ProtobufRenameLayoutResponse, RenameWebLoginTokenPayload,
+        RenameWebTokenResponse, ReplacePaneWithExistingPanePayload, RequestPluginPermissionPayload,
+        RerunCommandPanePayload, ResizePaneIdWithDirectionPayload, ResizePayload,
+        RespondToHttpRequestPayload, RespondToRpcRequestPayload, RevokeAllWebTokensResponse,
+        RevokePluginPermissionsPayload, RevokeTokenResponse, RevokeWebLoginTokenPayload,
         RunActionPayload, RunCommandPayload, SaveLayoutPayload,
         SaveLayoutResponse as ProtobufSaveLayoutResponse, ScrollDownInPaneIdPayload,
         ScrollToBottomInPaneIdPayload, ScrollToTopInPaneIdPayload, ScrollUpInPaneIdPayload,
+        SearchMatch as ProtobufSearchMatch, SearchSessionPayload,
+        SearchSessionResponse as ProtobufSearchSessionResponse, SendRpcRequestPayload,
+        SendRpcRequestResponse as ProtobufSendRpcRequestResponse, SessionSearchResults,
         SetFloatingPanePinnedPayload, SetPaneBorderlessPayload,
         SetSelfMouseSelectionSupportPayload, SetTimeoutPayload, ShowCursorPayload,
-        ShowPaneWithIdPayload, StackPanesPayload, SubscribePayload, SwitchSessionPayload,
-        SwitchTabToPayload, TogglePaneBorderlessPayload, TogglePaneEmbedOrEjectForPaneIdPayload,
-        TogglePaneIdFullscreenPayload, UnsubscribePayload, WebRequestPayload,
-        WriteCharsToPaneIdPayload, WriteToPaneIdPayload,
+        ShowPaneWithIdPayload, StackPanesPayload, SubscribePayload, SubscribeToPaneOutputPayload,
+        SwitchSessionPayload, SwitchTabToPayload, TogglePaneBorderlessPayload,
+        TogglePaneEmbedOrEjectForPaneIdPayload, TogglePaneIdFullscreenPayload, UnsubscribePayload,
+        WebRequestPayload, WriteCharsToPaneIdPayload, WriteToPaneIdPayload,
     },
     plugin_permission::PermissionType as ProtobufPermissionType,
     resize::ResizeAction as ProtobufResizeAction,
@@ -60,8 +70,9 @@ pub use super::generated_api::api::{
 use crate::data::{
     ConnectToSession, DeleteLayoutResponse, EditLayoutResponse, FloatingPaneCoordinates,
     GetFocusedPaneInfoResponse, GetPanePidResponse, HttpVerb, InputMode, KeyWithModifier,
-    MessageToPlugin, NewPluginArgs, PaneId, PermissionType, PluginCommand, RenameLayoutResponse,
-    SaveLayoutResponse,
+    KvResponse, KvScope, MessageToPlugin, NewPluginArgs, PaneId, PaneOutputFormat,
+    PaneSearchResults, PermissionType, PluginCommand, PluginHttpResponse, RenameLayoutResponse,
+    SaveLayoutResponse, SearchMatch, SearchQuery, SessionSearchResponse,
 };
 use crate::input::actions::Action;
 use crate::input::layout::PercentOrFixed;
@@ -198,6 +209,42 @@ impl Into<ProtobufHttpVerb> for HttpVerb {
     }
 }
 
+impl Into<PaneOutputFormat> for ProtobufPaneOutputFormat {
+    fn into(self) -> PaneOutputFormat {
+        match self {
+            ProtobufPaneOutputFormat::Raw => PaneOutputFormat::Raw,
+            ProtobufPaneOutputFormat::Lines => PaneOutputFormat::Lines,
+        }
+    }
+}
+
+impl Into<ProtobufPaneOutputFormat> for PaneOutputFormat {
+    fn into(self) -> ProtobufPaneOutputFormat {
+        match self {
+            PaneOutputFormat::Raw => ProtobufPaneOutputFormat::Raw,
+            PaneOutputFormat::Lines => ProtobufPaneOutputFormat::Lines,
+        }
+    }
+}
+
+impl Into<KvScope> for ProtobufKvScope {
+    fn into(self) -> KvScope {
+        match self {
+            ProtobufKvScope::Session => KvScope::Session,
+            ProtobufKvScope::Global => KvScope::Global,
+        }
+    }
+}
+
+impl Into<ProtobufKvScope> for KvScope {
+    fn into(self) -> ProtobufKvScope {
+        match self {
+            KvScope::Session => ProtobufKvScope::Session,
+            KvScope::Global => ProtobufKvScope::Global,
+        }
+    }
+}
+
 impl TryFrom<ProtobufPaneId> for PaneId {
     type Error = &'static str;
     fn try_from(protobuf_pane_id: ProtobufPaneId) -> Result<Self, &'static str> {
@@ -249,6 +296,48 @@ impl From<GetPanePidResponse> for ProtobufGetPanePidResponse {
     }
 }
 
+impl TryFrom<ProtobufKvResponse> for KvResponse {
+    type Error = &'static str;
+    fn try_from(protobuf_response: ProtobufKvResponse) -> Result<Self, &'static str> {
+        match protobuf_response.result {
+            Some(kv_response::Result::Done(_)) => Ok(KvResponse::Done),
+            Some(kv_response::Result::Value(value)) => Ok(KvResponse::Value(value.value)),
+            Some(kv_response::Result::Keys(keys)) => Ok(KvResponse::Keys(keys.keys)),
+            Some(kv_response::Result::Error(error)) => Ok(KvResponse::Err(error)),
+            None => Err("Empty KvResponse"),
+        }
+    }
+}
+
+impl From<KvResponse> for ProtobufKvResponse {
+    fn from(response: KvResponse) -> Self {
+        let result = match response {
+            KvResponse::Done => kv_response::Result::Done(true),
+            KvResponse::Value(value) => kv_response::Result::Value(KvValue { value }),
+            KvResponse::Keys(keys) => kv_response::Result::Keys(KvKeys { keys }),
+            KvResponse::Err(error) => kv_response::Result::Error(error),
+        };
+        ProtobufKvResponse {
+            result: Some(result),
+        }
+    }
+}
+
+fn kv_scope_from_payload(payload: &KvPayload) -> Result<KvScope, &'static str> {
+    ProtobufKvScope::from_i32(payload.scope)
+        .map(|scope| scope.into())
+        .ok_or("Unrecognized key/value store scope")
+}
+
+fn kv_payload(scope: KvScope, key: String, value: Vec<u8>) -> KvPayload {
+    let scope: ProtobufKvScope = scope.into();
+    KvPayload {
+        scope: scope as i32,
+        key,
+        value,
+    }
+}
+
 impl From<GetFocusedPaneInfoResponse> for ProtobufGetFocusedPaneInfoResponse {
     fn from(response: GetFocusedPaneInfoResponse) -> Self {
         match response {
@@ -278,6 +367,130 @@ impl From<GetFocusedPaneInfoResponse> for ProtobufGetFocusedPaneInfoResponse {
     }
 }
 
+impl From<SearchSessionPayload> for SearchQuery {
+    fn from(payload: SearchSessionPayload) -> Self {
+        SearchQuery {
+            needle: payload.needle,
+            regex: payload.regex,
+            case_insensitive: payload.case_insensitive,
+            whole_word: payload.whole_word,
+            context_lines: payload.context_lines as usize,
+        }
+    }
+}
+
+impl From<SearchQuery> for SearchSessionPayload {
+    fn from(query: SearchQuery) -> Self {
+        SearchSessionPayload {
+            needle: query.needle,
+            regex: query.regex,
+            case_insensitive: query.case_insensitive,
+            whole_word: query.whole_word,
+            context_lines: query.context_lines as u32,
+        }
+    }
+}
+
+impl From<ProtobufSearchMatch> for SearchMatch {
+    fn from(protobuf_search_match: ProtobufSearchMatch) -> Self {
+        SearchMatch {
+            line: protobuf_search_match.line as usize,
+            start_column: protobuf_search_match.start_column as usize,
+            end_column: protobuf_search_match.end_column as usize,
+            text: protobuf_search_match.text,
+            context_before: protobuf_search_match.context_before,
+            context_after: protobuf_search_match.context_after,
+        }
+    }
+}
+
+impl From<SearchMatch> for ProtobufSearchMatch {
+    fn from(search_match: SearchMatch) -> Self {
+        ProtobufSearchMatch {
+            line: search_match.line as u64,
+            start_column: search_match.start_column as u64,
+            end_column: search_match.end_column as u64,
+            text: search_match.text,
+            context_before: search_match.context_before,
+            context_after: search_match.context_after,
+        }
+    }
+}
+
+impl TryFrom<ProtobufPaneSearchResults> for PaneSearchResults {
+    type Error = &'static str;
+    fn try_from(protobuf_results: ProtobufPaneSearchResults) -> Result<Self, &'static str> {
+        Ok(PaneSearchResults {
+            tab_position: protobuf_results.tab_position as usize,
+            tab_name: protobuf_results.tab_name,
+            pane_id: protobuf_results
+                .pane_id
+                .ok_or("PaneSearchResults missing pane_id")?
+                .try_into()?,
+            pane_title: protobuf_results.pane_title,
+            matches: protobuf_results
+                .matches
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<PaneSearchResults> for ProtobufPaneSearchResults {
+    type Error = &'static str;
+    fn try_from(results: PaneSearchResults) -> Result<Self, &'static str> {
+        Ok(ProtobufPaneSearchResults {
+            tab_position: results.tab_position as u32,
+            tab_name: results.tab_name,
+            pane_id: Some(results.pane_id.try_into()?),
+            pane_title: results.pane_title,
+            matches: results.matches.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl TryFrom<ProtobufSearchSessionResponse> for SessionSearchResponse {
+    type Error = &'static str;
+    fn try_from(protobuf_response: ProtobufSearchSessionResponse) -> Result<Self, &'static str> {
+        match protobuf_response.result {
+            Some(search_session_response::Result::Results(results)) => {
+                Ok(SessionSearchResponse::Ok(
+                    results
+                        .panes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<_, _>>()?,
+                ))
+            },
+            Some(search_session_response::Result::Error(error)) => {
+                Ok(SessionSearchResponse::Err(error))
+            },
+            None => Err("Empty SearchSessionResponse"),
+        }
+    }
+}
+
+impl TryFrom<SessionSearchResponse> for ProtobufSearchSessionResponse {
+    type Error = &'static str;
+    fn try_from(response: SessionSearchResponse) -> Result<Self, &'static str> {
+        let result = match response {
+            SessionSearchResponse::Ok(panes) => {
+                search_session_response::Result::Results(SessionSearchResults {
+                    panes: panes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<_, _>>()?,
+                })
+            },
+            SessionSearchResponse::Err(error) => search_session_response::Result::Error(error),
+        };
+        Ok(ProtobufSearchSessionResponse {
+            result: Some(result),
+        })
+    }
+}
+
 impl TryFrom<ProtobufSaveLayoutResponse> for SaveLayoutResponse {
     type Error = &'static str;
     fn try_from(protobuf_response: ProtobufSaveLayoutResponse) -> Result<Self, &'static str> {
@@ -2103,6 +2316,158 @@ impl TryFrom<ProtobufPluginCommand> for PluginCommand {
             },
             Some(CommandName::GetLayoutDir) => Ok(PluginCommand::GetLayoutDir),
             Some(CommandName::GetFocusedPaneInfo) => Ok(PluginCommand::GetFocusedPaneInfo),
+            Some(CommandName::SearchSession) => match protobuf_plugin_command.payload {
+                Some(Payload::SearchSessionPayload(payload)) => {
+                    Ok(PluginCommand::SearchSession(payload.into()))
+                },
+                _ => Err("Mismatched payload for SearchSession"),
+            },
+            Some(CommandName::JumpToSearchMatch) => match protobuf_plugin_command.payload {
+                Some(Payload::JumpToSearchMatchPayload(payload)) => match payload.pane_id {
+                    Some(pane_id) => Ok(PluginCommand::JumpToSearchMatch {
+                        pane_id: pane_id.try_into()?,
+                        line: payload.line as usize,
+                    }),
+                    None => Err("Malformed JumpToSearchMatch payload"),
+                },
+                _ => Err("Mismatched payload for JumpToSearchMatch"),
+            },
+            Some(CommandName::SubscribeToPaneOutput) => match protobuf_plugin_command.payload {
+                Some(Payload::SubscribeToPaneOutputPayload(payload)) => {
+                    let format = ProtobufPaneOutputFormat::from_i32(payload.format)
+                        .ok_or("Unrecognized pane output format")?;
+                    match payload.pane_id {
+                        Some(pane_id) => Ok(PluginCommand::SubscribeToPaneOutput(
+                            pane_id.try_into()?,
+                            format.into(),
+                        )),
+                        None => Err("Malformed SubscribeToPaneOutput payload"),
+                    }
+                },
+                _ => Err("Mismatched payload for SubscribeToPaneOutput"),
+            },
+            Some(CommandName::UnsubscribeFromPaneOutput) => match protobuf_plugin_command.payload {
+                Some(Payload::UnsubscribeFromPaneOutputPayload(pane_id)) => Ok(
+                    PluginCommand::UnsubscribeFromPaneOutput(pane_id.try_into()?),
+                ),
+                _ => Err("Mismatched payload for UnsubscribeFromPaneOutput"),
+            },
+            Some(CommandName::KvGet) => match protobuf_plugin_command.payload {
+                Some(Payload::KvGetPayload(payload)) => Ok(PluginCommand::KvGet {
+                    scope: kv_scope_from_payload(&payload)?,
+                    key: payload.key,
+                }),
+                _ => Err("Mismatched payload for KvGet"),
+            },
+            Some(CommandName::KvSet) => match protobuf_plugin_command.payload {
+                Some(Payload::KvSetPayload(payload)) => Ok(PluginCommand::KvSet {
+                    scope: kv_scope_from_payload(&payload)?,
+                    key: payload.key,
+                    value: payload.value,
+                }),
+                _ => Err("Mismatched payload for KvSet"),
+            },
+            Some(CommandName::KvDelete) => match protobuf_plugin_command.payload {
+                Some(Payload::KvDeletePayload(payload)) => Ok(PluginCommand::KvDelete {
+                    scope: kv_scope_from_payload(&payload)?,
+                    key: payload.key,
+                }),
+                _ => Err("Mismatched payload for KvDelete"),
+            },
+            Some(CommandName::KvList) => match protobuf_plugin_command.payload {
+                Some(Payload::KvListPayload(payload)) => Ok(PluginCommand::KvList {
+                    scope: kv_scope_from_payload(&payload)?,
+                }),
+                _ => Err("Mismatched payload for KvList"),
+            },
+            Some(CommandName::RegisterRpcMethod) => match protobuf_plugin_command.payload {
+                Some(Payload::RegisterRpcMethodPayload(method)) => {
+                    Ok(PluginCommand::RegisterRpcMethod(method))
+                },
+                _ => Err("Mismatched payload for RegisterRpcMethod"),
+            },
+            Some(CommandName::UnregisterRpcMethod) => match protobuf_plugin_command.payload {
+                Some(Payload::UnregisterRpcMethodPayload(method)) => {
+                    Ok(PluginCommand::UnregisterRpcMethod(method))
+                },
+                _ => Err("Mismatched payload for UnregisterRpcMethod"),
+            },
+            Some(CommandName::SendRpcRequest) => match protobuf_plugin_command.payload {
+                Some(Payload::SendRpcRequestPayload(payload)) => {
+                    Ok(PluginCommand::SendRpcRequest {
+                        method: payload.method,
+                        payload: payload.payload,
+                        destination_plugin_id: payload.destination_plugin_id,
+                        timeout_ms: payload.timeout_ms,
+                    })
+                },
+                _ => Err("Mismatched payload for SendRpcRequest"),
+            },
+            Some(CommandName::RespondToRpcRequest) => match protobuf_plugin_command.payload {
+                Some(Payload::RespondToRpcRequestPayload(payload)) => {
+                    let result = match payload.result {
+                        Some(respond_to_rpc_request_payload::Result::Payload(payload)) => {
+                            Ok(payload)
+                        },
+                        Some(respond_to_rpc_request_payload::Result::Error(error)) => Err(error),
+                        None => return Err("Malformed RespondToRpcRequest payload"),
+                    };
+                    Ok(PluginCommand::RespondToRpcRequest {
+                        request_id: payload.request_id,
+                        result,
+                    })
+                },
+                _ => Err("Mismatched payload for RespondToRpcRequest"),
+            },
+            Some(CommandName::RegisterHttpRoute) => match protobuf_plugin_command.payload {
+                Some(Payload::RegisterHttpRoutePayload(route)) => {
+                    Ok(PluginCommand::RegisterHttpRoute(route))
+                },
+                _ => Err("Mismatched payload for RegisterHttpRoute"),
+            },
+            Some(CommandName::UnregisterHttpRoute) => match protobuf_plugin_command.payload {
+                Some(Payload::UnregisterHttpRoutePayload(route)) => {
+                    Ok(PluginCommand::UnregisterHttpRoute(route))
+                },
+                _ => Err("Mismatched payload for UnregisterHttpRoute"),
+            },
+            Some(CommandName::RespondToHttpRequest) => match protobuf_plugin_command.payload {
+                Some(Payload::RespondToHttpRequestPayload(payload)) => {
+                    let status = u16::try_from(payload.status)
+                        .map_err(|_| "Malformed status in RespondToHttpRequest payload")?;
+                    Ok(PluginCommand::RespondToHttpRequest {
+                        request_id: payload.request_id,
+                        response: PluginHttpResponse {
+                            status,
+                            headers: payload
+                                .headers
+                                .into_iter()
+                                .map(|header| (header.name, header.value))
+                                .collect(),
+                            body: payload.body,
+                        },
+                    })
+                },
+                _ => Err("Mismatched payload for RespondToHttpRequest"),
+            },
+            Some(CommandName::ListGrantedPermissions) => match protobuf_plugin_command.payload {
+                Some(_) => Err("ListGrantedPermissions should have no payload, found a payload"),
+                None => Ok(PluginCommand::ListGrantedPermissions),
+            },
+            Some(CommandName::RevokePluginPermissions) => match protobuf_plugin_command.payload {
+                Some(Payload::RevokePluginPermissionsPayload(payload)) => {
+                    Ok(PluginCommand::RevokePluginPermissions {
+                        plugin_url: payload.plugin_url,
+                        permissions: payload
+                            .permissions
+                            .iter()
+                            .filter_map(|p| ProtobufPermissionType::from_i32(*p))
+                            .filter_map(|p| PermissionType::try_from(p).ok())
+                            .collect(),
+                    })
+                },
+                _ => Err("Mismatched payload for RevokePluginPermissions"),
+            },
             None => Err("Unrecognized plugin command"),
         }
     }
@@ -3443,6 +3808,140 @@ impl TryFrom<PluginCommand> for ProtobufPluginCommand {
                     GetFocusedPaneInfoPayload {},
                 )),
             }),
+            PluginCommand::SearchSession(query) => Ok(ProtobufPluginCommand {
+                name: CommandName::SearchSession as i32,
+                payload: Some(Payload::SearchSessionPayload(query.into())),
+            }),
+            PluginCommand::JumpToSearchMatch { pane_id, line } => Ok(ProtobufPluginCommand {
+                name: CommandName::JumpToSearchMatch as i32,
+                payload: Some(Payload::JumpToSearchMatchPayload(
+                    JumpToSearchMatchPayload {
+                        pane_id: Some(pane_id.try_into()?),
+                        line: line as u64,
+                    },
+                )),
+            }),
+            PluginCommand::SubscribeToPaneOutput(pane_id, format) => {
+                let format: ProtobufPaneOutputFormat = format.into();
+                Ok(ProtobufPluginCommand {
+                    name: CommandName::SubscribeToPaneOutput as i32,
+                    payload: Some(Payload::SubscribeToPaneOutputPayload(
+                        SubscribeToPaneOutputPayload {
+                            pane_id: Some(pane_id.try_into()?),
+                            format: format as i32,
+                        },
+                    )),
+                })
+            },
+            PluginCommand::UnsubscribeFromPaneOutput(pane_id) => Ok(ProtobufPluginCommand {
+                name: CommandName::UnsubscribeFromPaneOutput as i32,
+                payload: Some(Payload::UnsubscribeFromPaneOutputPayload(
+                    pane_id.try_into()?,
+                )),
+            }),
+            PluginCommand::KvGet { scope, key } => Ok(ProtobufPluginCommand {
+                name: CommandName::KvGet as i32,
+                payload: Some(Payload::KvGetPayload(kv_payload(scope, key, vec![]))),
+            }),
+            PluginCommand::KvSet { scope, key, value } => Ok(ProtobufPluginCommand {
+                name: CommandName::KvSet as i32,
+                payload: Some(Payload::KvSetPayload(kv_payload(scope, key, value))),
+            }),
+            PluginCommand::KvDelete { scope, key } => Ok(ProtobufPluginCommand {
+                name: CommandName::KvDelete as i32,
+                payload: Some(Payload::KvDeletePayload(kv_payload(scope, key, vec![]))),
+            }),
+            PluginCommand::RegisterRpcMethod(method) => Ok(ProtobufPluginCommand {
+                name: CommandName::RegisterRpcMethod as i32,
+                payload: Some(Payload::RegisterRpcMethodPayload(method)),
+            }),
+            PluginCommand::UnregisterRpcMethod(method) => Ok(ProtobufPluginCommand {
+                name: CommandName::UnregisterRpcMethod as i32,
+                payload: Some(Payload::UnregisterRpcMethodPayload(method)),
+            }),
+            PluginCommand::SendRpcRequest {
+                method,
+                payload,
+                destination_plugin_id,
+                timeout_ms,
+            } => Ok(ProtobufPluginCommand {
+                name: CommandName::SendRpcRequest as i32,
+                payload: Some(Payload::SendRpcRequestPayload(SendRpcRequestPayload {
+                    method,
+                    payload,
+                    destination_plugin_id,
+                    timeout_ms,
+                })),
+            }),
+            PluginCommand::RespondToRpcRequest { request_id, result } => {
+                let result = match result {
+                    Ok(payload) => respond_to_rpc_request_payload::Result::Payload(payload),
+                    Err(error) => respond_to_rpc_request_payload::Result::Error(error),
+                };
+                Ok(ProtobufPluginCommand {
+                    name: CommandName::RespondToRpcRequest as i32,
+                    payload: Some(Payload::RespondToRpcRequestPayload(
+                        RespondToRpcRequestPayload {
+                            request_id,
+                            result: Some(result),
+                        },
+                    )),
+                })
+            },
+            PluginCommand::RegisterHttpRoute(route) => Ok(ProtobufPluginCommand {
+                name: CommandName::RegisterHttpRoute as i32,
+                payload: Some(Payload::RegisterHttpRoutePayload(route)),
+            }),
+            PluginCommand::UnregisterHttpRoute(route) => Ok(ProtobufPluginCommand {
+                name: CommandName::UnregisterHttpRoute as i32,
+                payload: Some(Payload::UnregisterHttpRoutePayload(route)),
+            }),
+            PluginCommand::RespondToHttpRequest {
+                request_id,
+                response,
+            } => Ok(ProtobufPluginCommand {
+                name: CommandName::RespondToHttpRequest as i32,
+                payload: Some(Payload::RespondToHttpRequestPayload(
+                    RespondToHttpRequestPayload {
+                        request_id,
+                        status: response.status as u32,
+                        headers: response
+                            .headers
+                            .into_iter()
+                            .map(|(name, value)| Header { name, value })
+                            .collect(),
+                        body: response.body,
+                    },
+                )),
+            }),
+            PluginCommand::ListGrantedPermissions => Ok(ProtobufPluginCommand {
+                name: CommandName::ListGrantedPermissions as i32,
+                payload: None,
+            }),
+            PluginCommand::RevokePluginPermissions {
+                plugin_url,
+                permissions,
+            } => Ok(ProtobufPluginCommand {
+                name: CommandName::RevokePluginPermissions as i32,
+                payload: Some(Payload::RevokePluginPermissionsPayload(
+                    RevokePluginPermissionsPayload {
+                        plugin_url,
+                        permissions: permissions
+                            .iter()
+                            .filter_map(|p| ProtobufPermissionType::try_from(*p).ok())
+                            .map(|p| p as i32)
+                            .collect(),
+                    },
+                )),
+            }),
+            PluginCommand::KvList { scope } => Ok(ProtobufPluginCommand {
+                name: CommandName::KvList as i32,
+                payload: Some(Payload::KvListPayload(kv_payload(
+                    scope,
+                    String::new(),
+                    vec![],
+                ))),
+            }),
         }
     }
 }
@@ -31,6 +31,7 @@ impl TryFrom<ProtobufPermissionType> for PermissionType {
             ProtobufPermissionType::ReadPaneContents => Ok(PermissionType::ReadPaneContents),
             ProtobufPermissionType::RunActionsAsUser => Ok(PermissionType::RunActionsAsUser),
             ProtobufPermissionType::WriteToClipboard => Ok(PermissionType::WriteToClipboard),
+            ProtobufPermissionType::ServeHttpRoutes => Ok(PermissionType::ServeHttpRoutes),
         }
     }
 }
@@ -63,6 +64,7 @@ impl TryFrom<PermissionType> for ProtobufPermissionType {
             PermissionType::ReadPaneContents => Ok(ProtobufPermissionType::ReadPaneContents),
             PermissionType::RunActionsAsUser => Ok(ProtobufPermissionType::RunActionsAsUser),
             PermissionType::WriteToClipboard => Ok(ProtobufPermissionType::WriteToClipboard),
+            PermissionType::ServeHttpRoutes => Ok(ProtobufPermissionType::ServeHttpRoutes),
         }
     }
 }
@@ -0,0 +1,273 @@
+//! Metadata that plugins built with zellij-tile embed in a custom section of their wasm binary, so
+//! that it can be checked (eg. for compatibility) and displayed without running the plugin
+
+use crate::data::PermissionType;
+use std::str::FromStr;
+
+/// The revision of the plugin API - the protobuf plugin commands and events and the functions the
+/// host and plugins export to each other. It is bumped whenever a change makes plugins built
+/// against the previous revision misbehave, adding new commands or events does not require it.
+pub const PLUGIN_API_VERSION: u32 = 1;
+
+/// The oldest revision of the plugin API whose plugins can still be loaded
+pub const MIN_SUPPORTED_PLUGIN_API_VERSION: u32 = 1;
+
+/// The name of the wasm custom section holding the metadata
+pub const PLUGIN_METADATA_SECTION: &str = "zellij_plugin_metadata";
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const CUSTOM_SECTION_ID: u8 = 0;
+
+/// The metadata section is made of `key value` lines, eg.
+/// ```text
+/// api_version 1
+/// zellij_tile_version 0.38.0
+/// permissions ReadApplicationState RunCommands
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginMetadata {
+    pub api_version: u32,
+    pub zellij_tile_version: Option<String>,
+    /// The permissions the plugin declared it will request, None if it did not declare them
+    pub permissions: Option<Vec<PermissionType>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginCompatibility {
+    Compatible,
+    /// Built against an older revision of the API that is no longer supported
+    TooOld,
+    /// Built against a newer revision of the API than this version knows about
+    TooNew,
+}
+
+impl PluginMetadata {
+    /// Returns None if the plugin has no metadata (eg. it was built with a zellij-tile version
+    /// that predates it)
+    pub fn from_wasm(wasm_bytes: &[u8]) -> Result<Option<Self>, String> {
+        match find_custom_section(wasm_bytes, PLUGIN_METADATA_SECTION)? {
+            Some(section) => {
+                let section = std::str::from_utf8(section)
+                    .map_err(|e| format!("Plugin metadata is not valid UTF-8: {}", e))?;
+                PluginMetadata::from_section(section).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+    pub fn from_section(section: &str) -> Result<Self, String> {
+        let mut api_version = None;
+        let mut zellij_tile_version = None;
+        let mut permissions = None;
+        for line in section.lines() {
+            let mut fields = line.split_whitespace();
+            match fields.next() {
+                Some("api_version") => {
+                    let version = fields.next().unwrap_or_default();
+                    api_version = Some(version.parse::<u32>().map_err(|_| {
+                        format!(
+                            "Invalid plugin API version in plugin metadata: \"{}\"",
+                            version
+                        )
+                    })?);
+                },
+                Some("zellij_tile_version") => {
+                    zellij_tile_version = fields.next().map(|v| v.to_owned());
+                },
+                Some("permissions") => {
+                    let mut declared_permissions = vec![];
+                    for permission in fields {
+                        declared_permissions.push(PermissionType::from_str(permission).map_err(
+                            |_| {
+                                format!("Unknown permission in plugin metadata: \"{}\"", permission)
+                            },
+                        )?);
+                    }
+                    permissions = Some(declared_permissions);
+                },
+                // unknown keys are left for newer versions to interpret
+                _ => {},
+            }
+        }
+        Ok(PluginMetadata {
+            api_version: api_version.ok_or("Plugin metadata has no API version")?,
+            zellij_tile_version,
+            permissions,
+        })
+    }
+    pub fn compatibility(&self) -> PluginCompatibility {
+        if self.api_version < MIN_SUPPORTED_PLUGIN_API_VERSION {
+            PluginCompatibility::TooOld
+        } else if self.api_version > PLUGIN_API_VERSION {
+            PluginCompatibility::TooNew
+        } else {
+            PluginCompatibility::Compatible
+        }
+    }
+    /// An explanation of why the plugin cannot be loaded, if it cannot
+    pub fn compatibility_error(&self) -> Option<String> {
+        let built_against = match &self.zellij_tile_version {
+            Some(zellij_tile_version) => format!(
+                "plugin API version {} (zellij-tile {})",
+                self.api_version, zellij_tile_version
+            ),
+            None => format!("plugin API version {}", self.api_version),
+        };
+        match self.compatibility() {
+            PluginCompatibility::Compatible => None,
+            PluginCompatibility::TooOld => Some(format!(
+                "This plugin was built against {}, which is no longer supported (the oldest supported version is {}). Please rebuild it against zellij-tile {}.",
+                built_against,
+                MIN_SUPPORTED_PLUGIN_API_VERSION,
+                crate::consts::VERSION
+            )),
+            PluginCompatibility::TooNew => Some(format!(
+                "This plugin was built against {}, which is newer than the plugin API version supported here ({}). Please upgrade, or use a build of the plugin made with zellij-tile {}.",
+                built_against,
+                PLUGIN_API_VERSION,
+                crate::consts::VERSION
+            )),
+        }
+    }
+}
+
+/// The contents of the first custom section with this name in the wasm binary
+fn find_custom_section<'a>(wasm_bytes: &'a [u8], name: &str) -> Result<Option<&'a [u8]>, String> {
+    if wasm_bytes.len() < 8 || &wasm_bytes[..4] != WASM_MAGIC {
+        return Err("Not a wasm binary".to_owned());
+    }
+    let mut position = 8; // magic and version
+    while position < wasm_bytes.len() {
+        let section_id = wasm_bytes[position];
+        position += 1;
+        let section_size = read_leb128_u32(wasm_bytes, &mut position)? as usize;
+        let section_end = position
+            .checked_add(section_size)
+            .filter(|end| *end <= wasm_bytes.len())
+            .ok_or("Truncated wasm section")?;
+        if section_id == CUSTOM_SECTION_ID {
+            let mut name_position = position;
+            let name_len = read_leb128_u32(wasm_bytes, &mut name_position)? as usize;
+            let name_end = name_position
+                .checked_add(name_len)
+                .filter(|end| *end <= section_end)
+                .ok_or("Truncated wasm custom section name")?;
+            if &wasm_bytes[name_position..name_end] == name.as_bytes() {
+                return Ok(Some(&wasm_bytes[name_end..section_end]));
+            }
+        }
+        position = section_end;
+    }
+    Ok(None)
+}
+
+fn read_leb128_u32(bytes: &[u8], position: &mut usize) -> Result<u32, String> {
+    let mut result: u32 = 0;
+    for shift in (0..35).step_by(7) {
+        let byte = *bytes.get(*position).ok_or("Truncated wasm binary")?;
+        *position += 1;
+        result |= ((byte & 0x7f) as u32)
+            .checked_shl(shift)
+            .ok_or("Malformed wasm binary")?;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err("Malformed wasm binary".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wasm_with_sections(sections: &[(u8, &[u8])]) -> Vec<u8> {
+        let mut wasm_bytes = b"\0asm\x01\0\0\0".to_vec();
+        for (section_id, contents) in sections {
+            wasm_bytes.push(*section_id);
+            wasm_bytes.push(contents.len() as u8); // single byte LEB128, enough for the tests
+            wasm_bytes.extend_from_slice(contents);
+        }
+        wasm_bytes
+    }
+
+    fn custom_section(name: &str, contents: &str) -> Vec<u8> {
+        let mut section = vec![name.len() as u8];
+        section.extend_from_slice(name.as_bytes());
+        section.extend_from_slice(contents.as_bytes());
+        section
+    }
+
+    #[test]
+    fn reads_metadata_from_custom_section() {
+        let metadata_section = custom_section(
+            PLUGIN_METADATA_SECTION,
+            "api_version 1\nzellij_tile_version 0.38.0\npermissions ReadApplicationState RunCommands\n",
+        );
+        let other_section = custom_section("producers", "rustc");
+        let wasm_bytes = wasm_with_sections(&[
+            (1, &[0x00]),
+            (CUSTOM_SECTION_ID, &other_section),
+            (CUSTOM_SECTION_ID, &metadata_section),
+        ]);
+        assert_eq!(
+            PluginMetadata::from_wasm(&wasm_bytes),
+            Ok(Some(PluginMetadata {
+                api_version: 1,
+                zellij_tile_version: Some("0.38.0".to_owned()),
+                permissions: Some(vec![
+                    PermissionType::ReadApplicationState,
+                    PermissionType::RunCommands
+                ]),
+            }))
+        );
+    }
+
+    #[test]
+    fn plugins_without_metadata() {
+        let other_section = custom_section("producers", "rustc");
+        let wasm_bytes = wasm_with_sections(&[(CUSTOM_SECTION_ID, &other_section)]);
+        assert_eq!(PluginMetadata::from_wasm(&wasm_bytes), Ok(None));
+    }
+
+    #[test]
+    fn undeclared_permissions_and_unknown_keys() {
+        let metadata =
+            PluginMetadata::from_section("api_version 1\nsome_future_key value\n").unwrap();
+        assert_eq!(metadata.permissions, None);
+        assert_eq!(metadata.zellij_tile_version, None);
+    }
+
+    #[test]
+    fn malformed_metadata() {
+        assert!(PluginMetadata::from_wasm(b"not wasm").is_err());
+        let truncated_wasm_bytes = &wasm_with_sections(&[(1, &[0x00, 0x01])])[..9];
+        assert!(PluginMetadata::from_wasm(truncated_wasm_bytes).is_err());
+        assert!(PluginMetadata::from_section("zellij_tile_version 0.38.0").is_err());
+        assert!(PluginMetadata::from_section("api_version one").is_err());
+        assert!(PluginMetadata::from_section("api_version 1\npermissions Teleport").is_err());
+    }
+
+    #[test]
+    fn compatibility() {
+        let metadata = |api_version| PluginMetadata {
+            api_version,
+            zellij_tile_version: None,
+            permissions: None,
+        };
+        assert_eq!(
+            metadata(PLUGIN_API_VERSION).compatibility(),
+            PluginCompatibility::Compatible
+        );
+        assert_eq!(metadata(PLUGIN_API_VERSION).compatibility_error(), None);
+        assert_eq!(
+            metadata(PLUGIN_API_VERSION + 1).compatibility(),
+            PluginCompatibility::TooNew
+        );
+        assert!(metadata(PLUGIN_API_VERSION + 1)
+            .compatibility_error()
+            .is_some());
+        assert_eq!(
+            metadata(MIN_SUPPORTED_PLUGIN_API_VERSION - 1).compatibility(),
+            PluginCompatibility::TooOld
+        );
+    }
+}
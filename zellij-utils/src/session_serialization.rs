@@ -3,6 +3,8 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 
 use crate::{
+    data::TilingMode,
+    input::command::RestartPolicy,
     input::layout::PluginUserConfiguration,
     input::layout::{
         FloatingPaneLayout, Layout, LayoutConstraint, PercentOrFixed, Run, RunPluginOrAlias,
@@ -25,6 +27,7 @@ pub struct TabLayoutManifest {
     pub floating_panes: Vec<PaneLayoutManifest>,
     pub is_focused: bool,
     pub hide_floating_panes: bool,
+    pub tiling_mode: TilingMode,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -36,6 +39,7 @@ pub struct PaneLayoutManifest {
     pub title: Option<String>,
     pub is_focused: bool,
     pub pane_contents: Option<String>,
+    pub mark: Option<String>,
 }
 
 pub fn serialize_session_layout(
@@ -84,6 +88,7 @@ fn serialize_tab(
     tab_name: String,
     is_focused: bool,
     hide_floating_panes: bool,
+    tiling_mode: TilingMode,
     tiled_panes: &Vec<PaneLayoutManifest>,
     floating_panes: &Vec<PaneLayoutManifest>,
     pane_contents: &mut BTreeMap<String, String>,
@@ -115,6 +120,11 @@ fn serialize_tab(
                     KdlValue::Bool(true),
                 ));
             }
+            if !tiling_mode.is_manual() {
+                serialized_tab
+                    .entries_mut()
+                    .push(KdlEntry::new_prop("tiling_mode", tiling_mode.to_string()));
+            }
 
             serialize_tiled_and_floating_panes(
                 &tiled_panes,
@@ -163,6 +173,7 @@ fn serialize_tiled_pane(
     pane_contents: &mut BTreeMap<String, String>,
 ) -> KdlNode {
     let (command, args) = extract_command_and_args(&layout.run);
+    let env = extract_command_env(&layout.run);
     let (plugin, plugin_config) = extract_plugin_and_config(&layout.run);
     let (edit, _line_number) = extract_edit_and_line_number(&layout.run);
     let cwd = layout.run.as_ref().and_then(|r| r.get_cwd());
@@ -173,6 +184,7 @@ fn serialize_tiled_pane(
         &command,
         &edit,
         &layout.name,
+        &layout.mark,
         cwd,
         layout.focus,
         &layout.pane_initial_contents,
@@ -185,11 +197,14 @@ fn serialize_tiled_pane(
     let has_child_attributes = !layout.children.is_empty()
         || layout.external_children_index.is_some()
         || !args.is_empty()
+        || !env.is_empty()
         || plugin.is_some()
         || command.is_some();
     if has_child_attributes {
         let mut tiled_pane_node_children = KdlDocument::new();
         serialize_args(args, &mut tiled_pane_node_children);
+        serialize_env(env, &mut tiled_pane_node_children);
+        serialize_restart(&layout.run, &mut tiled_pane_node_children);
         serialize_start_suspended(&command, &mut tiled_pane_node_children);
         serialize_plugin(plugin, plugin_config, &mut tiled_pane_node_children);
         if layout.children.is_empty() && layout.external_children_index.is_some() {
@@ -222,6 +237,12 @@ pub fn extract_command_and_args(layout_run: &Option<Run>) -> (Option<String>, Ve
         _ => (None, vec![]),
     }
 }
+fn extract_command_env(layout_run: &Option<Run>) -> BTreeMap<String, String> {
+    match layout_run {
+        Some(Run::Command(run_command)) => run_command.env.clone(),
+        _ => BTreeMap::new(),
+    }
+}
 pub fn extract_plugin_and_config(
     layout_run: &Option<Run>,
 ) -> (Option<String>, Option<PluginUserConfiguration>) {
@@ -264,6 +285,7 @@ fn serialize_pane_title_and_attributes(
     command: &Option<String>,
     edit: &Option<String>,
     name: &Option<String>,
+    mark: &Option<String>,
     cwd: Option<PathBuf>,
     focus: Option<bool>,
     initial_pane_contents: &Option<String>,
@@ -285,6 +307,11 @@ fn serialize_pane_title_and_attributes(
             .entries_mut()
             .push(KdlEntry::new_prop("name", name.to_owned()));
     }
+    if let Some(mark) = mark {
+        kdl_node
+            .entries_mut()
+            .push(KdlEntry::new_prop("mark", mark.to_owned()));
+    }
     if let Some(cwd) = cwd {
         let path = cwd.display().to_string();
         if !path.is_empty() && !has_children {
@@ -320,6 +347,20 @@ fn serialize_args(args: Vec<String>, pane_node_children: &mut KdlDocument) {
     }
 }
 
+fn serialize_env(env: BTreeMap<String, String>, pane_node_children: &mut KdlDocument) {
+    if !env.is_empty() {
+        let mut env_node = KdlNode::new("env");
+        let mut env_vars = KdlDocument::new();
+        for (env_key, env_value) in env {
+            let mut variable_node = KdlNode::new(env_key);
+            variable_node.push(env_value);
+            env_vars.nodes_mut().push(variable_node);
+        }
+        env_node.set_children(env_vars);
+        pane_node_children.nodes_mut().push(env_node);
+    }
+}
+
 fn serialize_plugin(
     plugin: Option<String>,
     plugin_config: Option<PluginUserConfiguration>,
@@ -463,6 +504,25 @@ fn serialize_floating_layout_attributes(
     }
 }
 
+fn serialize_restart(layout_run: &Option<Run>, pane_node_children: &mut KdlDocument) {
+    if let Some(Run::Command(run_command)) = layout_run {
+        if run_command.restart != RestartPolicy::Never {
+            let mut restart_node = KdlNode::new("restart");
+            restart_node
+                .entries_mut()
+                .push(KdlEntry::new(run_command.restart.to_string()));
+            pane_node_children.nodes_mut().push(restart_node);
+            if let Some(max_restarts) = run_command.max_restarts {
+                let mut max_restarts_node = KdlNode::new("max_restarts");
+                max_restarts_node
+                    .entries_mut()
+                    .push(KdlEntry::new(max_restarts as i64));
+                pane_node_children.nodes_mut().push(max_restarts_node);
+            }
+        }
+    }
+}
+
 fn serialize_start_suspended(command: &Option<String>, pane_node_children: &mut KdlDocument) {
     if command.is_some() {
         let mut start_suspended_node = KdlNode::new("start_suspended");
@@ -624,6 +684,7 @@ fn serialize_multiple_tabs(
             tab_name.clone(),
             tab_layout_manifest.is_focused,
             hide_floating_panes,
+            tab_layout_manifest.tiling_mode,
             &tiled_panes,
             &floating_panes,
             pane_contents,
@@ -644,6 +705,7 @@ fn serialize_floating_pane(
     let mut floating_pane_node = KdlNode::new("pane");
     let mut floating_pane_node_children = KdlDocument::new();
     let (command, args) = extract_command_and_args(&layout.run);
+    let env = extract_command_env(&layout.run);
     let (plugin, plugin_config) = extract_plugin_and_config(&layout.run);
     let (edit, _line_number) = extract_edit_and_line_number(&layout.run);
     let cwd = layout.run.as_ref().and_then(|r| r.get_cwd());
@@ -652,6 +714,7 @@ fn serialize_floating_pane(
         &command,
         &edit,
         &layout.name,
+        &layout.mark,
         cwd,
         layout.focus,
         &layout.pane_initial_contents,
@@ -662,6 +725,8 @@ fn serialize_floating_pane(
     serialize_start_suspended(&command, &mut floating_pane_node_children);
     serialize_floating_layout_attributes(&layout, &mut floating_pane_node_children);
     serialize_args(args, &mut floating_pane_node_children);
+    serialize_env(env, &mut floating_pane_node_children);
+    serialize_restart(&layout.run, &mut floating_pane_node_children);
     serialize_plugin(plugin, plugin_config, &mut floating_pane_node_children);
     floating_pane_node.set_children(floating_pane_node_children);
     floating_pane_node
@@ -709,26 +774,28 @@ fn tiled_pane_layout_from_manifest(
     manifest: Option<&PaneLayoutManifest>,
     split_size: Option<SplitSize>,
 ) -> TiledPaneLayout {
-    let (run, borderless, is_expanded_in_stack, name, focus, pane_initial_contents) = manifest
-        .map(|g| {
-            let mut run = g.run.clone();
-            if let Some(cwd) = &g.cwd {
-                if let Some(run) = run.as_mut() {
-                    run.add_cwd(cwd);
-                } else {
-                    run = Some(Run::Cwd(cwd.clone()));
+    let (run, borderless, is_expanded_in_stack, name, focus, pane_initial_contents, mark) =
+        manifest
+            .map(|g| {
+                let mut run = g.run.clone();
+                if let Some(cwd) = &g.cwd {
+                    if let Some(run) = run.as_mut() {
+                        run.add_cwd(cwd);
+                    } else {
+                        run = Some(Run::Cwd(cwd.clone()));
+                    }
                 }
-            }
-            (
-                run,
-                Some(g.is_borderless),
-                g.geom.is_stacked() && g.geom.rows.inner > 1,
-                g.title.clone(),
-                Some(g.is_focused),
-                g.pane_contents.clone(),
-            )
-        })
-        .unwrap_or((None, None, false, None, None, None));
+                (
+                    run,
+                    Some(g.is_borderless),
+                    g.geom.is_stacked() && g.geom.rows.inner > 1,
+                    g.title.clone(),
+                    Some(g.is_focused),
+                    g.pane_contents.clone(),
+                    g.mark.clone(),
+                )
+            })
+            .unwrap_or((None, None, false, None, None, None, None));
     TiledPaneLayout {
         split_size,
         run,
@@ -737,6 +804,7 @@ fn tiled_pane_layout_from_manifest(
         name,
         focus,
         pane_initial_contents,
+        mark,
         ..Default::default()
     }
 }
@@ -845,6 +913,9 @@ fn get_floating_panes_layout_from_panegeoms(
                 pane_initial_contents: m.pane_contents.clone(),
                 logical_position: None,
                 borderless: Some(m.is_borderless),
+                log_to: None,
+                depends_on: vec![],
+                mark: m.mark.clone(),
             }
         })
         .collect()
@@ -1356,6 +1427,159 @@ mod tests {
         assert_snapshot!(kdl.0);
     }
     #[test]
+    fn can_serialize_tab_tiling_mode() {
+        let tab_layout_manifest = TabLayoutManifest {
+            tiled_panes: vec![
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][0]),
+                    ..Default::default()
+                },
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][1]),
+                    ..Default::default()
+                },
+            ],
+            tiling_mode: TilingMode::MasterStack,
+            ..Default::default()
+        };
+        let global_layout_manifest = GlobalLayoutManifest {
+            tabs: vec![("Tab #1".to_owned(), tab_layout_manifest)],
+            ..Default::default()
+        };
+        let kdl = serialize_session_layout(global_layout_manifest).unwrap();
+        assert_snapshot!(kdl.0);
+        let layout = Layout::from_kdl(&kdl.0, None, None, None).unwrap();
+        let (_tab_name, tiled_layout, _floating_layouts) = &layout.tabs[0];
+        assert_eq!(tiled_layout.tiling_mode, Some(TilingMode::MasterStack));
+    }
+    #[test]
+    fn can_serialize_command_pane_env() {
+        use crate::input::command::RunCommand;
+        let env = BTreeMap::from([
+            ("PERTH_PANE_ROLE".to_owned(), "reviewer".to_owned()),
+            ("AGENT_ID".to_owned(), "agent \"7\"".to_owned()),
+        ]);
+        let run = Some(Run::Command(
+            RunCommand::new(PathBuf::from("agent")).with_env(env.clone()),
+        ));
+        let tab_layout_manifest = TabLayoutManifest {
+            tiled_panes: vec![
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][0]),
+                    run: run.clone(),
+                    ..Default::default()
+                },
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][1]),
+                    ..Default::default()
+                },
+            ],
+            floating_panes: vec![PaneLayoutManifest {
+                run,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let global_layout_manifest = GlobalLayoutManifest {
+            tabs: vec![("Tab #1".to_owned(), tab_layout_manifest)],
+            ..Default::default()
+        };
+        let kdl = serialize_session_layout(global_layout_manifest).unwrap();
+        assert_snapshot!(kdl.0);
+        let layout = Layout::from_kdl(&kdl.0, None, None, None).unwrap();
+        let (_tab_name, tiled_layout, floating_layouts) = &layout.tabs[0];
+        let tiled_run = &tiled_layout.children[1].run;
+        let floating_run = &floating_layouts[0].run;
+        for run in [tiled_run, floating_run] {
+            match run {
+                Some(Run::Command(run_command)) => assert_eq!(run_command.env, env),
+                other => panic!("expected a command pane, got: {:?}", other),
+            }
+        }
+    }
+    #[test]
+    fn can_serialize_command_pane_restart_policy() {
+        use crate::input::command::{RestartPolicy, RunCommand};
+        let mut run_command = RunCommand::new(PathBuf::from("dev-server"));
+        run_command.restart = RestartPolicy::OnFailure;
+        run_command.max_restarts = Some(5);
+        let run = Some(Run::Command(run_command));
+        let tab_layout_manifest = TabLayoutManifest {
+            tiled_panes: vec![
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][0]),
+                    run: run.clone(),
+                    ..Default::default()
+                },
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][1]),
+                    ..Default::default()
+                },
+            ],
+            floating_panes: vec![PaneLayoutManifest {
+                run,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let global_layout_manifest = GlobalLayoutManifest {
+            tabs: vec![("Tab #1".to_owned(), tab_layout_manifest)],
+            ..Default::default()
+        };
+        let kdl = serialize_session_layout(global_layout_manifest).unwrap();
+        assert_snapshot!(kdl.0);
+        let layout = Layout::from_kdl(&kdl.0, None, None, None).unwrap();
+        let (_tab_name, tiled_layout, floating_layouts) = &layout.tabs[0];
+        let tiled_run = &tiled_layout.children[1].run;
+        let floating_run = &floating_layouts[0].run;
+        for run in [tiled_run, floating_run] {
+            match run {
+                Some(Run::Command(run_command)) => {
+                    assert_eq!(run_command.restart, RestartPolicy::OnFailure);
+                    assert_eq!(run_command.max_restarts, Some(5));
+                },
+                other => panic!("expected a command pane, got: {:?}", other),
+            }
+        }
+    }
+    #[test]
+    fn can_serialize_pane_marks() {
+        let tab_layout_manifest = TabLayoutManifest {
+            tiled_panes: vec![
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][0]),
+                    mark: Some("api".to_owned()),
+                    ..Default::default()
+                },
+                PaneLayoutManifest {
+                    geom: parse_panegeom_from_json(PANEGEOMS_JSON[0][1]),
+                    ..Default::default()
+                },
+            ],
+            floating_panes: vec![PaneLayoutManifest {
+                mark: Some("logs".to_owned()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let global_layout_manifest = GlobalLayoutManifest {
+            tabs: vec![("Tab #1".to_owned(), tab_layout_manifest)],
+            ..Default::default()
+        };
+        let kdl = serialize_session_layout(global_layout_manifest).unwrap();
+        assert_snapshot!(kdl.0);
+        let layout = Layout::from_kdl(&kdl.0, None, None, None).unwrap();
+        let (_tab_name, tiled_layout, floating_layouts) = &layout.tabs[0];
+        let tiled_marks: Vec<Option<String>> = tiled_layout
+            .children
+            .iter()
+            .map(|c| c.mark.clone())
+            .collect();
+        assert!(tiled_marks.contains(&Some("api".to_owned())));
+        assert!(tiled_marks.contains(&None));
+        assert_eq!(floating_layouts[0].mark, Some("logs".to_owned()));
+    }
+    #[test]
     fn can_serialize_tab_with_tiled_panes() {
         use crate::input::command::RunCommand;
         use crate::input::layout::RunPlugin;
@@ -702,7 +702,12 @@ impl Setup {
         } else {
             // we merge-override the config here because the layout might contain configuration
             // that needs to take precedence
-            Layout::from_path_or_default(chosen_layout.as_ref(), layout_dir.clone(), config)
+            Layout::from_path_or_default(
+                chosen_layout.as_ref(),
+                layout_dir.clone(),
+                config,
+                cli_args.params.iter().cloned().collect(),
+            )
         }
     }
     fn handle_setup_commands(cli_args: &CliArgs) {
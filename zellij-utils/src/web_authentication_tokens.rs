@@ -230,6 +230,27 @@ pub fn is_session_token_read_only(session_token: &str) -> Result<bool> {
     Ok(read_only != 0)
 }
 
+pub fn session_token_name(session_token: &str) -> Result<String> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(db_path)?;
+    init_db(&conn)?;
+
+    let session_token_hash = hash_token(session_token);
+
+    // Join session_tokens to tokens table to get the name of the token used to log in
+    match conn.query_row(
+        "SELECT t.name FROM tokens t
+         JOIN session_tokens st ON st.auth_token_hash = t.token_hash
+         WHERE st.session_token_hash = ?1 AND st.expires_at > datetime('now')",
+        [&session_token_hash],
+        |row| row.get(0),
+    ) {
+        Ok(name) => Ok(name),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Err(TokenError::InvalidToken),
+        Err(e) => Err(TokenError::Database(e)),
+    }
+}
+
 pub fn cleanup_expired_sessions() -> Result<usize> {
     let db_path = get_db_path()?;
     let conn = Connection::open(db_path)?;